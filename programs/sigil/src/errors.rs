@@ -258,7 +258,7 @@ pub enum SigilError {
     SysvarScanBoundExceeded,
 
     // --- C4 audit fix: async-fulfillment programs ---
-    #[msg("Async-fulfillment program is not permitted in V1 (Jupiter Perps, Drift, Drift JIT). Spending cannot be measured because keeper submits the actual transfer in a separate transaction after finalize_session returns.")]
+    #[msg("Async-fulfillment program is not permitted (Jupiter Perps, Drift, Drift JIT) unless the policy opts in, and then only for a single-leg stablecoin spending session. The keeper submits the actual transfer in a separate transaction after finalize_session returns.")]
     AsyncFulfillmentNotPermitted,
 
     // --- Orphan constraints PDA cleanup (F3-H1 audit fix) ---
@@ -294,4 +294,219 @@ pub enum SigilError {
     // Added at the END of the enum so existing error codes are not renumbered.
     #[msg("Invalid destination mode (must be 0 = Restricted or 1 = OpenWithCap)")]
     InvalidDestinationMode,
+
+    // --- Fast-path policy tightening ---
+    #[msg("tighten_policy only accepts changes that strictly lower risk; use queue_policy_update for anything else")]
+    PolicyChangeNotTightening,
+
+    // --- Multi-horizon spending caps ---
+    #[msg("Rolling 7-day spending cap would be exceeded")]
+    WeeklySpendingCapExceeded,
+
+    #[msg("Rolling 30-day spending cap would be exceeded")]
+    MonthlySpendingCapExceeded,
+
+    #[msg("SpendTrackerExtension account is required once the vault has one or sets a weekly, monthly, per-mint or fee cap")]
+    TrackerExtensionRequired,
+
+    // --- Per-mint spending caps ---
+    #[msg("Rolling 24h spending cap for this stablecoin mint would be exceeded")]
+    MintSpendingCapExceeded,
+
+    #[msg("Invalid per-mint caps: too many entries, duplicate mint, or non-stablecoin mint")]
+    InvalidMintCaps,
+
+    // --- Per-destination caps ---
+    #[msg("Rolling 24h cap for this transfer destination would be exceeded")]
+    DestinationSpendCapExceeded,
+
+    #[msg("Destination caps must be empty or match allowed_destinations length")]
+    DestinationCapsMismatch,
+
+    #[msg("DestinationSpendTracker account is required when the destination has a cap")]
+    DestinationTrackerRequired,
+
+    // --- Per-agent session rate limit ---
+    #[msg("Agent has reached its max_sessions_per_hour limit")]
+    AgentSessionRateExceeded,
+
+    // --- Trading-hours schedule ---
+    #[msg("Current UTC hour is outside the policy's trading-hours schedule")]
+    OutsideTradingWindow,
+
+    // --- Per-agent settings ---
+    #[msg("AgentSettings account is required for this agent")]
+    AgentSettingsRequired,
+
+    #[msg("Protocol not in this agent's protocol subset")]
+    AgentProtocolNotAllowed,
+
+    #[msg("Per-agent override must be at or below the vault policy value")]
+    AgentOverrideNotTighter,
+
+    // --- Agent key expiry ---
+    #[msg("Agent key has passed its valid_until timestamp")]
+    AgentKeyExpired,
+
+    #[msg("Agent key expiry must be 0 (never) or a future timestamp before 2106")]
+    InvalidAgentExpiry,
+
+    // --- Guardian ---
+    #[msg("Only the vault owner or guardian can perform this action")]
+    UnauthorizedOwnerOrGuardian,
+
+    #[msg("Guardian must differ from the owner and every registered agent")]
+    InvalidGuardian,
+
+    // --- Ownership transfer ---
+    #[msg("New owner must be a fresh key: not the current owner, guardian or an agent")]
+    InvalidNewOwner,
+
+    // --- Recovery ---
+    #[msg("Recovery authority and destination must be set together; the authority must differ from the owner and every agent")]
+    InvalidRecoveryConfig,
+
+    #[msg("Signer is not the vault's recovery authority")]
+    UnauthorizedRecoveryAuthority,
+
+    // --- Dead-man switch ---
+    #[msg("Inactivity window and beneficiary must be set together: 0 and no beneficiary, or at least 7 days with a non-agent beneficiary")]
+    InvalidDeadManSwitchConfig,
+
+    #[msg("Dead-man switch is disabled or the owner has been active within the window")]
+    OwnerNotInactive,
+
+    #[msg("Dead-man switch has fired; funds can only go to the beneficiary")]
+    DeadManSwitchTriggered,
+
+    #[msg("Dead-man switch has not fired")]
+    DeadManSwitchNotTriggered,
+
+    #[msg("Signer is not the vault's beneficiary")]
+    UnauthorizedBeneficiary,
+
+    // --- Owner permits ---
+    #[msg(
+        "No valid owner-signed permit for this vault, agent and protocol precedes this instruction"
+    )]
+    InvalidPermit,
+
+    #[msg("Owner permit has expired")]
+    PermitExpired,
+
+    #[msg("Permit nonce already used or below the nonce window")]
+    PermitNonceUsed,
+
+    #[msg("Transaction exceeds the owner permit's amount ceiling")]
+    PermitCeilingExceeded,
+
+    // --- Spend requests ---
+    #[msg("Spend request expiry must be in the future and within 7 days")]
+    InvalidSpendRequestExpiry,
+
+    #[msg("Spend request is not pending")]
+    SpendRequestNotPending,
+
+    #[msg("Spend request has not been approved")]
+    SpendRequestNotApproved,
+
+    #[msg("Spend request has expired")]
+    SpendRequestExpired,
+
+    #[msg("Transfer does not match the approved spend request")]
+    SpendRequestMismatch,
+
+    #[msg("Only the requesting agent, owner or guardian can cancel a spend request")]
+    UnauthorizedSpendRequestAction,
+
+    // --- Governance bundles ---
+    #[msg("Governance bundle must reference at least two distinct pending updates")]
+    InvalidGovernanceBundle,
+
+    #[msg("Pending update does not match the one recorded in the governance bundle")]
+    GovernanceBundleMismatch,
+
+    // --- Approver set ---
+    #[msg("Approver set must be unique non-agent keys (max 5) with 1 <= threshold <= set size")]
+    InvalidApproverConfig,
+
+    #[msg("Not enough approvers co-signed this instruction")]
+    InsufficientApprovals,
+
+    // --- Owner CPI callers ---
+    #[msg("CPI caller allowlist must hold unique programs (max 4), excluding Sigil and allowed protocols")]
+    InvalidCpiCallers,
+
+    #[msg("Top-level program is not on the vault's CPI caller allowlist")]
+    CpiCallerNotAllowed,
+
+    // --- Multi-leg sessions ---
+    #[msg("Session legs must be 2-4 allowed protocols, starting with target_protocol, for a spending session")]
+    InvalidSessionLegs,
+
+    #[msg("DeFi instructions do not match the declared session legs")]
+    SessionLegMismatch,
+
+    // --- Concurrent sessions ---
+    #[msg("Session nonce exceeds the agent's concurrent session limit")]
+    TooManyConcurrentSessions,
+
+    #[msg("max_concurrent_sessions exceeds MAX_CONCURRENT_SESSIONS")]
+    InvalidConcurrentSessionLimit,
+
+    // --- Fee budget ---
+    #[msg("Abandoned-session fees would exceed the daily fee budget")]
+    FeeCapExceeded,
+
+    // --- Async fulfillment ---
+    #[msg("Pending fulfillment account missing, unexpected or mismatched for this session")]
+    InvalidPendingFulfillment,
+
+    #[msg("Fulfillment window has not elapsed; reservation cannot be reconciled yet")]
+    FulfillmentWindowOpen,
+
+    #[msg("Vault has unreconciled async-fulfillment reservations")]
+    OpenFulfillmentsExist,
+
+    // --- Minimum output (advisory) ---
+    #[msg("Policy requires an expected output for non-Jupiter spending")]
+    ExpectedOutputRequired,
+
+    #[msg(
+        "Expected output mint, account or minimum is invalid or exceeds max_protocol_slippage_bps"
+    )]
+    InvalidExpectedOutput,
+
+    #[msg("Session output is below the declared minimum")]
+    MinimumOutputNotMet,
+
+    // --- Tighten policy ---
+    #[msg("Policy was tightened after this update was queued; cancel and re-queue it")]
+    PendingPolicySuperseded,
+
+    // --- Spend request approval ---
+    #[msg("Spend request contents differ from those the approver reviewed")]
+    SpendRequestChanged,
+
+    // --- Governance bundles (cont.) ---
+    #[msg(
+        "A governance bundle is open; apply or cancel it before applying its updates individually"
+    )]
+    GovernanceBundleOpen,
+
+    // --- Multi-leg sessions (cont.) ---
+    #[msg("Multi-leg sessions cannot include a protocol with a per-protocol cap")]
+    CappedProtocolInMultiLegSession,
+
+    // --- Agent key rotation ---
+    #[msg("Agent has open sessions; finalize them before rotating its key")]
+    AgentSessionsOpen,
+
+    // --- Ownership transfer (cont.) ---
+    #[msg("Update was queued before the vault changed owner; cancel and re-queue it")]
+    QueuedBeforeOwnerTransfer,
+
+    // --- Vault migration ---
+    #[msg("Vault, policy or spend overlay is not in the pre-migration layout")]
+    NotLegacyVault,
 }
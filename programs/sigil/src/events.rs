@@ -22,6 +22,8 @@ pub struct AgentRegistered {
     pub agent: Pubkey,
     pub capability: u8,
     pub spending_limit_usd: u64,
+    pub max_sessions_per_hour: u16,
+    pub valid_until: i64,
     pub timestamp: i64,
 }
 
@@ -82,6 +84,15 @@ pub struct AgentRevoked {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AgentKeyRotated {
+    pub vault: Pubkey,
+    pub old_agent: Pubkey,
+    pub new_agent: Pubkey,
+    pub old_agent_cosigned: bool,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct VaultReactivated {
     pub vault: Pubkey,
@@ -121,6 +132,16 @@ pub struct VaultClosed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VaultMigrated {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub agents: u8,
+    /// Pending updates queued under the old layout that were closed
+    pub pendings_closed: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct PolicyChangeQueued {
     pub vault: Pubkey,
@@ -138,12 +159,26 @@ pub struct PolicyChangeCancelled {
     pub vault: Pubkey,
 }
 
+/// Emitted by the tighten_policy fast path (no timelock). Distinct from
+/// PolicyChangeApplied so monitors can tell immediate changes apart.
+#[event]
+pub struct PolicyTightened {
+    pub vault: Pubkey,
+    pub policy_version: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AgentTransferExecuted {
     pub vault: Pubkey,
     pub destination: Pubkey,
     pub amount: u64,
     pub mint: Pubkey,
+    /// Per-destination rolling 24h cap in USD (0 = no per-destination cap)
+    pub destination_cap_usd: u64,
+    /// Destination's rolling 24h spend in USD after this transfer
+    /// (0 when the destination tracker was not passed)
+    pub destination_rolling_spend_usd: u64,
 }
 
 // AgentPermissionsUpdated event removed — replaced by AgentPermissionsChangeApplied (queue/apply path).
@@ -235,11 +270,16 @@ pub struct EscrowRefunded {
 pub struct VaultFrozen {
     pub vault: Pubkey,
     pub owner: Pubkey,
+    /// Signer that froze the vault: the owner or the guardian.
+    pub frozen_by: Pubkey,
     pub agents_preserved: u8,
     /// Number of active session SPL delegations revoked during freeze (F2-H1 fix).
     /// Caller passes (session_pda, vault_token_account) pairs in remaining_accounts;
     /// each pair whose session_pda matches the expected derivation is revoked.
     pub sessions_revoked: u32,
+    /// FREEZE_REASON_* constant: manual kill switch, recovery queued, or
+    /// dead-man switch.
+    pub reason: u8,
     pub timestamp: i64,
 }
 
@@ -279,6 +319,178 @@ pub struct AgentPermissionsChangeCancelled {
     pub agent: Pubkey,
 }
 
+#[event]
+pub struct OwnerTransferQueued {
+    pub vault: Pubkey,
+    pub current_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub executes_at: i64,
+}
+
+#[event]
+pub struct OwnerTransferApplied {
+    pub vault: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub applied_at: i64,
+}
+
+#[event]
+pub struct OwnerTransferCancelled {
+    pub vault: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct RecoveryQueued {
+    pub vault: Pubkey,
+    pub recovery_authority: Pubkey,
+    pub destination: Pubkey,
+    pub executes_at: i64,
+}
+
+#[event]
+pub struct RecoverySwept {
+    pub vault: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub vault: Pubkey,
+    pub recovery_authority: Pubkey,
+    pub authority_revoked: bool,
+}
+
+#[event]
+pub struct FeeDestinationChangeQueued {
+    pub vault: Pubkey,
+    pub current_fee_destination: Pubkey,
+    pub new_fee_destination: Pubkey,
+    pub executes_at: i64,
+}
+
+#[event]
+pub struct FeeDestinationChangeApplied {
+    pub vault: Pubkey,
+    pub previous_fee_destination: Pubkey,
+    pub new_fee_destination: Pubkey,
+    pub applied_at: i64,
+}
+
+#[event]
+pub struct FeeDestinationChangeCancelled {
+    pub vault: Pubkey,
+    pub current_fee_destination: Pubkey,
+    pub cancelled_fee_destination: Pubkey,
+}
+
+#[event]
+pub struct SpendRequestCreated {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub request_id: u64,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SpendRequestApproved {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub request_id: u64,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SpendRequestExecuted {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SpendRequestCancelled {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub request_id: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct BeneficiaryFundsClaimed {
+    pub vault: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub beneficiary: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceBundleCreated {
+    pub vault: Pubkey,
+    pub component_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceBundleApplied {
+    pub vault: Pubkey,
+    pub includes_policy: bool,
+    pub includes_constraints: bool,
+    pub includes_close_constraints: bool,
+    pub agents: Vec<Pubkey>,
+    pub policy_version: u64,
+    pub applied_at: i64,
+}
+
+#[event]
+pub struct GovernanceBundleCancelled {
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct AgentAutoPaused {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    /// Abandoned sessions counted in the last hour, including this one
+    pub abandoned_sessions: u32,
+    /// Paused because abandoned-session fees went over `daily_fee_cap_usd`
+    pub fee_cap_exceeded: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FulfillmentReserved {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub session: Pubkey,
+    pub protocol: Pubkey,
+    pub reserved_usd: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct FulfillmentReconciled {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    pub session: Pubkey,
+    pub reserved_usd: u64,
+    pub used_usd: u64,
+    pub released_usd: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct CloseConstraintsQueued {
     pub vault: Pubkey,
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::get_stack_height;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::SigilError;
-use crate::events::{AgentSpendLimitChecked, AgentTransferExecuted, FeesCollected};
+use crate::events::{
+    AgentSpendLimitChecked, AgentTransferExecuted, FeesCollected, SpendRequestExecuted,
+};
 use crate::state::*;
 
-use super::utils::stablecoin_to_usd;
+use super::utils::{
+    enforce_extension_caps, resolve_agent_settings, stablecoin_to_usd, verify_owner_permit,
+};
 
 #[derive(Accounts)]
 pub struct AgentTransfer<'info> {
@@ -16,7 +21,7 @@ pub struct AgentTransfer<'info> {
     #[account(
         mut,
         constraint = vault.is_agent(&agent.key()) @ SigilError::UnauthorizedAgent,
-        seeds = [b"vault", vault.owner.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -44,6 +49,64 @@ pub struct AgentTransfer<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy SpendTrackerExtension — weekly/monthly windows.
+    /// Required when the vault has one (`AgentVault::has_tracker_extension`).
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
+    /// Zero-copy DestinationSpendTracker — per-destination rolling spend.
+    /// Required when the vault has one (`AgentVault::has_destination_tracker`).
+    #[account(
+        mut,
+        seeds = [b"destination_spend", vault.key().as_ref()],
+        bump = destination_spend.load()?.bump,
+    )]
+    pub destination_spend: Option<AccountLoader<'info, DestinationSpendTracker>>,
+
+    /// Per-agent settings. Required when `AgentEntry::has_settings` is set.
+    #[account(
+        seeds = [b"agent_settings", vault.key().as_ref(), agent.key().as_ref()],
+        bump = agent_settings.bump,
+    )]
+    pub agent_settings: Option<Account<'info, AgentSettings>>,
+
+    /// Permit nonce bitmap. Required, with `instructions_sysvar`, when the
+    /// amount exceeds the per-transaction limit and an owner permit is used.
+    #[account(
+        mut,
+        seeds = [b"permit_nonces", vault.key().as_ref()],
+        bump = permit_nonces.bump,
+    )]
+    pub permit_nonces: Option<Account<'info, PermitNonces>>,
+
+    /// Approved spend request authorizing this over-limit transfer.
+    /// Consumed (closed) by this instruction.
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = agent @ SigilError::SpendRequestMismatch,
+        seeds = [
+            b"spend_request",
+            vault.key().as_ref(),
+            agent.key().as_ref(),
+            spend_request.request_id.to_le_bytes().as_ref(),
+        ],
+        bump = spend_request.bump,
+        close = agent,
+    )]
+    pub spend_request: Option<Account<'info, SpendRequest>>,
+
+    /// Instructions sysvar, for locating the owner permit.
+    /// CHECK: address constrained to sysvar::instructions::ID
+    #[account(
+        address = anchor_lang::solana_program::sysvar::instructions::ID
+    )]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
     /// Vault's PDA-owned token account (source)
     #[account(
         mut,
@@ -105,6 +168,10 @@ pub fn handler(
         !vault.is_agent_paused(&ctx.accounts.agent.key()),
         SigilError::AgentPaused
     );
+    require!(
+        !vault.is_agent_expired(&ctx.accounts.agent.key(), clock.unix_timestamp),
+        SigilError::AgentKeyExpired
+    );
 
     // 1a. Agent must have capability (single lookup replaces has_permission + get_agent)
     let agent_key = ctx.accounts.agent.key();
@@ -119,6 +186,12 @@ pub fn handler(
     // 2. Amount must be positive
     require!(amount > 0, SigilError::TransactionTooLarge);
 
+    // 2b. Trading-hours schedule
+    require!(
+        policy.is_within_trading_hours(clock.unix_timestamp),
+        SigilError::OutsideTradingWindow
+    );
+
     let token_mint = ctx.accounts.vault_token_account.mint;
 
     // 3. Token must be a stablecoin (stablecoin-only enforcement)
@@ -145,11 +218,62 @@ pub fn handler(
     // 7. Convert stablecoin to USD (1:1)
     let usd_amount = stablecoin_to_usd(amount, token_decimals)?;
 
-    // 8. Single tx USD check
-    require!(
-        usd_amount <= policy.max_transaction_size_usd,
-        SigilError::TransactionTooLarge
-    );
+    // 8. Single tx USD check (tightened by the agent's override). Above the
+    // limit, an approved spend request or an owner permit naming the
+    // destination wallet may lift it.
+    let agent_settings =
+        resolve_agent_settings(vault, &agent_key, ctx.accounts.agent_settings.as_ref())?;
+    let max_transaction_size_usd = agent_settings.map_or(policy.max_transaction_size_usd, |s| {
+        s.effective_max_transaction_size_usd(policy.max_transaction_size_usd)
+    });
+    if let Some(request) = ctx.accounts.spend_request.as_ref() {
+        require!(
+            request.status == SpendRequestStatus::Approved,
+            SigilError::SpendRequestNotApproved
+        );
+        require!(
+            !request.is_expired(clock.unix_timestamp),
+            SigilError::SpendRequestExpired
+        );
+        require!(
+            request.token_mint == token_mint
+                && request.destination == ctx.accounts.destination_token_account.owner
+                && amount <= request.amount,
+            SigilError::SpendRequestMismatch
+        );
+        emit!(SpendRequestExecuted {
+            vault: vault.key(),
+            agent: agent_key,
+            request_id: request.request_id,
+            amount,
+            destination: request.destination,
+            timestamp: clock.unix_timestamp,
+        });
+    } else if usd_amount > max_transaction_size_usd {
+        let (Some(nonces), Some(ix_sysvar)) = (
+            ctx.accounts.permit_nonces.as_mut(),
+            ctx.accounts.instructions_sysvar.as_ref(),
+        ) else {
+            return Err(error!(SigilError::TransactionTooLarge));
+        };
+        let ix_sysvar = ix_sysvar.to_account_info();
+        let current_idx = load_current_index_checked(&ix_sysvar)
+            .map_err(|_| error!(SigilError::InvalidPermit))?;
+        let permit = verify_owner_permit(
+            &ix_sysvar,
+            current_idx as usize,
+            &vault.key(),
+            &vault.owner,
+            &agent_key,
+            &ctx.accounts.destination_token_account.owner,
+            clock.unix_timestamp,
+        )?;
+        require!(
+            usd_amount <= permit.amount_ceiling_usd,
+            SigilError::PermitCeilingExceeded
+        );
+        nonces.consume(permit.nonce)?;
+    }
 
     // 9. Rolling 24h USD check
     let mut tracker = ctx.accounts.tracker.load_mut()?;
@@ -162,9 +286,41 @@ pub fn handler(
         SigilError::SpendingCapExceeded
     );
 
-    // --- Per-agent cap check via contribution overlay ---
+    // 9b. Rolling weekly/monthly + per-mint USD checks
+    enforce_extension_caps(
+        ctx.accounts.tracker_extension.as_ref(),
+        vault.has_tracker_extension,
+        policy,
+        &clock,
+        &ctx.accounts.vault_token_account.mint,
+        usd_amount,
+    )?;
+
+    // 9c. Per-destination rolling 24h check. Recorded for uncapped
+    // destinations too, so a cap added later sees what already went out.
+    let destination_owner = ctx.accounts.destination_token_account.owner;
+    let destination_cap_usd = policy.get_destination_cap(&destination_owner);
+    let destination_rolling_spend_usd = match ctx.accounts.destination_spend.as_ref() {
+        Some(loader) => loader.load_mut()?.check_and_record(
+            &clock,
+            &destination_owner,
+            destination_cap_usd,
+            usd_amount,
+            |d| policy.get_destination_cap(d) > 0,
+        )?,
+        None => {
+            require!(
+                !vault.has_destination_tracker && destination_cap_usd == 0,
+                SigilError::DestinationTrackerRequired
+            );
+            0
+        }
+    };
+
+    // --- Per-agent cap and session rate checks via contribution overlay ---
     let mut overlay = ctx.accounts.agent_spend_overlay.load_mut()?;
     if let Some(agent_slot) = overlay.find_agent_slot(&agent_key) {
+        overlay.check_and_record_session(&clock, agent_slot, agent_entry.max_sessions_per_hour)?;
         if agent_entry.spending_limit_usd > 0 {
             let agent_rolling = overlay.get_agent_rolling_24h_usd(&clock, agent_slot);
             let new_agent_spend = agent_rolling
@@ -184,7 +340,7 @@ pub fn handler(
             });
         }
         overlay.record_agent_contribution(&clock, agent_slot, usd_amount)?;
-    } else if agent_entry.spending_limit_usd > 0 {
+    } else if agent_entry.spending_limit_usd > 0 || agent_entry.max_sessions_per_hour > 0 {
         return Err(error!(SigilError::AgentSlotNotFound));
     }
     drop(overlay);
@@ -194,7 +350,7 @@ pub fn handler(
     drop(tracker);
 
     // Build vault PDA signer seeds
-    let owner_key = vault.owner;
+    let creator_key = vault.creator;
     let vault_id_bytes = vault.vault_id.to_le_bytes();
     let vault_bump = vault.bump;
     let vault_fee_destination = vault.fee_destination;
@@ -203,7 +359,7 @@ pub fn handler(
     let bump_slice = [vault_bump];
     let signer_seeds = [
         b"vault" as &[u8],
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump_slice.as_ref(),
     ];
@@ -329,6 +485,8 @@ pub fn handler(
         destination: ctx.accounts.destination_token_account.owner,
         amount,
         mint: token_mint,
+        destination_cap_usd,
+        destination_rolling_spend_usd,
     });
 
     Ok(())
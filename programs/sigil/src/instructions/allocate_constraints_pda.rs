@@ -5,6 +5,8 @@ use crate::errors::SigilError;
 use crate::events::PdaAllocated;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 /// Maximum account size that can be created via a single CPI to the system program.
 /// Solana runtime enforces MAX_PERMITTED_DATA_INCREASE = 10,240 bytes per inner instruction.
 pub const MAX_CPI_ACCOUNT_SIZE: usize = 10_240;
@@ -15,8 +17,9 @@ pub struct AllocateConstraintsPda<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -42,7 +45,11 @@ pub struct AllocateConstraintsPda<'info> {
 }
 
 pub fn handler(ctx: Context<AllocateConstraintsPda>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     // Guard: account must not already exist
     require!(
@@ -5,6 +5,8 @@ use crate::errors::SigilError;
 use crate::events::PdaAllocated;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 use super::allocate_constraints_pda::MAX_CPI_ACCOUNT_SIZE;
 
 #[derive(Accounts)]
@@ -13,8 +15,9 @@ pub struct AllocatePendingConstraintsPda<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -46,7 +49,11 @@ pub struct AllocatePendingConstraintsPda<'info> {
 }
 
 pub fn handler(ctx: Context<AllocatePendingConstraintsPda>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let policy = &ctx.accounts.policy;
 
@@ -5,6 +5,8 @@ use crate::errors::SigilError;
 use crate::events::AgentPermissionsChangeApplied;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct ApplyAgentPermissionsUpdate<'info> {
     #[account(mut)]
@@ -13,7 +15,7 @@ pub struct ApplyAgentPermissionsUpdate<'info> {
     #[account(
         mut,
         constraint = vault.owner == owner.key() @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", vault.owner.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -46,10 +48,36 @@ pub struct ApplyAgentPermissionsUpdate<'info> {
         bump = agent_spend_overlay.load()?.bump,
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
+
+    /// Per-agent settings. Required when the pending update carries a
+    /// protocol subset or an override; created on first use.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = AgentSettings::SIZE,
+        seeds = [
+            b"agent_settings",
+            vault.key().as_ref(),
+            pending_agent_perms.agent.as_ref(),
+        ],
+        bump,
+    )]
+    pub agent_settings: Option<Account<'info, AgentSettings>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<ApplyAgentPermissionsUpdate>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    require!(
+        !ctx.accounts.vault.open_bundle,
+        SigilError::GovernanceBundleOpen
+    );
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let clock = Clock::get()?;
     let pending = &ctx.accounts.pending_agent_perms;
@@ -66,34 +94,128 @@ pub fn handler(ctx: Context<ApplyAgentPermissionsUpdate>) -> Result<()> {
         clock.slot.saturating_sub(pending.queued_at_slot) < MAX_APPLY_AGE_SLOTS,
         SigilError::QueuedUpdateExpired,
     );
+    // Queued under a previous owner; the new owner re-queues what it wants.
+    require!(
+        ctx.accounts
+            .vault
+            .accepts_queued_update(pending.queued_at_slot),
+        SigilError::QueuedBeforeOwnerTransfer
+    );
 
+    let agent = pending.agent;
+    let vault_key = ctx.accounts.vault.key();
+    let settings = match ctx.accounts.agent_settings.as_mut() {
+        Some(settings) => {
+            settings.bump = ctx.bumps.agent_settings.unwrap_or(settings.bump);
+            Some(&mut **settings)
+        }
+        None => None,
+    };
+    apply_agent_permissions(
+        pending,
+        &mut ctx.accounts.vault,
+        &vault_key,
+        &ctx.accounts.agent_spend_overlay,
+        settings,
+    )?;
+
+    // Bump policy version — permission changes affect security posture
+    let policy = &mut ctx.accounts.policy;
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    emit!(AgentPermissionsChangeApplied {
+        vault: vault_key,
+        agent,
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Write a queued agent permissions update into the vault entry, the
+/// agent's settings (when passed) and the overlay slot bookkeeping. Shared
+/// with apply_bundle; callers do the readiness checks and the version bump.
+pub(crate) fn apply_agent_permissions(
+    pending: &PendingAgentPermissionsUpdate,
+    vault: &mut AgentVault,
+    vault_key: &Pubkey,
+    overlay: &AccountLoader<AgentSpendOverlay>,
+    settings: Option<&mut AgentSettings>,
+) -> Result<()> {
     let agent = pending.agent;
     let new_capability = pending.new_capability;
     let spending_limit_usd = pending.spending_limit_usd;
+    let max_sessions_per_hour = pending.max_sessions_per_hour;
+    let protocols = pending.protocols.clone();
+    let max_transaction_size_usd = pending.max_transaction_size_usd;
+    let session_expiry_seconds = pending.session_expiry_seconds;
+    let valid_until = pending.valid_until;
+    let touches_settings = protocols.is_some()
+        || max_transaction_size_usd.is_some()
+        || session_expiry_seconds.is_some();
+
+    // Write the per-agent protocol subset and overrides carried by this update
+    let has_settings = match settings {
+        Some(settings) => {
+            settings.vault = *vault_key;
+            settings.agent = agent;
+            if let Some(protos) = protocols {
+                settings.protocols = protos;
+            }
+            if let Some(max_tx) = max_transaction_size_usd {
+                settings.max_transaction_size_usd = max_tx;
+            }
+            if let Some(expiry) = session_expiry_seconds {
+                settings.session_expiry_seconds = expiry;
+            }
+            Some(settings.is_restrictive())
+        }
+        None => {
+            require!(!touches_settings, SigilError::AgentSettingsRequired);
+            None
+        }
+    };
 
-    // Find agent entry and update capability + spending limit
-    let vault = &mut ctx.accounts.vault;
+    // Find agent entry and update capability + spending limit + session rate
     let entry = vault
         .agents
         .iter_mut()
         .find(|a| a.pubkey == agent)
         .ok_or(error!(SigilError::UnauthorizedAgent))?;
-    let old_spending_limit = entry.spending_limit_usd;
+    let had_limits = entry.spending_limit_usd > 0 || entry.max_sessions_per_hour > 0;
     entry.capability = new_capability;
     entry.spending_limit_usd = spending_limit_usd;
+    entry.max_sessions_per_hour = max_sessions_per_hour;
+    if let Some(flag) = has_settings {
+        entry.has_settings = flag;
+    }
+    if let Some(ts) = valid_until {
+        // Validated at queue time; an expiry that has since passed simply
+        // leaves the key expired.
+        entry.valid_until =
+            u32::try_from(ts).map_err(|_| error!(SigilError::InvalidAgentExpiry))?;
+    }
+    if let Some(limit) = pending.max_concurrent_sessions {
+        // Sessions already open under a higher limit finalize normally.
+        entry.max_concurrent_sessions = limit;
+    }
+    let has_limits = spending_limit_usd > 0 || max_sessions_per_hour > 0;
 
-    // Manage overlay slot when spending limit changes
+    // Manage overlay slot when per-agent limits change
     // (lifted verbatim from update_agent_permissions.rs:66-81)
-    if let Ok(mut overlay) = ctx.accounts.agent_spend_overlay.load_mut() {
+    if let Ok(mut overlay) = overlay.load_mut() {
         let has_slot = overlay.find_agent_slot(&agent).is_some();
 
-        if spending_limit_usd > 0 && !has_slot {
+        if has_limits && !has_slot {
             // Need a slot but don't have one — claim it
             require!(
                 overlay.claim_slot(&agent).is_some(),
                 SigilError::OverlaySlotExhausted
             );
-        } else if spending_limit_usd == 0 && old_spending_limit > 0 && has_slot {
+        } else if !has_limits && had_limits && has_slot {
             // No longer need a slot — release it
             if let Some(idx) = overlay.find_agent_slot(&agent) {
                 overlay.release_slot(idx);
@@ -101,18 +223,5 @@ pub fn handler(ctx: Context<ApplyAgentPermissionsUpdate>) -> Result<()> {
         }
     }
 
-    // Bump policy version — permission changes affect security posture
-    let policy = &mut ctx.accounts.policy;
-    policy.policy_version = policy
-        .policy_version
-        .checked_add(1)
-        .ok_or(error!(SigilError::Overflow))?;
-
-    emit!(AgentPermissionsChangeApplied {
-        vault: vault.key(),
-        agent,
-        applied_at: clock.unix_timestamp,
-    });
-
     Ok(())
 }
@@ -0,0 +1,320 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::AccountsClose;
+
+use crate::errors::SigilError;
+use crate::events::GovernanceBundleApplied;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+use super::apply_agent_permissions_update::apply_agent_permissions;
+use super::apply_constraints_update::apply_pending_constraints;
+use super::apply_pending_policy::apply_policy_fields;
+
+#[derive(Accounts)]
+pub struct ApplyBundle<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"governance_bundle", vault.key().as_ref()],
+        bump = bundle.bump,
+        close = owner,
+    )]
+    pub bundle: Account<'info, GovernanceBundle>,
+
+    /// Required iff the bundle includes a policy update.
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"pending_policy", vault.key().as_ref()],
+        bump = pending_policy.bump,
+        close = owner,
+    )]
+    pub pending_policy: Option<Account<'info, PendingPolicyUpdate>>,
+
+    /// Required iff the bundle includes a constraints update.
+    #[account(
+        mut,
+        seeds = [b"pending_constraints", vault.key().as_ref()],
+        bump = pending_constraints.load()?.bump,
+        close = owner,
+    )]
+    pub pending_constraints: Option<AccountLoader<'info, PendingConstraintsUpdate>>,
+
+    /// Required iff the bundle includes a constraints closure.
+    #[account(
+        mut,
+        constraint = pending_close_constraints.vault == vault.key(),
+        seeds = [b"pending_close_constraints", vault.key().as_ref()],
+        bump = pending_close_constraints.bump,
+        close = owner,
+    )]
+    pub pending_close_constraints: Option<Account<'info, PendingCloseConstraints>>,
+
+    /// Live constraints — written by a constraints update, closed by a
+    /// constraints closure.
+    #[account(
+        mut,
+        seeds = [b"constraints", vault.key().as_ref()],
+        bump = constraints.load()?.bump,
+    )]
+    pub constraints: Option<AccountLoader<'info, InstructionConstraints>>,
+
+    /// Agent spend overlay — per-agent tracking slot.
+    #[account(
+        mut,
+        seeds = [b"agent_spend", vault.key().as_ref(), &[0u8]],
+        bump = agent_spend_overlay.load()?.bump,
+    )]
+    pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Caller contract for `remaining_accounts`: pairs of
+/// (pending_agent_perms PDA, agent_settings PDA) in the bundle's
+/// `agent_updates` order. The settings PDA is created here if an update
+/// touches settings and it does not exist yet.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ApplyBundle<'info>>) -> Result<()> {
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .vault
+        .record_owner_activity(clock.unix_timestamp);
+    let vault_key = ctx.accounts.vault.key();
+    let bundle = &ctx.accounts.bundle;
+
+    // Every referenced update must be passed, unchanged since bundling.
+    let slot_matches = |included: bool, pinned: u64, actual: Option<u64>| match actual {
+        Some(slot) => included && slot == pinned,
+        None => !included,
+    };
+    let policy_slot = ctx
+        .accounts
+        .pending_policy
+        .as_ref()
+        .map(|p| p.queued_at_slot);
+    let constraints_slot = match ctx.accounts.pending_constraints.as_ref() {
+        Some(loader) => Some(loader.load()?.queued_at_slot),
+        None => None,
+    };
+    let close_slot = ctx
+        .accounts
+        .pending_close_constraints
+        .as_ref()
+        .map(|p| p.queued_at_slot);
+    require!(
+        slot_matches(
+            bundle.includes_policy,
+            bundle.policy_queued_at_slot,
+            policy_slot
+        ) && slot_matches(
+            bundle.includes_constraints,
+            bundle.constraints_queued_at_slot,
+            constraints_slot
+        ) && slot_matches(
+            bundle.includes_close_constraints,
+            bundle.close_constraints_queued_at_slot,
+            close_slot
+        ),
+        SigilError::GovernanceBundleMismatch
+    );
+    require!(
+        remaining_accounts.len() == bundle.agent_updates.len() * 2,
+        SigilError::GovernanceBundleMismatch
+    );
+    require!(
+        ctx.accounts.constraints.is_some()
+            || !(bundle.includes_constraints || bundle.includes_close_constraints),
+        SigilError::InvalidConstraintsPda
+    );
+
+    // Policy
+    if let Some(pending) = ctx.accounts.pending_policy.as_ref() {
+        require_fresh(
+            &ctx.accounts.vault,
+            pending.is_ready(clock.unix_timestamp),
+            pending.queued_at_slot,
+            &clock,
+        )?;
+        apply_policy_fields(pending, &mut ctx.accounts.policy, &mut ctx.accounts.vault)?;
+    }
+
+    // Constraints replacement (readiness checked by the shared helper)
+    if let (Some(pending), Some(constraints)) = (
+        ctx.accounts.pending_constraints.as_ref(),
+        ctx.accounts.constraints.as_ref(),
+    ) {
+        apply_pending_constraints(pending, constraints, &ctx.accounts.vault, &clock)?;
+    }
+
+    // Agent permissions
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let mut agents: Vec<Pubkey> = Vec::with_capacity(bundle.agent_updates.len());
+    for (update, pair) in bundle
+        .agent_updates
+        .iter()
+        .zip(remaining_accounts.chunks_exact(2))
+    {
+        let (pending_info, settings_info) = (&pair[0], &pair[1]);
+        let pending = Account::<PendingAgentPermissionsUpdate>::try_from(pending_info)?;
+        let (expected_pending, _) = Pubkey::find_program_address(
+            &[
+                b"pending_agent_perms",
+                vault_key.as_ref(),
+                update.agent.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(
+            pending_info.key() == expected_pending
+                && pending.vault == vault_key
+                && pending.agent == update.agent
+                && pending.queued_at_slot == update.queued_at_slot,
+            SigilError::GovernanceBundleMismatch
+        );
+        require_fresh(
+            &ctx.accounts.vault,
+            pending.is_ready(clock.unix_timestamp),
+            pending.queued_at_slot,
+            &clock,
+        )?;
+
+        let (expected_settings, settings_bump) = Pubkey::find_program_address(
+            &[b"agent_settings", vault_key.as_ref(), update.agent.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            settings_info.key(),
+            expected_settings,
+            SigilError::GovernanceBundleMismatch
+        );
+        let touches_settings = pending.protocols.is_some()
+            || pending.max_transaction_size_usd.is_some()
+            || pending.session_expiry_seconds.is_some();
+        let mut settings = if settings_info.owner == ctx.program_id {
+            Some(Account::<AgentSettings>::try_from(settings_info)?)
+        } else if touches_settings {
+            let signer_seeds: &[&[u8]] = &[
+                b"agent_settings",
+                vault_key.as_ref(),
+                update.agent.as_ref(),
+                &[settings_bump],
+            ];
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::CreateAccount {
+                        from: owner_info.clone(),
+                        to: settings_info.clone(),
+                    },
+                    &[signer_seeds],
+                ),
+                Rent::get()?.minimum_balance(AgentSettings::SIZE),
+                AgentSettings::SIZE as u64,
+                ctx.program_id,
+            )?;
+            let mut created = Account::<AgentSettings>::try_from_unchecked(settings_info)?;
+            created.bump = settings_bump;
+            Some(created)
+        } else {
+            None
+        };
+
+        apply_agent_permissions(
+            &pending,
+            &mut ctx.accounts.vault,
+            &vault_key,
+            &ctx.accounts.agent_spend_overlay,
+            settings.as_deref_mut(),
+        )?;
+
+        if let Some(settings) = settings.as_ref() {
+            settings.exit(ctx.program_id)?;
+        }
+        pending.close(owner_info.clone())?;
+        agents.push(update.agent);
+    }
+
+    // Constraints closure
+    if let Some(pending) = ctx.accounts.pending_close_constraints.as_ref() {
+        require_fresh(
+            &ctx.accounts.vault,
+            pending.is_ready(clock.unix_timestamp),
+            pending.queued_at_slot,
+            &clock,
+        )?;
+        if let Some(constraints) = ctx.accounts.constraints.as_ref() {
+            require!(
+                constraints.load()?.vault == vault_key.to_bytes(),
+                SigilError::InvalidConstraintsPda
+            );
+            constraints.close(owner_info.clone())?;
+        }
+        ctx.accounts.policy.has_constraints = false;
+    }
+
+    ctx.accounts.vault.open_bundle = false;
+
+    // One version bump for the whole bundle
+    let policy = &mut ctx.accounts.policy;
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    emit!(GovernanceBundleApplied {
+        vault: vault_key,
+        includes_policy: ctx.accounts.bundle.includes_policy,
+        includes_constraints: ctx.accounts.bundle.includes_constraints,
+        includes_close_constraints: ctx.accounts.bundle.includes_close_constraints,
+        agents,
+        policy_version: policy.policy_version,
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Timelock, slot-freshness and owner-transfer gate shared by every bundled
+/// component.
+fn require_fresh(
+    vault: &AgentVault,
+    is_ready: bool,
+    queued_at_slot: u64,
+    clock: &Clock,
+) -> Result<()> {
+    require!(is_ready, SigilError::TimelockNotExpired);
+    // F-10 audit fix: slot-bounded freshness check defends against durable-nonce
+    // pre-signing attacks (Drift Protocol April 2026 $285M analog).
+    require!(
+        clock.slot.saturating_sub(queued_at_slot) < MAX_APPLY_AGE_SLOTS,
+        SigilError::QueuedUpdateExpired,
+    );
+    require!(
+        vault.accepts_queued_update(queued_at_slot),
+        SigilError::QueuedBeforeOwnerTransfer
+    );
+    Ok(())
+}
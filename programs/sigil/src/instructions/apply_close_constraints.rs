@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::CloseConstraintsApplied;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct ApplyCloseConstraints<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -43,7 +46,16 @@ pub struct ApplyCloseConstraints<'info> {
 }
 
 pub fn handler(ctx: Context<ApplyCloseConstraints>) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    require!(
+        !ctx.accounts.vault.open_bundle,
+        SigilError::GovernanceBundleOpen
+    );
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let clock = Clock::get()?;
     let pending = &ctx.accounts.pending_close_constraints;
@@ -69,6 +81,13 @@ pub fn handler(ctx: Context<ApplyCloseConstraints>) -> Result<()> {
         clock.slot.saturating_sub(pending.queued_at_slot) < MAX_APPLY_AGE_SLOTS,
         SigilError::QueuedUpdateExpired,
     );
+    // Queued under a previous owner; the new owner re-queues what it wants.
+    require!(
+        ctx.accounts
+            .vault
+            .accepts_queued_update(pending.queued_at_slot),
+        SigilError::QueuedBeforeOwnerTransfer
+    );
 
     // Clear the has_constraints flag so validate_and_authorize skips constraint checks
     let policy = &mut ctx.accounts.policy;
@@ -87,7 +106,7 @@ pub fn handler(ctx: Context<ApplyCloseConstraints>) -> Result<()> {
 
     // If caller provides PendingConstraintsUpdate in remaining_accounts, close it too
     // (same pattern as the old close_instruction_constraints.rs:53-70)
-    if let Some(pending_info) = ctx.remaining_accounts.first() {
+    if let Some(pending_info) = remaining_accounts.first() {
         let (expected_pda, _) = Pubkey::find_program_address(
             &[b"pending_constraints", ctx.accounts.vault.key().as_ref()],
             ctx.program_id,
@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::ConstraintsChangeApplied;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct ApplyConstraintsUpdate<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -42,14 +45,65 @@ pub struct ApplyConstraintsUpdate<'info> {
 }
 
 pub fn handler(ctx: Context<ApplyConstraintsUpdate>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    require!(
+        !ctx.accounts.vault.open_bundle,
+        SigilError::GovernanceBundleOpen
+    );
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let clock = Clock::get()?;
     let vault_key = ctx.accounts.vault.key();
 
+    apply_pending_constraints(
+        &ctx.accounts.pending_constraints,
+        &ctx.accounts.constraints,
+        &ctx.accounts.vault,
+        &clock,
+    )?;
+
+    // Bump policy version — constraint changes affect security posture
+    let policy = &mut ctx.accounts.policy;
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    // Collect discriminator formats from the freshly-applied entries for the event.
+    // Read from constraints (not pending — pending is closed by Anchor after this handler).
+    let discriminator_formats = {
+        let constraints = ctx.accounts.constraints.load()?;
+        let count = constraints.entry_count as usize;
+        (0..count)
+            .map(|i| constraints.entries[i].discriminator_format)
+            .collect::<Vec<u8>>()
+    };
+
+    emit!(ConstraintsChangeApplied {
+        vault: vault_key,
+        discriminator_formats,
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Check readiness of a queued constraints update and copy it into the live
+/// constraints account. Shared with apply_bundle; callers bump the version.
+pub(crate) fn apply_pending_constraints(
+    pending_constraints: &AccountLoader<PendingConstraintsUpdate>,
+    constraints: &AccountLoader<InstructionConstraints>,
+    vault: &Account<AgentVault>,
+    clock: &Clock,
+) -> Result<()> {
+    let vault_key = vault.key();
     // Read pending: verify vault + timelock + slot freshness, extract scalar fields
     let (new_entry_count, new_strict_mode) = {
-        let pending = ctx.accounts.pending_constraints.load()?;
+        let pending = pending_constraints.load()?;
         require!(
             pending.vault == vault_key.to_bytes(),
             SigilError::InvalidPendingConstraintsPda
@@ -64,6 +118,11 @@ pub fn handler(ctx: Context<ApplyConstraintsUpdate>) -> Result<()> {
             clock.slot.saturating_sub(pending.queued_at_slot) < MAX_APPLY_AGE_SLOTS,
             SigilError::QueuedUpdateExpired,
         );
+        // Queued under a previous owner; the new owner re-queues what it wants.
+        require!(
+            vault.accepts_queued_update(pending.queued_at_slot),
+            SigilError::QueuedBeforeOwnerTransfer
+        );
         (pending.entry_count, pending.strict_mode)
     };
 
@@ -77,8 +136,8 @@ pub fn handler(ctx: Context<ApplyConstraintsUpdate>) -> Result<()> {
     // the alternative (re-validation on apply) would add ~50K CU and could reject entries that
     // were valid when the owner queued them, breaking the timelock contract.
     {
-        let pending_info = ctx.accounts.pending_constraints.to_account_info();
-        let constraints_info = ctx.accounts.constraints.to_account_info();
+        let pending_info = pending_constraints.to_account_info();
+        let constraints_info = constraints.to_account_info();
         let pending_data = pending_info.try_borrow_data()?;
         let mut constraints_data = constraints_info.try_borrow_mut_data()?;
 
@@ -92,7 +151,7 @@ pub fn handler(ctx: Context<ApplyConstraintsUpdate>) -> Result<()> {
 
     // Set scalar fields via load_mut
     {
-        let mut constraints = ctx.accounts.constraints.load_mut()?;
+        let mut constraints = constraints.load_mut()?;
         require!(
             constraints.vault == vault_key.to_bytes(),
             SigilError::InvalidConstraintsPda
@@ -101,28 +160,5 @@ pub fn handler(ctx: Context<ApplyConstraintsUpdate>) -> Result<()> {
         constraints.strict_mode = new_strict_mode;
     }
 
-    // Bump policy version — constraint changes affect security posture
-    let policy = &mut ctx.accounts.policy;
-    policy.policy_version = policy
-        .policy_version
-        .checked_add(1)
-        .ok_or(error!(SigilError::Overflow))?;
-
-    // Collect discriminator formats from the freshly-applied entries for the event.
-    // Read from constraints (not pending — pending is closed by Anchor after this handler).
-    let discriminator_formats = {
-        let constraints = ctx.accounts.constraints.load()?;
-        let count = constraints.entry_count as usize;
-        (0..count)
-            .map(|i| constraints.entries[i].discriminator_format)
-            .collect::<Vec<u8>>()
-    };
-
-    emit!(ConstraintsChangeApplied {
-        vault: vault_key,
-        discriminator_formats,
-        applied_at: clock.unix_timestamp,
-    });
-
     Ok(())
 }
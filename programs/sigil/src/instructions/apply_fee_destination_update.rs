@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::FeeDestinationChangeApplied;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct ApplyFeeDestinationUpdate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"pending_fee_destination", vault.key().as_ref()],
+        bump = pending_fee_destination.bump,
+        close = owner,
+    )]
+    pub pending_fee_destination: Account<'info, PendingFeeDestinationUpdate>,
+}
+
+pub fn handler(ctx: Context<ApplyFeeDestinationUpdate>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .vault
+        .record_owner_activity(clock.unix_timestamp);
+
+    let pending = &ctx.accounts.pending_fee_destination;
+
+    require!(
+        pending.is_ready(clock.unix_timestamp),
+        SigilError::TimelockNotExpired
+    );
+    // F-10 audit fix: slot-bounded freshness check defends against durable-nonce
+    // pre-signing attacks (Drift Protocol April 2026 $285M analog).
+    require!(
+        clock.slot.saturating_sub(pending.queued_at_slot) < MAX_APPLY_AGE_SLOTS,
+        SigilError::QueuedUpdateExpired,
+    );
+    // Queued under a previous owner; the new owner re-queues what it wants.
+    require!(
+        ctx.accounts
+            .vault
+            .accepts_queued_update(pending.queued_at_slot),
+        SigilError::QueuedBeforeOwnerTransfer
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let previous_fee_destination = vault.fee_destination;
+    vault.fee_destination = pending.new_fee_destination;
+
+    emit!(FeeDestinationChangeApplied {
+        vault: vault.key(),
+        previous_fee_destination,
+        new_fee_destination: vault.fee_destination,
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
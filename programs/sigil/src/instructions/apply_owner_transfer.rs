@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::OwnerTransferApplied;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct ApplyOwnerTransfer<'info> {
+    /// Incoming owner — accepting proves the key is controlled.
+    pub new_owner: Signer<'info>,
+
+    /// CHECK: current vault owner; only receives the pending PDA's rent.
+    #[account(mut, address = vault.owner @ SigilError::UnauthorizedOwner)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = new_owner @ SigilError::InvalidNewOwner,
+        seeds = [b"pending_owner_transfer", vault.key().as_ref()],
+        bump = pending_owner_transfer.bump,
+        close = owner,
+    )]
+    pub pending_owner_transfer: Account<'info, PendingOwnerTransfer>,
+}
+
+/// Hand the vault to the queued owner. The PDA address is derived from the
+/// immutable `creator`, so every vault-scoped PDA keeps working.
+///
+/// The previous owner's approvers, guardian, recovery and dead-man keys are
+/// cleared and its queued updates invalidated (`accepts_queued_update`), so
+/// it keeps no say over the vault and the new owner applies nothing it did
+/// not queue itself.
+pub fn handler(ctx: Context<ApplyOwnerTransfer>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    let clock = Clock::get()?;
+    let pending = &ctx.accounts.pending_owner_transfer;
+
+    require!(
+        pending.is_ready(clock.unix_timestamp),
+        SigilError::TimelockNotExpired
+    );
+    // F-10 audit fix: slot-bounded freshness check defends against durable-nonce
+    // pre-signing attacks (Drift Protocol April 2026 $285M analog).
+    require!(
+        clock.slot.saturating_sub(pending.queued_at_slot) < MAX_APPLY_AGE_SLOTS,
+        SigilError::QueuedUpdateExpired,
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    // The vault's funds now belong to the beneficiary.
+    require!(
+        !vault.dead_man_triggered,
+        SigilError::DeadManSwitchTriggered
+    );
+    // Re-check: an agent or guardian may have been set to this key since queue time.
+    require!(
+        pending.new_owner != vault.guardian && !vault.is_agent(&pending.new_owner),
+        SigilError::InvalidNewOwner
+    );
+
+    let previous_owner = vault.owner;
+    vault.transfer_ownership(pending.new_owner, clock.slot);
+
+    // Bump policy version — the authority behind the policy changed
+    let policy = &mut ctx.accounts.policy;
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    emit!(OwnerTransferApplied {
+        vault: vault.key(),
+        previous_owner,
+        new_owner: vault.owner,
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::PolicyChangeApplied;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct ApplyPendingPolicy<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -35,9 +38,18 @@ pub struct ApplyPendingPolicy<'info> {
 }
 
 pub fn handler(ctx: Context<ApplyPendingPolicy>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    require!(
+        !ctx.accounts.vault.open_bundle,
+        SigilError::GovernanceBundleOpen
+    );
 
     let clock = Clock::get()?;
+    ctx.accounts
+        .vault
+        .record_owner_activity(clock.unix_timestamp);
+
     let pending = &ctx.accounts.pending_policy;
 
     // Timelock must have expired
@@ -56,6 +68,39 @@ pub fn handler(ctx: Context<ApplyPendingPolicy>) -> Result<()> {
     );
 
     let policy = &mut ctx.accounts.policy;
+    apply_policy_fields(pending, policy, &mut ctx.accounts.vault)?;
+
+    // Bump policy version — agents will detect this via PolicyVersionMismatch
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    emit!(PolicyChangeApplied {
+        vault: ctx.accounts.vault.key(),
+        applied_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Write every non-None field of a queued policy update. Shared with
+/// apply_bundle; callers do the readiness checks and the version bump.
+pub(crate) fn apply_policy_fields(
+    pending: &PendingPolicyUpdate,
+    policy: &mut PolicyConfig,
+    vault: &mut AgentVault,
+) -> Result<()> {
+    // An update queued before an emergency tighten would undo it.
+    require!(
+        policy.accepts_pending_update(pending.queued_at_slot),
+        SigilError::PendingPolicySuperseded
+    );
+    // Queued under a previous owner; the new owner re-queues what it wants.
+    require!(
+        vault.accepts_queued_update(pending.queued_at_slot),
+        SigilError::QueuedBeforeOwnerTransfer
+    );
 
     // Apply each non-None field
     if let Some(cap) = pending.daily_spending_cap_usd {
@@ -81,6 +126,9 @@ pub fn handler(ctx: Context<ApplyPendingPolicy>) -> Result<()> {
         policy.timelock_duration = tl;
     }
     if let Some(ref destinations) = pending.allowed_destinations {
+        if pending.destination_caps.is_none() {
+            policy.destination_caps = policy.realigned_destination_caps(destinations);
+        }
         policy.allowed_destinations = destinations.clone();
     }
     if let Some(expiry) = pending.session_expiry_seconds {
@@ -92,6 +140,11 @@ pub fn handler(ctx: Context<ApplyPendingPolicy>) -> Result<()> {
     if let Some(ref caps) = pending.protocol_caps {
         policy.protocol_caps = caps.clone();
     }
+    // Re-check alignment, as for destination caps below.
+    require!(
+        !policy.has_protocol_caps || policy.protocol_caps.len() == policy.protocols.len(),
+        SigilError::ProtocolCapsMismatch
+    );
     if let Some(mode) = pending.destination_mode {
         // Validate again at apply time — defence in depth.
         require!(
@@ -100,19 +153,77 @@ pub fn handler(ctx: Context<ApplyPendingPolicy>) -> Result<()> {
         );
         policy.destination_mode = mode;
     }
+    if let Some(cap) = pending.weekly_spending_cap_usd {
+        policy.weekly_spending_cap_usd = cap;
+    }
+    if let Some(cap) = pending.monthly_spending_cap_usd {
+        policy.monthly_spending_cap_usd = cap;
+    }
+    if let Some(ref caps) = pending.mint_caps {
+        policy.mint_caps = caps.clone();
+    }
+    if let Some(ref caps) = pending.destination_caps {
+        // Re-check: tighten_policy may have shrunk the list since queue time.
+        require!(
+            caps.is_empty() || caps.len() == policy.allowed_destinations.len(),
+            SigilError::DestinationCapsMismatch
+        );
+        policy.destination_caps = caps.clone();
+    }
+    if let Some(hours) = pending.trading_hours {
+        policy.trading_hours = hours;
+    }
+    if let Some(exempt) = pending.trading_hours_exempt_non_spending {
+        policy.trading_hours_exempt_non_spending = exempt;
+    }
+    if let Some(guardian) = pending.guardian {
+        vault.guardian = guardian;
+    }
+    if let (Some(authority), Some(destination)) =
+        (pending.recovery_authority, pending.recovery_destination)
+    {
+        vault.recovery_authority = authority;
+        vault.recovery_destination = destination;
+    }
+    if let (Some(window), Some(beneficiary)) =
+        (pending.inactivity_window_seconds, pending.beneficiary)
+    {
+        // Queued before the switch fired: the trigger is terminal.
+        require!(
+            !vault.dead_man_triggered,
+            SigilError::DeadManSwitchTriggered
+        );
+        vault.inactivity_window_seconds = window;
+        vault.beneficiary = beneficiary;
+    }
+    if let (Some(ref approvers), Some(threshold)) = (&pending.approvers, pending.approval_threshold)
+    {
+        vault.approvers = approvers.clone();
+        vault.approval_threshold = threshold;
+    }
+    if let Some(ref callers) = pending.cpi_callers {
+        vault.cpi_callers = callers.clone();
+    }
+    // Re-check: the caller list was validated against the protocol mode and
+    // list known at queue time, which may since have changed.
+    require!(
+        cpi_callers_unreachable(&vault.cpi_callers, policy.protocol_mode, &policy.protocols),
+        SigilError::InvalidCpiCallers
+    );
+    if let Some(cap) = pending.daily_fee_cap_usd {
+        policy.daily_fee_cap_usd = cap;
+    }
+    if let Some(limit) = pending.max_abandoned_sessions_per_hour {
+        policy.max_abandoned_sessions_per_hour = limit;
+    }
+    if let Some(allow) = pending.allow_async_fulfillment {
+        policy.allow_async_fulfillment = allow;
+    }
+    if let Some(slippage) = pending.max_protocol_slippage_bps {
+        policy.max_protocol_slippage_bps = slippage;
+    }
 
     policy.has_pending_policy = false;
 
-    // Bump policy version — agents will detect this via PolicyVersionMismatch
-    policy.policy_version = policy
-        .policy_version
-        .checked_add(1)
-        .ok_or(error!(SigilError::Overflow))?;
-
-    emit!(PolicyChangeApplied {
-        vault: ctx.accounts.vault.key(),
-        applied_at: clock.unix_timestamp,
-    });
-
     Ok(())
 }
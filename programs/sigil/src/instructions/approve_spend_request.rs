@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::SpendRequestApproved;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct ApproveSpendRequest<'info> {
+    /// Vault owner or guardian
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.is_owner_or_guardian(&authority.key())
+            @ SigilError::UnauthorizedOwnerOrGuardian,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [
+            b"spend_request",
+            vault.key().as_ref(),
+            spend_request.agent.as_ref(),
+            spend_request.request_id.to_le_bytes().as_ref(),
+        ],
+        bump = spend_request.bump,
+    )]
+    pub spend_request: Account<'info, SpendRequest>,
+}
+
+/// The approver passes the request contents they reviewed; the approval
+/// fails if the agent re-created the request with different contents
+/// before it landed.
+pub fn handler(
+    ctx: Context<ApproveSpendRequest>,
+    expected_amount: u64,
+    destination: Pubkey,
+    token_mint: Pubkey,
+    reason_hash: [u8; 32],
+    created_at: i64,
+) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+    if ctx.accounts.vault.is_owner(&authority) {
+        ctx.accounts
+            .vault
+            .record_owner_activity(clock.unix_timestamp);
+    }
+
+    require!(ctx.accounts.vault.is_active(), SigilError::VaultNotActive);
+
+    let request = &mut ctx.accounts.spend_request;
+    require!(
+        request.status == SpendRequestStatus::Pending,
+        SigilError::SpendRequestNotPending
+    );
+    require!(
+        !request.is_expired(clock.unix_timestamp),
+        SigilError::SpendRequestExpired
+    );
+    require!(
+        request.matches(
+            expected_amount,
+            &destination,
+            &token_mint,
+            &reason_hash,
+            created_at
+        ),
+        SigilError::SpendRequestChanged
+    );
+
+    request.status = SpendRequestStatus::Approved;
+    request.approved_by = authority;
+
+    emit!(SpendRequestApproved {
+        vault: request.vault,
+        agent: request.agent,
+        request_id: request.request_id,
+        approved_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
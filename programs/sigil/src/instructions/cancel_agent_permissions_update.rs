@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::AgentPermissionsChangeCancelled;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct CancelAgentPermissionsUpdate<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -31,7 +34,11 @@ pub struct CancelAgentPermissionsUpdate<'info> {
 }
 
 pub fn handler(ctx: Context<CancelAgentPermissionsUpdate>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     emit!(AgentPermissionsChangeCancelled {
         vault: ctx.accounts.vault.key(),
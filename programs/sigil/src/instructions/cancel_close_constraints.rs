@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::CloseConstraintsCancelled;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct CancelCloseConstraints<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -27,7 +30,11 @@ pub struct CancelCloseConstraints<'info> {
 }
 
 pub fn handler(ctx: Context<CancelCloseConstraints>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     emit!(CloseConstraintsCancelled {
         vault: ctx.accounts.vault.key(),
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::FeeDestinationChangeCancelled;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct CancelFeeDestinationUpdate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"pending_fee_destination", vault.key().as_ref()],
+        bump = pending_fee_destination.bump,
+        close = owner,
+    )]
+    pub pending_fee_destination: Account<'info, PendingFeeDestinationUpdate>,
+}
+
+pub fn handler(ctx: Context<CancelFeeDestinationUpdate>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    emit!(FeeDestinationChangeCancelled {
+        vault: ctx.accounts.vault.key(),
+        current_fee_destination: ctx.accounts.vault.fee_destination,
+        cancelled_fee_destination: ctx.accounts.pending_fee_destination.new_fee_destination,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::GovernanceBundleCancelled;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+/// Dissolve a bundle. The referenced pending updates stay queued and can
+/// be applied or cancelled on their own again.
+#[derive(Accounts)]
+pub struct CancelGovernanceBundle<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"governance_bundle", vault.key().as_ref()],
+        bump = bundle.bump,
+        close = owner,
+    )]
+    pub bundle: Account<'info, GovernanceBundle>,
+}
+
+pub fn handler(ctx: Context<CancelGovernanceBundle>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+    ctx.accounts.vault.open_bundle = false;
+
+    emit!(GovernanceBundleCancelled {
+        vault: ctx.accounts.vault.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::OwnerTransferCancelled;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct CancelOwnerTransfer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"pending_owner_transfer", vault.key().as_ref()],
+        bump = pending_owner_transfer.bump,
+        close = owner,
+    )]
+    pub pending_owner_transfer: Account<'info, PendingOwnerTransfer>,
+}
+
+pub fn handler(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    emit!(OwnerTransferCancelled {
+        vault: ctx.accounts.vault.key(),
+        new_owner: ctx.accounts.pending_owner_transfer.new_owner,
+    });
+
+    Ok(())
+}
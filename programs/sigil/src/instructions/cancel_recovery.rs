@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::RecoveryCancelled;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"pending_recovery", vault.key().as_ref()],
+        bump = pending_recovery.bump,
+        close = owner,
+    )]
+    pub pending_recovery: Account<'info, PendingRecovery>,
+}
+
+/// Cancel a queued recovery sweep. The vault stays frozen; the owner
+/// unfreezes it with reactivate_vault once satisfied.
+///
+/// Every queue refreezes the vault, so a compromised recovery key could
+/// re-queue after each cancel and keep the owner locked out. With
+/// `revoke_authority` the owner removes it in the same instruction; a
+/// replacement goes through the timelocked queue_policy_update path.
+pub fn handler(ctx: Context<CancelRecovery>, revoke_authority: bool) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_owner_activity(Clock::get()?.unix_timestamp);
+
+    if revoke_authority {
+        vault.recovery_authority = Pubkey::default();
+        vault.recovery_destination = Pubkey::default();
+    }
+
+    emit!(RecoveryCancelled {
+        vault: vault.key(),
+        recovery_authority: ctx.accounts.pending_recovery.recovery_authority,
+        authority_revoked: revoke_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::SpendRequestCancelled;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelSpendRequest<'info> {
+    /// Requesting agent, vault owner or guardian
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = authority.key() == spend_request.agent
+            || vault.is_owner_or_guardian(&authority.key())
+            @ SigilError::UnauthorizedSpendRequestAction,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    /// CHECK: rent recipient, constrained to the agent that paid for the request
+    #[account(mut, address = spend_request.agent)]
+    pub agent: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [
+            b"spend_request",
+            vault.key().as_ref(),
+            spend_request.agent.as_ref(),
+            spend_request.request_id.to_le_bytes().as_ref(),
+        ],
+        bump = spend_request.bump,
+        close = agent,
+    )]
+    pub spend_request: Account<'info, SpendRequest>,
+}
+
+/// Close an unexecuted spend request (pending or approved). Rent returns to
+/// the agent that created it.
+pub fn handler(ctx: Context<CancelSpendRequest>) -> Result<()> {
+    crate::reject_cpi!();
+
+    let request = &ctx.accounts.spend_request;
+    emit!(SpendRequestCancelled {
+        vault: request.vault,
+        agent: request.agent,
+        request_id: request.request_id,
+        cancelled_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
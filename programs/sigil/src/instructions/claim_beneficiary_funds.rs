@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::SigilError;
+use crate::events::BeneficiaryFundsClaimed;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimBeneficiaryFunds<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = beneficiary @ SigilError::UnauthorizedBeneficiary,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Vault's PDA-controlled token account
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Beneficiary's token account to receive funds
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw from a vault whose dead-man switch has fired. Beneficiary only.
+pub fn handler(ctx: Context<ClaimBeneficiaryFunds>, amount: u64) -> Result<()> {
+    crate::reject_cpi!();
+
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    require!(
+        vault.dead_man_triggered,
+        SigilError::DeadManSwitchNotTriggered
+    );
+    require!(
+        ctx.accounts.vault_token_account.amount >= amount,
+        SigilError::InsufficientBalance
+    );
+
+    // PDA signer seeds
+    let creator_key = vault.creator;
+    let vault_id_bytes = vault.vault_id.to_le_bytes();
+    let bump = [vault.bump];
+    let signer_seeds = [
+        b"vault" as &[u8],
+        creator_key.as_ref(),
+        vault_id_bytes.as_ref(),
+        bump.as_ref(),
+    ];
+    let binding = [signer_seeds.as_slice()];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.beneficiary_token_account.to_account_info(),
+        authority: vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        &binding,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    // P&L tracking: a claim is a withdrawal.
+    if is_stablecoin_mint(&ctx.accounts.mint.key()) {
+        vault.total_withdrawn_usd = vault
+            .total_withdrawn_usd
+            .checked_add(amount)
+            .ok_or(error!(SigilError::Overflow))?;
+    }
+
+    emit!(BeneficiaryFundsClaimed {
+        vault: vault.key(),
+        token_mint: ctx.accounts.mint.key(),
+        amount,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
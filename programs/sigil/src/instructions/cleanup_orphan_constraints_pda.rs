@@ -4,6 +4,8 @@ use crate::errors::SigilError;
 use crate::events::OrphanConstraintsPdaCleaned;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 /// Cleanup an orphaned InstructionConstraints PDA after a partial
 /// `allocate_constraints_pda` + `extend_pda` chain that never reached
 /// `create_instruction_constraints` (e.g. ran out of compute budget mid-extend).
@@ -37,8 +39,9 @@ pub struct CleanupOrphanConstraintsPda<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -62,7 +65,11 @@ pub struct CleanupOrphanConstraintsPda<'info> {
 }
 
 pub fn handler(ctx: Context<CleanupOrphanConstraintsPda>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     // Guard 2: there is no live constraint set on this vault.
     let policy = &ctx.accounts.policy;
@@ -5,6 +5,8 @@ use crate::state::policy::PolicyConfig;
 use crate::state::post_assertions::PostExecutionAssertions;
 use crate::state::vault::AgentVault;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct ClosePostAssertions<'info> {
     #[account(mut)]
@@ -13,7 +15,7 @@ pub struct ClosePostAssertions<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -37,7 +39,11 @@ pub struct ClosePostAssertions<'info> {
 }
 
 pub fn handler(ctx: Context<ClosePostAssertions>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let vault_key = ctx.accounts.vault.key();
 
@@ -10,8 +10,9 @@ pub struct CloseSettledEscrow<'info> {
     pub signer: Signer<'info>,
 
     #[account(
+        mut,
         constraint = source_vault.owner == signer.key() @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", source_vault.owner.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", source_vault.creator.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
         bump = source_vault.bump,
     )]
     pub source_vault: Account<'info, AgentVault>,
@@ -34,6 +35,10 @@ pub struct CloseSettledEscrow<'info> {
 pub fn handler(ctx: Context<CloseSettledEscrow>, _escrow_id: u64) -> Result<()> {
     crate::reject_cpi!();
 
+    ctx.accounts
+        .source_vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
     let escrow = &ctx.accounts.escrow;
 
     // Escrow must be settled or refunded (not Active)
@@ -4,6 +4,8 @@ use crate::errors::SigilError;
 use crate::events::VaultClosed;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct CloseVault<'info> {
     #[account(mut)]
@@ -12,7 +14,7 @@ pub struct CloseVault<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         close = owner,
     )]
@@ -45,11 +47,32 @@ pub struct CloseVault<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy SpendTrackerExtension — close returns rent to owner.
+    /// Optional: a vault rewritten by migrate_vault has none until the owner
+    /// calls initialize_tracker_extension.
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+        close = owner,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
+    /// Zero-copy DestinationSpendTracker — close returns rent to owner.
+    /// Optional for the same reason as `tracker_extension`.
+    #[account(
+        mut,
+        seeds = [b"destination_spend", vault.key().as_ref()],
+        bump = destination_spend.load()?.bump,
+        close = owner,
+    )]
+    pub destination_spend: Option<AccountLoader<'info, DestinationSpendTracker>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
 
     let vault = &ctx.accounts.vault;
 
@@ -62,6 +85,10 @@ pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
         SigilError::ActiveEscrowsExist
     );
     require!(vault.active_sessions == 0, SigilError::ActiveSessionsExist);
+    require!(
+        vault.open_fulfillments == 0,
+        SigilError::OpenFulfillmentsExist
+    );
     require!(
         !ctx.accounts.policy.has_constraints,
         SigilError::ConstraintsNotClosed
@@ -109,7 +136,7 @@ pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
             ctx.program_id,
         );
         // Search remaining_accounts for this PDA
-        for pending_info in ctx.remaining_accounts.iter().skip(start_idx) {
+        for pending_info in remaining_accounts.iter().skip(start_idx) {
             if pending_info.key() == expected_pda && pending_info.lamports() > 0 {
                 let owner_info = ctx.accounts.owner.to_account_info();
                 let dest_lamports = owner_info.lamports();
@@ -129,7 +156,7 @@ pub fn handler(ctx: Context<CloseVault>) -> Result<()> {
         &[b"pending_close_constraints", vault.key().as_ref()],
         ctx.program_id,
     );
-    for pending_info in ctx.remaining_accounts.iter().skip(start_idx) {
+    for pending_info in remaining_accounts.iter().skip(start_idx) {
         if pending_info.key() == expected_close_constraints_pda && pending_info.lamports() > 0 {
             let owner_info = ctx.accounts.owner.to_account_info();
             let dest_lamports = owner_info.lamports();
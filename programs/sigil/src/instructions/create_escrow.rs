@@ -8,7 +8,7 @@ use crate::errors::SigilError;
 use crate::events::{AgentSpendLimitChecked, EscrowCreated, FeesCollected};
 use crate::state::*;
 
-use super::utils::stablecoin_to_usd;
+use super::utils::{enforce_extension_caps, resolve_agent_settings, stablecoin_to_usd};
 
 #[derive(Accounts)]
 #[instruction(escrow_id: u64)]
@@ -20,7 +20,7 @@ pub struct CreateEscrow<'info> {
         mut,
         constraint = source_vault.is_agent(&agent.key()) @ SigilError::UnauthorizedAgent,
         constraint = source_vault.is_active() @ SigilError::VaultNotActive,
-        seeds = [b"vault", source_vault.owner.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", source_vault.creator.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
         bump = source_vault.bump,
     )]
     pub source_vault: Account<'info, AgentVault>,
@@ -47,10 +47,26 @@ pub struct CreateEscrow<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy SpendTrackerExtension — weekly/monthly windows.
+    /// Required when the vault has one (`AgentVault::has_tracker_extension`).
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", source_vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
+    /// Per-agent settings. Required when `AgentEntry::has_settings` is set.
+    #[account(
+        seeds = [b"agent_settings", source_vault.key().as_ref(), agent.key().as_ref()],
+        bump = agent_settings.bump,
+    )]
+    pub agent_settings: Option<Account<'info, AgentSettings>>,
+
     #[account(
         constraint = destination_vault.is_active() @ SigilError::VaultNotActive,
         constraint = destination_vault.key() != source_vault.key() @ SigilError::InvalidEscrowVault,
-        seeds = [b"vault", destination_vault.owner.as_ref(), destination_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", destination_vault.creator.as_ref(), destination_vault.vault_id.to_le_bytes().as_ref()],
         bump = destination_vault.bump,
     )]
     pub destination_vault: Box<Account<'info, AgentVault>>,
@@ -113,11 +129,15 @@ pub fn handler(
     let policy = &ctx.accounts.policy;
     let clock = Clock::get()?;
 
-    // 0b. Agent must not be paused
+    // 0b. Agent must not be paused or expired
     require!(
         !source_vault.is_agent_paused(&ctx.accounts.agent.key()),
         SigilError::AgentPaused
     );
+    require!(
+        !source_vault.is_agent_expired(&ctx.accounts.agent.key(), clock.unix_timestamp),
+        SigilError::AgentKeyExpired
+    );
 
     // 1. Permission check
     require!(
@@ -135,6 +155,12 @@ pub fn handler(
     // 3. Amount must be positive
     require!(amount > 0, SigilError::InsufficientBalance);
 
+    // 3b. Trading-hours schedule
+    require!(
+        policy.is_within_trading_hours(clock.unix_timestamp),
+        SigilError::OutsideTradingWindow
+    );
+
     // 4-5. Validate expiry: must be in the future and within max duration
     require!(
         expires_at > clock.unix_timestamp
@@ -146,9 +172,17 @@ pub fn handler(
     let token_decimals = ctx.accounts.token_mint.decimals;
     let usd_amount = stablecoin_to_usd(amount, token_decimals)?;
 
-    // Single tx USD check
+    // Single tx USD check (tightened by the agent's override)
+    let agent_settings = resolve_agent_settings(
+        source_vault,
+        &ctx.accounts.agent.key(),
+        ctx.accounts.agent_settings.as_ref(),
+    )?;
+    let max_transaction_size_usd = agent_settings.map_or(policy.max_transaction_size_usd, |s| {
+        s.effective_max_transaction_size_usd(policy.max_transaction_size_usd)
+    });
     require!(
-        usd_amount <= policy.max_transaction_size_usd,
+        usd_amount <= max_transaction_size_usd,
         SigilError::TransactionTooLarge
     );
 
@@ -165,6 +199,16 @@ pub fn handler(
     tracker.record_spend(&clock, usd_amount)?;
     drop(tracker);
 
+    // Rolling weekly/monthly + per-mint USD checks
+    enforce_extension_caps(
+        ctx.accounts.tracker_extension.as_ref(),
+        ctx.accounts.source_vault.has_tracker_extension,
+        policy,
+        &clock,
+        &ctx.accounts.token_mint.key(),
+        usd_amount,
+    )?;
+
     // 6b. Per-agent cap check via contribution overlay
     let agent_key = ctx.accounts.agent.key();
     let agent_entry = source_vault
@@ -207,7 +251,7 @@ pub fn handler(
         .ok_or(SigilError::Overflow)?;
 
     // Build vault PDA signer seeds
-    let owner_key = source_vault.owner;
+    let creator_key = source_vault.creator;
     let vault_id_bytes = source_vault.vault_id.to_le_bytes();
     let vault_bump = source_vault.bump;
     let vault_fee_destination = source_vault.fee_destination;
@@ -215,7 +259,7 @@ pub fn handler(
     let bump_slice = [vault_bump];
     let signer_seeds = [
         b"vault" as &[u8],
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump_slice.as_ref(),
     ];
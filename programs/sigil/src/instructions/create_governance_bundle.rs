@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::GovernanceBundleCreated;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct CreateGovernanceBundle<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = GovernanceBundle::SIZE,
+        seeds = [b"governance_bundle", vault.key().as_ref()],
+        bump,
+    )]
+    pub bundle: Account<'info, GovernanceBundle>,
+
+    #[account(
+        has_one = vault,
+        seeds = [b"pending_policy", vault.key().as_ref()],
+        bump = pending_policy.bump,
+    )]
+    pub pending_policy: Option<Account<'info, PendingPolicyUpdate>>,
+
+    #[account(
+        seeds = [b"pending_constraints", vault.key().as_ref()],
+        bump = pending_constraints.load()?.bump,
+    )]
+    pub pending_constraints: Option<AccountLoader<'info, PendingConstraintsUpdate>>,
+
+    #[account(
+        constraint = pending_close_constraints.vault == vault.key(),
+        seeds = [b"pending_close_constraints", vault.key().as_ref()],
+        bump = pending_close_constraints.bump,
+    )]
+    pub pending_close_constraints: Option<Account<'info, PendingCloseConstraints>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Caller contract for `remaining_accounts`: one `PendingAgentPermissionsUpdate`
+/// PDA per agent whose queued update joins the bundle (at most
+/// MAX_BUNDLE_AGENT_UPDATES, no agent twice).
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateGovernanceBundle<'info>>,
+) -> Result<()> {
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .vault
+        .record_owner_activity(clock.unix_timestamp);
+    let vault_key = ctx.accounts.vault.key();
+
+    require!(
+        remaining_accounts.len() <= MAX_BUNDLE_AGENT_UPDATES,
+        SigilError::InvalidGovernanceBundle
+    );
+    let mut agent_updates: Vec<BundledAgentUpdate> = Vec::with_capacity(remaining_accounts.len());
+    for info in remaining_accounts.iter() {
+        let pending = Account::<PendingAgentPermissionsUpdate>::try_from(info)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[
+                b"pending_agent_perms",
+                vault_key.as_ref(),
+                pending.agent.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(
+            info.key() == expected && pending.vault == vault_key,
+            SigilError::GovernanceBundleMismatch
+        );
+        require!(
+            !agent_updates.iter().any(|u| u.agent == pending.agent),
+            SigilError::InvalidGovernanceBundle
+        );
+        agent_updates.push(BundledAgentUpdate {
+            agent: pending.agent,
+            queued_at_slot: pending.queued_at_slot,
+        });
+    }
+
+    // Replacing constraints and closing them cannot both land at once.
+    require!(
+        ctx.accounts.pending_constraints.is_none()
+            || ctx.accounts.pending_close_constraints.is_none(),
+        SigilError::InvalidGovernanceBundle
+    );
+
+    let bundle = &mut ctx.accounts.bundle;
+    bundle.vault = vault_key;
+    if let Some(pending) = ctx.accounts.pending_policy.as_ref() {
+        bundle.includes_policy = true;
+        bundle.policy_queued_at_slot = pending.queued_at_slot;
+    }
+    if let Some(loader) = ctx.accounts.pending_constraints.as_ref() {
+        let pending = loader.load()?;
+        require!(
+            pending.vault == vault_key.to_bytes(),
+            SigilError::InvalidPendingConstraintsPda
+        );
+        bundle.includes_constraints = true;
+        bundle.constraints_queued_at_slot = pending.queued_at_slot;
+    }
+    if let Some(pending) = ctx.accounts.pending_close_constraints.as_ref() {
+        bundle.includes_close_constraints = true;
+        bundle.close_constraints_queued_at_slot = pending.queued_at_slot;
+    }
+    bundle.agent_updates = agent_updates;
+    bundle.created_at = clock.unix_timestamp;
+    bundle.bump = ctx.bumps.bundle;
+
+    let component_count = bundle.component_count();
+    require!(component_count >= 2, SigilError::InvalidGovernanceBundle);
+    ctx.accounts.vault.open_bundle = true;
+
+    emit!(GovernanceBundleCreated {
+        vault: vault_key,
+        component_count: component_count as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -6,6 +6,8 @@ use crate::events::InstructionConstraintsCreated;
 use crate::state::constraints::{pack_entries, InstructionConstraints};
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 /// Populate a pre-allocated InstructionConstraints PDA with entries.
 ///
 /// The PDA must have been created via `allocate_constraints_pda` + `extend_pda`
@@ -17,8 +19,9 @@ pub struct CreateInstructionConstraints<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -46,7 +49,11 @@ pub fn handler(
     entries: Vec<ConstraintEntry>,
     strict_mode: bool,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     InstructionConstraints::validate_entries(&entries)?;
 
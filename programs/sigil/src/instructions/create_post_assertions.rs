@@ -5,6 +5,8 @@ use crate::state::policy::PolicyConfig;
 use crate::state::post_assertions::*;
 use crate::state::vault::AgentVault;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct CreatePostAssertions<'info> {
     #[account(mut)]
@@ -13,7 +15,7 @@ pub struct CreatePostAssertions<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -38,7 +40,11 @@ pub struct CreatePostAssertions<'info> {
 }
 
 pub fn handler(ctx: Context<CreatePostAssertions>, entries: Vec<PostAssertionEntry>) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     // Validate entries
     PostExecutionAssertions::validate_entries(&entries)?;
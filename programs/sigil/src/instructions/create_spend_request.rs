@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::SpendRequestCreated;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CreateSpendRequest<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        constraint = vault.is_agent(&agent.key()) @ SigilError::UnauthorizedAgent,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = SpendRequest::SIZE,
+        seeds = [
+            b"spend_request",
+            vault.key().as_ref(),
+            agent.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub spend_request: Account<'info, SpendRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateSpendRequest>,
+    request_id: u64,
+    token_mint: Pubkey,
+    amount: u64,
+    destination: Pubkey,
+    reason_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    crate::reject_cpi!();
+
+    let vault = &ctx.accounts.vault;
+    let policy = &ctx.accounts.policy;
+    let agent_key = ctx.accounts.agent.key();
+    let clock = Clock::get()?;
+
+    require!(vault.is_active(), SigilError::VaultNotActive);
+    require!(!vault.is_agent_paused(&agent_key), SigilError::AgentPaused);
+    require!(
+        !vault.is_agent_expired(&agent_key, clock.unix_timestamp),
+        SigilError::AgentKeyExpired
+    );
+    require!(
+        vault.has_capability(&agent_key, true),
+        SigilError::InsufficientPermissions
+    );
+    require!(amount > 0, SigilError::TransactionTooLarge);
+    require!(
+        is_stablecoin_mint(&token_mint),
+        SigilError::UnsupportedToken
+    );
+    require!(
+        policy.is_destination_allowed(&destination),
+        SigilError::DestinationNotAllowed
+    );
+    require!(
+        expires_at > clock.unix_timestamp
+            && expires_at.saturating_sub(clock.unix_timestamp) <= MAX_SPEND_REQUEST_DURATION,
+        SigilError::InvalidSpendRequestExpiry
+    );
+
+    let request = &mut ctx.accounts.spend_request;
+    request.vault = vault.key();
+    request.agent = agent_key;
+    request.request_id = request_id;
+    request.token_mint = token_mint;
+    request.amount = amount;
+    request.destination = destination;
+    request.reason_hash = reason_hash;
+    request.created_at = clock.unix_timestamp;
+    request.expires_at = expires_at;
+    request.status = SpendRequestStatus::Pending;
+    request.approved_by = Pubkey::default();
+    request.bump = ctx.bumps.spend_request;
+
+    emit!(SpendRequestCreated {
+        vault: vault.key(),
+        agent: agent_key,
+        request_id,
+        token_mint,
+        amount,
+        destination,
+        reason_hash,
+        expires_at,
+    });
+
+    Ok(())
+}
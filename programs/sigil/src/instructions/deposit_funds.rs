@@ -6,6 +6,8 @@ use crate::errors::SigilError;
 use crate::events::FundsDeposited;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct DepositFunds<'info> {
     #[account(mut)]
@@ -14,7 +16,7 @@ pub struct DepositFunds<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -44,7 +46,11 @@ pub struct DepositFunds<'info> {
 }
 
 pub fn handler(ctx: Context<DepositFunds>, amount: u64) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let vault = &mut ctx.accounts.vault;
     require!(
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::SigilError;
+use crate::events::RecoverySwept;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.recovery_authority == recovery_authority.key()
+            @ SigilError::UnauthorizedRecoveryAuthority,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = recovery_authority @ SigilError::UnauthorizedRecoveryAuthority,
+        seeds = [b"pending_recovery", vault.key().as_ref()],
+        bump = pending_recovery.bump,
+    )]
+    pub pending_recovery: Account<'info, PendingRecovery>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Sweep vault token accounts to the recovery destination once the
+/// recovery timelock has elapsed.
+///
+/// Caller contract for `remaining_accounts`:
+/// - PAIRS of `(vault_token_account, destination_token_account)`.
+/// - The vault token account must be owned by the vault PDA; the
+///   destination token account must be owned by the queued destination
+///   and hold the same mint. Any mismatch fails the whole instruction.
+/// - The full balance of each vault token account is transferred.
+///
+/// A vault with many mints may need several calls. Pass
+/// `close_pending = true` on the last one to reclaim the pending PDA rent.
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteRecovery<'info>>,
+    close_pending: bool,
+) -> Result<()> {
+    crate::reject_cpi!();
+
+    let clock = Clock::get()?;
+    let pending = &ctx.accounts.pending_recovery;
+
+    require!(
+        pending.is_ready(clock.unix_timestamp),
+        SigilError::TimelockNotExpired
+    );
+    require!(
+        clock.slot.saturating_sub(pending.queued_at_slot) < MAX_RECOVERY_APPLY_AGE_SLOTS,
+        SigilError::QueuedUpdateExpired,
+    );
+    require!(
+        !ctx.accounts.vault.dead_man_triggered,
+        SigilError::DeadManSwitchTriggered
+    );
+    // The owner may have re-pointed recovery since queue time.
+    require!(
+        pending.destination == ctx.accounts.vault.recovery_destination,
+        SigilError::InvalidRecoveryConfig
+    );
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        SigilError::InvalidTokenAccount
+    );
+
+    let vault_key = ctx.accounts.vault.key();
+    let destination = pending.destination;
+    let creator_key = ctx.accounts.vault.creator;
+    let vault_id_bytes = ctx.accounts.vault.vault_id.to_le_bytes();
+    let bump = [ctx.accounts.vault.bump];
+    let signer_seeds = [
+        b"vault" as &[u8],
+        creator_key.as_ref(),
+        vault_id_bytes.as_ref(),
+        bump.as_ref(),
+    ];
+    let binding = [signer_seeds.as_slice()];
+
+    let mut stablecoin_swept: u64 = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let vault_token = read_token_account(&pair[0])?;
+        let destination_token = read_token_account(&pair[1])?;
+
+        require!(
+            vault_token.owner == vault_key
+                && destination_token.owner == destination
+                && destination_token.mint == vault_token.mint,
+            SigilError::InvalidTokenAccount
+        );
+
+        let amount = vault_token.amount;
+        if amount == 0 {
+            continue;
+        }
+
+        let cpi_accounts = Transfer {
+            from: pair[0].clone(),
+            to: pair[1].clone(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            &binding,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        if is_stablecoin_mint(&vault_token.mint) {
+            stablecoin_swept = stablecoin_swept
+                .checked_add(amount)
+                .ok_or(error!(SigilError::Overflow))?;
+        }
+
+        emit!(RecoverySwept {
+            vault: vault_key,
+            token_mint: vault_token.mint,
+            amount,
+            destination,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // P&L tracking: a sweep is a withdrawal.
+    let vault = &mut ctx.accounts.vault;
+    vault.total_withdrawn_usd = vault
+        .total_withdrawn_usd
+        .checked_add(stablecoin_swept)
+        .ok_or(error!(SigilError::Overflow))?;
+    // Keep agents out even if the owner reactivated without cancelling.
+    if vault.is_active() {
+        vault.status = VaultStatus::Frozen;
+    }
+
+    if close_pending {
+        ctx.accounts
+            .pending_recovery
+            .close(ctx.accounts.recovery_authority.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+fn read_token_account(info: &AccountInfo) -> Result<TokenAccount> {
+    require!(info.owner == &token::ID, SigilError::InvalidTokenAccount);
+    let data = info.try_borrow_data()?;
+    let mut data_slice: &[u8] = &data;
+    TokenAccount::try_deserialize(&mut data_slice)
+}
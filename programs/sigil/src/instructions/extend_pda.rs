@@ -5,6 +5,8 @@ use crate::errors::SigilError;
 use crate::events::PdaExtended;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 use super::allocate_constraints_pda::MAX_CPI_ACCOUNT_SIZE;
 
 /// Grow a program-owned PDA by up to MAX_CPI_ACCOUNT_SIZE bytes.
@@ -19,8 +21,9 @@ pub struct ExtendPda<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -34,7 +37,11 @@ pub struct ExtendPda<'info> {
 }
 
 pub fn handler(ctx: Context<ExtendPda>, target_size: u32) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let pda_info = ctx.accounts.pda.to_account_info();
     let vault_key = ctx.accounts.vault.key();
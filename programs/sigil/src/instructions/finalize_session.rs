@@ -8,9 +8,11 @@ use anchor_spl::token::{self, Revoke, Token, TokenAccount};
 use anchor_lang::accounts::account_loader::AccountLoader;
 
 use crate::errors::SigilError;
-use crate::events::{AgentSpendLimitChecked, DelegationRevoked, SessionFinalized};
+use crate::events::{AgentAutoPaused, AgentSpendLimitChecked, DelegationRevoked, SessionFinalized};
 use crate::state::*;
 
+use super::utils::enforce_extension_caps;
+
 #[derive(Accounts)]
 pub struct FinalizeSession<'info> {
     #[account(mut)]
@@ -18,7 +20,7 @@ pub struct FinalizeSession<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", vault.owner.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -33,6 +35,7 @@ pub struct FinalizeSession<'info> {
             vault.key().as_ref(),
             session.agent.as_ref(),
             session.authorized_token.as_ref(),
+            session_nonce_seed(&session.nonce),
         ],
         bump = session.bump,
         close = session_rent_recipient,
@@ -66,6 +69,15 @@ pub struct FinalizeSession<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy SpendTrackerExtension — weekly/monthly windows and fee budget.
+    /// Required when the vault has one (`AgentVault::has_tracker_extension`).
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
     /// Vault's PDA token account for the session's token
     #[account(mut)]
     pub vault_token_account: Option<Account<'info, TokenAccount>>,
@@ -75,6 +87,20 @@ pub struct FinalizeSession<'info> {
     #[account(mut)]
     pub output_stablecoin_account: Option<Account<'info, TokenAccount>>,
 
+    /// Vault's token account for the declared expected output. Required when
+    /// a stablecoin-input session declared one.
+    pub output_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Spend reservation for async-fulfillment sessions. Required exactly when
+    /// session.async_fulfillment is set; stays open until reconcile_fulfillment.
+    #[account(
+        mut,
+        has_one = vault @ SigilError::InvalidPendingFulfillment,
+        seeds = [b"pending_fulfillment", vault.key().as_ref(), session.key().as_ref()],
+        bump = pending_fulfillment.bump,
+    )]
+    pub pending_fulfillment: Option<Account<'info, PendingFulfillment>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 
@@ -116,6 +142,11 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
         require!(session.authorized, SigilError::SessionNotAuthorized);
     }
 
+    require!(
+        session.async_fulfillment == ctx.accounts.pending_fulfillment.is_some(),
+        SigilError::InvalidPendingFulfillment
+    );
+
     // Extract session data before we lose access
     let session_agent = session.agent;
     let session_is_spending = session.is_spending;
@@ -128,22 +159,29 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
     let session_authorized_protocol = session.authorized_protocol;
     let session_authorized_token = session.authorized_token;
     let session_protocol_fee = session.protocol_fee;
+    let session_async = session.async_fulfillment;
+    let session_min_output_mint = session.min_output_mint;
+    let session_min_output_amount = session.min_output_amount;
+    let session_min_output_token_account = session.min_output_token_account;
+    let session_min_output_balance_before = session.min_output_balance_before;
     // Phase B2: extract snapshot data for delta assertions
     let session_snapshots = session.assertion_snapshots;
     let session_snapshot_lens = session.snapshot_lens;
+    let max_transaction_size_usd =
+        session.effective_max_transaction_size_usd(ctx.accounts.policy.max_transaction_size_usd);
 
     let vault_key = ctx.accounts.vault.key();
     let vault = &mut ctx.accounts.vault;
 
     // Extract vault PDA seeds data upfront
-    let owner_key = vault.owner;
+    let creator_key = vault.creator;
     let vault_id_bytes = vault.vault_id.to_le_bytes();
     let vault_bump = vault.bump;
 
     let bump_slice = [vault_bump];
     let signer_seeds = [
         b"vault" as &[u8],
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump_slice.as_ref(),
     ];
@@ -228,6 +266,27 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
         // P&L: set balance_after once — covers both branches (M-5 fix)
         balance_after_tracked = stablecoin_current;
 
+        // Minimum output declared at validate (any protocol)
+        if session_min_output_mint != Pubkey::default() {
+            let output_acct = if is_stablecoin_input {
+                ctx.accounts.output_token_account.as_ref()
+            } else {
+                ctx.accounts.output_stablecoin_account.as_ref()
+            }
+            .ok_or(error!(SigilError::InvalidTokenAccount))?;
+            require!(
+                output_acct.key() == session_min_output_token_account,
+                SigilError::InvalidTokenAccount
+            );
+            let received = output_acct
+                .amount
+                .saturating_sub(session_min_output_balance_before);
+            require!(
+                received >= session_min_output_amount,
+                SigilError::MinimumOutputNotMet
+            );
+        }
+
         // CPI balance audit: verify vault balance didn't decrease more than authorized.
         // Catches compromised DeFi programs that CPI burn/transfer vault tokens via
         // the agent's SPL delegation. stablecoin_balance_before is snapshotted BEFORE
@@ -254,11 +313,13 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
             let actual_spend = total_decrease.saturating_sub(fees_collected);
             actual_spend_tracked = actual_spend;
 
-            if actual_spend > 0 {
-                // Per-transaction limit
+            // Async fulfillment: the full amount was reserved against every
+            // cap in validate_and_authorize; reconcile_fulfillment settles it.
+            if actual_spend > 0 && !session_async {
+                // Per-transaction limit (tightened by the agent's override)
                 let policy = &ctx.accounts.policy;
                 require!(
-                    actual_spend <= policy.max_transaction_size_usd,
+                    actual_spend <= max_transaction_size_usd,
                     SigilError::TransactionTooLarge
                 );
 
@@ -273,6 +334,16 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
                     SigilError::SpendingCapExceeded
                 );
 
+                // Rolling weekly/monthly + per-mint caps
+                enforce_extension_caps(
+                    ctx.accounts.tracker_extension.as_ref(),
+                    vault.has_tracker_extension,
+                    policy,
+                    &clock,
+                    &session_authorized_token,
+                    actual_spend,
+                )?;
+
                 // Per-agent cap
                 let agent_entry = vault
                     .get_agent(&session_agent)
@@ -344,10 +415,10 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
                 .ok_or(SigilError::Overflow)?;
             actual_spend_tracked = stablecoin_delta;
 
-            // Per-transaction limit
+            // Per-transaction limit (tightened by the agent's override)
             let policy = &ctx.accounts.policy;
             require!(
-                stablecoin_delta <= policy.max_transaction_size_usd,
+                stablecoin_delta <= max_transaction_size_usd,
                 SigilError::TransactionTooLarge
             );
 
@@ -362,6 +433,16 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
                 SigilError::SpendingCapExceeded
             );
 
+            // Rolling weekly/monthly + per-mint caps
+            enforce_extension_caps(
+                ctx.accounts.tracker_extension.as_ref(),
+                vault.has_tracker_extension,
+                policy,
+                &clock,
+                &session_output_mint,
+                stablecoin_delta,
+            )?;
+
             // Per-agent cap
             let agent_entry = vault
                 .get_agent(&session_agent)
@@ -423,17 +504,58 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
         }
     }
 
+    // Async fulfillment: snapshot the post-session balance so reconciliation
+    // can tell what actually left the vault. Expired sessions keep the full
+    // reservation.
+    if session_async && run_outcome_check {
+        let vault_token = ctx
+            .accounts
+            .vault_token_account
+            .as_ref()
+            .ok_or(error!(SigilError::InvalidTokenAccount))?;
+        let pending = ctx
+            .accounts
+            .pending_fulfillment
+            .as_mut()
+            .ok_or(error!(SigilError::InvalidPendingFulfillment))?;
+        require!(
+            vault_token.key() == pending.token_account,
+            SigilError::InvalidTokenAccount
+        );
+        pending.finalized = true;
+        pending.balance_after_finalize = balance_after_tracked;
+        pending.deposited_usd_at_finalize = vault.total_deposited_usd;
+    }
+
     // --- Fee-to-cap fallback (OUTSIDE run_outcome_check) ---
     // When no DeFi spend occurred (actual_spend_tracked == 0) but fees were collected
-    // in validate_and_authorize, charge those fees to the spending cap. This prevents
-    // fee drain attacks where an agent repeatedly calls validate+finalize with no DeFi
-    // instruction to extract fees without cap enforcement.
+    // in validate_and_authorize, charge those fees to the fee budget, or to the
+    // spending cap when no budget is set. This prevents fee drain attacks where an
+    // agent repeatedly calls validate+finalize with no DeFi instruction to extract
+    // fees without cap enforcement.
     // Runs unconditionally — covers both expired sessions and zero-DeFi-spend sessions.
     let fees_collected_total = session_protocol_fee
         .checked_add(session_developer_fee)
         .ok_or(SigilError::Overflow)?;
 
-    if actual_spend_tracked == 0 && fees_collected_total > 0 {
+    // Async sessions settle later, so a zero delta here is not abandonment.
+    let abandoned_fees = !session_async && actual_spend_tracked == 0 && fees_collected_total > 0;
+    let mut fee_cap_exceeded = false;
+    if abandoned_fees && ctx.accounts.policy.daily_fee_cap_usd > 0 {
+        // Separate budget: abandoned sessions no longer consume the spending
+        // caps. Failing here would revert the close and leave the session
+        // open, so going over the budget pauses the agent instead.
+        let ext = ctx
+            .accounts
+            .tracker_extension
+            .as_ref()
+            .ok_or(error!(SigilError::TrackerExtensionRequired))?;
+        fee_cap_exceeded = ext.load_mut()?.record_fees(
+            &clock,
+            ctx.accounts.policy.daily_fee_cap_usd,
+            fees_collected_total,
+        )?;
+    } else if abandoned_fees {
         let policy = &ctx.accounts.policy;
         let mut tracker = ctx.accounts.tracker.load_mut()?;
         let rolling_usd = tracker.get_rolling_24h_usd(&clock);
@@ -444,10 +566,54 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
             new_total <= policy.daily_spending_cap_usd,
             SigilError::SpendingCapExceeded
         );
+
+        // Rolling weekly/monthly + per-mint caps
+        enforce_extension_caps(
+            ctx.accounts.tracker_extension.as_ref(),
+            vault.has_tracker_extension,
+            policy,
+            &clock,
+            &session_authorized_token,
+            fees_collected_total,
+        )?;
         tracker.record_spend(&clock, fees_collected_total)?;
         drop(tracker);
     }
 
+    // Abandoned-session strikes: a spending session that finalizes without
+    // measured spend counts against its agent. Too many within an hour, or
+    // going over the fee budget, pauses the agent until the owner unpauses it.
+    let abandon_limit = ctx.accounts.policy.max_abandoned_sessions_per_hour;
+    let mut strikes = 0;
+    if session_is_spending && !session_async && actual_spend_tracked == 0 && abandon_limit > 0 {
+        let mut overlay = ctx.accounts.agent_spend_overlay.load_mut()?;
+        let slot = match overlay.find_agent_slot(&session_agent) {
+            Some(slot) => Some(slot),
+            None => overlay.claim_slot(&session_agent),
+        };
+        if let Some(slot) = slot {
+            strikes = overlay.record_abandon(&clock, slot)?;
+        }
+    }
+
+    let abandon_limit_reached = abandon_limit > 0 && strikes >= abandon_limit as u32;
+    if abandon_limit_reached || fee_cap_exceeded {
+        if let Some(entry) = vault
+            .agents
+            .iter_mut()
+            .find(|a| a.pubkey == session_agent && !a.paused)
+        {
+            entry.paused = true;
+            emit!(AgentAutoPaused {
+                vault: vault_key,
+                agent: session_agent,
+                abandoned_sessions: strikes,
+                fee_cap_exceeded,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
     // Always track fees that were transferred in validate (regardless of expiry or outcome).
     // Fees are CPI-transferred in validate_and_authorize — accounting must match reality.
     if session_developer_fee > 0 {
@@ -681,6 +847,9 @@ pub fn handler(ctx: Context<FinalizeSession>) -> Result<()> {
 
     // H-1: Decrement active session counter (unconditional — both success and expired)
     vault.active_sessions = vault.active_sessions.saturating_sub(1);
+    if let Some(entry) = vault.agents.iter_mut().find(|a| a.pubkey == session_agent) {
+        entry.active_sessions = entry.active_sessions.saturating_sub(1);
+    }
 
     emit!(SessionFinalized {
         vault: vault_key,
@@ -5,14 +5,18 @@ use crate::errors::SigilError;
 use crate::events::{DelegationRevoked, VaultFrozen};
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct FreezeVault<'info> {
-    pub owner: Signer<'info>,
+    /// Vault owner or guardian
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        constraint = vault.is_owner_or_guardian(&authority.key())
+            @ SigilError::UnauthorizedOwnerOrGuardian,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -28,7 +32,7 @@ pub struct FreezeVault<'info> {
 /// - PAIRS of `(session_pda, vault_token_account)` for every active session
 ///   whose delegation should be terminated.
 /// - The handler verifies each `session_pda` derives from `[b"session",
-///   vault, agent, token_mint]` using fields read from the account itself,
+///   vault, agent, token_mint, nonce?]` using fields read from the account itself,
 ///   so callers cannot smuggle arbitrary accounts.
 /// - Pairs that fail any check (PDA mismatch, wrong vault, not delegated,
 ///   ATA mismatch) are skipped silently — freeze still succeeds, just no
@@ -39,7 +43,7 @@ pub struct FreezeVault<'info> {
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, FreezeVault<'info>>,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
 
     // Allow freezing only from Active status (matches pre-fix behavior).
     // Already-frozen / closed vaults reject with VaultNotActive — preserves
@@ -49,7 +53,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
     // Snapshot vault PDA seeds so we can sign Revoke CPIs without holding a
     // borrow on `ctx.accounts.vault` while iterating remaining_accounts.
     let vault_key = ctx.accounts.vault.key();
-    let owner_key = ctx.accounts.vault.owner;
+    let creator_key = ctx.accounts.vault.creator;
     let vault_id_bytes = ctx.accounts.vault.vault_id.to_le_bytes();
     let vault_bump = ctx.accounts.vault.bump;
     let agents_preserved = ctx.accounts.vault.agent_count() as u8;
@@ -57,21 +61,26 @@ pub fn handler<'a, 'b, 'c, 'info>(
     let bump_slice = [vault_bump];
     let signer_seeds: [&[u8]; 4] = [
         b"vault",
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump_slice.as_ref(),
     ];
     let binding = [signer_seeds.as_slice()];
 
     let clock = Clock::get()?;
+    if ctx.accounts.vault.is_owner(&ctx.accounts.authority.key()) {
+        ctx.accounts
+            .vault
+            .record_owner_activity(clock.unix_timestamp);
+    }
     let mut sessions_revoked: u32 = 0;
 
     // Walk pairs of (session_pda, vault_token_account) from remaining_accounts.
     // Skip the trailing odd account if caller passed an unpaired entry.
     let mut idx = 0usize;
-    while idx + 1 < ctx.remaining_accounts.len() {
-        let session_info = &ctx.remaining_accounts[idx];
-        let token_info = &ctx.remaining_accounts[idx + 1];
+    while idx + 1 < remaining_accounts.len() {
+        let session_info = &remaining_accounts[idx];
+        let token_info = &remaining_accounts[idx + 1];
         idx += 2;
 
         // 1. Session account must be owned by this program. Anything else
@@ -109,6 +118,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
                 vault_key.as_ref(),
                 session.agent.as_ref(),
                 session.authorized_token.as_ref(),
+                session_nonce_seed(&session.nonce),
                 &[session.bump],
             ],
             &crate::ID,
@@ -173,9 +183,11 @@ pub fn handler<'a, 'b, 'c, 'info>(
 
     emit!(VaultFrozen {
         vault: vault_key,
-        owner: ctx.accounts.owner.key(),
+        owner: ctx.accounts.vault.owner,
+        frozen_by: ctx.accounts.authority.key(),
         agents_preserved,
         sessions_revoked,
+        reason: FREEZE_REASON_MANUAL,
         timestamp: clock.unix_timestamp,
     });
 
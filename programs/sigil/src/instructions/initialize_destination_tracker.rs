@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::accounts::account_loader::AccountLoader;
+
+use crate::errors::SigilError;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct InitializeDestinationTracker<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = DestinationSpendTracker::SIZE,
+        seeds = [b"destination_spend", vault.key().as_ref()],
+        bump,
+    )]
+    pub destination_spend: AccountLoader<'info, DestinationSpendTracker>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the per-destination windows for a vault rewritten by
+/// migrate_vault, which starts without them. From here on every
+/// agent_transfer must pass the tracker.
+pub fn handler(ctx: Context<InitializeDestinationTracker>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let mut destination_spend = ctx.accounts.destination_spend.load_init()?;
+    destination_spend.vault = ctx.accounts.vault.key();
+    destination_spend.bump = ctx.bumps.destination_spend;
+    drop(destination_spend);
+
+    ctx.accounts.vault.has_destination_tracker = true;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct InitializePermitNonces<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = PermitNonces::SIZE,
+        seeds = [b"permit_nonces", vault.key().as_ref()],
+        bump,
+    )]
+    pub permit_nonces: Account<'info, PermitNonces>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the nonce bitmap that owner permits are checked against.
+/// One-time setup before the first permit is used.
+pub fn handler(ctx: Context<InitializePermitNonces>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let nonces = &mut ctx.accounts.permit_nonces;
+    nonces.vault = ctx.accounts.vault.key();
+    nonces.base_nonce = 0;
+    nonces.bitmap = [0; PERMIT_NONCE_WORDS];
+    nonces.bump = ctx.bumps.permit_nonces;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::accounts::account_loader::AccountLoader;
+
+use crate::errors::SigilError;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct InitializeTrackerExtension<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SpendTrackerExtension::SIZE,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump,
+    )]
+    pub tracker_extension: AccountLoader<'info, SpendTrackerExtension>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the weekly/monthly, per-mint and fee windows for a vault that has
+/// none: one rewritten by migrate_vault, which starts without them. From
+/// here on every spend path must pass the extension.
+pub fn handler(ctx: Context<InitializeTrackerExtension>) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let mut tracker_extension = ctx.accounts.tracker_extension.load_init()?;
+    tracker_extension.vault = ctx.accounts.vault.key();
+    tracker_extension.bump = ctx.bumps.tracker_extension;
+    drop(tracker_extension);
+
+    ctx.accounts.vault.has_tracker_extension = true;
+
+    Ok(())
+}
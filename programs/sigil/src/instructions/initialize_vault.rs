@@ -48,6 +48,26 @@ pub struct InitializeVault<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy weekly/monthly spend windows
+    #[account(
+        init,
+        payer = owner,
+        space = SpendTrackerExtension::SIZE,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump,
+    )]
+    pub tracker_extension: AccountLoader<'info, SpendTrackerExtension>,
+
+    /// Zero-copy per-destination spend windows for agent_transfer
+    #[account(
+        init,
+        payer = owner,
+        space = DestinationSpendTracker::SIZE,
+        seeds = [b"destination_spend", vault.key().as_ref()],
+        bump,
+    )]
+    pub destination_spend: AccountLoader<'info, DestinationSpendTracker>,
+
     /// CHECK: This is the fee destination wallet; validated by the caller/SDK.
     pub fee_destination: UncheckedAccount<'info>,
 
@@ -117,6 +137,7 @@ pub fn handler(
     // Initialize vault
     let vault = &mut ctx.accounts.vault;
     vault.owner = ctx.accounts.owner.key();
+    vault.creator = ctx.accounts.owner.key();
     vault.agents = Vec::new();
     vault.fee_destination = ctx.accounts.fee_destination.key();
     vault.vault_id = vault_id;
@@ -131,6 +152,21 @@ pub fn handler(
     vault.total_failed_transactions = 0;
     vault.active_sessions = 0;
     vault.active_escrow_count = 0; // L-2: explicit for auditability
+    vault.guardian = Pubkey::default();
+    vault.recovery_authority = Pubkey::default();
+    vault.recovery_destination = Pubkey::default();
+    vault.last_owner_activity = clock.unix_timestamp;
+    vault.inactivity_window_seconds = 0;
+    vault.beneficiary = Pubkey::default();
+    vault.dead_man_triggered = false;
+    vault.approvers = Vec::new();
+    vault.approval_threshold = 0;
+    vault.cpi_callers = Vec::new();
+    vault.open_fulfillments = 0;
+    vault.has_tracker_extension = true;
+    vault.open_bundle = false;
+    vault.has_destination_tracker = true;
+    vault.owner_changed_at_slot = 0;
 
     // Initialize policy
     let policy = &mut ctx.accounts.policy;
@@ -153,6 +189,24 @@ pub fn handler(
     // F-4 fix: default to Restricted mode (destination must appear in allowlist).
     // Owners switch to OpenWithCap explicitly via queue_policy_update.
     policy.destination_mode = DESTINATION_MODE_RESTRICTED;
+    // Weekly/monthly/per-mint/per-destination caps start disabled; set via
+    // queue_policy_update.
+    policy.weekly_spending_cap_usd = 0;
+    policy.monthly_spending_cap_usd = 0;
+    policy.mint_caps = Vec::new();
+    policy.destination_caps = Vec::new();
+    // No trading-hours schedule by default; set via queue_policy_update.
+    policy.trading_hours = [0; TRADING_HOURS_BYTES];
+    policy.trading_hours_exempt_non_spending = false;
+    // Abandoned-session fees count against the daily cap until a fee budget
+    // is set; no strike limit by default.
+    policy.daily_fee_cap_usd = 0;
+    policy.max_abandoned_sessions_per_hour = 0;
+    // Async-fulfillment programs stay rejected until the owner opts in.
+    policy.allow_async_fulfillment = false;
+    // Minimum-output declarations stay optional for non-Jupiter protocols.
+    policy.max_protocol_slippage_bps = 0;
+    policy.tightened_at_slot = 0;
 
     // Initialize zero-copy tracker (buckets + protocol_counters zero-initialized by allocator)
     let mut tracker = ctx.accounts.tracker.load_init()?;
@@ -164,6 +218,16 @@ pub fn handler(
     overlay.vault = vault.key();
     overlay.bump = ctx.bumps.agent_spend_overlay;
 
+    // Initialize weekly/monthly tracker (buckets zero-initialized by allocator)
+    let mut tracker_extension = ctx.accounts.tracker_extension.load_init()?;
+    tracker_extension.vault = vault.key();
+    tracker_extension.bump = ctx.bumps.tracker_extension;
+
+    // Initialize per-destination tracker (entries zero-initialized by allocator)
+    let mut destination_spend = ctx.accounts.destination_spend.load_init()?;
+    destination_spend.vault = vault.key();
+    destination_spend.bump = ctx.bumps.destination_spend;
+
     emit!(VaultCreated {
         vault: vault.key(),
         owner: ctx.accounts.owner.key(),
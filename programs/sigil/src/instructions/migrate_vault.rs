@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::SigilError;
+use crate::events::VaultMigrated;
+use crate::state::*;
+
+/// Rewrite a vault created by the pre-migration program into the current
+/// layout. Accounts are unchecked because they cannot be deserialized as
+/// their current types; the handler verifies owner program, discriminator
+/// and size before touching them.
+#[derive(Accounts)]
+#[instruction(vault_id: u64)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pre-migration AgentVault. Old vaults never set `creator`; the
+    /// PDA was derived from the owner, so derive it from the signer.
+    #[account(
+        mut,
+        seeds = [b"vault", owner.key().as_ref(), vault_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: pre-migration PolicyConfig, decoded in the handler.
+    #[account(
+        mut,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump,
+    )]
+    pub policy: UncheckedAccount<'info>,
+
+    /// CHECK: pre-migration AgentSpendOverlay, size-checked in the handler.
+    #[account(
+        mut,
+        seeds = [b"agent_spend", vault.key().as_ref(), &[0u8]],
+        bump,
+    )]
+    pub agent_spend_overlay: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow the vault, policy and spend overlay to their current sizes and
+/// rewrite vault and policy, setting `creator = owner`. Pending policy and
+/// agent-permission updates queued under the old layout cannot be read any
+/// more and are closed: the pending policy (when `has_pending_policy`) must
+/// be the first remaining account, followed by any pending_agent_perms PDAs.
+pub fn handler(ctx: Context<MigrateVault>, _vault_id: u64) -> Result<()> {
+    crate::reject_cpi!();
+
+    let clock = Clock::get()?;
+    let owner_key = ctx.accounts.owner.key();
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let policy_info = ctx.accounts.policy.to_account_info();
+    let overlay_info = ctx.accounts.agent_spend_overlay.to_account_info();
+    let vault_key = vault_info.key();
+
+    for info in [&vault_info, &policy_info, &overlay_info] {
+        require!(info.owner == &crate::ID, SigilError::NotLegacyVault);
+    }
+
+    let legacy_vault = LegacyAgentVault::try_from_data(&vault_info.try_borrow_data()?)
+        .ok_or(error!(SigilError::NotLegacyVault))?;
+    require!(
+        legacy_vault.owner == owner_key,
+        SigilError::UnauthorizedOwner
+    );
+    require!(
+        legacy_vault.active_sessions == 0,
+        SigilError::ActiveSessionsExist
+    );
+
+    let legacy_policy = LegacyPolicyConfig::try_from_data(&policy_info.try_borrow_data()?)
+        .ok_or(error!(SigilError::NotLegacyVault))?;
+    require!(legacy_policy.vault == vault_key, SigilError::NotLegacyVault);
+
+    {
+        let data = overlay_info.try_borrow_data()?;
+        require!(is_legacy_overlay(&data), SigilError::NotLegacyVault);
+        require!(
+            data[8..40] == vault_key.to_bytes(),
+            SigilError::NotLegacyVault
+        );
+    }
+
+    // Close pendings queued under the old layout.
+    let owner_info = ctx.accounts.owner.to_account_info();
+    let mut pendings_closed: u8 = 0;
+    let start_idx: usize = if legacy_policy.has_pending_policy {
+        let pending_info = ctx
+            .remaining_accounts
+            .first()
+            .ok_or(error!(SigilError::PendingPolicyExists))?;
+        let (expected_pda, _) =
+            Pubkey::find_program_address(&[b"pending_policy", vault_key.as_ref()], ctx.program_id);
+        require!(
+            pending_info.key() == expected_pda && pending_info.owner == &crate::ID,
+            SigilError::PendingPolicyExists
+        );
+        close_to(&owner_info, pending_info)?;
+        pendings_closed += 1;
+        1
+    } else {
+        0
+    };
+    for agent_entry in legacy_vault.agents.iter() {
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[
+                b"pending_agent_perms",
+                vault_key.as_ref(),
+                agent_entry.pubkey.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        for pending_info in ctx.remaining_accounts.iter().skip(start_idx) {
+            if pending_info.key() == expected_pda
+                && pending_info.owner == &crate::ID
+                && pending_info.lamports() > 0
+            {
+                close_to(&owner_info, pending_info)?;
+                pendings_closed += 1;
+                break;
+            }
+        }
+    }
+
+    let agents = legacy_vault.agents.len() as u8;
+    let vault = legacy_vault.into_current(clock.unix_timestamp);
+    let policy = legacy_policy.into_current();
+
+    let system_program_info = ctx.accounts.system_program.to_account_info();
+    grow(
+        &vault_info,
+        AgentVault::SIZE,
+        &owner_info,
+        &system_program_info,
+    )?;
+    {
+        let mut data = vault_info.try_borrow_mut_data()?;
+        data.fill(0);
+        let mut writer: &mut [u8] = &mut data;
+        vault.try_serialize(&mut writer)?;
+    }
+
+    grow(
+        &policy_info,
+        PolicyConfig::SIZE,
+        &owner_info,
+        &system_program_info,
+    )?;
+    {
+        let mut data = policy_info.try_borrow_mut_data()?;
+        data.fill(0);
+        let mut writer: &mut [u8] = &mut data;
+        policy.try_serialize(&mut writer)?;
+    }
+
+    // Session and abandon counters are appended; zeroes are their reset state.
+    grow(
+        &overlay_info,
+        AgentSpendOverlay::SIZE,
+        &owner_info,
+        &system_program_info,
+    )?;
+
+    emit!(VaultMigrated {
+        vault: vault_key,
+        owner: owner_key,
+        agents,
+        pendings_closed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Drain `info` into `owner` and hand it back to the system program.
+fn close_to(owner: &AccountInfo, info: &AccountInfo) -> Result<()> {
+    let dest_lamports = owner.lamports();
+    **owner.try_borrow_mut_lamports()? = dest_lamports
+        .checked_add(info.lamports())
+        .ok_or(error!(SigilError::Overflow))?;
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&anchor_lang::system_program::ID);
+    info.resize(0)?;
+    Ok(())
+}
+
+/// Realloc `info` to `size` (zero-filled) and top up its rent from `payer`.
+fn grow<'info>(
+    info: &AccountInfo<'info>,
+    size: usize,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+) -> Result<()> {
+    #[allow(deprecated)]
+    info.realloc(size, true)?;
+
+    let new_min_lamports = Rent::get()?.minimum_balance(size);
+    let current_lamports = info.lamports();
+    if new_min_lamports > current_lamports {
+        let diff = new_min_lamports
+            .checked_sub(current_lamports)
+            .ok_or(SigilError::Overflow)?;
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            diff,
+        )?;
+    }
+    Ok(())
+}
@@ -2,36 +2,60 @@ pub mod agent_transfer;
 pub mod allocate_constraints_pda;
 pub mod allocate_pending_constraints_pda;
 pub mod apply_agent_permissions_update;
+pub mod apply_bundle;
 pub mod apply_close_constraints;
 pub mod apply_constraints_update;
+pub mod apply_fee_destination_update;
+pub mod apply_owner_transfer;
 pub mod apply_pending_policy;
+pub mod approve_spend_request;
 pub mod cancel_agent_permissions_update;
 pub mod cancel_close_constraints;
 pub mod cancel_constraints_update;
+pub mod cancel_fee_destination_update;
+pub mod cancel_governance_bundle;
+pub mod cancel_owner_transfer;
 pub mod cancel_pending_policy;
+pub mod cancel_recovery;
+pub mod cancel_spend_request;
+pub mod claim_beneficiary_funds;
 pub mod cleanup_orphan_constraints_pda;
 pub mod close_post_assertions;
 pub mod close_settled_escrow;
 pub mod close_vault;
 pub mod create_escrow;
+pub mod create_governance_bundle;
 pub mod create_instruction_constraints;
 pub mod create_post_assertions;
+pub mod create_spend_request;
 pub mod deposit_funds;
+pub mod execute_recovery;
 pub mod extend_pda;
 pub mod finalize_session;
 pub mod freeze_vault;
+pub mod initialize_destination_tracker;
+pub mod initialize_permit_nonces;
+pub mod initialize_tracker_extension;
 pub mod initialize_vault;
 pub mod integrations;
+pub mod migrate_vault;
 pub mod pause_agent;
 pub mod queue_agent_permissions_update;
 pub mod queue_close_constraints;
 pub mod queue_constraints_update;
+pub mod queue_fee_destination_update;
+pub mod queue_owner_transfer;
 pub mod queue_policy_update;
+pub mod queue_recovery;
 pub mod reactivate_vault;
+pub mod reconcile_fulfillment;
 pub mod refund_escrow;
 pub mod register_agent;
 pub mod revoke_agent;
+pub mod rotate_agent_key;
 pub mod settle_escrow;
+pub mod tighten_policy;
+pub mod trigger_dead_man_switch;
 pub mod unpause_agent;
 pub mod utils;
 pub mod validate_and_authorize;
@@ -42,35 +66,59 @@ pub use agent_transfer::*;
 pub use allocate_constraints_pda::*;
 pub use allocate_pending_constraints_pda::*;
 pub use apply_agent_permissions_update::*;
+pub use apply_bundle::*;
 pub use apply_close_constraints::*;
 pub use apply_constraints_update::*;
+pub use apply_fee_destination_update::*;
+pub use apply_owner_transfer::*;
 pub use apply_pending_policy::*;
+pub use approve_spend_request::*;
 pub use cancel_agent_permissions_update::*;
 pub use cancel_close_constraints::*;
 pub use cancel_constraints_update::*;
+pub use cancel_fee_destination_update::*;
+pub use cancel_governance_bundle::*;
+pub use cancel_owner_transfer::*;
 pub use cancel_pending_policy::*;
+pub use cancel_recovery::*;
+pub use cancel_spend_request::*;
+pub use claim_beneficiary_funds::*;
 pub use cleanup_orphan_constraints_pda::*;
 pub use close_post_assertions::*;
 pub use close_settled_escrow::*;
 pub use close_vault::*;
 pub use create_escrow::*;
+pub use create_governance_bundle::*;
 pub use create_instruction_constraints::*;
 pub use create_post_assertions::*;
+pub use create_spend_request::*;
 pub use deposit_funds::*;
+pub use execute_recovery::*;
 pub use extend_pda::*;
 pub use finalize_session::*;
 pub use freeze_vault::*;
+pub use initialize_destination_tracker::*;
+pub use initialize_permit_nonces::*;
+pub use initialize_tracker_extension::*;
 pub use initialize_vault::*;
+pub use migrate_vault::*;
 pub use pause_agent::*;
 pub use queue_agent_permissions_update::*;
 pub use queue_close_constraints::*;
 pub use queue_constraints_update::*;
+pub use queue_fee_destination_update::*;
+pub use queue_owner_transfer::*;
 pub use queue_policy_update::*;
+pub use queue_recovery::*;
 pub use reactivate_vault::*;
+pub use reconcile_fulfillment::*;
 pub use refund_escrow::*;
 pub use register_agent::*;
 pub use revoke_agent::*;
+pub use rotate_agent_key::*;
 pub use settle_escrow::*;
+pub use tighten_policy::*;
+pub use trigger_dead_man_switch::*;
 pub use unpause_agent::*;
 pub use validate_and_authorize::*;
 pub use withdraw_funds::*;
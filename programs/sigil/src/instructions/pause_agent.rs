@@ -4,23 +4,31 @@ use crate::errors::SigilError;
 use crate::events::AgentPausedEvent;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
 pub struct PauseAgent<'info> {
-    pub owner: Signer<'info>,
+    /// Vault owner or guardian
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        constraint = vault.is_owner_or_guardian(&authority.key())
+            @ SigilError::UnauthorizedOwnerOrGuardian,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
 }
 
 pub fn handler(ctx: Context<PauseAgent>, agent_to_pause: Pubkey) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
 
+    let clock = Clock::get()?;
     let vault = &mut ctx.accounts.vault;
+    if vault.is_owner(&ctx.accounts.authority.key()) {
+        vault.record_owner_activity(clock.unix_timestamp);
+    }
 
     // Works on Active or Frozen vaults (not Closed)
     require!(
@@ -40,7 +48,6 @@ pub fn handler(ctx: Context<PauseAgent>, agent_to_pause: Pubkey) -> Result<()> {
 
     agent_entry.paused = true;
 
-    let clock = Clock::get()?;
     emit!(AgentPausedEvent {
         vault: vault.key(),
         agent: agent_to_pause,
@@ -4,6 +4,8 @@ use crate::errors::SigilError;
 use crate::events::AgentPermissionsChangeQueued;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 #[instruction(agent: Pubkey)]
 pub struct QueueAgentPermissionsUpdate<'info> {
@@ -11,8 +13,9 @@ pub struct QueueAgentPermissionsUpdate<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -36,13 +39,30 @@ pub struct QueueAgentPermissionsUpdate<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<QueueAgentPermissionsUpdate>,
     agent: Pubkey,
     new_capability: u8,
     spending_limit_usd: u64,
+    max_sessions_per_hour: u16,
+    protocols: Option<Vec<Pubkey>>,
+    max_transaction_size_usd: Option<u64>,
+    session_expiry_seconds: Option<u64>,
+    valid_until: Option<i64>,
+    max_concurrent_sessions: Option<u8>,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
 
     let vault = &ctx.accounts.vault;
     let policy = &ctx.accounts.policy;
@@ -70,12 +90,59 @@ pub fn handler(
         SigilError::InvalidPermissions
     );
 
+    if let Some(ref protos) = protocols {
+        require!(
+            protos.len() <= MAX_ALLOWED_PROTOCOLS,
+            SigilError::TooManyAllowedProtocols
+        );
+    }
+
+    // Per-agent overrides (0 = inherit) may only be tighter than the vault
+    if let Some(max_tx) = max_transaction_size_usd {
+        require!(
+            max_tx <= policy.max_transaction_size_usd,
+            SigilError::AgentOverrideNotTighter
+        );
+    }
+    if let Some(expiry) = session_expiry_seconds {
+        if expiry > 0 {
+            require!(
+                (MIN_SESSION_DURATION_SECONDS..=MAX_OWNER_SESSION_DURATION_SECONDS)
+                    .contains(&expiry),
+                SigilError::InvalidSessionExpiry
+            );
+            require!(
+                expiry <= policy.effective_session_expiry_seconds(),
+                SigilError::AgentOverrideNotTighter
+            );
+        }
+    }
+
+    if let Some(limit) = max_concurrent_sessions {
+        require!(
+            limit <= MAX_CONCURRENT_SESSIONS,
+            SigilError::InvalidConcurrentSessionLimit
+        );
+    }
+
     let clock = Clock::get()?;
+    if let Some(ts) = valid_until {
+        require!(
+            pack_valid_until(ts, clock.unix_timestamp).is_some(),
+            SigilError::InvalidAgentExpiry
+        );
+    }
     let pending = &mut ctx.accounts.pending_agent_perms;
     pending.vault = vault.key();
     pending.agent = agent;
     pending.new_capability = new_capability;
     pending.spending_limit_usd = spending_limit_usd;
+    pending.max_sessions_per_hour = max_sessions_per_hour;
+    pending.protocols = protocols;
+    pending.max_transaction_size_usd = max_transaction_size_usd;
+    pending.session_expiry_seconds = session_expiry_seconds;
+    pending.valid_until = valid_until;
+    pending.max_concurrent_sessions = max_concurrent_sessions;
     pending.queued_at = clock.unix_timestamp;
     pending.executes_at = clock
         .unix_timestamp
@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::CloseConstraintsQueued;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 pub struct QueueCloseConstraints<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -44,7 +47,17 @@ pub struct QueueCloseConstraints<'info> {
 }
 
 pub fn handler(ctx: Context<QueueCloseConstraints>) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
 
     let vault = &ctx.accounts.vault;
     let policy = &ctx.accounts.policy;
@@ -7,6 +7,8 @@ use crate::state::constraints::pack_entries;
 use crate::state::pending_constraints::PendingConstraintsUpdate;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 /// Queue a constraints update. The PendingConstraintsUpdate PDA must have been
 /// pre-allocated via `allocate_pending_constraints_pda` + `extend_pda` to reach
 /// `PendingConstraintsUpdate::SIZE` before this instruction is called.
@@ -16,8 +18,9 @@ pub struct QueueConstraintsUpdate<'info> {
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -51,7 +54,17 @@ pub fn handler(
     entries: Vec<ConstraintEntry>,
     strict_mode: bool,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
 
     let policy = &ctx.accounts.policy;
     let vault_key = ctx.accounts.vault.key();
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::FeeDestinationChangeQueued;
+use crate::state::*;
+
+use super::utils::{reject_owner_cpi, require_approvals};
+
+#[derive(Accounts)]
+pub struct QueueFeeDestinationUpdate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = PendingFeeDestinationUpdate::SIZE,
+        seeds = [b"pending_fee_destination", vault.key().as_ref()],
+        bump,
+    )]
+    pub pending_fee_destination: Account<'info, PendingFeeDestinationUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a fee destination rotation. Waits the longer of the policy timelock
+/// and `FEE_DESTINATION_TIMELOCK_SECONDS`, so the current recipient always
+/// has time to react to the queued event.
+pub fn handler(ctx: Context<QueueFeeDestinationUpdate>, new_fee_destination: Pubkey) -> Result<()> {
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .vault
+        .record_owner_activity(clock.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
+
+    let vault = &ctx.accounts.vault;
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    require!(
+        new_fee_destination != Pubkey::default() && new_fee_destination != vault.fee_destination,
+        SigilError::InvalidFeeDestination
+    );
+
+    let delay = ctx
+        .accounts
+        .policy
+        .timelock_duration
+        .max(FEE_DESTINATION_TIMELOCK_SECONDS);
+
+    let pending = &mut ctx.accounts.pending_fee_destination;
+    pending.vault = vault.key();
+    pending.new_fee_destination = new_fee_destination;
+    pending.queued_at = clock.unix_timestamp;
+    pending.executes_at = clock
+        .unix_timestamp
+        .checked_add(delay as i64)
+        .ok_or(error!(SigilError::Overflow))?;
+    // F-10 audit fix: capture queue slot for slot-bounded freshness check.
+    pending.queued_at_slot = clock.slot;
+    pending.bump = ctx.bumps.pending_fee_destination;
+
+    emit!(FeeDestinationChangeQueued {
+        vault: vault.key(),
+        current_fee_destination: vault.fee_destination,
+        new_fee_destination,
+        executes_at: pending.executes_at,
+    });
+
+    Ok(())
+}
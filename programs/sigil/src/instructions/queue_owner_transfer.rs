@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::OwnerTransferQueued;
+use crate::state::*;
+
+use super::utils::{reject_owner_cpi, require_approvals};
+
+#[derive(Accounts)]
+pub struct QueueOwnerTransfer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = PendingOwnerTransfer::SIZE,
+        seeds = [b"pending_owner_transfer", vault.key().as_ref()],
+        bump,
+    )]
+    pub pending_owner_transfer: Account<'info, PendingOwnerTransfer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueOwnerTransfer>, new_owner: Pubkey) -> Result<()> {
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
+
+    let vault = &ctx.accounts.vault;
+    let policy = &ctx.accounts.policy;
+
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    require!(
+        policy.timelock_duration > 0,
+        SigilError::NoTimelockConfigured
+    );
+    require!(
+        new_owner != Pubkey::default()
+            && new_owner != vault.owner
+            && new_owner != vault.guardian
+            && !vault.is_agent(&new_owner),
+        SigilError::InvalidNewOwner
+    );
+
+    let clock = Clock::get()?;
+    let pending = &mut ctx.accounts.pending_owner_transfer;
+    pending.vault = vault.key();
+    pending.new_owner = new_owner;
+    pending.queued_at = clock.unix_timestamp;
+    pending.executes_at = clock
+        .unix_timestamp
+        .checked_add(policy.timelock_duration as i64)
+        .ok_or(error!(SigilError::Overflow))?;
+    // F-10 audit fix: capture queue slot for slot-bounded freshness check.
+    pending.queued_at_slot = clock.slot;
+    pending.bump = ctx.bumps.pending_owner_transfer;
+
+    emit!(OwnerTransferQueued {
+        vault: vault.key(),
+        current_owner: vault.owner,
+        new_owner,
+        executes_at: pending.executes_at,
+    });
+
+    Ok(())
+}
@@ -4,14 +4,17 @@ use crate::errors::SigilError;
 use crate::events::PolicyChangeQueued;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 pub struct QueuePolicyUpdate<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
+        mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -51,12 +54,36 @@ pub fn handler(
     has_protocol_caps: Option<bool>,
     protocol_caps: Option<Vec<u64>>,
     destination_mode: Option<u8>,
+    weekly_spending_cap_usd: Option<u64>,
+    monthly_spending_cap_usd: Option<u64>,
+    mint_caps: Option<Vec<MintSpendCap>>,
+    destination_caps: Option<Vec<u64>>,
+    trading_hours: Option<[u8; TRADING_HOURS_BYTES]>,
+    trading_hours_exempt_non_spending: Option<bool>,
+    guardian: Option<Pubkey>,
+    recovery_authority: Option<Pubkey>,
+    recovery_destination: Option<Pubkey>,
+    inactivity_window_seconds: Option<u64>,
+    beneficiary: Option<Pubkey>,
+    approvers: Option<Vec<Pubkey>>,
+    approval_threshold: Option<u8>,
+    cpi_callers: Option<Vec<Pubkey>>,
+    daily_fee_cap_usd: Option<u64>,
+    max_abandoned_sessions_per_hour: Option<u16>,
+    allow_async_fulfillment: Option<bool>,
+    max_protocol_slippage_bps: Option<u16>,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let vault = &ctx.accounts.vault;
     let policy = &ctx.accounts.policy;
 
+    require_approvals(vault, &ctx.accounts.owner.key(), remaining_accounts)?;
+
     require!(
         vault.status != VaultStatus::Closed,
         SigilError::VaultAlreadyClosed
@@ -99,12 +126,98 @@ pub fn handler(
             SigilError::SlippageBpsTooHigh
         );
     }
+    if let Some(ref slippage) = max_protocol_slippage_bps {
+        require!(
+            *slippage <= MAX_SLIPPAGE_BPS,
+            SigilError::SlippageBpsTooHigh
+        );
+    }
     if let Some(ref destinations) = allowed_destinations {
         require!(
             destinations.len() <= MAX_ALLOWED_DESTINATIONS,
             SigilError::TooManyDestinations
         );
     }
+    if let Some(ref g) = guardian {
+        require!(
+            *g != vault.owner && !vault.is_agent(g),
+            SigilError::InvalidGuardian
+        );
+    }
+    require!(
+        recovery_authority.is_some() == recovery_destination.is_some(),
+        SigilError::InvalidRecoveryConfig
+    );
+    if let (Some(authority), Some(destination)) = (recovery_authority, recovery_destination) {
+        let cleared = authority == Pubkey::default() && destination == Pubkey::default();
+        let set = authority != Pubkey::default()
+            && destination != Pubkey::default()
+            && authority != vault.owner
+            && !vault.is_agent(&authority);
+        require!(cleared || set, SigilError::InvalidRecoveryConfig);
+    }
+    require!(
+        inactivity_window_seconds.is_some() == beneficiary.is_some(),
+        SigilError::InvalidDeadManSwitchConfig
+    );
+    if let (Some(window), Some(beneficiary)) = (inactivity_window_seconds, beneficiary) {
+        require!(
+            !vault.dead_man_triggered,
+            SigilError::DeadManSwitchTriggered
+        );
+        let disabled = window == 0 && beneficiary == Pubkey::default();
+        let armed = window >= MIN_INACTIVITY_WINDOW_SECONDS
+            && beneficiary != Pubkey::default()
+            && !vault.is_agent(&beneficiary);
+        require!(disabled || armed, SigilError::InvalidDeadManSwitchConfig);
+    }
+    require!(
+        approvers.is_some() == approval_threshold.is_some(),
+        SigilError::InvalidApproverConfig
+    );
+    if let (Some(ref approvers), Some(threshold)) = (&approvers, approval_threshold) {
+        let disabled = approvers.is_empty() && threshold == 0;
+        let enabled = approvers.len() <= MAX_APPROVERS
+            && threshold > 0
+            && threshold as usize <= approvers.len()
+            && approvers.iter().enumerate().all(|(i, a)| {
+                *a != Pubkey::default() && !vault.is_agent(a) && !approvers[..i].contains(a)
+            });
+        require!(disabled || enabled, SigilError::InvalidApproverConfig);
+    }
+    if let Some(ref callers) = cpi_callers {
+        require!(
+            callers.len() <= MAX_CPI_CALLERS
+                && callers.iter().enumerate().all(|(i, c)| {
+                    *c != Pubkey::default() && *c != crate::ID && !callers[..i].contains(c)
+                }),
+            SigilError::InvalidCpiCallers
+        );
+    }
+    // A program agents can invoke must not drive owner instructions, or the
+    // re-entry path reopens. Checked against the resulting mode and list,
+    // whichever of callers, mode or protocols this update changes.
+    require!(
+        cpi_callers_unreachable(
+            cpi_callers.as_ref().unwrap_or(&vault.cpi_callers),
+            protocol_mode.unwrap_or(policy.protocol_mode),
+            protocols.as_ref().unwrap_or(&policy.protocols),
+        ),
+        SigilError::InvalidCpiCallers
+    );
+    if let Some(ref caps) = mint_caps {
+        require!(mint_caps_valid(caps), SigilError::InvalidMintCaps);
+    }
+    // These caps live in the SpendTrackerExtension; migrated vaults create it
+    // first with initialize_tracker_extension.
+    let sets_extension_cap = weekly_spending_cap_usd.is_some_and(|c| c > 0)
+        || monthly_spending_cap_usd.is_some_and(|c| c > 0)
+        || mint_caps.as_ref().is_some_and(|c| !c.is_empty())
+        || daily_fee_cap_usd.is_some_and(|c| c > 0);
+    require!(
+        vault.has_tracker_extension || !sets_extension_cap,
+        SigilError::TrackerExtensionRequired
+    );
     if let Some(ref tl) = timelock_duration {
         require!(*tl >= MIN_TIMELOCK_DURATION, SigilError::TimelockTooShort);
     }
@@ -143,6 +256,24 @@ pub fn handler(
         }
     }
 
+    // Validate per-destination caps against the resulting destination list.
+    // When omitted, existing caps follow their destination at apply time.
+    if let Some(ref caps) = destination_caps {
+        let effective_dest_len = allowed_destinations
+            .as_ref()
+            .map_or(policy.allowed_destinations.len(), |d| d.len());
+        require!(
+            caps.is_empty() || caps.len() == effective_dest_len,
+            SigilError::DestinationCapsMismatch
+        );
+        // Caps live in the DestinationSpendTracker; migrated vaults create it
+        // first with initialize_destination_tracker.
+        require!(
+            vault.has_destination_tracker || caps.iter().all(|c| *c == 0),
+            SigilError::DestinationTrackerRequired
+        );
+    }
+
     let clock = Clock::get()?;
     let executes_at = clock
         .unix_timestamp
@@ -167,6 +298,24 @@ pub fn handler(
     pending.has_protocol_caps = has_protocol_caps;
     pending.protocol_caps = protocol_caps;
     pending.destination_mode = destination_mode;
+    pending.weekly_spending_cap_usd = weekly_spending_cap_usd;
+    pending.monthly_spending_cap_usd = monthly_spending_cap_usd;
+    pending.mint_caps = mint_caps;
+    pending.destination_caps = destination_caps;
+    pending.trading_hours = trading_hours;
+    pending.trading_hours_exempt_non_spending = trading_hours_exempt_non_spending;
+    pending.guardian = guardian;
+    pending.recovery_authority = recovery_authority;
+    pending.recovery_destination = recovery_destination;
+    pending.inactivity_window_seconds = inactivity_window_seconds;
+    pending.beneficiary = beneficiary;
+    pending.approvers = approvers;
+    pending.approval_threshold = approval_threshold;
+    pending.cpi_callers = cpi_callers;
+    pending.daily_fee_cap_usd = daily_fee_cap_usd;
+    pending.max_abandoned_sessions_per_hour = max_abandoned_sessions_per_hour;
+    pending.allow_async_fulfillment = allow_async_fulfillment;
+    pending.max_protocol_slippage_bps = max_protocol_slippage_bps;
     pending.bump = ctx.bumps.pending_policy;
 
     ctx.accounts.policy.has_pending_policy = true;
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::{RecoveryQueued, VaultFrozen};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct QueueRecovery<'info> {
+    #[account(mut)]
+    pub recovery_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.has_recovery()
+            && vault.recovery_authority == recovery_authority.key()
+            @ SigilError::UnauthorizedRecoveryAuthority,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        init,
+        payer = recovery_authority,
+        space = PendingRecovery::SIZE,
+        seeds = [b"pending_recovery", vault.key().as_ref()],
+        bump,
+    )]
+    pub pending_recovery: Account<'info, PendingRecovery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a full sweep to the registered recovery destination. Freezes the
+/// vault immediately so no agent can act while the owner decides whether
+/// to cancel. In-flight sessions are bounded by the session expiry, far
+/// shorter than the recovery timelock, so no delegation revoke is needed.
+pub fn handler(ctx: Context<QueueRecovery>) -> Result<()> {
+    crate::reject_cpi!();
+
+    let clock = Clock::get()?;
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let pending = &mut ctx.accounts.pending_recovery;
+    pending.vault = vault.key();
+    pending.recovery_authority = ctx.accounts.recovery_authority.key();
+    pending.destination = vault.recovery_destination;
+    pending.queued_at = clock.unix_timestamp;
+    pending.executes_at = clock
+        .unix_timestamp
+        .checked_add(RECOVERY_TIMELOCK_SECONDS)
+        .ok_or(error!(SigilError::Overflow))?;
+    // F-10 audit fix: capture queue slot for slot-bounded freshness check.
+    pending.queued_at_slot = clock.slot;
+    pending.bump = ctx.bumps.pending_recovery;
+
+    if vault.is_active() {
+        vault.status = VaultStatus::Frozen;
+        emit!(VaultFrozen {
+            vault: vault.key(),
+            owner: vault.owner,
+            frozen_by: ctx.accounts.recovery_authority.key(),
+            agents_preserved: vault.agent_count() as u8,
+            sessions_revoked: 0,
+            reason: FREEZE_REASON_RECOVERY,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(RecoveryQueued {
+        vault: vault.key(),
+        recovery_authority: pending.recovery_authority,
+        destination: pending.destination,
+        executes_at: pending.executes_at,
+    });
+
+    Ok(())
+}
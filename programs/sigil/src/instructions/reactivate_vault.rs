@@ -4,6 +4,8 @@ use crate::errors::SigilError;
 use crate::events::VaultReactivated;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 pub struct ReactivateVault<'info> {
     pub owner: Signer<'info>,
@@ -11,7 +13,7 @@ pub struct ReactivateVault<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -22,15 +24,32 @@ pub fn handler(
     new_agent: Option<Pubkey>,
     new_agent_capability: Option<u8>,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    // Adding an agent is gated like register_agent; a plain unfreeze is not.
+    if new_agent.is_some() {
+        require_approvals(
+            &ctx.accounts.vault,
+            &ctx.accounts.owner.key(),
+            remaining_accounts,
+        )?;
+    }
 
     let vault = &mut ctx.accounts.vault;
 
-    // 1. Check frozen
+    // 1. Check frozen (a fired dead-man switch is terminal)
     require!(
         vault.status == VaultStatus::Frozen,
         SigilError::VaultNotFrozen
     );
+    require!(
+        !vault.dead_man_triggered,
+        SigilError::DeadManSwitchTriggered
+    );
 
     // 2. Validate mutual presence of new_agent and new_agent_capability
     require!(
@@ -43,6 +62,11 @@ pub fn handler(
         let capability = new_agent_capability.unwrap();
         require!(agent_key != Pubkey::default(), SigilError::InvalidAgentKey);
         require!(agent_key != vault.owner, SigilError::AgentIsOwner);
+        require!(agent_key != vault.guardian, SigilError::InvalidGuardian);
+        require!(
+            agent_key != vault.recovery_authority,
+            SigilError::InvalidRecoveryConfig
+        );
         require!(
             capability <= FULL_CAPABILITY,
             SigilError::InvalidPermissions
@@ -58,9 +82,13 @@ pub fn handler(
         vault.agents.push(AgentEntry {
             pubkey: agent_key,
             capability,
-            _reserved: [0u8; 7],
             spending_limit_usd: 0, // reactivation agent starts with no per-agent limit
             paused: false,
+            max_sessions_per_hour: 0,
+            has_settings: false,
+            valid_until: 0,
+            max_concurrent_sessions: 0,
+            active_sessions: 0,
         });
     }
 
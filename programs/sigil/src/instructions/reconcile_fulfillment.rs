@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use anchor_lang::accounts::account_loader::AccountLoader;
+
+use crate::errors::SigilError;
+use crate::events::FulfillmentReconciled;
+use crate::state::*;
+
+/// Settles an async-fulfillment reservation once its window has passed.
+/// Anyone may close it, which keeps the full reservation recorded until it
+/// ages out of the rolling windows. Only the owner's reconcile releases the
+/// part that did not leave the vault, since the balance cannot rule out an
+/// untracked inflow masking a later keeper outflow.
+#[derive(Accounts)]
+pub struct ReconcileFulfillment<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault @ SigilError::InvalidPendingFulfillment,
+        seeds = [b"pending_fulfillment", vault.key().as_ref(), pending_fulfillment.session.as_ref()],
+        bump = pending_fulfillment.bump,
+        close = rent_recipient,
+    )]
+    pub pending_fulfillment: Account<'info, PendingFulfillment>,
+
+    /// CHECK: constrained to the agent that paid for the reservation.
+    #[account(mut, address = pending_fulfillment.agent @ SigilError::InvalidPendingFulfillment)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tracker", vault.key().as_ref()],
+        bump = tracker.load()?.bump,
+    )]
+    pub tracker: AccountLoader<'info, SpendTracker>,
+
+    #[account(
+        mut,
+        seeds = [b"agent_spend", vault.key().as_ref(), &[0u8]],
+        bump = agent_spend_overlay.load()?.bump,
+    )]
+    pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
+
+    /// Required when the reservation was recorded in the extension.
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
+    #[account(address = pending_fulfillment.token_account @ SigilError::InvalidTokenAccount)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<ReconcileFulfillment>) -> Result<()> {
+    crate::reject_cpi!();
+
+    let clock = Clock::get()?;
+    let pending = &ctx.accounts.pending_fulfillment;
+    require!(
+        clock.unix_timestamp >= pending.expires_at,
+        SigilError::FulfillmentWindowOpen
+    );
+
+    let by_owner = ctx.accounts.caller.key() == ctx.accounts.vault.owner;
+    let used = if by_owner {
+        pending.used_usd(
+            ctx.accounts.vault_token_account.amount,
+            ctx.accounts.vault.total_deposited_usd,
+        )
+    } else {
+        pending.reserved_usd
+    };
+    let released = pending.reserved_usd.saturating_sub(used);
+
+    if released > 0 {
+        let mut tracker = ctx.accounts.tracker.load_mut()?;
+        tracker.release_spend(pending.reserved_at, released);
+        if pending.recorded_protocol_spend {
+            tracker.release_protocol_spend(pending.reserved_at, &pending.protocol, released);
+        }
+        drop(tracker);
+
+        if pending.recorded_in_overlay {
+            let mut overlay = ctx.accounts.agent_spend_overlay.load_mut()?;
+            // Slot may have been released (agent revoked) since the reservation.
+            if let Some(slot) = overlay.find_agent_slot(&pending.agent) {
+                overlay.release_agent_contribution(pending.reserved_at, slot, released);
+            }
+        }
+
+        if pending.recorded_in_extension {
+            let ext = ctx
+                .accounts
+                .tracker_extension
+                .as_ref()
+                .ok_or(error!(SigilError::TrackerExtensionRequired))?;
+            ext.load_mut()?
+                .release_spend(pending.reserved_at, &pending.token_mint, released);
+        }
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    if by_owner {
+        vault.record_owner_activity(clock.unix_timestamp);
+    }
+    vault.open_fulfillments = vault.open_fulfillments.saturating_sub(1);
+
+    emit!(FulfillmentReconciled {
+        vault: vault.key(),
+        agent: pending.agent,
+        session: pending.session,
+        reserved_usd: pending.reserved_usd,
+        used_usd: used,
+        released_usd: released,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -17,7 +17,7 @@ pub struct RefundEscrow<'info> {
         constraint = source_vault.is_agent(&source_signer.key())
             || source_vault.owner == source_signer.key()
             @ SigilError::UnauthorizedAgent,
-        seeds = [b"vault", source_vault.owner.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", source_vault.creator.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
         bump = source_vault.bump,
     )]
     pub source_vault: Account<'info, AgentVault>,
@@ -67,12 +67,16 @@ pub fn handler(ctx: Context<RefundEscrow>) -> Result<()> {
     let source_vault = &ctx.accounts.source_vault;
     let clock = Clock::get()?;
 
-    // 1. If signer is agent (not owner), check pause status and RefundEscrow permission
+    // 1. If signer is agent (not owner), check pause status, expiry and RefundEscrow permission
     if source_vault.owner != ctx.accounts.source_signer.key() {
         require!(
             !source_vault.is_agent_paused(&ctx.accounts.source_signer.key()),
             SigilError::AgentPaused
         );
+        require!(
+            !source_vault.is_agent_expired(&ctx.accounts.source_signer.key(), clock.unix_timestamp),
+            SigilError::AgentKeyExpired
+        );
         require!(
             source_vault.has_capability(&ctx.accounts.source_signer.key(), true),
             SigilError::InsufficientPermissions
@@ -141,6 +145,9 @@ pub fn handler(ctx: Context<RefundEscrow>) -> Result<()> {
     // 7b. Decrement source vault escrow counter
     let source_vault = &mut ctx.accounts.source_vault;
     source_vault.active_escrow_count = source_vault.active_escrow_count.saturating_sub(1);
+    if source_vault.is_owner(&ctx.accounts.source_signer.key()) {
+        source_vault.record_owner_activity(clock.unix_timestamp);
+    }
 
     // 8. Emit event
     emit!(EscrowRefunded {
@@ -4,6 +4,8 @@ use crate::errors::SigilError;
 use crate::events::AgentRegistered;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 pub struct RegisterAgent<'info> {
     pub owner: Signer<'info>,
@@ -11,7 +13,7 @@ pub struct RegisterAgent<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -30,8 +32,20 @@ pub fn handler(
     agent: Pubkey,
     capability: u8,
     spending_limit_usd: u64,
+    max_sessions_per_hour: u16,
+    valid_until: Option<i64>,
 ) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
 
     let vault = &mut ctx.accounts.vault;
 
@@ -50,42 +64,257 @@ pub fn handler(
     );
     require!(agent != Pubkey::default(), SigilError::InvalidAgentKey);
     require!(agent != vault.owner, SigilError::AgentIsOwner);
+    require!(agent != vault.guardian, SigilError::InvalidGuardian);
+    require!(
+        agent != vault.recovery_authority,
+        SigilError::InvalidRecoveryConfig
+    );
+
+    let clock = Clock::get()?;
+    let valid_until = pack_valid_until(valid_until.unwrap_or(0), clock.unix_timestamp)
+        .ok_or(error!(SigilError::InvalidAgentExpiry))?;
 
     vault.agents.push(AgentEntry {
         pubkey: agent,
         capability,
-        _reserved: [0u8; 7],
         spending_limit_usd,
         paused: false,
+        max_sessions_per_hour,
+        has_settings: false,
+        valid_until,
+        max_concurrent_sessions: 0,
+        active_sessions: 0,
     });
 
     // Claim a slot in the overlay for per-agent tracking.
-    // Fail-closed: if spending_limit_usd > 0 but no slot available,
-    // reject registration to guarantee per-agent limits are enforced.
+    // Fail-closed: if a spending limit or session rate limit is set but no
+    // slot is available, reject registration to guarantee it is enforced.
     if let Ok(mut overlay) = ctx.accounts.agent_spend_overlay.load_mut() {
         if overlay.find_agent_slot(&agent).is_none() {
             match overlay.claim_slot(&agent) {
                 Some(_) => {} // slot claimed successfully
                 None => {
-                    if spending_limit_usd > 0 {
+                    if spending_limit_usd > 0 || max_sessions_per_hour > 0 {
                         // Remove the agent we just pushed — no slot to enforce limit
                         vault.agents.retain(|a| a.pubkey != agent);
                         return Err(error!(SigilError::OverlaySlotExhausted));
                     }
-                    // spending_limit_usd == 0: no per-agent limit needed, continue
+                    // No per-agent limits configured, continue
                 }
             }
         }
     }
 
-    let clock = Clock::get()?;
     emit!(AgentRegistered {
         vault: vault.key(),
         agent,
         capability,
         spending_limit_usd,
+        max_sessions_per_hour,
+        valid_until: valid_until as i64,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use std::collections::BTreeSet;
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// Top-level instruction with a fixed clock.
+    struct Runtime;
+
+    impl SyscallStubs for Runtime {
+        fn sol_get_stack_height(&self) -> u64 {
+            anchor_lang::solana_program::instruction::TRANSACTION_LEVEL_STACK_HEIGHT as u64
+        }
+
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW,
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            0
+        }
+    }
+
+    fn vault(owner: Pubkey, approvers: Vec<Pubkey>, approval_threshold: u8) -> AgentVault {
+        AgentVault {
+            owner,
+            vault_id: 0,
+            agents: Vec::new(),
+            fee_destination: Pubkey::new_unique(),
+            status: VaultStatus::Active,
+            bump: 0,
+            created_at: NOW,
+            total_transactions: 0,
+            total_volume: 0,
+            active_escrow_count: 0,
+            total_fees_collected: 0,
+            total_deposited_usd: 0,
+            total_withdrawn_usd: 0,
+            total_failed_transactions: 0,
+            active_sessions: 0,
+            guardian: Pubkey::default(),
+            creator: owner,
+            recovery_authority: Pubkey::default(),
+            recovery_destination: Pubkey::default(),
+            last_owner_activity: NOW,
+            inactivity_window_seconds: 0,
+            beneficiary: Pubkey::default(),
+            dead_man_triggered: false,
+            approvers,
+            approval_threshold,
+            cpi_callers: Vec::new(),
+            open_fulfillments: 0,
+            has_tracker_extension: true,
+            open_bundle: false,
+            has_destination_tracker: true,
+            owner_changed_at_slot: 0,
+        }
+    }
+
+    /// Run `register_agent` against `vault` with `cosigners` passed as
+    /// signing remaining accounts. Returns the handler result and the
+    /// number of agents registered afterwards.
+    fn register(mut vault: AgentVault, cosigners: &[Pubkey]) -> (Result<()>, usize) {
+        set_syscall_stubs(Box::new(Runtime));
+
+        let program_id = crate::ID;
+        let owner = vault.owner;
+        let (vault_key, vault_bump) = Pubkey::find_program_address(
+            &[
+                b"vault",
+                vault.creator.as_ref(),
+                &vault.vault_id.to_le_bytes(),
+            ],
+            &program_id,
+        );
+        vault.bump = vault_bump;
+        let (overlay_key, overlay_bump) = Pubkey::find_program_address(
+            &[b"agent_spend", vault_key.as_ref(), &[0u8]],
+            &program_id,
+        );
+
+        let mut vault_data = Vec::new();
+        vault.try_serialize(&mut vault_data).unwrap();
+
+        // Zero-copy accounts must be 8-byte aligned.
+        let overlay_len = 8 + core::mem::size_of::<AgentSpendOverlay>();
+        let mut overlay_words = vec![0u64; overlay_len.div_ceil(8)];
+        let overlay_data: &mut [u8] = bytemuck::cast_slice_mut(&mut overlay_words);
+        overlay_data[..8].copy_from_slice(AgentSpendOverlay::DISCRIMINATOR);
+        {
+            let overlay: &mut AgentSpendOverlay = bytemuck::from_bytes_mut(
+                &mut overlay_data[8..8 + core::mem::size_of::<AgentSpendOverlay>()],
+            );
+            overlay.vault = vault_key;
+            overlay.bump = overlay_bump;
+        }
+
+        let system = Pubkey::default();
+        let mut lamports = vec![1_000_000_000u64; 3 + cosigners.len()];
+        let mut lamports = lamports.iter_mut();
+        let mut empty: Vec<Vec<u8>> = vec![Vec::new(); 1 + cosigners.len()];
+        let mut empty = empty.iter_mut();
+
+        let mut infos = vec![
+            AccountInfo::new(
+                &owner,
+                true,
+                false,
+                lamports.next().unwrap(),
+                empty.next().unwrap(),
+                &system,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &vault_key,
+                false,
+                true,
+                lamports.next().unwrap(),
+                &mut vault_data,
+                &program_id,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &overlay_key,
+                false,
+                true,
+                lamports.next().unwrap(),
+                overlay_data,
+                &program_id,
+                false,
+                0,
+            ),
+        ];
+        for cosigner in cosigners {
+            infos.push(AccountInfo::new(
+                cosigner,
+                true,
+                false,
+                lamports.next().unwrap(),
+                empty.next().unwrap(),
+                &system,
+                false,
+                0,
+            ));
+        }
+
+        let mut remaining: &[AccountInfo] = &infos;
+        let mut bumps = RegisterAgentBumps::default();
+        let mut reallocs = BTreeSet::new();
+        let mut accounts = RegisterAgent::try_accounts(
+            &program_id,
+            &mut remaining,
+            &[],
+            &mut bumps,
+            &mut reallocs,
+        )
+        .unwrap();
+        let result = handler(
+            Context::new(&program_id, &mut accounts, remaining, bumps),
+            Pubkey::new_unique(),
+            FULL_CAPABILITY,
+            1_000_000,
+            0,
+            None,
+        );
+        let agents = accounts.vault.agents.len();
+        (result, agents)
+    }
+
+    #[test]
+    fn register_agent_rejected_below_approval_threshold() {
+        let owner = Pubkey::new_unique();
+        let approvers = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let (result, agents) = register(vault(owner, approvers.clone(), 2), &approvers[..1]);
+        assert_eq!(
+            result.unwrap_err(),
+            error!(SigilError::InsufficientApprovals)
+        );
+        assert_eq!(agents, 0);
+
+        let (result, agents) = register(vault(owner, approvers.clone(), 2), &approvers);
+        assert!(result.is_ok());
+        assert_eq!(agents, 1);
+    }
+
+    #[test]
+    fn register_agent_without_quorum_needs_only_owner() {
+        let owner = Pubkey::new_unique();
+        let (result, agents) = register(vault(owner, Vec::new(), 0), &[]);
+        assert!(result.is_ok());
+        assert_eq!(agents, 1);
+    }
+}
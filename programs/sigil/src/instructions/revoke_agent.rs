@@ -5,14 +5,18 @@ use crate::errors::SigilError;
 use crate::events::AgentRevoked;
 use crate::state::*;
 
+use super::utils::reject_owner_cpi;
+
 #[derive(Accounts)]
+#[instruction(agent_to_remove: Pubkey)]
 pub struct RevokeAgent<'info> {
+    #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -24,10 +28,24 @@ pub struct RevokeAgent<'info> {
         bump = agent_spend_overlay.load()?.bump,
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
+
+    /// Per-agent settings — closed with the agent so a re-registered key
+    /// starts from the vault policy. Optional for agents that never had one.
+    #[account(
+        mut,
+        seeds = [b"agent_settings", vault.key().as_ref(), agent_to_remove.as_ref()],
+        bump = agent_settings.bump,
+        close = owner,
+    )]
+    pub agent_settings: Option<Account<'info, AgentSettings>>,
 }
 
 pub fn handler(ctx: Context<RevokeAgent>, agent_to_remove: Pubkey) -> Result<()> {
-    crate::reject_cpi!();
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
 
     let vault = &mut ctx.accounts.vault;
 
@@ -0,0 +1,145 @@
+use anchor_lang::accounts::account_loader::AccountLoader;
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::AgentKeyRotated;
+use crate::state::*;
+
+use super::utils::{reject_owner_cpi, require_approvals};
+
+#[derive(Accounts)]
+#[instruction(old_agent: Pubkey, new_agent: Pubkey)]
+pub struct RotateAgentKey<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ SigilError::UnauthorizedOwner,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    /// Agent spend overlay — slot is re-keyed in place, history preserved.
+    #[account(
+        mut,
+        seeds = [b"agent_spend", vault.key().as_ref(), &[0u8]],
+        bump = agent_spend_overlay.load()?.bump,
+    )]
+    pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
+
+    /// Optional co-signature from the outgoing key, proving the rotation
+    /// was not forced on a key the operator still controls.
+    pub old_agent_signer: Option<Signer<'info>>,
+
+    /// Outgoing key's settings. Required when the agent has settings;
+    /// closed after its contents move to `new_agent_settings`.
+    #[account(
+        mut,
+        seeds = [b"agent_settings", vault.key().as_ref(), old_agent.as_ref()],
+        bump = old_agent_settings.bump,
+        close = owner,
+    )]
+    pub old_agent_settings: Option<Account<'info, AgentSettings>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AgentSettings::SIZE,
+        seeds = [b"agent_settings", vault.key().as_ref(), new_agent.as_ref()],
+        bump,
+    )]
+    pub new_agent_settings: Option<Account<'info, AgentSettings>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace an agent's signing key in place. Capability, limits, expiry and
+/// the overlay slot (rolling contributions, lifetime stats) carry over, so
+/// rotation cannot be used to reset the per-agent 24h limit. The agent must
+/// have no open sessions.
+pub fn handler(ctx: Context<RotateAgentKey>, old_agent: Pubkey, new_agent: Pubkey) -> Result<()> {
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    require!(new_agent != Pubkey::default(), SigilError::InvalidAgentKey);
+    require!(new_agent != vault.owner, SigilError::AgentIsOwner);
+    require!(new_agent != vault.guardian, SigilError::InvalidGuardian);
+    require!(
+        new_agent != vault.recovery_authority,
+        SigilError::InvalidRecoveryConfig
+    );
+    require!(
+        !vault.is_agent(&new_agent),
+        SigilError::AgentAlreadyRegistered
+    );
+
+    let old_agent_cosigned = match ctx.accounts.old_agent_signer.as_ref() {
+        Some(signer) => {
+            require_keys_eq!(signer.key(), old_agent, SigilError::UnauthorizedAgent);
+            true
+        }
+        None => false,
+    };
+
+    let entry = vault
+        .agents
+        .iter_mut()
+        .find(|a| a.pubkey == old_agent)
+        .ok_or(error!(SigilError::UnauthorizedAgent))?;
+    // Open sessions are keyed to the old agent; they must finalize first so
+    // the concurrent-session count carries over exactly.
+    require!(entry.active_sessions == 0, SigilError::AgentSessionsOpen);
+
+    // Settings PDAs are keyed by agent, so they move rather than re-key.
+    match (
+        ctx.accounts.old_agent_settings.as_ref(),
+        ctx.accounts.new_agent_settings.as_mut(),
+    ) {
+        (Some(old), Some(new)) => {
+            new.vault = old.vault;
+            new.agent = new_agent;
+            new.protocols = old.protocols.clone();
+            new.max_transaction_size_usd = old.max_transaction_size_usd;
+            new.session_expiry_seconds = old.session_expiry_seconds;
+            new.bump = ctx.bumps.new_agent_settings.unwrap_or_default();
+        }
+        (None, None) => {
+            require!(!entry.has_settings, SigilError::AgentSettingsRequired);
+        }
+        _ => return Err(error!(SigilError::AgentSettingsRequired)),
+    }
+
+    entry.pubkey = new_agent;
+
+    if let Ok(mut overlay) = ctx.accounts.agent_spend_overlay.load_mut() {
+        overlay.rekey_slot(&old_agent, &new_agent);
+    }
+
+    let clock = Clock::get()?;
+    emit!(AgentKeyRotated {
+        vault: vault.key(),
+        old_agent,
+        new_agent,
+        old_agent_cosigned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -21,14 +21,14 @@ pub struct SettleEscrow<'info> {
 
     #[account(
         constraint = destination_vault.is_agent(&destination_agent.key()) @ SigilError::UnauthorizedAgent,
-        seeds = [b"vault", destination_vault.owner.as_ref(), destination_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", destination_vault.creator.as_ref(), destination_vault.vault_id.to_le_bytes().as_ref()],
         bump = destination_vault.bump,
     )]
     pub destination_vault: Account<'info, AgentVault>,
 
     #[account(
         mut,
-        seeds = [b"vault", source_vault.owner.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", source_vault.creator.as_ref(), source_vault.vault_id.to_le_bytes().as_ref()],
         bump = source_vault.bump,
     )]
     pub source_vault: Account<'info, AgentVault>,
@@ -78,13 +78,19 @@ pub fn handler(ctx: Context<SettleEscrow>, proof: Vec<u8>) -> Result<()> {
     let escrow = &ctx.accounts.escrow;
     let clock = Clock::get()?;
 
-    // 0b. Destination agent must not be paused
+    // 0b. Destination agent must not be paused or expired
     require!(
         !ctx.accounts
             .destination_vault
             .is_agent_paused(&ctx.accounts.destination_agent.key()),
         SigilError::AgentPaused
     );
+    require!(
+        !ctx.accounts
+            .destination_vault
+            .is_agent_expired(&ctx.accounts.destination_agent.key(), clock.unix_timestamp),
+        SigilError::AgentKeyExpired
+    );
 
     // 1. Permission check
     require!(
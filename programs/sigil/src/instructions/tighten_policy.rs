@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::PolicyTightened;
+use crate::state::*;
+
+use super::utils::reject_owner_cpi;
+
+#[derive(Accounts)]
+pub struct TightenPolicy<'info> {
+    /// Vault owner or guardian
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.is_owner_or_guardian(&authority.key())
+            @ SigilError::UnauthorizedOwnerOrGuardian,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"policy", vault.key().as_ref()],
+        bump = policy.bump,
+    )]
+    pub policy: Account<'info, PolicyConfig>,
+}
+
+/// Apply risk-reducing policy changes immediately, bypassing the timelock.
+/// Callable by the owner or the guardian.
+/// Every provided field must provably tighten the current policy; a single
+/// loosening field rejects the whole instruction. A queued update (if any)
+/// can no longer be applied; the owner cancels and re-queues it.
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<TightenPolicy>,
+    daily_spending_cap_usd: Option<u64>,
+    max_transaction_amount_usd: Option<u64>,
+    protocols: Option<Vec<Pubkey>>,
+    max_slippage_bps: Option<u16>,
+    allowed_destinations: Option<Vec<Pubkey>>,
+    session_expiry_seconds: Option<u64>,
+    protocol_caps: Option<Vec<u64>>,
+    destination_mode: Option<u8>,
+    weekly_spending_cap_usd: Option<u64>,
+    monthly_spending_cap_usd: Option<u64>,
+    mint_caps: Option<Vec<MintSpendCap>>,
+    destination_caps: Option<Vec<u64>>,
+) -> Result<()> {
+    reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    if ctx.accounts.vault.is_owner(&ctx.accounts.authority.key()) {
+        ctx.accounts
+            .vault
+            .record_owner_activity(clock.unix_timestamp);
+    }
+
+    require!(
+        ctx.accounts.vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+
+    let policy = &mut ctx.accounts.policy;
+
+    if let Some(cap) = daily_spending_cap_usd {
+        require!(
+            cap <= policy.daily_spending_cap_usd,
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.daily_spending_cap_usd = cap;
+    }
+
+    if let Some(max_tx) = max_transaction_amount_usd {
+        require!(
+            max_tx <= policy.max_transaction_size_usd,
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.max_transaction_size_usd = max_tx;
+    }
+
+    if let Some(cap) = weekly_spending_cap_usd {
+        require!(
+            limit_tightens(policy.weekly_spending_cap_usd, cap),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.weekly_spending_cap_usd = cap;
+    }
+
+    if let Some(cap) = monthly_spending_cap_usd {
+        require!(
+            limit_tightens(policy.monthly_spending_cap_usd, cap),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.monthly_spending_cap_usd = cap;
+    }
+
+    if let Some(caps) = mint_caps {
+        require!(mint_caps_valid(&caps), SigilError::InvalidMintCaps);
+        require!(
+            policy.mint_caps_tighten(&caps),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.mint_caps = caps;
+    }
+
+    if let Some(slippage) = max_slippage_bps {
+        require!(
+            slippage <= policy.max_slippage_bps,
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.max_slippage_bps = slippage;
+    }
+
+    if let Some(expiry) = session_expiry_seconds {
+        if expiry > 0 {
+            require!(
+                (MIN_SESSION_DURATION_SECONDS..=MAX_OWNER_SESSION_DURATION_SECONDS)
+                    .contains(&expiry),
+                SigilError::InvalidSessionExpiry
+            );
+        }
+        require!(
+            policy.session_expiry_tightens(expiry),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.session_expiry_seconds = expiry;
+    }
+
+    // Destinations: mode and list are judged together so that switching to
+    // Restricted can carry a fresh list in the same instruction. Existing
+    // per-destination caps follow their destination.
+    if destination_mode.is_some() || allowed_destinations.is_some() {
+        let new_mode = destination_mode.unwrap_or(policy.destination_mode);
+        require!(
+            new_mode <= DESTINATION_MODE_OPEN_WITH_CAP,
+            SigilError::InvalidDestinationMode
+        );
+        let new_destinations =
+            allowed_destinations.unwrap_or_else(|| policy.allowed_destinations.clone());
+        require!(
+            new_destinations.len() <= MAX_ALLOWED_DESTINATIONS,
+            SigilError::TooManyDestinations
+        );
+        require!(
+            policy.destinations_tighten(new_mode, &new_destinations),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.destination_caps = policy.realigned_destination_caps(&new_destinations);
+        policy.destination_mode = new_mode;
+        policy.allowed_destinations = new_destinations;
+    }
+
+    if let Some(caps) = destination_caps {
+        require!(
+            caps.len() == policy.allowed_destinations.len(),
+            SigilError::DestinationCapsMismatch
+        );
+        // An empty current list means every destination is uncapped.
+        let current = if policy.destination_caps.is_empty() {
+            vec![0; caps.len()]
+        } else {
+            policy.destination_caps.clone()
+        };
+        require!(
+            current
+                .iter()
+                .zip(caps.iter())
+                .all(|(old, new)| limit_tightens(*old, *new)),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.destination_caps = caps;
+    }
+
+    // Protocols: existing caps follow their protocol so a shrunk allowlist
+    // keeps caps index-aligned.
+    if let Some(protos) = protocols {
+        require!(
+            protos.len() <= MAX_ALLOWED_PROTOCOLS,
+            SigilError::TooManyAllowedProtocols
+        );
+        require!(
+            policy.protocols_tighten(&protos),
+            SigilError::PolicyChangeNotTightening
+        );
+        if policy.has_protocol_caps {
+            policy.protocol_caps = policy.realigned_protocol_caps(&protos);
+        }
+        policy.protocols = protos;
+        // Tightening only shrinks the programs agents reach, but re-check
+        // against the resulting list rather than rely on that.
+        require!(
+            cpi_callers_unreachable(
+                &ctx.accounts.vault.cpi_callers,
+                policy.protocol_mode,
+                &policy.protocols,
+            ),
+            SigilError::InvalidCpiCallers
+        );
+    }
+
+    if let Some(caps) = protocol_caps {
+        require!(
+            policy.has_protocol_caps && caps.len() == policy.protocols.len(),
+            SigilError::ProtocolCapsMismatch
+        );
+        require!(
+            policy
+                .protocol_caps
+                .iter()
+                .zip(caps.iter())
+                .all(|(old, new)| limit_tightens(*old, *new)),
+            SigilError::PolicyChangeNotTightening
+        );
+        policy.protocol_caps = caps;
+    }
+
+    policy.tightened_at_slot = clock.slot;
+    policy.policy_version = policy
+        .policy_version
+        .checked_add(1)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    emit!(PolicyTightened {
+        vault: ctx.accounts.vault.key(),
+        policy_version: policy.policy_version,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+use crate::events::VaultFrozen;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct TriggerDeadManSwitch<'info> {
+    /// Anyone — the crank is permissionless once the owner is inactive.
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, AgentVault>,
+}
+
+/// Fire the dead-man switch after `inactivity_window_seconds` without an
+/// owner-signed instruction. Freezes the vault (if still active) and routes
+/// all future withdrawals to the beneficiary. Also fires on a vault the owner
+/// froze before going silent.
+pub fn handler(ctx: Context<TriggerDeadManSwitch>) -> Result<()> {
+    crate::reject_cpi!();
+
+    let clock = Clock::get()?;
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.status != VaultStatus::Closed,
+        SigilError::VaultAlreadyClosed
+    );
+    require!(
+        !vault.dead_man_triggered,
+        SigilError::DeadManSwitchTriggered
+    );
+    require!(
+        vault.is_owner_inactive(clock.unix_timestamp),
+        SigilError::OwnerNotInactive
+    );
+
+    vault.status = VaultStatus::Frozen;
+    vault.dead_man_triggered = true;
+
+    emit!(VaultFrozen {
+        vault: vault.key(),
+        owner: vault.owner,
+        frozen_by: ctx.accounts.caller.key(),
+        agents_preserved: vault.agent_count() as u8,
+        sessions_revoked: 0,
+        reason: FREEZE_REASON_DEAD_MAN_SWITCH,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
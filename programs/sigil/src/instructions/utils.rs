@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::solana_program::sysvar::instructions::{
+    get_instruction_relative, load_instruction_at_checked,
+};
+use solana_program::ed25519_program;
 
 use crate::errors::SigilError;
 use crate::state::*;
@@ -18,6 +24,9 @@ use crate::state::*;
 /// nested. Re-entry into any other state-mutating handler bypasses it
 /// unless that handler has its own guard.
 ///
+/// Owner-path handlers use `reject_owner_cpi` instead, which additionally
+/// accepts CPIs from programs on the vault's `cpi_callers` allowlist.
+///
 /// Fully-qualified paths so handlers don't need to import
 /// `get_stack_height`, `TRANSACTION_LEVEL_STACK_HEIGHT`, or
 /// `SigilError` just to use the macro.
@@ -40,6 +49,45 @@ macro_rules! reject_cpi {
     };
 }
 
+/// Owner-path variant of `reject_cpi!`: a CPI is accepted when the
+/// transaction's top-level program is on the vault's owner-set
+/// `cpi_callers` allowlist. Agent-path handlers keep `reject_cpi!`.
+///
+/// Only a direct CPI from the top-level caller is accepted; deeper nesting
+/// would let any program the caller invokes act as the owner.
+///
+/// A CPI caller must append the instructions sysvar as the last remaining
+/// account. Returns the remaining accounts without it, so handlers that
+/// parse `remaining_accounts` see the same layout either way.
+pub(crate) fn reject_owner_cpi<'c, 'info>(
+    vault: &AgentVault,
+    remaining_accounts: &'c [AccountInfo<'info>],
+) -> Result<&'c [AccountInfo<'info>]> {
+    let stack_height = get_stack_height();
+    if stack_height == TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(remaining_accounts);
+    }
+    require!(
+        stack_height == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+        SigilError::CpiCallNotAllowed
+    );
+    require!(!vault.cpi_callers.is_empty(), SigilError::CpiCallNotAllowed);
+    let (ix_sysvar, rest) = remaining_accounts
+        .split_last()
+        .ok_or(error!(SigilError::CpiCallNotAllowed))?;
+    require_keys_eq!(
+        ix_sysvar.key(),
+        sysvar::instructions::ID,
+        SigilError::CpiCallNotAllowed
+    );
+    let top_level = get_instruction_relative(0, ix_sysvar)?;
+    require!(
+        vault.cpi_callers.contains(&top_level.program_id),
+        SigilError::CpiCallerNotAllowed
+    );
+    Ok(rest)
+}
+
 /// Convert stablecoin amount to USD (6 decimals).
 /// usd = amount * 10^USD_DECIMALS / 10^token_decimals
 pub(crate) fn stablecoin_to_usd(amount: u64, token_decimals: u8) -> Result<u64> {
@@ -66,3 +114,146 @@ pub(crate) fn stablecoin_to_usd(amount: u64, token_decimals: u8) -> Result<u64>
             .ok_or(error!(SigilError::Overflow))
     }
 }
+
+/// Check and record `usd_amount` spent from `mint` against the weekly,
+/// monthly and per-mint caps.
+///
+/// Fail-closed: once the vault has an extension (`has_tracker_extension`)
+/// it must be passed on every spend, caps or not, so its windows hold the
+/// full history when the owner enables a cap later. A vault without one
+/// cannot configure these caps until `initialize_tracker_extension` runs.
+pub(crate) fn enforce_extension_caps(
+    tracker_extension: Option<&AccountLoader<SpendTrackerExtension>>,
+    has_tracker_extension: bool,
+    policy: &PolicyConfig,
+    clock: &Clock,
+    mint: &Pubkey,
+    usd_amount: u64,
+) -> Result<()> {
+    match tracker_extension {
+        Some(loader) => loader.load_mut()?.check_and_record(
+            clock,
+            policy.weekly_spending_cap_usd,
+            policy.monthly_spending_cap_usd,
+            &policy.mint_caps,
+            mint,
+            usd_amount,
+        ),
+        None => {
+            require!(
+                !has_tracker_extension && !policy.requires_tracker_extension(),
+                SigilError::TrackerExtensionRequired
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Resolve an agent's `AgentSettings`, failing closed when the vault marks
+/// the agent as having settings but the account was not passed.
+pub(crate) fn resolve_agent_settings<'a>(
+    vault: &AgentVault,
+    agent: &Pubkey,
+    agent_settings: Option<&'a Account<AgentSettings>>,
+) -> Result<Option<&'a AgentSettings>> {
+    let has_settings = vault.get_agent(agent).is_some_and(|a| a.has_settings);
+    require!(
+        !has_settings || agent_settings.is_some(),
+        SigilError::AgentSettingsRequired
+    );
+    Ok(agent_settings.map(|s| &**s))
+}
+
+/// Require `vault.approval_threshold` approvers among the owner and the
+/// signing `remaining_accounts`. No-op while no approver set is configured.
+pub(crate) fn require_approvals(
+    vault: &AgentVault,
+    owner: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if vault.approval_threshold == 0 {
+        return Ok(());
+    }
+    let signers: Vec<Pubkey> = core::iter::once(*owner)
+        .chain(
+            remaining_accounts
+                .iter()
+                .filter(|info| info.is_signer)
+                .map(|info| info.key()),
+        )
+        .collect();
+    require!(
+        vault.approval_count(&signers) >= vault.approval_threshold as usize,
+        SigilError::InsufficientApprovals
+    );
+    Ok(())
+}
+
+/// Find and check the owner permit for this spend among the instructions
+/// preceding `current_idx`. The caller must burn the returned nonce in the
+/// vault's `PermitNonces`.
+///
+/// The permit travels as an Ed25519 program instruction signed by
+/// `vault.owner`. The runtime verifies the signature before any instruction
+/// runs, so it only remains to confirm that the instruction carries its
+/// pubkey and message inline (instruction index `u16::MAX`) and that the
+/// message binds this vault, agent and protocol.
+pub(crate) fn verify_owner_permit(
+    ix_sysvar: &AccountInfo,
+    current_idx: usize,
+    vault_key: &Pubkey,
+    owner: &Pubkey,
+    agent: &Pubkey,
+    protocol: &Pubkey,
+    now: i64,
+) -> Result<OwnerPermit> {
+    let mut found: Option<OwnerPermit> = None;
+    for idx in 0..current_idx.min(super::MAX_SYSVAR_SCAN_ITERATIONS) {
+        let Ok(ix) = load_instruction_at_checked(idx, ix_sysvar) else {
+            continue;
+        };
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        let Some((signer, message)) = parse_ed25519_instruction(&ix.data) else {
+            continue;
+        };
+        if signer != *owner {
+            continue;
+        }
+        if let Some(permit) = OwnerPermit::from_message(message) {
+            if permit.vault == *vault_key && permit.agent == *agent {
+                found = Some(permit);
+                break;
+            }
+        }
+    }
+
+    let permit = found.ok_or(error!(SigilError::InvalidPermit))?;
+    require!(permit.protocol == *protocol, SigilError::InvalidPermit);
+    require!(now <= permit.expires_at, SigilError::PermitExpired);
+    Ok(permit)
+}
+
+/// Extract `(pubkey, message)` from a single-signature Ed25519 program
+/// instruction whose offsets all point into its own data.
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    // [num_signatures u8][padding u8][7 x u16 offsets]
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    if data.len() < OFFSETS_START + OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let u16_at = |i: usize| {
+        let at = OFFSETS_START + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let (sig_ix, pubkey_offset, pubkey_ix) = (u16_at(1), u16_at(2) as usize, u16_at(3));
+    let (msg_offset, msg_len, msg_ix) = (u16_at(4) as usize, u16_at(5) as usize, u16_at(6));
+    if sig_ix != u16::MAX || pubkey_ix != u16::MAX || msg_ix != u16::MAX {
+        return None;
+    }
+    let pubkey = Pubkey::try_from(data.get(pubkey_offset..pubkey_offset.checked_add(32)?)?).ok()?;
+    let message = data.get(msg_offset..msg_offset.checked_add(msg_len)?)?;
+    Some((pubkey, message))
+}
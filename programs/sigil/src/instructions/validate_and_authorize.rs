@@ -6,10 +6,11 @@ use anchor_lang::solana_program::sysvar::instructions::{
 use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::SigilError;
-use crate::events::{ActionAuthorized, FeesCollected};
+use crate::events::{ActionAuthorized, FeesCollected, FulfillmentReserved};
 use crate::state::*;
 
 use super::integrations::{generic_constraints, jupiter};
+use super::utils::{enforce_extension_caps, resolve_agent_settings, verify_owner_permit};
 
 /// Maximum instructions to scan from any sysvar introspection loop.
 ///
@@ -30,7 +31,14 @@ use super::integrations::{generic_constraints, jupiter};
 pub const MAX_SYSVAR_SCAN_ITERATIONS: usize = 64;
 
 #[derive(Accounts)]
-#[instruction(token_mint: Pubkey)]
+#[instruction(
+    token_mint: Pubkey,
+    amount: u64,
+    target_protocol: Pubkey,
+    expected_policy_version: u64,
+    legs: Vec<SessionLeg>,
+    session_nonce: u8,
+)]
 pub struct ValidateAndAuthorize<'info> {
     #[account(mut)]
     pub agent: Signer<'info>,
@@ -38,7 +46,7 @@ pub struct ValidateAndAuthorize<'info> {
     #[account(
         mut,
         constraint = vault.is_agent(&agent.key()) @ SigilError::UnauthorizedAgent,
-        seeds = [b"vault", vault.owner.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -66,8 +74,35 @@ pub struct ValidateAndAuthorize<'info> {
     )]
     pub agent_spend_overlay: AccountLoader<'info, AgentSpendOverlay>,
 
+    /// Zero-copy SpendTrackerExtension — weekly/monthly windows. Only read
+    /// when an async-fulfillment session reserves its amount here; then
+    /// required if the vault has one.
+    #[account(
+        mut,
+        seeds = [b"tracker_ext", vault.key().as_ref()],
+        bump = tracker_extension.load()?.bump,
+    )]
+    pub tracker_extension: Option<AccountLoader<'info, SpendTrackerExtension>>,
+
+    /// Per-agent settings. Required when `AgentEntry::has_settings` is set.
+    #[account(
+        seeds = [b"agent_settings", vault.key().as_ref(), agent.key().as_ref()],
+        bump = agent_settings.bump,
+    )]
+    pub agent_settings: Option<Account<'info, AgentSettings>>,
+
+    /// Permit nonce bitmap. Passing it opts this session into an owner
+    /// permit, which must then precede this instruction.
+    #[account(
+        mut,
+        seeds = [b"permit_nonces", vault.key().as_ref()],
+        bump = permit_nonces.bump,
+    )]
+    pub permit_nonces: Option<Account<'info, PermitNonces>>,
+
     /// Ephemeral session PDA — `init` ensures no double-authorization.
-    /// Seeds include token_mint for per-token concurrent sessions.
+    /// Seeds include token_mint for per-token concurrent sessions, and the
+    /// session nonce for concurrent sessions on the same mint.
     #[account(
         init,
         payer = agent,
@@ -77,11 +112,23 @@ pub struct ValidateAndAuthorize<'info> {
             vault.key().as_ref(),
             agent.key().as_ref(),
             token_mint.as_ref(),
+            session_nonce_seed(&session_nonce),
         ],
         bump,
     )]
     pub session: Account<'info, SessionAuthority>,
 
+    /// Cap reservation for a session that invokes an async-fulfillment
+    /// program. Required exactly when one is invoked.
+    #[account(
+        init,
+        payer = agent,
+        space = PendingFulfillment::SIZE,
+        seeds = [b"pending_fulfillment", vault.key().as_ref(), session.key().as_ref()],
+        bump,
+    )]
+    pub pending_fulfillment: Option<Account<'info, PendingFulfillment>>,
+
     /// Vault's PDA-owned token account for the spend token
     #[account(
         mut,
@@ -112,6 +159,10 @@ pub struct ValidateAndAuthorize<'info> {
     #[account(mut)]
     pub output_stablecoin_account: Option<Account<'info, TokenAccount>>,
 
+    /// Vault's token account for `ExpectedOutput::mint`. Required when a
+    /// stablecoin-input session declares an expected output.
+    pub output_token_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 
@@ -124,12 +175,16 @@ pub struct ValidateAndAuthorize<'info> {
     pub instructions_sysvar: UncheckedAccount<'info>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<ValidateAndAuthorize>,
     token_mint: Pubkey,
     amount: u64,
     target_protocol: Pubkey,
     expected_policy_version: u64,
+    legs: Vec<SessionLeg>,
+    session_nonce: u8,
+    expected_output: Option<ExpectedOutput>,
 ) -> Result<()> {
     // 0. Reject CPI calls — only top-level transaction instructions allowed.
     require!(
@@ -214,6 +269,18 @@ pub fn handler(
         !vault.is_agent_paused(&ctx.accounts.agent.key()),
         SigilError::AgentPaused
     );
+    require!(
+        !vault.is_agent_expired(&ctx.accounts.agent.key(), clock.unix_timestamp),
+        SigilError::AgentKeyExpired
+    );
+
+    // 1a-conc. Concurrent session limit for this agent
+    require!(
+        vault
+            .get_agent(&ctx.accounts.agent.key())
+            .is_some_and(|a| a.can_open_session(session_nonce)),
+        SigilError::TooManyConcurrentSessions
+    );
 
     // 1a. Agent must have capability for the spending level
     require!(
@@ -227,6 +294,83 @@ pub fn handler(
         SigilError::ProtocolNotAllowed
     );
 
+    // 2-post. Agent's own protocol subset, when it has one
+    let agent_settings = resolve_agent_settings(
+        vault,
+        &ctx.accounts.agent.key(),
+        ctx.accounts.agent_settings.as_ref(),
+    )?;
+    if let Some(settings) = agent_settings {
+        require!(
+            settings.is_protocol_allowed(&target_protocol),
+            SigilError::AgentProtocolNotAllowed
+        );
+    }
+
+    // 2-legs. Multi-leg session: the first leg is the declared target, every
+    // leg's protocol must pass the same allowlists, and pinned constraint
+    // entries must exist for that protocol.
+    let is_multi_leg = !legs.is_empty();
+    if is_multi_leg {
+        require!(
+            is_spending
+                && legs.len() >= 2
+                && legs.len() <= MAX_SESSION_LEGS
+                && legs[0].protocol == target_protocol,
+            SigilError::InvalidSessionLegs
+        );
+        // Per-protocol spend is checked and recorded against one protocol;
+        // a capped leg anywhere in the session would escape its cap.
+        require!(
+            !policy.any_protocol_capped(legs.iter().map(|l| &l.protocol)),
+            SigilError::CappedProtocolInMultiLegSession
+        );
+        for leg in legs.iter() {
+            require!(
+                policy.is_protocol_allowed(&leg.protocol),
+                SigilError::ProtocolNotAllowed
+            );
+            if let Some(settings) = agent_settings {
+                require!(
+                    settings.is_protocol_allowed(&leg.protocol),
+                    SigilError::AgentProtocolNotAllowed
+                );
+            }
+            if let Some(entry) = leg.constraint_entry {
+                let constraints =
+                    loaded_constraints.ok_or(error!(SigilError::InvalidSessionLegs))?;
+                require!(
+                    entry < constraints.entry_count
+                        && constraints.entries[entry as usize].program_id
+                            == leg.protocol.to_bytes(),
+                    SigilError::InvalidSessionLegs
+                );
+            }
+        }
+    }
+
+    // 2a-pre. Trading-hours schedule (non-spending optionally exempt)
+    if policy.is_trading_hours_enforced(is_spending) {
+        require!(
+            policy.is_within_trading_hours(clock.unix_timestamp),
+            SigilError::OutsideTradingWindow
+        );
+    }
+
+    // 2a. Per-agent session rate limit — ALL sessions, spending or not
+    let max_sessions_per_hour = vault
+        .get_agent(&ctx.accounts.agent.key())
+        .map_or(0, |a| a.max_sessions_per_hour);
+    {
+        let mut overlay = ctx.accounts.agent_spend_overlay.load_mut()?;
+        match overlay.find_agent_slot(&ctx.accounts.agent.key()) {
+            Some(agent_slot) => {
+                overlay.check_and_record_session(&clock, agent_slot, max_sessions_per_hour)?
+            }
+            None => require!(max_sessions_per_hour == 0, SigilError::AgentSlotNotFound),
+        }
+    }
+
     // --- Stablecoin-only spending path ---
     let mut output_mint = Pubkey::default();
     let mut stablecoin_balance_before: u64 = 0;
@@ -308,12 +452,32 @@ pub fn handler(
         if let Ok(ix) = load_instruction_at_checked(pre_idx, &ix_sysvar) {
             require!(
                 ix.program_id == compute_budget_id
-                    || ix.program_id == anchor_lang::solana_program::system_program::ID,
+                    || ix.program_id == anchor_lang::solana_program::system_program::ID
+                    || ix.program_id == solana_program::ed25519_program::ID,
                 SigilError::UnauthorizedPreValidateInstruction
             );
         }
         pre_iter_count = pre_iter_count.saturating_add(1);
     }
+
+    // 5a-post. Owner permit: lifts the per-transaction ceiling for this
+    // session to the permit's amount ceiling. Enforced at finalize.
+    let permit_ceiling_usd = match ctx.accounts.permit_nonces.as_mut() {
+        Some(nonces) => {
+            let permit = verify_owner_permit(
+                &ix_sysvar,
+                current_idx_usize,
+                &vault_key,
+                &vault.owner,
+                &ctx.accounts.agent.key(),
+                &target_protocol,
+                clock.unix_timestamp,
+            )?;
+            nonces.consume(permit.nonce)?;
+            permit.amount_ceiling_usd
+        }
+        None => 0,
+    };
     let finalize_hash = FINALIZE_SESSION_DISCRIMINATOR;
 
     // ── Shared instruction scan helper ──────────────────────────────
@@ -322,18 +486,23 @@ pub fn handler(
     enum ScanAction {
         FoundFinalize,
         Infrastructure,
-        /// Passed shared checks (constraint entry match, if any, is used for strict-mode
-        /// side-effect only — generic_constraints returns Err if strict mode is violated).
-        PassedSharedChecks,
+        /// Passed shared checks. Carries the matched constraint entry, if any;
+        /// generic_constraints returns Err if strict mode is violated.
+        PassedSharedChecks {
+            matched_entry: Option<usize>,
+        },
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn scan_instruction_shared(
         ix: &Instruction,
         spl_token_id: &Pubkey,
         compute_budget_id: &Pubkey,
         finalize_hash: &[u8; 8],
         policy: &PolicyConfig,
+        agent_settings: Option<&AgentSettings>,
         loaded_constraints: &Option<&InstructionConstraints>,
+        allow_async: bool,
     ) -> anchor_lang::Result<ScanAction> {
         // Stop at finalize_session
         if ix.program_id == crate::ID && ix.data.len() >= 8 && ix.data[..8] == *finalize_hash {
@@ -441,33 +610,41 @@ pub fn handler(
         // transfer 5-45s after finalize_session returns. Sigil's stablecoin
         // balance-delta measurement is always 0 at finalize, so daily caps +
         // protocol caps + spend tracker never record the real spend.
-        if KNOWN_ASYNC_FULFILLMENT_PROGRAMS.contains(&ix.program_id) {
+        // With `allow_async_fulfillment`, spending sessions may call them
+        // and reserve their amount up front instead (see 6c).
+        if KNOWN_ASYNC_FULFILLMENT_PROGRAMS.contains(&ix.program_id) && !allow_async {
             return Err(error!(SigilError::AsyncFulfillmentNotPermitted));
         }
 
-        // Protocol allowlist
+        // Protocol allowlist — vault policy, then the agent's own subset
         require!(
             policy.is_protocol_allowed(&ix.program_id),
             SigilError::ProtocolNotAllowed
         );
+        if let Some(settings) = agent_settings {
+            require!(
+                settings.is_protocol_allowed(&ix.program_id),
+                SigilError::AgentProtocolNotAllowed
+            );
+        }
 
         // Generic instruction constraints (OR across entries, zero-copy).
-        // Return value (matched entry index) was only used by position_effect extraction,
-        // which was deleted with the counter system. Strict-mode check remains as the
-        // required side-effect.
+        // The matched entry index is checked against pinned legs in
+        // multi-leg sessions; the strict-mode check applies to every ix.
+        let mut matched_entry = None;
         if let Some(constraints) = loaded_constraints {
-            let matched = generic_constraints::verify_against_entries_zc(
+            matched_entry = generic_constraints::verify_against_entries_zc(
                 constraints,
                 &ix.program_id,
                 &ix.data,
                 &ix.accounts,
             )?;
-            if matched.is_none() && constraints.strict_mode != 0 {
+            if matched_entry.is_none() && constraints.strict_mode != 0 {
                 return Err(error!(SigilError::UnconstrainedProgramBlocked));
             }
         }
 
-        Ok(ScanAction::PassedSharedChecks)
+        Ok(ScanAction::PassedSharedChecks { matched_entry })
     }
 
     // ── Jupiter slippage enforcement helper ─────────────────────────────
@@ -496,10 +673,13 @@ pub fn handler(
     // 6. Instruction scan — validates all instructions between validate and finalize.
     // Shared checks (scan_instruction_shared): SPL/Token-2022 blocking, infrastructure
     // whitelist, protocol allowlist, generic constraints.
-    // Spending-only checks (inline): recognized DeFi, ProtocolMismatch, defi_ix_count.
+    // Spending-only checks (inline): recognized DeFi, ProtocolMismatch, defi_ix_count,
+    // or ordered leg matching for multi-leg sessions.
     // Jupiter slippage: enforced via `enforce_jupiter_slippage_if_jupiter` in BOTH branches.
+    let mut uses_async = false;
     if is_spending {
         let mut defi_ix_count: u8 = 0;
+        let mut leg_idx: usize = 0;
         let mut found_finalize = false;
         let mut scan_idx = current_idx_usize.saturating_add(1);
         // M11 hardening (SIMD-0296 pad-attack DoS): bound iteration count.
@@ -516,7 +696,9 @@ pub fn handler(
                 &compute_budget_id,
                 &finalize_hash,
                 policy,
+                agent_settings,
                 &loaded_constraints,
+                policy.allow_async_fulfillment,
             )? {
                 ScanAction::FoundFinalize => {
                     found_finalize = true;
@@ -527,7 +709,7 @@ pub fn handler(
                     iter_count = iter_count.saturating_add(1);
                     continue;
                 }
-                ScanAction::PassedSharedChecks => {
+                ScanAction::PassedSharedChecks { matched_entry } => {
                     // === SPENDING-ONLY CHECKS (must remain inline) ===
 
                     // Recognized DeFi: protocol mismatch + defi_ix_count
@@ -536,13 +718,36 @@ pub fn handler(
                         || ix.program_id == JUPITER_LEND_PROGRAM
                         || ix.program_id == JUPITER_EARN_PROGRAM
                         || ix.program_id == JUPITER_BORROW_PROGRAM;
-
-                    if is_recognized_defi {
+                    // Only reachable with allow_async_fulfillment (shared checks)
+                    let is_async = KNOWN_ASYNC_FULFILLMENT_PROGRAMS.contains(&ix.program_id);
+
+                    if is_multi_leg {
+                        require!(!is_async, SigilError::AsyncFulfillmentNotPermitted);
+                        // Any recognized DeFi ix or ix to a leg protocol is the
+                        // next leg, in declared order.
+                        if is_recognized_defi || legs.iter().any(|l| l.protocol == ix.program_id) {
+                            let leg = legs
+                                .get(leg_idx)
+                                .ok_or(error!(SigilError::SessionLegMismatch))?;
+                            require!(
+                                ix.program_id == leg.protocol,
+                                SigilError::SessionLegMismatch
+                            );
+                            if let Some(entry) = leg.constraint_entry {
+                                require!(
+                                    matched_entry == Some(entry as usize),
+                                    SigilError::SessionLegMismatch
+                                );
+                            }
+                            leg_idx = leg_idx.saturating_add(1);
+                        }
+                    } else if is_recognized_defi || is_async {
                         require!(
                             ix.program_id == target_protocol,
                             SigilError::ProtocolMismatch
                         );
                         defi_ix_count = defi_ix_count.saturating_add(1);
+                        uses_async |= is_async;
                     }
 
                     // Slippage verification on Jupiter V6 swaps.
@@ -557,7 +762,9 @@ pub fn handler(
         }
 
         // DeFi instruction count enforcement
-        if is_stablecoin_input {
+        if is_multi_leg {
+            require!(leg_idx == legs.len(), SigilError::SessionLegMismatch);
+        } else if is_stablecoin_input {
             require!(defi_ix_count <= 1, SigilError::TooManyDeFiInstructions);
         } else {
             require!(defi_ix_count == 1, SigilError::TooManyDeFiInstructions);
@@ -566,6 +773,82 @@ pub fn handler(
         require!(found_finalize, SigilError::MissingFinalizeInstruction);
     }
 
+    // 6c. Async fulfillment is priced in the input stablecoin, and the
+    // reservation account must be passed exactly when it is used.
+    if uses_async {
+        require!(
+            is_stablecoin_input,
+            SigilError::AsyncFulfillmentNotPermitted
+        );
+    }
+    require!(
+        uses_async == ctx.accounts.pending_fulfillment.is_some(),
+        SigilError::InvalidPendingFulfillment
+    );
+
+    // 6d. Advisory minimum output. Jupiter carries its own quote and
+    // slippage in the instruction data; every other protocol declares an
+    // agent-chosen minimum here once the policy sets
+    // max_protocol_slippage_bps. A multi-leg session is exempt
+    // only if every leg is Jupiter. Async output lands after finalize, so
+    // it cannot be measured.
+    let jupiter_only = target_protocol == JUPITER_PROGRAM
+        && legs.iter().all(|leg| leg.protocol == JUPITER_PROGRAM);
+    if is_spending && !uses_async && policy.max_protocol_slippage_bps > 0 && !jupiter_only {
+        require!(
+            expected_output.is_some(),
+            SigilError::ExpectedOutputRequired
+        );
+    }
+    let mut min_output_mint = Pubkey::default();
+    let mut min_output_amount: u64 = 0;
+    let mut min_output_token_account = Pubkey::default();
+    let mut min_output_balance_before: u64 = 0;
+    if let Some(expected) = expected_output.as_ref() {
+        // The quote is self-declared: without a price source it cannot be
+        // checked, and `amount` is in input-token units for non-stablecoin
+        // input, so there is no USD value to bound it by either.
+        require!(
+            is_spending && !uses_async,
+            SigilError::InvalidExpectedOutput
+        );
+        require!(
+            expected.within_slippage(policy.max_protocol_slippage_bps),
+            SigilError::InvalidExpectedOutput
+        );
+        if is_stablecoin_input {
+            // Output is the token bought with the stablecoin.
+            let output_acct = ctx
+                .accounts
+                .output_token_account
+                .as_ref()
+                .ok_or(error!(SigilError::InvalidExpectedOutput))?;
+            require!(
+                output_acct.owner == vault_key
+                    && output_acct.mint == expected.mint
+                    && expected.mint != token_mint,
+                SigilError::InvalidExpectedOutput
+            );
+            min_output_token_account = output_acct.key();
+            min_output_balance_before = output_acct.amount;
+        } else {
+            // Output is the stablecoin already snapshotted above.
+            require!(
+                expected.mint == output_mint,
+                SigilError::InvalidExpectedOutput
+            );
+            min_output_token_account = ctx
+                .accounts
+                .output_stablecoin_account
+                .as_ref()
+                .ok_or(error!(SigilError::InvalidTokenAccount))?
+                .key();
+            min_output_balance_before = stablecoin_balance_before;
+        }
+        min_output_mint = expected.mint;
+        min_output_amount = expected.min_amount;
+    }
+
     // 6b. Non-spending instruction scan
     if !is_spending {
         let mut found_finalize = false;
@@ -584,7 +867,9 @@ pub fn handler(
                 &compute_budget_id,
                 &finalize_hash,
                 policy,
+                agent_settings,
                 &loaded_constraints,
+                false,
             )? {
                 ScanAction::FoundFinalize => {
                     found_finalize = true;
@@ -595,7 +880,7 @@ pub fn handler(
                     iter_count = iter_count.saturating_add(1);
                     continue;
                 }
-                ScanAction::PassedSharedChecks => {
+                ScanAction::PassedSharedChecks { .. } => {
                     // Pentester MED — non-spending forward scan must enforce Jupiter
                     // slippage too. Without this call, an agent could send a
                     // `validate_and_authorize` with `amount=0` (taking the
@@ -621,7 +906,7 @@ pub fn handler(
     // See Plans/we-need-to-plan-serialized-summit.md for rationale.
 
     // Extract vault PDA seeds data upfront
-    let owner_key = vault.owner;
+    let creator_key = vault.creator;
     let vault_id_bytes = vault.vault_id.to_le_bytes();
     let vault_bump = vault.bump;
     let vault_fee_destination = vault.fee_destination;
@@ -630,7 +915,7 @@ pub fn handler(
     let bump_slice = [vault_bump];
     let signer_seeds = [
         b"vault" as &[u8],
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump_slice.as_ref(),
     ];
@@ -743,10 +1028,17 @@ pub fn handler(
     session.is_spending = is_spending;
     // Wall-clock based — congestion-immune (audit F5-H1).
     // The slot is no longer load-bearing for expiry; only Clock::unix_timestamp.
+    // Per-agent overrides can only shorten the session / lower the ceiling.
+    let vault_expiry_seconds = policy.effective_session_expiry_seconds();
     session.expires_at_timestamp = SessionAuthority::calculate_expiry(
         clock.unix_timestamp,
-        policy.effective_session_expiry_seconds(),
+        agent_settings.map_or(vault_expiry_seconds, |s| {
+            s.effective_session_expiry_seconds(vault_expiry_seconds)
+        }),
     );
+    session.agent_max_transaction_size_usd =
+        agent_settings.map_or(0, |s| s.max_transaction_size_usd);
+    session.permit_ceiling_usd = permit_ceiling_usd;
     session.delegation_token_account = ctx.accounts.vault_token_account.key();
     session.protocol_fee = protocol_fee;
     session.developer_fee = developer_fee;
@@ -754,6 +1046,12 @@ pub fn handler(
     session.output_mint = output_mint;
     session.stablecoin_balance_before = stablecoin_balance_before;
     session.bump = ctx.bumps.session;
+    session.nonce = session_nonce;
+    session.async_fulfillment = uses_async;
+    session.min_output_mint = min_output_mint;
+    session.min_output_amount = min_output_amount;
+    session.min_output_token_account = min_output_token_account;
+    session.min_output_balance_before = min_output_balance_before;
     // Initialize snapshot fields to zero (default for non-delta sessions)
     session.assertion_snapshots = [[0u8; 32]; 4];
     session.snapshot_lens = [0u8; 4];
@@ -830,6 +1128,119 @@ pub fn handler(
         // finalize_session will hard-fail (existing B1 defense at finalize line 508).
     }
 
+    // 6c-post. Async fulfillment: the keeper's transfer lands after
+    // finalize_session, so the full authorized amount is reserved against
+    // every rolling cap now. reconcile_fulfillment releases what provably
+    // did not leave the vault once the fulfillment window has passed.
+    if uses_async {
+        let agent_key = ctx.accounts.agent.key();
+        let session_key = session.key();
+        require!(
+            amount <= session.effective_max_transaction_size_usd(policy.max_transaction_size_usd),
+            SigilError::TransactionTooLarge
+        );
+
+        // Rolling 24h cap
+        let mut tracker = ctx.accounts.tracker.load_mut()?;
+        let new_total = tracker
+            .get_rolling_24h_usd(&clock)
+            .checked_add(amount)
+            .ok_or(SigilError::Overflow)?;
+        require!(
+            new_total <= policy.daily_spending_cap_usd,
+            SigilError::SpendingCapExceeded
+        );
+
+        // Rolling weekly/monthly + per-mint caps
+        enforce_extension_caps(
+            ctx.accounts.tracker_extension.as_ref(),
+            vault.has_tracker_extension,
+            policy,
+            &clock,
+            &token_mint,
+            amount,
+        )?;
+
+        // Per-agent cap
+        let agent_entry = vault
+            .get_agent(&agent_key)
+            .ok_or(error!(SigilError::UnauthorizedAgent))?;
+        let mut overlay = ctx.accounts.agent_spend_overlay.load_mut()?;
+        let agent_slot = overlay.find_agent_slot(&agent_key);
+        if let Some(slot) = agent_slot {
+            if agent_entry.spending_limit_usd > 0 {
+                let new_agent = overlay
+                    .get_agent_rolling_24h_usd(&clock, slot)
+                    .checked_add(amount)
+                    .ok_or(SigilError::Overflow)?;
+                require!(
+                    new_agent <= agent_entry.spending_limit_usd,
+                    SigilError::AgentSpendLimitExceeded
+                );
+            }
+            overlay.record_agent_contribution(&clock, slot, amount)?;
+        } else {
+            require!(
+                agent_entry.spending_limit_usd == 0,
+                SigilError::AgentSlotNotFound
+            );
+        }
+        drop(overlay);
+
+        // Per-protocol cap
+        if let Some(proto_cap) = policy.get_protocol_cap(&target_protocol) {
+            if proto_cap > 0 {
+                let new_proto = tracker
+                    .get_protocol_spend(&clock, &target_protocol)
+                    .checked_add(amount)
+                    .ok_or(SigilError::Overflow)?;
+                require!(new_proto <= proto_cap, SigilError::ProtocolCapExceeded);
+            }
+        }
+
+        tracker.record_spend(&clock, amount)?;
+        if policy.has_protocol_caps {
+            tracker.record_protocol_spend(&clock, &target_protocol, amount)?;
+        }
+        drop(tracker);
+
+        let expires_at = clock
+            .unix_timestamp
+            .checked_add(ASYNC_FULFILLMENT_WINDOW_SECONDS)
+            .ok_or(SigilError::Overflow)?;
+        let pending = ctx
+            .accounts
+            .pending_fulfillment
+            .as_mut()
+            .ok_or(error!(SigilError::InvalidPendingFulfillment))?;
+        pending.vault = vault_key;
+        pending.agent = agent_key;
+        pending.session = session_key;
+        pending.protocol = target_protocol;
+        pending.token_mint = token_mint;
+        pending.token_account = ctx.accounts.vault_token_account.key();
+        pending.reserved_usd = amount;
+        pending.reserved_at = clock.unix_timestamp;
+        pending.expires_at = expires_at;
+        pending.balance_before = stablecoin_balance_before;
+        pending.finalized = false;
+        pending.balance_after_finalize = 0;
+        pending.deposited_usd_at_finalize = 0;
+        pending.recorded_in_overlay = agent_slot.is_some();
+        pending.recorded_in_extension = ctx.accounts.tracker_extension.is_some();
+        pending.recorded_protocol_spend = policy.has_protocol_caps;
+        pending.bump = ctx.bumps.pending_fulfillment.unwrap_or_default();
+
+        emit!(FulfillmentReserved {
+            vault: vault_key,
+            agent: agent_key,
+            session: session_key,
+            protocol: target_protocol,
+            reserved_usd: amount,
+            expires_at,
+        });
+    }
+
     emit!(ActionAuthorized {
         vault: vault_key,
         agent: ctx.accounts.agent.key(),
@@ -844,13 +1255,29 @@ pub fn handler(
         timestamp: clock.unix_timestamp,
     });
 
-    // H-1: Track active sessions for close_vault guard
+    // H-1: Track active sessions for close_vault guard, per agent and vault-wide
     {
+        let agent_key = ctx.accounts.agent.key();
         let vault = &mut ctx.accounts.vault;
         vault.active_sessions = vault
             .active_sessions
             .checked_add(1)
             .ok_or(SigilError::Overflow)?;
+        let entry = vault
+            .agents
+            .iter_mut()
+            .find(|a| a.pubkey == agent_key)
+            .ok_or(error!(SigilError::UnauthorizedAgent))?;
+        entry.active_sessions = entry
+            .active_sessions
+            .checked_add(1)
+            .ok_or(SigilError::Overflow)?;
+        if uses_async {
+            vault.open_fulfillments = vault
+                .open_fulfillments
+                .checked_add(1)
+                .ok_or(SigilError::Overflow)?;
+        }
     }
 
     Ok(())
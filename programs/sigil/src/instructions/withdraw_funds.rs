@@ -5,6 +5,8 @@ use crate::errors::SigilError;
 use crate::events::FundsWithdrawn;
 use crate::state::*;
 
+use super::utils::{reject_owner_cpi, require_approvals};
+
 #[derive(Accounts)]
 pub struct WithdrawFunds<'info> {
     #[account(mut)]
@@ -13,7 +15,7 @@ pub struct WithdrawFunds<'info> {
     #[account(
         mut,
         has_one = owner @ SigilError::UnauthorizedOwner,
-        seeds = [b"vault", owner.key().as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        seeds = [b"vault", vault.creator.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, AgentVault>,
@@ -40,7 +42,17 @@ pub struct WithdrawFunds<'info> {
 }
 
 pub fn handler(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
-    crate::reject_cpi!();
+    let remaining_accounts = reject_owner_cpi(&ctx.accounts.vault, ctx.remaining_accounts)?;
+
+    ctx.accounts
+        .vault
+        .record_owner_activity(Clock::get()?.unix_timestamp);
+
+    require_approvals(
+        &ctx.accounts.vault,
+        &ctx.accounts.owner.key(),
+        remaining_accounts,
+    )?;
 
     let vault = &mut ctx.accounts.vault;
 
@@ -48,18 +60,22 @@ pub fn handler(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
         vault.status != VaultStatus::Closed,
         SigilError::VaultAlreadyClosed
     );
+    require!(
+        !vault.dead_man_triggered,
+        SigilError::DeadManSwitchTriggered
+    );
     require!(
         ctx.accounts.vault_token_account.amount >= amount,
         SigilError::InsufficientBalance
     );
 
     // PDA signer seeds
-    let owner_key = vault.owner;
+    let creator_key = vault.creator;
     let vault_id_bytes = vault.vault_id.to_le_bytes();
     let bump = [vault.bump];
     let signer_seeds = [
         b"vault" as &[u8],
-        owner_key.as_ref(),
+        creator_key.as_ref(),
         vault_id_bytes.as_ref(),
         bump.as_ref(),
     ];
@@ -22,7 +22,7 @@ pub mod sigil {
 
     /// Initialize a new agent vault with policy configuration.
     /// Only the owner can call this. Creates vault PDA, policy PDA,
-    /// and zero-copy spend tracker PDA.
+    /// and the zero-copy spend tracker PDAs (24h + weekly/monthly).
     pub fn initialize_vault(
         ctx: Context<InitializeVault>,
         vault_id: u64,
@@ -64,8 +64,17 @@ pub mod sigil {
         agent: Pubkey,
         capability: u8,
         spending_limit_usd: u64,
+        max_sessions_per_hour: u16,
+        valid_until: Option<i64>,
     ) -> Result<()> {
-        instructions::register_agent::handler(ctx, agent, capability, spending_limit_usd)
+        instructions::register_agent::handler(
+            ctx,
+            agent,
+            capability,
+            spending_limit_usd,
+            max_sessions_per_hour,
+            valid_until,
+        )
     }
 
     // update_policy DELETED — all policy changes now route through
@@ -73,14 +82,24 @@ pub mod sigil {
 
     /// Core permission check. Called by the agent before a DeFi action.
     /// Validates against policy constraints, stablecoin-only enforcement,
-    /// and protocol slippage verification.
+    /// and Jupiter slippage verification.
     /// Creates a SessionAuthority PDA, delegates tokens to agent.
+    /// Non-empty `legs` authorizes an ordered multi-leg spending session;
+    /// none of its protocols may carry a per-protocol cap.
+    /// `session_nonce` lets an agent hold concurrent sessions on one mint.
+    /// `expected_output` declares an advisory minimum output verified at
+    /// finalize; quote and minimum are the agent's own, not checked against
+    /// a price or an owner-signed bound.
+    #[allow(clippy::too_many_arguments)]
     pub fn validate_and_authorize(
         ctx: Context<ValidateAndAuthorize>,
         token_mint: Pubkey,
         amount: u64,
         target_protocol: Pubkey,
         expected_policy_version: u64,
+        legs: Vec<state::SessionLeg>,
+        session_nonce: u8,
+        expected_output: Option<state::ExpectedOutput>,
     ) -> Result<()> {
         instructions::validate_and_authorize::handler(
             ctx,
@@ -88,6 +107,9 @@ pub mod sigil {
             amount,
             target_protocol,
             expected_policy_version,
+            legs,
+            session_nonce,
+            expected_output,
         )
     }
 
@@ -103,6 +125,17 @@ pub mod sigil {
         instructions::revoke_agent::handler(ctx, agent_to_remove)
     }
 
+    /// Replace an agent's signing key in place, keeping its permissions,
+    /// limits and per-agent spend history. Owner-signed; the old key may co-sign.
+    /// The agent's open sessions must be finalized first.
+    pub fn rotate_agent_key(
+        ctx: Context<RotateAgentKey>,
+        old_agent: Pubkey,
+        new_agent: Pubkey,
+    ) -> Result<()> {
+        instructions::rotate_agent_key::handler(ctx, old_agent, new_agent)
+    }
+
     /// Reactivate a frozen vault. Optionally add a new agent with permissions.
     pub fn reactivate_vault(
         ctx: Context<ReactivateVault>,
@@ -137,6 +170,24 @@ pub mod sigil {
         has_protocol_caps: Option<bool>,
         protocol_caps: Option<Vec<u64>>,
         destination_mode: Option<u8>,
+        weekly_spending_cap_usd: Option<u64>,
+        monthly_spending_cap_usd: Option<u64>,
+        mint_caps: Option<Vec<state::MintSpendCap>>,
+        destination_caps: Option<Vec<u64>>,
+        trading_hours: Option<[u8; state::TRADING_HOURS_BYTES]>,
+        trading_hours_exempt_non_spending: Option<bool>,
+        guardian: Option<Pubkey>,
+        recovery_authority: Option<Pubkey>,
+        recovery_destination: Option<Pubkey>,
+        inactivity_window_seconds: Option<u64>,
+        beneficiary: Option<Pubkey>,
+        approvers: Option<Vec<Pubkey>>,
+        approval_threshold: Option<u8>,
+        cpi_callers: Option<Vec<Pubkey>>,
+        daily_fee_cap_usd: Option<u64>,
+        max_abandoned_sessions_per_hour: Option<u16>,
+        allow_async_fulfillment: Option<bool>,
+        max_protocol_slippage_bps: Option<u16>,
     ) -> Result<()> {
         instructions::queue_policy_update::handler(
             ctx,
@@ -152,6 +203,24 @@ pub mod sigil {
             has_protocol_caps,
             protocol_caps,
             destination_mode,
+            weekly_spending_cap_usd,
+            monthly_spending_cap_usd,
+            mint_caps,
+            destination_caps,
+            trading_hours,
+            trading_hours_exempt_non_spending,
+            guardian,
+            recovery_authority,
+            recovery_destination,
+            inactivity_window_seconds,
+            beneficiary,
+            approvers,
+            approval_threshold,
+            cpi_callers,
+            daily_fee_cap_usd,
+            max_abandoned_sessions_per_hour,
+            allow_async_fulfillment,
+            max_protocol_slippage_bps,
         )
     }
 
@@ -165,6 +234,42 @@ pub mod sigil {
         instructions::cancel_pending_policy::handler(ctx)
     }
 
+    /// Apply a strictly risk-reducing policy change immediately (no timelock).
+    /// Rejects the whole update if any field would loosen the policy.
+    /// Owner or guardian.
+    #[allow(clippy::too_many_arguments)]
+    pub fn tighten_policy(
+        ctx: Context<TightenPolicy>,
+        daily_spending_cap_usd: Option<u64>,
+        max_transaction_amount_usd: Option<u64>,
+        protocols: Option<Vec<Pubkey>>,
+        max_slippage_bps: Option<u16>,
+        allowed_destinations: Option<Vec<Pubkey>>,
+        session_expiry_seconds: Option<u64>,
+        protocol_caps: Option<Vec<u64>>,
+        destination_mode: Option<u8>,
+        weekly_spending_cap_usd: Option<u64>,
+        monthly_spending_cap_usd: Option<u64>,
+        mint_caps: Option<Vec<state::MintSpendCap>>,
+        destination_caps: Option<Vec<u64>>,
+    ) -> Result<()> {
+        instructions::tighten_policy::handler(
+            ctx,
+            daily_spending_cap_usd,
+            max_transaction_amount_usd,
+            protocols,
+            max_slippage_bps,
+            allowed_destinations,
+            session_expiry_seconds,
+            protocol_caps,
+            destination_mode,
+            weekly_spending_cap_usd,
+            monthly_spending_cap_usd,
+            mint_caps,
+            destination_caps,
+        )
+    }
+
     /// Allocate the InstructionConstraints PDA at 10,240 bytes (CPI limit).
     /// Must be followed by extend_pda calls + create_instruction_constraints
     /// in the same atomic transaction to reach full SIZE.
@@ -277,12 +382,24 @@ pub mod sigil {
         agent: Pubkey,
         new_capability: u8,
         spending_limit_usd: u64,
+        max_sessions_per_hour: u16,
+        protocols: Option<Vec<Pubkey>>,
+        max_transaction_size_usd: Option<u64>,
+        session_expiry_seconds: Option<u64>,
+        valid_until: Option<i64>,
+        max_concurrent_sessions: Option<u8>,
     ) -> Result<()> {
         instructions::queue_agent_permissions_update::handler(
             ctx,
             agent,
             new_capability,
             spending_limit_usd,
+            max_sessions_per_hour,
+            protocols,
+            max_transaction_size_usd,
+            session_expiry_seconds,
+            valid_until,
+            max_concurrent_sessions,
         )
     }
 
@@ -298,6 +415,168 @@ pub mod sigil {
         instructions::cancel_agent_permissions_update::handler(ctx)
     }
 
+    /// Queue a vault ownership transfer. Timelock-gated.
+    pub fn queue_owner_transfer(ctx: Context<QueueOwnerTransfer>, new_owner: Pubkey) -> Result<()> {
+        instructions::queue_owner_transfer::handler(ctx, new_owner)
+    }
+
+    /// Accept a queued ownership transfer after the timelock expires.
+    /// Signed by the incoming owner.
+    pub fn apply_owner_transfer(ctx: Context<ApplyOwnerTransfer>) -> Result<()> {
+        instructions::apply_owner_transfer::handler(ctx)
+    }
+
+    /// Cancel a queued ownership transfer.
+    pub fn cancel_owner_transfer(ctx: Context<CancelOwnerTransfer>) -> Result<()> {
+        instructions::cancel_owner_transfer::handler(ctx)
+    }
+
+    /// Queue a sweep of all vault funds to the recovery destination.
+    /// Signed by the recovery authority; freezes the vault immediately.
+    pub fn queue_recovery(ctx: Context<QueueRecovery>) -> Result<()> {
+        instructions::queue_recovery::handler(ctx)
+    }
+
+    /// Execute a queued recovery sweep after RECOVERY_TIMELOCK_SECONDS.
+    pub fn execute_recovery<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ExecuteRecovery<'info>>,
+        close_pending: bool,
+    ) -> Result<()> {
+        instructions::execute_recovery::handler(ctx, close_pending)
+    }
+
+    /// Cancel a queued recovery sweep. Owner only. `revoke_authority` also
+    /// removes the recovery authority so it cannot queue (and freeze) again.
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>, revoke_authority: bool) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx, revoke_authority)
+    }
+
+    /// Queue a developer fee destination rotation. Timelock-gated.
+    pub fn queue_fee_destination_update(
+        ctx: Context<QueueFeeDestinationUpdate>,
+        new_fee_destination: Pubkey,
+    ) -> Result<()> {
+        instructions::queue_fee_destination_update::handler(ctx, new_fee_destination)
+    }
+
+    /// Apply a queued fee destination rotation after the timelock expires.
+    pub fn apply_fee_destination_update(ctx: Context<ApplyFeeDestinationUpdate>) -> Result<()> {
+        instructions::apply_fee_destination_update::handler(ctx)
+    }
+
+    /// Cancel a queued fee destination rotation.
+    pub fn cancel_fee_destination_update(ctx: Context<CancelFeeDestinationUpdate>) -> Result<()> {
+        instructions::cancel_fee_destination_update::handler(ctx)
+    }
+
+    /// Create the permit nonce bitmap used by owner-signed spend permits.
+    pub fn initialize_permit_nonces(ctx: Context<InitializePermitNonces>) -> Result<()> {
+        instructions::initialize_permit_nonces::handler(ctx)
+    }
+
+    /// Create the SpendTrackerExtension for a vault migrated without one.
+    pub fn initialize_tracker_extension(ctx: Context<InitializeTrackerExtension>) -> Result<()> {
+        instructions::initialize_tracker_extension::handler(ctx)
+    }
+
+    /// Create the DestinationSpendTracker for a vault migrated without one.
+    pub fn initialize_destination_tracker(
+        ctx: Context<InitializeDestinationTracker>,
+    ) -> Result<()> {
+        instructions::initialize_destination_tracker::handler(ctx)
+    }
+
+    /// Rewrite a vault created by the pre-migration program (634-byte
+    /// AgentVault) into the current layout. Owner-only.
+    pub fn migrate_vault(ctx: Context<MigrateVault>, vault_id: u64) -> Result<()> {
+        instructions::migrate_vault::handler(ctx, vault_id)
+    }
+
+    /// Agent: request approval for a transfer above the per-transaction limit.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_spend_request(
+        ctx: Context<CreateSpendRequest>,
+        request_id: u64,
+        token_mint: Pubkey,
+        amount: u64,
+        destination: Pubkey,
+        reason_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_spend_request::handler(
+            ctx,
+            request_id,
+            token_mint,
+            amount,
+            destination,
+            reason_hash,
+            expires_at,
+        )
+    }
+
+    /// Owner or guardian: approve a pending spend request. The arguments
+    /// must match the request as reviewed.
+    pub fn approve_spend_request(
+        ctx: Context<ApproveSpendRequest>,
+        expected_amount: u64,
+        destination: Pubkey,
+        token_mint: Pubkey,
+        reason_hash: [u8; 32],
+        created_at: i64,
+    ) -> Result<()> {
+        instructions::approve_spend_request::handler(
+            ctx,
+            expected_amount,
+            destination,
+            token_mint,
+            reason_hash,
+            created_at,
+        )
+    }
+
+    /// Agent, owner or guardian: close an unexecuted spend request.
+    pub fn cancel_spend_request(ctx: Context<CancelSpendRequest>) -> Result<()> {
+        instructions::cancel_spend_request::handler(ctx)
+    }
+
+    /// Fire the dead-man switch once the owner has been inactive for the
+    /// configured window. Permissionless.
+    pub fn trigger_dead_man_switch(ctx: Context<TriggerDeadManSwitch>) -> Result<()> {
+        instructions::trigger_dead_man_switch::handler(ctx)
+    }
+
+    /// Withdraw funds to the beneficiary after the dead-man switch fired.
+    pub fn claim_beneficiary_funds(ctx: Context<ClaimBeneficiaryFunds>, amount: u64) -> Result<()> {
+        instructions::claim_beneficiary_funds::handler(ctx, amount)
+    }
+
+    /// Bundle already-queued policy, constraints and agent permission
+    /// updates so they apply together. Owner-only.
+    pub fn create_governance_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateGovernanceBundle<'info>>,
+    ) -> Result<()> {
+        instructions::create_governance_bundle::handler(ctx)
+    }
+
+    /// Apply every update in the governance bundle atomically with a single
+    /// policy_version bump. Owner-only.
+    pub fn apply_bundle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyBundle<'info>>,
+    ) -> Result<()> {
+        instructions::apply_bundle::handler(ctx)
+    }
+
+    /// Dissolve a governance bundle, leaving its updates queued. Owner-only.
+    pub fn cancel_governance_bundle(ctx: Context<CancelGovernanceBundle>) -> Result<()> {
+        instructions::cancel_governance_bundle::handler(ctx)
+    }
+
+    /// Settle an async-fulfillment reservation after its window. Permissionless;
+    /// only the owner's call releases the unused part from the spend caps.
+    pub fn reconcile_fulfillment(ctx: Context<ReconcileFulfillment>) -> Result<()> {
+        instructions::reconcile_fulfillment::handler(ctx)
+    }
+
     // sync_positions instruction DELETED — position counter system removed per council decision
     // (9-1 vote, 2026-04-19). See Plans/we-need-to-plan-serialized-summit.md.
 
@@ -331,7 +610,7 @@ pub mod sigil {
     }
 
     /// Freeze the vault immediately. Preserves all agent entries.
-    /// Only the owner can call this. Use reactivate_vault to unfreeze.
+    /// Owner or guardian. Use reactivate_vault (owner only) to unfreeze.
     /// F2-H1 fix: pairs of (session_pda, vault_token_account) in remaining_accounts
     /// are revoked so a runaway agent cannot continue spending against an
     /// in-flight session window.
@@ -342,7 +621,7 @@ pub mod sigil {
     }
 
     /// Pause a specific agent. Blocks all agent actions while preserving config.
-    /// Only the owner can call this.
+    /// Owner or guardian.
     pub fn pause_agent(ctx: Context<PauseAgent>, agent_to_pause: Pubkey) -> Result<()> {
         instructions::pause_agent::handler(ctx, agent_to_pause)
     }
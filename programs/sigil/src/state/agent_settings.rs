@@ -0,0 +1,85 @@
+use super::MAX_ALLOWED_PROTOCOLS;
+use anchor_lang::prelude::*;
+
+/// Per-agent settings that narrow the vault policy for a single agent.
+/// Created / updated by `apply_agent_permissions_update`, closed by
+/// `revoke_agent`. `AgentEntry::has_settings` records whether the PDA holds
+/// any restriction, in which case spend paths must pass it.
+///
+/// PDA seeds: `[b"agent_settings", vault.key().as_ref(), agent.as_ref()]`
+#[account]
+pub struct AgentSettings {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Agent these settings apply to
+    pub agent: Pubkey,
+
+    /// Protocols this agent may reach, checked in addition to the vault's
+    /// `is_protocol_allowed`. Empty = no per-agent restriction.
+    /// Bounded to MAX_ALLOWED_PROTOCOLS entries.
+    pub protocols: Vec<Pubkey>,
+
+    /// Per-agent single transaction ceiling in USD (6 decimals).
+    /// 0 = inherit the vault's `max_transaction_size_usd`; otherwise the
+    /// lower of the two applies.
+    pub max_transaction_size_usd: u64,
+
+    /// Per-agent session duration in seconds. 0 = inherit the vault's
+    /// effective session expiry; otherwise the shorter of the two applies.
+    pub session_expiry_seconds: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AgentSettings {
+    /// 8 (discriminator) + 32 (vault) + 32 (agent)
+    /// + protocols vec (4 + 32 * MAX) + max_transaction_size_usd (8)
+    /// + session_expiry_seconds (8) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + (4 + 32 * MAX_ALLOWED_PROTOCOLS) + 8 + 8 + 1;
+
+    /// Check if the agent's own subset allows `program_id`.
+    pub fn is_protocol_allowed(&self, program_id: &Pubkey) -> bool {
+        self.protocols.is_empty() || self.protocols.contains(program_id)
+    }
+
+    /// Effective per-transaction ceiling given the vault's.
+    pub fn effective_max_transaction_size_usd(&self, vault_max_usd: u64) -> u64 {
+        tighter_override(vault_max_usd, self.max_transaction_size_usd)
+    }
+
+    /// Effective session duration given the vault's effective expiry.
+    pub fn effective_session_expiry_seconds(&self, vault_seconds: u64) -> u64 {
+        tighter_override(vault_seconds, self.session_expiry_seconds)
+    }
+
+    /// Whether any setting narrows the vault policy for this agent.
+    pub fn is_restrictive(&self) -> bool {
+        !self.protocols.is_empty()
+            || self.max_transaction_size_usd > 0
+            || self.session_expiry_seconds > 0
+    }
+}
+
+/// Apply a per-agent override (0 = none) on top of a vault value. The
+/// override can only tighten: a larger override never beats the vault value.
+pub fn tighter_override(vault_value: u64, agent_override: u64) -> u64 {
+    if agent_override == 0 {
+        vault_value
+    } else {
+        vault_value.min(agent_override)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_only_tightens() {
+        assert_eq!(tighter_override(1_000_000_000, 0), 1_000_000_000);
+        assert_eq!(tighter_override(1_000_000_000, 50_000_000), 50_000_000);
+        assert_eq!(tighter_override(50_000_000, 1_000_000_000), 50_000_000);
+    }
+}
@@ -15,7 +15,7 @@ pub const OVERLAY_ROLLING_WINDOW_SECONDS: i64 = 86_400;
 
 /// Maximum number of agent entries per overlay.
 /// 10 matches MAX_AGENTS_PER_VAULT so every registered agent can have per-agent tracking.
-/// Account size: 2,528 bytes (well within Solana's 10,240-byte CPI limit).
+/// Account size: 2,688 bytes (well within Solana's 10,240-byte CPI limit).
 pub const MAX_OVERLAY_ENTRIES: usize = 10;
 
 /// Per-agent contribution entry within an overlay.
@@ -38,6 +38,68 @@ pub struct AgentContributionEntry {
     pub contributions: [u64; OVERLAY_NUM_EPOCHS],
 }
 
+/// Per-agent session counter on the overlay's hourly epoch.
+/// Holds the current and previous epoch counts so the last-hour count can be
+/// approximated as a sliding window without storing per-session timestamps.
+///
+/// Layout: 8 (epoch) + 4 (current) + 4 (previous) = 16 bytes
+#[zero_copy]
+pub struct AgentSessionCounter {
+    /// Epoch of `current`: unix_timestamp / OVERLAY_EPOCH_DURATION
+    pub epoch: i64,
+
+    /// Sessions started during `epoch`
+    pub current: u32,
+
+    /// Sessions started during `epoch - 1`
+    pub previous: u32,
+}
+
+impl AgentSessionCounter {
+    /// Approximate count over the last hour at `now`.
+    ///
+    /// Sliding-window estimate: the current epoch's count plus the previous
+    /// epoch's count scaled by the part of it still inside the window,
+    /// rounded up so the estimate never undercounts.
+    pub fn last_hour(&self, now: i64) -> u32 {
+        if now <= 0 {
+            return 0;
+        }
+
+        let current_epoch = now / OVERLAY_EPOCH_DURATION;
+        let (current, previous) = if self.epoch == current_epoch {
+            (self.current, self.previous)
+        } else if self.epoch == current_epoch - 1 {
+            (0, self.current)
+        } else {
+            return 0;
+        };
+
+        let remaining = (OVERLAY_EPOCH_DURATION - now % OVERLAY_EPOCH_DURATION) as u64;
+        let scaled = (previous as u64 * remaining).div_ceil(OVERLAY_EPOCH_DURATION as u64);
+        current.saturating_add(scaled as u32)
+    }
+
+    /// Count one occurrence at `now`, rolling the epochs forward first.
+    pub fn record(&mut self, now: i64) -> Result<()> {
+        let current_epoch = now / OVERLAY_EPOCH_DURATION;
+        if self.epoch != current_epoch {
+            self.previous = if self.epoch == current_epoch - 1 {
+                self.current
+            } else {
+                0
+            };
+            self.current = 0;
+            self.epoch = current_epoch;
+        }
+        self.current = self
+            .current
+            .checked_add(1)
+            .ok_or(error!(SigilError::Overflow))?;
+        Ok(())
+    }
+}
+
 /// Per-vault overlay PDA tracking per-agent spend contributions.
 ///
 /// Seeds: `[b"agent_spend", vault.key().as_ref(), &[0u8]]`
@@ -45,7 +107,7 @@ pub struct AgentContributionEntry {
 /// Supports up to 10 agents (matches MAX_AGENTS_PER_VAULT).
 ///
 /// Size calculation:
-///   8 (discriminator) + 32 (vault) + 232 × 10 (entries) + 1 (bump) + 7 (padding) + 80 (lifetime_spend) + 80 (lifetime_tx_count) = 2,528 bytes
+///   8 (discriminator) + 32 (vault) + 232 × 10 (entries) + 1 (bump) + 7 (padding) + 80 (lifetime_spend) + 80 (lifetime_tx_count) + 160 (session_counters) + 160 (abandon_counters) = 2,848 bytes
 #[account(zero_copy)]
 pub struct AgentSpendOverlay {
     /// Associated vault pubkey
@@ -74,8 +136,18 @@ pub struct AgentSpendOverlay {
     /// Incremented in finalize_session for EVERY successful spending session.
     /// Used for: avg TX size (lifetime_spend / lifetime_tx_count), agent activity ranking.
     pub lifetime_tx_count: [u64; MAX_OVERLAY_ENTRIES], // 80 bytes
+
+    /// Per-agent session counters for `max_sessions_per_hour`. Index matches entries[i].
+    /// Incremented in validate_and_authorize and agent_transfer.
+    /// Appended AFTER existing layout to preserve zero-copy byte offsets.
+    pub session_counters: [AgentSessionCounter; MAX_OVERLAY_ENTRIES], // 160 bytes
+
+    /// Per-agent abandoned-session strikes for `max_abandoned_sessions_per_hour`.
+    /// Index matches entries[i]. Incremented in finalize_session.
+    /// Appended AFTER existing layout to preserve zero-copy byte offsets.
+    pub abandon_counters: [AgentSessionCounter; MAX_OVERLAY_ENTRIES], // 160 bytes
 }
-// Total data: 2,360 + 80 + 80 bytes + 8 (discriminator) = 2,528 bytes
+// Total data: 2,360 + 80 + 80 + 160 + 160 bytes + 8 (discriminator) = 2,848 bytes
 
 impl AgentSpendOverlay {
     /// Total account size including 8-byte discriminator
@@ -85,8 +157,10 @@ impl AgentSpendOverlay {
         + 1
         + 7
         + (8 * MAX_OVERLAY_ENTRIES)
-        + (8 * MAX_OVERLAY_ENTRIES);
-    // = 8 + 32 + 2320 + 1 + 7 + 80 + 80 = 2,528
+        + (8 * MAX_OVERLAY_ENTRIES)
+        + (16 * MAX_OVERLAY_ENTRIES)
+        + (16 * MAX_OVERLAY_ENTRIES);
+    // = 8 + 32 + 2320 + 1 + 7 + 80 + 80 + 160 + 160 = 2,848
 
     /// Find the slot index for a given agent, or None if not present.
     pub fn find_agent_slot(&self, agent: &Pubkey) -> Option<usize> {
@@ -107,6 +181,19 @@ impl AgentSpendOverlay {
         }
     }
 
+    /// Move `old_agent`'s slot to `new_agent` in place, keeping contributions,
+    /// lifetime stats and session counters. Returns false if `old_agent` has
+    /// no slot.
+    pub fn rekey_slot(&mut self, old_agent: &Pubkey, new_agent: &Pubkey) -> bool {
+        match self.find_agent_slot(old_agent) {
+            Some(idx) => {
+                self.entries[idx].agent = new_agent.to_bytes();
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Release a slot by zeroing the agent key, last_write_epoch, and all contribution buckets.
     /// Called when an agent is revoked to prevent slot leaks.
     pub fn release_slot(&mut self, slot_idx: usize) {
@@ -120,6 +207,16 @@ impl AgentSpendOverlay {
         }
         self.lifetime_spend[slot_idx] = 0;
         self.lifetime_tx_count[slot_idx] = 0;
+        self.session_counters[slot_idx] = AgentSessionCounter {
+            epoch: 0,
+            current: 0,
+            previous: 0,
+        };
+        self.abandon_counters[slot_idx] = AgentSessionCounter {
+            epoch: 0,
+            current: 0,
+            previous: 0,
+        };
     }
 
     /// Zero contribution buckets in the gap between last_write_epoch and current_epoch.
@@ -249,4 +346,183 @@ impl AgentSpendOverlay {
 
         Ok(())
     }
+
+    /// Release part of a contribution recorded at `recorded_at`. No-op once
+    /// that epoch's bucket has left the 24h ring.
+    pub fn release_agent_contribution(
+        &mut self,
+        recorded_at: i64,
+        slot_idx: usize,
+        usd_amount: u64,
+    ) {
+        if recorded_at <= 0 || slot_idx >= MAX_OVERLAY_ENTRIES {
+            return;
+        }
+        let epoch = recorded_at / OVERLAY_EPOCH_DURATION;
+        let entry = &mut self.entries[slot_idx];
+        if epoch > entry.last_write_epoch
+            || entry.last_write_epoch - epoch >= OVERLAY_NUM_EPOCHS as i64
+        {
+            return;
+        }
+        let idx = (epoch % OVERLAY_NUM_EPOCHS as i64) as usize;
+        entry.contributions[idx] = entry.contributions[idx].saturating_sub(usd_amount);
+    }
+
+    /// Approximate number of sessions the agent started in the last hour.
+    pub fn get_agent_sessions_last_hour(&self, clock: &Clock, slot_idx: usize) -> u32 {
+        if slot_idx >= MAX_OVERLAY_ENTRIES {
+            return 0;
+        }
+        self.session_counters[slot_idx].last_hour(clock.unix_timestamp)
+    }
+
+    /// Enforce `max_sessions_per_hour` (0 = unlimited) and record one session.
+    /// The session is recorded even when no limit is set, so the history is
+    /// already populated if the owner enables a limit later.
+    pub fn check_and_record_session(
+        &mut self,
+        clock: &Clock,
+        slot_idx: usize,
+        max_sessions_per_hour: u16,
+    ) -> Result<()> {
+        if slot_idx >= MAX_OVERLAY_ENTRIES {
+            return Err(error!(SigilError::Overflow));
+        }
+
+        if max_sessions_per_hour > 0 {
+            require!(
+                self.get_agent_sessions_last_hour(clock, slot_idx) < max_sessions_per_hour as u32,
+                SigilError::AgentSessionRateExceeded
+            );
+        }
+
+        self.session_counters[slot_idx].record(clock.unix_timestamp)
+    }
+
+    /// Record one abandoned session for the agent and return the strikes
+    /// in the last hour, including this one.
+    pub fn record_abandon(&mut self, clock: &Clock, slot_idx: usize) -> Result<u32> {
+        if slot_idx >= MAX_OVERLAY_ENTRIES {
+            return Err(error!(SigilError::Overflow));
+        }
+        let counter = &mut self.abandon_counters[slot_idx];
+        counter.record(clock.unix_timestamp)?;
+        Ok(counter.last_hour(clock.unix_timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AGENT: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+    fn overlay() -> AgentSpendOverlay {
+        let mut overlay: AgentSpendOverlay = bytemuck::Zeroable::zeroed();
+        overlay.claim_slot(&AGENT);
+        overlay
+    }
+
+    fn clock_at(ts: i64) -> Clock {
+        Clock {
+            unix_timestamp: ts,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn session_limit_blocks_after_max() {
+        let mut o = overlay();
+        let clock = clock_at(1_000 * OVERLAY_EPOCH_DURATION);
+        for _ in 0..3 {
+            o.check_and_record_session(&clock, 0, 3).unwrap();
+        }
+        assert!(o.check_and_record_session(&clock, 0, 3).is_err());
+        assert_eq!(o.get_agent_sessions_last_hour(&clock, 0), 3);
+    }
+
+    #[test]
+    fn unlimited_still_records() {
+        let mut o = overlay();
+        let clock = clock_at(1_000 * OVERLAY_EPOCH_DURATION);
+        for _ in 0..5 {
+            o.check_and_record_session(&clock, 0, 0).unwrap();
+        }
+        assert_eq!(o.get_agent_sessions_last_hour(&clock, 0), 5);
+    }
+
+    #[test]
+    fn previous_epoch_decays_across_boundary() {
+        let mut o = overlay();
+        let start = 1_000 * OVERLAY_EPOCH_DURATION;
+        for _ in 0..4 {
+            o.check_and_record_session(&clock_at(start + 3_000), 0, 4)
+                .unwrap();
+        }
+        // Half-way into the next epoch, half of the previous count remains.
+        let half = clock_at(start + OVERLAY_EPOCH_DURATION + OVERLAY_EPOCH_DURATION / 2);
+        assert_eq!(o.get_agent_sessions_last_hour(&half, 0), 2);
+        o.check_and_record_session(&half, 0, 4).unwrap();
+        o.check_and_record_session(&half, 0, 4).unwrap();
+        assert!(o.check_and_record_session(&half, 0, 4).is_err());
+
+        // Two epochs later the window is empty.
+        let later = clock_at(start + 3 * OVERLAY_EPOCH_DURATION);
+        assert_eq!(o.get_agent_sessions_last_hour(&later, 0), 0);
+    }
+
+    #[test]
+    fn rekey_slot_keeps_history() {
+        let mut o = overlay();
+        let clock = clock_at(1_000 * OVERLAY_EPOCH_DURATION);
+        o.record_agent_contribution(&clock, 0, 40_000_000).unwrap();
+        o.check_and_record_session(&clock, 0, 0).unwrap();
+
+        let new_agent = Pubkey::new_from_array([9u8; 32]);
+        assert!(o.rekey_slot(&AGENT, &new_agent));
+        assert_eq!(o.find_agent_slot(&AGENT), None);
+        let slot = o.find_agent_slot(&new_agent).unwrap();
+        assert_eq!(o.get_agent_rolling_24h_usd(&clock, slot), 40_000_000);
+        assert_eq!(o.get_agent_sessions_last_hour(&clock, slot), 1);
+        assert!(!o.rekey_slot(&AGENT, &new_agent));
+    }
+
+    #[test]
+    fn release_slot_clears_session_counter() {
+        let mut o = overlay();
+        let clock = clock_at(1_000 * OVERLAY_EPOCH_DURATION);
+        o.check_and_record_session(&clock, 0, 0).unwrap();
+        o.release_slot(0);
+        assert_eq!(o.get_agent_sessions_last_hour(&clock, 0), 0);
+    }
+
+    #[test]
+    fn abandon_strikes_are_separate_from_sessions() {
+        let mut o = overlay();
+        let clock = clock_at(1_000 * OVERLAY_EPOCH_DURATION);
+        o.check_and_record_session(&clock, 0, 0).unwrap();
+        assert_eq!(o.record_abandon(&clock, 0).unwrap(), 1);
+        assert_eq!(o.record_abandon(&clock, 0).unwrap(), 2);
+        assert_eq!(o.get_agent_sessions_last_hour(&clock, 0), 1);
+
+        o.release_slot(0);
+        assert_eq!(o.record_abandon(&clock, 0).unwrap(), 1);
+    }
+
+    #[test]
+    fn release_contribution_within_window() {
+        let mut o = overlay();
+        let t0 = 1_000 * OVERLAY_EPOCH_DURATION;
+        o.record_agent_contribution(&clock_at(t0), 0, 1_000)
+            .unwrap();
+        o.release_agent_contribution(t0, 0, 300);
+        assert_eq!(o.get_agent_rolling_24h_usd(&clock_at(t0), 0), 700);
+
+        let later = t0 + OVERLAY_NUM_EPOCHS as i64 * OVERLAY_EPOCH_DURATION;
+        o.record_agent_contribution(&clock_at(later), 0, 50)
+            .unwrap();
+        o.release_agent_contribution(t0, 0, 700);
+        assert_eq!(o.get_agent_rolling_24h_usd(&clock_at(later), 0), 50);
+    }
 }
@@ -0,0 +1,202 @@
+use crate::errors::SigilError;
+use crate::state::{record_in_buckets, rolling_sum, EpochBucket, MAX_ALLOWED_DESTINATIONS};
+use anchor_lang::prelude::*;
+
+/// 1-hour epoch duration for per-destination windows
+pub const DESTINATION_EPOCH_DURATION: i64 = 3_600;
+
+/// Number of epochs in a per-destination 24h window (24 × 1h = 24h)
+pub const NUM_DESTINATION_EPOCHS: usize = 24;
+
+/// Rolling 24h spend sent to a single destination owner.
+/// 416 bytes per entry (32 + 24 × 16).
+#[zero_copy]
+pub struct DestinationSpendEntry {
+    /// Destination owner stored as raw bytes. All-zero = unused slot.
+    pub destination: [u8; 32],
+
+    /// 24 one-hour buckets for the rolling 24h window
+    pub buckets: [EpochBucket; NUM_DESTINATION_EPOCHS],
+}
+
+/// Per-vault tracker for `PolicyConfig::destination_caps`. One entry per
+/// destination owner that `agent_transfer` has paid, so a listed vendor
+/// cannot absorb the whole daily cap.
+///
+/// Seeds: `[b"destination_spend", vault.key().as_ref()]`
+#[account(zero_copy)]
+pub struct DestinationSpendTracker {
+    /// Associated vault pubkey
+    pub vault: Pubkey, // 32 bytes
+
+    /// Per-destination windows (up to MAX_ALLOWED_DESTINATIONS)
+    pub entries: [DestinationSpendEntry; MAX_ALLOWED_DESTINATIONS], // 4,160 bytes (10 × 416)
+
+    /// Bump seed for PDA
+    pub bump: u8, // 1 byte
+
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7], // 7 bytes
+}
+// Total data: 4,200 bytes + 8 (discriminator) = 4,208 bytes
+
+impl DestinationSpendTracker {
+    /// Total account size including 8-byte discriminator
+    pub const SIZE: usize =
+        8 + 32 + ((32 + 16 * NUM_DESTINATION_EPOCHS) * MAX_ALLOWED_DESTINATIONS) + 1 + 7;
+
+    /// Rolling 24h USD sent to `destination`. 0 if it has no entry.
+    pub fn get_destination_rolling_24h_usd(&self, clock: &Clock, destination: &Pubkey) -> u64 {
+        let dest_bytes = destination.to_bytes();
+        self.entries
+            .iter()
+            .find(|e| e.destination == dest_bytes)
+            .map_or(0, |e| {
+                rolling_sum(&e.buckets, DESTINATION_EPOCH_DURATION, clock.unix_timestamp)
+            })
+    }
+
+    /// Check `usd_amount` against `cap_usd` (0 = no per-destination cap),
+    /// then record it. Returns the destination's rolling 24h spend after
+    /// recording.
+    ///
+    /// A destination without an entry takes an unused slot, or else recycles
+    /// one for which `is_capped` is false. If every slot holds a capped
+    /// destination, `destination` itself is uncapped (there are at most
+    /// MAX_ALLOWED_DESTINATIONS caps) and is not tracked.
+    pub fn check_and_record(
+        &mut self,
+        clock: &Clock,
+        destination: &Pubkey,
+        cap_usd: u64,
+        usd_amount: u64,
+        is_capped: impl Fn(&Pubkey) -> bool,
+    ) -> Result<u64> {
+        require!(clock.unix_timestamp > 0, SigilError::Overflow);
+        let new_total = self
+            .get_destination_rolling_24h_usd(clock, destination)
+            .checked_add(usd_amount)
+            .ok_or(SigilError::Overflow)?;
+        if cap_usd > 0 {
+            require!(
+                new_total <= cap_usd,
+                SigilError::DestinationSpendCapExceeded
+            );
+        }
+
+        let dest_bytes = destination.to_bytes();
+        let slot = match self
+            .entries
+            .iter()
+            .position(|e| e.destination == dest_bytes)
+        {
+            Some(idx) => idx,
+            None => {
+                let free = self
+                    .entries
+                    .iter()
+                    .position(|e| e.destination == [0u8; 32])
+                    .or_else(|| {
+                        self.entries
+                            .iter()
+                            .position(|e| !is_capped(&Pubkey::new_from_array(e.destination)))
+                    });
+                let Some(idx) = free else {
+                    return Ok(new_total);
+                };
+                self.entries[idx] = DestinationSpendEntry {
+                    destination: dest_bytes,
+                    buckets: [EpochBucket::default(); NUM_DESTINATION_EPOCHS],
+                };
+                idx
+            }
+        };
+        record_in_buckets(
+            &mut self.entries[slot].buckets,
+            DESTINATION_EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )?;
+        Ok(new_total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: i64 = 1_700_000_000;
+
+    fn clock_at(ts: i64) -> Clock {
+        Clock {
+            unix_timestamp: ts,
+            ..Clock::default()
+        }
+    }
+
+    fn empty() -> DestinationSpendTracker {
+        DestinationSpendTracker {
+            vault: Pubkey::default(),
+            entries: [DestinationSpendEntry {
+                destination: [0; 32],
+                buckets: [EpochBucket::default(); NUM_DESTINATION_EPOCHS],
+            }; MAX_ALLOWED_DESTINATIONS],
+            bump: 0,
+            _padding: [0; 7],
+        }
+    }
+
+    #[test]
+    fn size_matches_layout() {
+        assert_eq!(
+            DestinationSpendTracker::SIZE,
+            8 + core::mem::size_of::<DestinationSpendTracker>()
+        );
+    }
+
+    #[test]
+    fn cap_is_per_destination() {
+        let mut t = empty();
+        let clock = clock_at(T0);
+        let (vendor, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(
+            t.check_and_record(&clock, &vendor, 200, 150, |_| true)
+                .unwrap(),
+            150
+        );
+        assert!(t
+            .check_and_record(&clock, &vendor, 200, 51, |_| true)
+            .is_err());
+        t.check_and_record(&clock, &other, 200, 200, |_| true)
+            .unwrap();
+        assert_eq!(t.get_destination_rolling_24h_usd(&clock, &vendor), 150);
+    }
+
+    #[test]
+    fn window_rolls_over_after_24h() {
+        let mut t = empty();
+        let vendor = Pubkey::new_unique();
+        t.check_and_record(&clock_at(T0), &vendor, 200, 200, |_| true)
+            .unwrap();
+        t.check_and_record(&clock_at(T0 + 86_400 + 3_600), &vendor, 200, 200, |_| true)
+            .unwrap();
+    }
+
+    #[test]
+    fn full_tracker_recycles_uncapped_destination() {
+        let mut t = empty();
+        let clock = clock_at(T0);
+        let uncapped: Vec<Pubkey> = (0..MAX_ALLOWED_DESTINATIONS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        for d in &uncapped {
+            t.check_and_record(&clock, d, 0, 10, |_| false).unwrap();
+        }
+        let vendor = Pubkey::new_unique();
+        t.check_and_record(&clock, &vendor, 100, 60, |d| *d == vendor)
+            .unwrap();
+        assert!(t
+            .check_and_record(&clock, &vendor, 100, 60, |d| *d == vendor)
+            .is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of agent permission updates one bundle can reference.
+pub const MAX_BUNDLE_AGENT_UPDATES: usize = 4;
+
+/// An agent permissions update referenced by a bundle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BundledAgentUpdate {
+    pub agent: Pubkey,
+    /// `queued_at_slot` of the referenced `PendingAgentPermissionsUpdate`
+    pub queued_at_slot: u64,
+}
+
+/// Groups already-queued pending updates so `apply_bundle` lands them in one
+/// transaction with a single `policy_version` bump, or not at all.
+/// Each component is pinned by the `queued_at_slot` it had at bundle
+/// creation; cancelling and re-queuing a component breaks the bundle.
+///
+/// PDA seeds: `[b"governance_bundle", vault.key().as_ref()]`
+#[account]
+pub struct GovernanceBundle {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Whether the vault's `PendingPolicyUpdate` is part of the bundle
+    pub includes_policy: bool,
+    pub policy_queued_at_slot: u64,
+
+    /// Whether the vault's `PendingConstraintsUpdate` is part of the bundle
+    pub includes_constraints: bool,
+    pub constraints_queued_at_slot: u64,
+
+    /// Whether the vault's `PendingCloseConstraints` is part of the bundle
+    pub includes_close_constraints: bool,
+    pub close_constraints_queued_at_slot: u64,
+
+    /// Referenced `PendingAgentPermissionsUpdate`s, one per agent.
+    /// Bounded to MAX_BUNDLE_AGENT_UPDATES entries.
+    pub agent_updates: Vec<BundledAgentUpdate>,
+
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GovernanceBundle {
+    /// 8 (discriminator) + 32 (vault) + 3 x (1 flag + 8 slot)
+    /// + agent_updates vec (4 + 40 * MAX) + 8 (created_at) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 3 * (1 + 8) + (4 + 40 * MAX_BUNDLE_AGENT_UPDATES) + 8 + 1;
+
+    /// Number of pending updates the bundle references.
+    pub fn component_count(&self) -> usize {
+        usize::from(self.includes_policy)
+            + usize::from(self.includes_constraints)
+            + usize::from(self.includes_close_constraints)
+            + self.agent_updates.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> GovernanceBundle {
+        GovernanceBundle {
+            vault: Pubkey::new_unique(),
+            includes_policy: false,
+            policy_queued_at_slot: 0,
+            includes_constraints: false,
+            constraints_queued_at_slot: 0,
+            includes_close_constraints: false,
+            close_constraints_queued_at_slot: 0,
+            agent_updates: Vec::new(),
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn component_count_covers_every_kind() {
+        let mut b = bundle();
+        assert_eq!(b.component_count(), 0);
+        b.includes_policy = true;
+        b.includes_constraints = true;
+        b.agent_updates.push(BundledAgentUpdate {
+            agent: Pubkey::new_unique(),
+            queued_at_slot: 7,
+        });
+        assert_eq!(b.component_count(), 3);
+    }
+
+    #[test]
+    fn size_fits_full_bundle() {
+        let mut b = bundle();
+        b.includes_policy = true;
+        b.includes_close_constraints = true;
+        for _ in 0..MAX_BUNDLE_AGENT_UPDATES {
+            b.agent_updates.push(BundledAgentUpdate {
+                agent: Pubkey::new_unique(),
+                queued_at_slot: u64::MAX,
+            });
+        }
+        let mut data = Vec::new();
+        b.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), GovernanceBundle::SIZE);
+    }
+}
@@ -0,0 +1,358 @@
+use super::{
+    AgentEntry, AgentSpendOverlay, AgentVault, PolicyConfig, VaultStatus, TRADING_HOURS_BYTES,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// `AgentVault::SIZE` before migration. Vaults created before creator, the
+/// delegated keys, the approver set and the tracker flags were added have
+/// this size and fail to deserialize as the current `AgentVault`; their
+/// policy and overlay are short as well. `migrate_vault` rewrites all three.
+pub const LEGACY_VAULT_SIZE: usize = 634;
+
+/// `AgentEntry::SIZE` before migration. The 7 reserved bytes now hold
+/// max_sessions_per_hour, has_settings and valid_until; the concurrent
+/// session fields widened each entry to 51 bytes, so migrate_vault rewrites
+/// the agents list rather than reinterpreting it in place.
+pub const LEGACY_AGENT_ENTRY_SIZE: usize = 49;
+
+/// `AgentSpendOverlay::SIZE` before session and abandon counters.
+pub const LEGACY_OVERLAY_SIZE: usize = 2_528;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct LegacyAgentEntry {
+    pub pubkey: Pubkey,
+    pub capability: u8,
+    pub spending_limit_usd: u64,
+    pub paused: bool,
+    pub _reserved: [u8; 7],
+}
+
+impl From<LegacyAgentEntry> for AgentEntry {
+    /// The reserved bytes were always zero, which is also the "unset" value
+    /// of every field carved from them.
+    fn from(entry: LegacyAgentEntry) -> Self {
+        AgentEntry {
+            pubkey: entry.pubkey,
+            capability: entry.capability,
+            spending_limit_usd: entry.spending_limit_usd,
+            paused: entry.paused,
+            max_sessions_per_hour: 0,
+            has_settings: false,
+            valid_until: 0,
+            max_concurrent_sessions: 0,
+            active_sessions: 0,
+        }
+    }
+}
+
+/// `AgentVault` as laid out before migration. Its PDA was derived from
+/// `owner`, which is why `creator` starts out equal to it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct LegacyAgentVault {
+    pub owner: Pubkey,
+    pub vault_id: u64,
+    pub agents: Vec<LegacyAgentEntry>,
+    pub fee_destination: Pubkey,
+    pub status: VaultStatus,
+    pub bump: u8,
+    pub created_at: i64,
+    pub total_transactions: u64,
+    pub total_volume: u64,
+    pub active_escrow_count: u8,
+    pub total_fees_collected: u64,
+    pub total_deposited_usd: u64,
+    pub total_withdrawn_usd: u64,
+    pub total_failed_transactions: u64,
+    pub active_sessions: u8,
+}
+
+impl LegacyAgentVault {
+    /// Decode raw account data, or None if it is not a pre-migration vault.
+    pub fn try_from_data(data: &[u8]) -> Option<Self> {
+        if data.len() != LEGACY_VAULT_SIZE || &data[..8] != AgentVault::DISCRIMINATOR {
+            return None;
+        }
+        Self::deserialize(&mut &data[8..]).ok()
+    }
+
+    /// Current layout of this vault. The optional trackers start out absent
+    /// so the owner creates them with initialize_tracker_extension and
+    /// initialize_destination_tracker.
+    pub fn into_current(self, now: i64) -> AgentVault {
+        AgentVault {
+            owner: self.owner,
+            vault_id: self.vault_id,
+            agents: self.agents.into_iter().map(AgentEntry::from).collect(),
+            fee_destination: self.fee_destination,
+            status: self.status,
+            bump: self.bump,
+            created_at: self.created_at,
+            total_transactions: self.total_transactions,
+            total_volume: self.total_volume,
+            active_escrow_count: self.active_escrow_count,
+            total_fees_collected: self.total_fees_collected,
+            total_deposited_usd: self.total_deposited_usd,
+            total_withdrawn_usd: self.total_withdrawn_usd,
+            total_failed_transactions: self.total_failed_transactions,
+            active_sessions: self.active_sessions,
+            guardian: Pubkey::default(),
+            creator: self.owner,
+            recovery_authority: Pubkey::default(),
+            recovery_destination: Pubkey::default(),
+            last_owner_activity: now,
+            inactivity_window_seconds: 0,
+            beneficiary: Pubkey::default(),
+            dead_man_triggered: false,
+            approvers: Vec::new(),
+            approval_threshold: 0,
+            cpi_callers: Vec::new(),
+            open_fulfillments: 0,
+            has_tracker_extension: false,
+            open_bundle: false,
+            has_destination_tracker: false,
+            owner_changed_at_slot: 0,
+        }
+    }
+}
+
+/// `PolicyConfig` as laid out before migration (through `destination_mode`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct LegacyPolicyConfig {
+    pub vault: Pubkey,
+    pub daily_spending_cap_usd: u64,
+    pub max_transaction_size_usd: u64,
+    pub protocol_mode: u8,
+    pub protocols: Vec<Pubkey>,
+    pub developer_fee_rate: u16,
+    pub max_slippage_bps: u16,
+    pub timelock_duration: u64,
+    pub allowed_destinations: Vec<Pubkey>,
+    pub has_constraints: bool,
+    pub has_pending_policy: bool,
+    pub has_protocol_caps: bool,
+    pub protocol_caps: Vec<u64>,
+    pub session_expiry_seconds: u64,
+    pub bump: u8,
+    pub policy_version: u64,
+    pub has_post_assertions: u8,
+    pub destination_mode: u8,
+}
+
+impl LegacyPolicyConfig {
+    /// Decode raw account data, or None if it is not a pre-migration policy.
+    /// Bytes past `destination_mode` may be left over from a longer list
+    /// and are ignored.
+    pub fn try_from_data(data: &[u8]) -> Option<Self> {
+        if data.len() >= PolicyConfig::SIZE || data.len() < 8 {
+            return None;
+        }
+        if &data[..8] != PolicyConfig::DISCRIMINATOR {
+            return None;
+        }
+        Self::deserialize(&mut &data[8..]).ok()
+    }
+
+    /// Current layout of this policy. Everything added since starts
+    /// disabled. A pending update queued under the old layout cannot be
+    /// read any more, so `has_pending_policy` is cleared and migrate_vault
+    /// closes the account.
+    pub fn into_current(self) -> PolicyConfig {
+        PolicyConfig {
+            vault: self.vault,
+            daily_spending_cap_usd: self.daily_spending_cap_usd,
+            max_transaction_size_usd: self.max_transaction_size_usd,
+            protocol_mode: self.protocol_mode,
+            protocols: self.protocols,
+            developer_fee_rate: self.developer_fee_rate,
+            max_slippage_bps: self.max_slippage_bps,
+            timelock_duration: self.timelock_duration,
+            allowed_destinations: self.allowed_destinations,
+            has_constraints: self.has_constraints,
+            has_pending_policy: false,
+            has_protocol_caps: self.has_protocol_caps,
+            protocol_caps: self.protocol_caps,
+            session_expiry_seconds: self.session_expiry_seconds,
+            bump: self.bump,
+            policy_version: self.policy_version,
+            has_post_assertions: self.has_post_assertions,
+            destination_mode: self.destination_mode,
+            weekly_spending_cap_usd: 0,
+            monthly_spending_cap_usd: 0,
+            mint_caps: Vec::new(),
+            destination_caps: Vec::new(),
+            trading_hours: [0; TRADING_HOURS_BYTES],
+            trading_hours_exempt_non_spending: false,
+            daily_fee_cap_usd: 0,
+            max_abandoned_sessions_per_hour: 0,
+            allow_async_fulfillment: false,
+            max_protocol_slippage_bps: 0,
+            tightened_at_slot: 0,
+        }
+    }
+}
+
+/// True when `data` is a pre-migration `AgentSpendOverlay`. The counters
+/// added since are appended, so growing it with zeroes is the migration.
+pub fn is_legacy_overlay(data: &[u8]) -> bool {
+    data.len() == LEGACY_OVERLAY_SIZE && &data[..8] == AgentSpendOverlay::DISCRIMINATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MAX_AGENTS_PER_VAULT, MAX_APPROVERS, MAX_CPI_CALLERS};
+    use anchor_lang::AccountDeserialize;
+
+    fn legacy_vault(owner: Pubkey, agents: usize) -> LegacyAgentVault {
+        LegacyAgentVault {
+            owner,
+            vault_id: 7,
+            agents: (0..agents)
+                .map(|i| LegacyAgentEntry {
+                    pubkey: Pubkey::new_unique(),
+                    capability: 2,
+                    spending_limit_usd: 100_000_000 * (i as u64 + 1),
+                    paused: i == 1,
+                    _reserved: [0; 7],
+                })
+                .collect(),
+            fee_destination: Pubkey::new_unique(),
+            status: VaultStatus::Active,
+            bump: 254,
+            created_at: 1_700_000_000,
+            total_transactions: 42,
+            total_volume: 9_000_000,
+            active_escrow_count: 0,
+            total_fees_collected: 1_234,
+            total_deposited_usd: 50_000_000,
+            total_withdrawn_usd: 10_000_000,
+            total_failed_transactions: 3,
+            active_sessions: 0,
+        }
+    }
+
+    /// Account data as the deployed program allocated it: discriminator,
+    /// borsh body, zero padding up to 634 bytes.
+    fn account_data(vault: &LegacyAgentVault) -> Vec<u8> {
+        let mut data = AgentVault::DISCRIMINATOR.to_vec();
+        vault.serialize(&mut data).unwrap();
+        data.resize(LEGACY_VAULT_SIZE, 0);
+        data
+    }
+
+    #[test]
+    fn baseline_vault_loads_and_migrates() {
+        let owner = Pubkey::new_unique();
+        let legacy = legacy_vault(owner, 2);
+        let data = account_data(&legacy);
+        assert_eq!(data.len(), LEGACY_VAULT_SIZE);
+        // This is the breakage the migration exists for.
+        assert!(AgentVault::try_deserialize(&mut &data[..]).is_err());
+
+        let decoded = LegacyAgentVault::try_from_data(&data).unwrap();
+        assert!(decoded == legacy);
+        let vault = decoded.into_current(1_800_000_000);
+        assert_eq!(vault.creator, owner);
+        assert_eq!(vault.owner, owner);
+        assert_eq!(vault.vault_id, 7);
+        assert_eq!(vault.total_deposited_usd, 50_000_000);
+        assert_eq!(vault.last_owner_activity, 1_800_000_000);
+        assert!(!vault.has_tracker_extension);
+        assert!(!vault.has_destination_tracker);
+
+        let mut out = Vec::new();
+        vault.try_serialize(&mut out).unwrap();
+        assert!(out.len() <= AgentVault::SIZE);
+        out.resize(AgentVault::SIZE, 0);
+        assert_eq!(
+            AgentVault::try_deserialize(&mut &out[..]).unwrap().creator,
+            owner
+        );
+    }
+
+    #[test]
+    fn full_agent_list_widens_to_current_entries() {
+        let legacy = legacy_vault(Pubkey::new_unique(), MAX_AGENTS_PER_VAULT);
+        let mut body = Vec::new();
+        legacy.serialize(&mut body).unwrap();
+        // A full list fills the old account exactly.
+        assert_eq!(8 + body.len(), LEGACY_VAULT_SIZE);
+        let mut entry = Vec::new();
+        legacy.agents[0].serialize(&mut entry).unwrap();
+        assert_eq!(entry.len(), LEGACY_AGENT_ENTRY_SIZE);
+
+        let vault = LegacyAgentVault::try_from_data(&account_data(&legacy))
+            .unwrap()
+            .into_current(0);
+        assert_eq!(vault.agents.len(), MAX_AGENTS_PER_VAULT);
+        for (old, new) in legacy.agents.iter().zip(vault.agents.iter()) {
+            assert_eq!(new.pubkey, old.pubkey);
+            assert_eq!(new.capability, old.capability);
+            assert_eq!(new.spending_limit_usd, old.spending_limit_usd);
+            assert_eq!(new.paused, old.paused);
+            assert_eq!(new.max_sessions_per_hour, 0);
+            assert!(!new.has_settings);
+            assert_eq!(new.valid_until, 0);
+            assert_eq!(new.max_concurrent_sessions, 0);
+            assert_eq!(new.active_sessions, 0);
+        }
+
+        // Only the empty approver and CPI-caller lists leave room unused.
+        let mut out = Vec::new();
+        vault.try_serialize(&mut out).unwrap();
+        assert_eq!(
+            out.len(),
+            AgentVault::SIZE - 32 * (MAX_APPROVERS + MAX_CPI_CALLERS)
+        );
+    }
+
+    #[test]
+    fn current_or_foreign_data_is_not_legacy() {
+        let mut data = account_data(&legacy_vault(Pubkey::new_unique(), 0));
+        data.resize(AgentVault::SIZE, 0);
+        assert!(LegacyAgentVault::try_from_data(&data).is_none());
+
+        let mut data = account_data(&legacy_vault(Pubkey::new_unique(), 0));
+        data[..8].copy_from_slice(PolicyConfig::DISCRIMINATOR);
+        assert!(LegacyAgentVault::try_from_data(&data).is_none());
+    }
+
+    #[test]
+    fn legacy_policy_ignores_stale_trailing_bytes() {
+        let legacy = LegacyPolicyConfig {
+            vault: Pubkey::new_unique(),
+            daily_spending_cap_usd: 500_000_000,
+            max_transaction_size_usd: 100_000_000,
+            protocol_mode: 1,
+            protocols: vec![Pubkey::new_unique()],
+            developer_fee_rate: 0,
+            max_slippage_bps: 100,
+            timelock_duration: 1_800,
+            allowed_destinations: Vec::new(),
+            has_constraints: false,
+            has_pending_policy: true,
+            has_protocol_caps: false,
+            protocol_caps: Vec::new(),
+            session_expiry_seconds: 0,
+            bump: 253,
+            policy_version: 4,
+            has_post_assertions: 0,
+            destination_mode: 0,
+        };
+        let mut data = PolicyConfig::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        // Left over from a protocol list that has since been shortened.
+        data.resize(data.len() + 9 * 32, 0xAB);
+
+        let policy = LegacyPolicyConfig::try_from_data(&data)
+            .unwrap()
+            .into_current();
+        assert_eq!(policy.protocols, legacy.protocols);
+        assert_eq!(policy.policy_version, 4);
+        assert!(!policy.has_pending_policy);
+        assert_eq!(policy.weekly_spending_cap_usd, 0);
+        assert!(policy.mint_caps.is_empty());
+        assert_eq!(policy.trading_hours, [0; TRADING_HOURS_BYTES]);
+    }
+}
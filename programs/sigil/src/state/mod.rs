@@ -1,27 +1,49 @@
+pub mod agent_settings;
 pub mod agent_spend_overlay;
 pub mod constraints;
+pub mod destination_spend;
 pub mod escrow;
+pub mod governance_bundle;
+pub mod legacy;
 pub mod pending_agent_perms;
 pub mod pending_close_constraints;
 pub mod pending_constraints;
+pub mod pending_fee_destination;
+pub mod pending_fulfillment;
+pub mod pending_owner_transfer;
 pub mod pending_policy;
+pub mod pending_recovery;
+pub mod permit;
 pub mod policy;
 pub mod post_assertions;
 pub mod session;
+pub mod spend_request;
 pub mod tracker;
+pub mod tracker_extension;
 pub mod vault;
 
+pub use agent_settings::*;
 pub use agent_spend_overlay::*;
 pub use constraints::*;
+pub use destination_spend::*;
 pub use escrow::*;
+pub use governance_bundle::*;
+pub use legacy::*;
 pub use pending_agent_perms::*;
 pub use pending_close_constraints::*;
 pub use pending_constraints::*;
+pub use pending_fee_destination::*;
+pub use pending_fulfillment::*;
+pub use pending_owner_transfer::*;
 pub use pending_policy::*;
+pub use pending_recovery::*;
+pub use permit::*;
 pub use policy::*;
 pub use post_assertions::*;
 pub use session::*;
+pub use spend_request::*;
 pub use tracker::*;
+pub use tracker_extension::*;
 pub use vault::*;
 
 /// Maximum number of agents per vault
@@ -37,6 +59,12 @@ pub const MAX_ALLOWED_PROTOCOLS: usize = 10;
 /// Maximum number of allowed destination addresses for agent transfers
 pub const MAX_ALLOWED_DESTINATIONS: usize = 10;
 
+/// Maximum number of per-mint spending caps (one each for USDC and USDT)
+pub const MAX_MINT_CAPS: usize = 2;
+
+/// Bytes in the weekly trading-hours bitmap: one bit per hour of the week (7 × 24 = 168)
+pub const TRADING_HOURS_BYTES: usize = 21;
+
 /// Default session duration in seconds (when `policy.session_expiry_seconds == 0`).
 ///
 /// **Why timestamp-based, not slot-based:** Solana slot times vary 400ms-1.5s
@@ -76,6 +104,9 @@ pub const MAX_SLIPPAGE_BPS: u16 = 5000;
 /// Maximum escrow duration: 30 days in seconds
 pub const MAX_ESCROW_DURATION: i64 = 2_592_000;
 
+/// Maximum lifetime of a spend request: 7 days in seconds
+pub const MAX_SPEND_REQUEST_DURATION: i64 = 604_800;
+
 /// Minimum timelock duration: 30 minutes in seconds.
 /// Enforced at vault creation and in all queue/apply paths.
 /// Once a vault has a timelock, it can never be reduced below this floor.
@@ -98,6 +129,45 @@ pub const MIN_TIMELOCK_DURATION: u64 = 1800;
 /// update is stale and must be re-queued by the owner.
 pub const MAX_APPLY_AGE_SLOTS: u64 = 216_000;
 
+/// Timelock on a recovery sweep (7 days). Deliberately much longer than any
+/// policy timelock: the owner needs time to notice and cancel a sweep queued
+/// by a compromised recovery key.
+pub const RECOVERY_TIMELOCK_SECONDS: i64 = 604_800;
+
+/// Freshness ceiling for `execute_recovery`. `MAX_APPLY_AGE_SLOTS` (~24h)
+/// is shorter than the recovery timelock itself, so the sweep gets its own
+/// bound: 3,024,000 slots = ~14 days at 400ms slots, leaving roughly a week
+/// to execute once the timelock clears.
+pub const MAX_RECOVERY_APPLY_AGE_SLOTS: u64 = 3_024_000;
+
+/// Minimum delay on a fee destination rotation (12h), applied even when the
+/// policy timelock is shorter so the current fee recipient can react.
+pub const FEE_DESTINATION_TIMELOCK_SECONDS: u64 = 43_200;
+
+/// Shortest configurable dead-man switch inactivity window (7 days).
+pub const MIN_INACTIVITY_WINDOW_SECONDS: u64 = 604_800;
+
+/// Maximum size of a vault's native approver set.
+pub const MAX_APPROVERS: usize = 5;
+
+/// Maximum number of programs allowed to CPI into owner instructions.
+pub const MAX_CPI_CALLERS: usize = 4;
+
+/// Maximum number of legs in a multi-leg spending session.
+pub const MAX_SESSION_LEGS: usize = 4;
+
+/// Upper bound on `AgentEntry::max_concurrent_sessions`.
+pub const MAX_CONCURRENT_SESSIONS: u8 = 8;
+
+/// Seconds after validate before `reconcile_fulfillment` may release an
+/// async-fulfillment reservation. Covers the 5-45s keeper latency with margin.
+pub const ASYNC_FULFILLMENT_WINDOW_SECONDS: i64 = 300;
+
+/// `VaultFrozen::reason` values.
+pub const FREEZE_REASON_MANUAL: u8 = 0;
+pub const FREEZE_REASON_RECOVERY: u8 = 1;
+pub const FREEZE_REASON_DEAD_MAN_SWITCH: u8 = 2;
+
 /// sha256("global:finalize_session")[0..8] — used by validate_and_authorize
 /// to identify finalize_session instructions in the transaction.
 pub const FINALIZE_SESSION_DISCRIMINATOR: [u8; 8] = [34, 148, 144, 47, 37, 130, 206, 161];
@@ -323,7 +393,9 @@ pub const DRIFT_JIT_PROXY_PROGRAM: Pubkey = Pubkey::new_from_array([
 /// Programs whose spending Sigil cannot measure synchronously inside
 /// `validate_and_authorize` because they use a request/fulfillment model
 /// (the keeper submits the actual SPL transfer 5-45s later in a separate
-/// transaction). Hardcode-rejected in V1; see C4 audit finding above.
+/// transaction). Rejected unless `PolicyConfig::allow_async_fulfillment` is
+/// set, in which case the session reserves its full amount against the caps
+/// (see `PendingFulfillment`); see C4 audit finding above.
 pub const KNOWN_ASYNC_FULFILLMENT_PROGRAMS: [Pubkey; 3] = [
     JUPITER_PERPS_PROGRAM,
     DRIFT_V2_PROGRAM,
@@ -1,3 +1,4 @@
+use super::MAX_ALLOWED_PROTOCOLS;
 use anchor_lang::prelude::*;
 
 /// Queued agent permissions update. Timelock-gated.
@@ -8,7 +9,9 @@ pub struct PendingAgentPermissionsUpdate {
     pub vault: Pubkey,
     pub agent: Pubkey,
     pub new_capability: u8,
-    pub _reserved_cap: [u8; 7],
+    /// Replacement `AgentEntry::max_sessions_per_hour` (0 = unlimited).
+    pub max_sessions_per_hour: u16,
+    pub _reserved_cap: [u8; 5],
     pub spending_limit_usd: u64,
     pub queued_at: i64,
     pub executes_at: i64,
@@ -17,13 +20,27 @@ pub struct PendingAgentPermissionsUpdate {
     /// attacks (F-10 audit fix, Drift Protocol April 2026 $285M analog).
     pub queued_at_slot: u64,
     pub bump: u8,
+    /// Replacement per-agent protocol subset (AgentSettings.protocols).
+    /// None = leave unchanged, Some(vec![]) = inherit the vault policy.
+    pub protocols: Option<Vec<Pubkey>>,
+    /// Replacement AgentSettings overrides. None = leave unchanged,
+    /// Some(0) = inherit the vault value.
+    pub max_transaction_size_usd: Option<u64>,
+    pub session_expiry_seconds: Option<u64>,
+    /// Replacement key expiry. None = leave unchanged, Some(0) = never expires.
+    pub valid_until: Option<i64>,
+    /// Replacement `AgentEntry::max_concurrent_sessions`. None = leave unchanged.
+    pub max_concurrent_sessions: Option<u8>,
 }
 
 impl PendingAgentPermissionsUpdate {
-    /// 8 (discriminator) + 32 (vault) + 32 (agent) + 8 (new_capability + reserved)
+    /// 8 (discriminator) + 32 (vault) + 32 (agent) + 8 (new_capability + max_sessions_per_hour + reserved)
     /// + 8 (spending_limit_usd) + 8 (queued_at) + 8 (executes_at)
-    /// + 8 (queued_at_slot, F-10) + 1 (bump)
-    pub const SIZE: usize = 113;
+    /// + 8 (queued_at_slot, F-10) + 1 (bump) + protocols (1 + 4 + 32 * MAX)
+    /// + max_transaction_size_usd (1 + 8) + session_expiry_seconds (1 + 8)
+    /// + valid_until (1 + 8) + max_concurrent_sessions (1 + 1)
+    pub const SIZE: usize =
+        113 + (1 + 4 + 32 * MAX_ALLOWED_PROTOCOLS) + (1 + 8) + (1 + 8) + (1 + 8) + (1 + 1);
 
     pub fn is_ready(&self, current_timestamp: i64) -> bool {
         current_timestamp >= self.executes_at
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Queued rotation of `AgentVault.fee_destination`.
+/// Created by `queue_fee_destination_update`, applied by
+/// `apply_fee_destination_update`, or cancelled by
+/// `cancel_fee_destination_update`.
+///
+/// PDA seeds: `[b"pending_fee_destination", vault.key().as_ref()]`
+#[account]
+pub struct PendingFeeDestinationUpdate {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Fee destination to install on apply
+    pub new_fee_destination: Pubkey,
+
+    /// Unix timestamp when this update was queued
+    pub queued_at: i64,
+
+    /// Unix timestamp when this update becomes executable
+    pub executes_at: i64,
+
+    /// Slot number when this update was queued. Paired with `MAX_APPLY_AGE_SLOTS`
+    /// to enforce a freshness ceiling — defends against durable-nonce pre-signing
+    /// attacks (F-10 audit fix, Drift Protocol April 2026 $285M analog).
+    pub queued_at_slot: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingFeeDestinationUpdate {
+    /// 8 (discriminator) + 32 (vault) + 32 (new_fee_destination)
+    /// + 8 (queued_at) + 8 (executes_at) + 8 (queued_at_slot) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn is_ready(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.executes_at
+    }
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+/// Cap reservation for a spending session that invoked an async-fulfillment
+/// program (`KNOWN_ASYNC_FULFILLMENT_PROGRAMS`) under
+/// `PolicyConfig::allow_async_fulfillment`. The full `reserved_usd` counts
+/// against the rolling caps from validate time until the owner reconciles it
+/// and releases whatever did not leave the vault.
+///
+/// PDA seeds: `[b"pending_fulfillment", vault.key().as_ref(), session.as_ref()]`
+#[account]
+pub struct PendingFulfillment {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Agent that opened the session; receives the rent on reconcile
+    pub agent: Pubkey,
+
+    /// Session PDA the reservation was made for
+    pub session: Pubkey,
+
+    /// Async-fulfillment program the session invoked
+    pub protocol: Pubkey,
+
+    /// Stablecoin mint spent and the vault token account it was spent from
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+
+    /// Amount reserved against the caps (the session's `authorized_amount`)
+    pub reserved_usd: u64,
+
+    /// Timestamp the reservation was recorded at. Releases target the
+    /// buckets of this moment; ones that have since aged out are skipped.
+    pub reserved_at: i64,
+
+    /// Earliest timestamp `reconcile_fulfillment` may run
+    pub expires_at: i64,
+
+    /// `token_account` balance at validate, before fees
+    pub balance_before: u64,
+
+    /// Set by finalize_session together with `balance_after_finalize`.
+    /// An unfinalized reservation is never released.
+    pub finalized: bool,
+    pub balance_after_finalize: u64,
+
+    /// `AgentVault::total_deposited_usd` at finalize, so deposits made
+    /// during the window are not mistaken for unused reservation
+    pub deposited_usd_at_finalize: u64,
+
+    /// Which trackers the reservation was recorded in
+    pub recorded_in_overlay: bool,
+    pub recorded_in_extension: bool,
+    pub recorded_protocol_spend: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingFulfillment {
+    /// 8 (discriminator) + 6 x 32 (vault, agent, session, protocol,
+    /// token_mint, token_account) + 8 (reserved_usd) + 8 (reserved_at)
+    /// + 8 (expires_at) + 8 (balance_before) + 1 (finalized)
+    /// + 8 (balance_after_finalize) + 8 (deposited_usd_at_finalize)
+    /// + 3 (recorded flags) + 1 (bump)
+    pub const SIZE: usize = 8 + 6 * 32 + 8 + 8 + 8 + 8 + 1 + 8 + 8 + 3 + 1;
+
+    /// Amount of the reservation that left the vault, never more than
+    /// `reserved_usd`: the outflow measured by finalize_session plus the
+    /// decrease of `token_account` since then, with stablecoin deposits
+    /// (`total_deposited_usd`) made in the meantime added back.
+    ///
+    /// Inflows that bypass deposit_funds (a direct transfer, another
+    /// session's output) still mask an outflow of the same size, so this
+    /// can understate usage. Only the owner's reconcile acts on it.
+    pub fn used_usd(&self, current_balance: u64, total_deposited_usd: u64) -> u64 {
+        if !self.finalized {
+            return self.reserved_usd;
+        }
+        let deposited = total_deposited_usd.saturating_sub(self.deposited_usd_at_finalize);
+        let later_outflow = self
+            .balance_after_finalize
+            .saturating_add(deposited)
+            .saturating_sub(current_balance);
+        self.balance_before
+            .saturating_sub(self.balance_after_finalize)
+            .saturating_add(later_outflow)
+            .min(self.reserved_usd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(finalized: bool) -> PendingFulfillment {
+        PendingFulfillment {
+            vault: Pubkey::default(),
+            agent: Pubkey::default(),
+            session: Pubkey::default(),
+            protocol: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            token_account: Pubkey::default(),
+            reserved_usd: 1_000,
+            reserved_at: 0,
+            expires_at: 0,
+            balance_before: 10_000,
+            finalized,
+            balance_after_finalize: 9_700,
+            deposited_usd_at_finalize: 50_000,
+            recorded_in_overlay: false,
+            recorded_in_extension: false,
+            recorded_protocol_spend: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn unfinalized_reservation_is_fully_used() {
+        assert_eq!(pending(false).used_usd(10_000, 50_000), 1_000);
+    }
+
+    #[test]
+    fn later_outflow_counts_inflow_does_not() {
+        let p = pending(true);
+        assert_eq!(p.used_usd(9_700, 50_000), 300);
+        assert_eq!(p.used_usd(9_500, 50_000), 500);
+        // A deposit after finalize cannot shrink the measured usage.
+        assert_eq!(p.used_usd(20_000, 60_300), 300);
+        assert_eq!(p.used_usd(0, 50_000), 1_000);
+    }
+
+    #[test]
+    fn deposit_does_not_mask_a_later_outflow() {
+        let mut p = pending(true);
+        p.balance_before = 1_000;
+        p.balance_after_finalize = 900;
+        p.reserved_usd = 500;
+        // Deposit 400, then the keeper pulls 400: the balance is back at
+        // 900 but 500 has left.
+        assert_eq!(p.used_usd(900, 50_400), 500);
+        // Deposit 400 and only 100 pulled.
+        assert_eq!(p.used_usd(1_200, 50_400), 200);
+    }
+
+    #[test]
+    fn size_matches_serialized_len() {
+        let mut data = Vec::new();
+        pending(true).try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), PendingFulfillment::SIZE);
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Queued vault ownership transfer. Timelock-gated; applied by the incoming
+/// owner so a mistyped key can never take over the vault.
+/// PDA seeds: [b"pending_owner_transfer", vault.key().as_ref()]
+#[account]
+pub struct PendingOwnerTransfer {
+    pub vault: Pubkey,
+    pub new_owner: Pubkey,
+    pub queued_at: i64,
+    pub executes_at: i64,
+    /// Slot number when this transfer was queued. Paired with `MAX_APPLY_AGE_SLOTS`
+    /// to enforce a freshness ceiling — defends against durable-nonce pre-signing
+    /// attacks (F-10 audit fix, Drift Protocol April 2026 $285M analog).
+    pub queued_at_slot: u64,
+    pub bump: u8,
+}
+
+impl PendingOwnerTransfer {
+    /// 8 (discriminator) + 32 (vault) + 32 (new_owner) + 8 (queued_at)
+    /// + 8 (executes_at) + 8 (queued_at_slot) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn is_ready(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.executes_at
+    }
+}
@@ -1,4 +1,7 @@
-use super::{MAX_ALLOWED_DESTINATIONS, MAX_ALLOWED_PROTOCOLS};
+use super::{
+    MintSpendCap, MAX_ALLOWED_DESTINATIONS, MAX_ALLOWED_PROTOCOLS, MAX_APPROVERS, MAX_CPI_CALLERS,
+    MAX_MINT_CAPS, TRADING_HOURS_BYTES,
+};
 use anchor_lang::prelude::*;
 
 /// Queued policy update that becomes executable after a timelock period.
@@ -39,6 +42,57 @@ pub struct PendingPolicyUpdate {
     /// Some(0) = Restricted, Some(1) = OpenWithCap, None = leave unchanged.
     pub destination_mode: Option<u8>,
 
+    /// Weekly / monthly cap updates. Some(0) disables that horizon.
+    pub weekly_spending_cap_usd: Option<u64>,
+    pub monthly_spending_cap_usd: Option<u64>,
+
+    /// Per-mint cap list replacement. Some(vec![]) removes all per-mint caps.
+    pub mint_caps: Option<Vec<MintSpendCap>>,
+
+    /// Per-destination cap replacement, index-aligned with the resulting
+    /// `allowed_destinations`. None = existing caps follow their destination.
+    pub destination_caps: Option<Vec<u64>>,
+
+    /// Trading-hours schedule replacement. Some([0; 21]) removes the schedule.
+    pub trading_hours: Option<[u8; TRADING_HOURS_BYTES]>,
+    pub trading_hours_exempt_non_spending: Option<bool>,
+
+    /// Guardian replacement (lives on AgentVault). Some(Pubkey::default())
+    /// removes the guardian.
+    pub guardian: Option<Pubkey>,
+
+    /// Recovery key + destination replacement (lives on AgentVault).
+    /// Always queued together; Some(Pubkey::default()) for both removes recovery.
+    pub recovery_authority: Option<Pubkey>,
+    pub recovery_destination: Option<Pubkey>,
+
+    /// Dead-man switch window + beneficiary replacement (lives on AgentVault).
+    /// Always queued together; Some(0) / Some(Pubkey::default()) disables it.
+    pub inactivity_window_seconds: Option<u64>,
+    pub beneficiary: Option<Pubkey>,
+
+    /// Approver set + threshold replacement (lives on AgentVault).
+    /// Always queued together; Some(vec![]) / Some(0) removes the set.
+    pub approvers: Option<Vec<Pubkey>>,
+    pub approval_threshold: Option<u8>,
+
+    /// CPI caller allowlist replacement (lives on AgentVault).
+    /// Some(vec![]) restricts owner instructions to top-level calls again.
+    pub cpi_callers: Option<Vec<Pubkey>>,
+
+    /// Fee budget replacement. Some(0) charges abandoned-session fees to the
+    /// daily spending cap again.
+    pub daily_fee_cap_usd: Option<u64>,
+
+    /// Abandoned-session strike limit replacement. Some(0) disables it.
+    pub max_abandoned_sessions_per_hour: Option<u16>,
+
+    /// Async-fulfillment opt-in replacement.
+    pub allow_async_fulfillment: Option<bool>,
+
+    /// Advisory minimum-output ceiling replacement. Some(0) disables it.
+    pub max_protocol_slippage_bps: Option<u16>,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -62,6 +116,24 @@ impl PendingPolicyUpdate {
         + (1 + 1) // has_protocol_caps
         + (1 + 4 + 8 * MAX_ALLOWED_PROTOCOLS) // protocol_caps
         + (1 + 1) // destination_mode (Option<u8>)
+        + (1 + 8) // weekly_spending_cap_usd
+        + (1 + 8) // monthly_spending_cap_usd
+        + (1 + 4 + MintSpendCap::SIZE * MAX_MINT_CAPS) // mint_caps
+        + (1 + 4 + 8 * MAX_ALLOWED_DESTINATIONS) // destination_caps
+        + (1 + TRADING_HOURS_BYTES) // trading_hours
+        + (1 + 1) // trading_hours_exempt_non_spending
+        + (1 + 32) // guardian
+        + (1 + 32) // recovery_authority
+        + (1 + 32) // recovery_destination
+        + (1 + 8) // inactivity_window_seconds
+        + (1 + 32) // beneficiary
+        + (1 + 4 + 32 * MAX_APPROVERS) // approvers
+        + (1 + 1) // approval_threshold
+        + (1 + 4 + 32 * MAX_CPI_CALLERS) // cpi_callers
+        + (1 + 8) // daily_fee_cap_usd
+        + (1 + 2) // max_abandoned_sessions_per_hour
+        + (1 + 1) // allow_async_fulfillment
+        + (1 + 2) // max_protocol_slippage_bps
         + 1; // bump
 
     /// Returns true if the timelock period has expired and the update
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Queued recovery sweep of every vault token account to the vault's
+/// pre-registered `recovery_destination`. Created by `queue_recovery`
+/// (signed by the recovery authority), executed by `execute_recovery`
+/// after `RECOVERY_TIMELOCK_SECONDS`, or cancelled by the owner via
+/// `cancel_recovery`.
+///
+/// PDA seeds: `[b"pending_recovery", vault.key().as_ref()]`
+#[account]
+pub struct PendingRecovery {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Recovery authority that queued the sweep
+    pub recovery_authority: Pubkey,
+
+    /// Destination wallet snapshotted at queue time. Token accounts
+    /// receiving the sweep must be owned by this key.
+    pub destination: Pubkey,
+
+    /// Unix timestamp when the sweep was queued
+    pub queued_at: i64,
+
+    /// Unix timestamp when the sweep becomes executable
+    pub executes_at: i64,
+
+    /// Slot number when the sweep was queued. Paired with
+    /// `MAX_RECOVERY_APPLY_AGE_SLOTS` for the same durable-nonce freshness
+    /// ceiling as the other queued updates (F-10 audit fix).
+    pub queued_at_slot: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PendingRecovery {
+    /// 8 (discriminator) + 32 (vault) + 32 (recovery_authority)
+    /// + 32 (destination) + 8 (queued_at) + 8 (executes_at)
+    /// + 8 (queued_at_slot) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    /// Returns true once the recovery timelock has elapsed.
+    pub fn is_ready(&self, current_timestamp: i64) -> bool {
+        current_timestamp >= self.executes_at
+    }
+}
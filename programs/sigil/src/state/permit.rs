@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SigilError;
+
+/// Domain prefix on every owner permit message, so an owner signature over a
+/// permit can never be replayed as some other protocol's ed25519 message.
+pub const PERMIT_MESSAGE_PREFIX: &[u8; 15] = b"SIGIL_PERMIT_V1";
+
+/// prefix (15) + vault (32) + agent (32) + amount_ceiling_usd (8)
+/// + protocol (32) + expires_at (8) + nonce (8)
+pub const PERMIT_MESSAGE_LEN: usize = 15 + 32 + 32 + 8 + 32 + 8 + 8;
+
+/// Number of u64 words in the permit nonce bitmap (1024-nonce window).
+pub const PERMIT_NONCE_WORDS: usize = 16;
+pub const PERMIT_NONCE_WINDOW: u64 = (PERMIT_NONCE_WORDS * 64) as u64;
+
+/// Owner approval for a single over-threshold spend. Signed off-chain by the
+/// vault owner and carried in an Ed25519 program instruction placed before
+/// `validate_and_authorize` / `agent_transfer` in the same transaction.
+///
+/// Message layout (little-endian integers), `PERMIT_MESSAGE_LEN` bytes:
+/// `PERMIT_MESSAGE_PREFIX | vault | agent | amount_ceiling_usd | protocol | expires_at | nonce`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OwnerPermit {
+    pub vault: Pubkey,
+    pub agent: Pubkey,
+    /// Largest USD amount (6 decimals) the permitted transaction may spend.
+    pub amount_ceiling_usd: u64,
+    /// Target protocol for validate_and_authorize; destination wallet for
+    /// agent_transfer.
+    pub protocol: Pubkey,
+    pub expires_at: i64,
+    pub nonce: u64,
+}
+
+impl OwnerPermit {
+    pub fn to_message(&self) -> [u8; PERMIT_MESSAGE_LEN] {
+        let mut msg = [0u8; PERMIT_MESSAGE_LEN];
+        msg[..15].copy_from_slice(PERMIT_MESSAGE_PREFIX);
+        msg[15..47].copy_from_slice(self.vault.as_ref());
+        msg[47..79].copy_from_slice(self.agent.as_ref());
+        msg[79..87].copy_from_slice(&self.amount_ceiling_usd.to_le_bytes());
+        msg[87..119].copy_from_slice(self.protocol.as_ref());
+        msg[119..127].copy_from_slice(&self.expires_at.to_le_bytes());
+        msg[127..135].copy_from_slice(&self.nonce.to_le_bytes());
+        msg
+    }
+
+    /// Decode a permit message. None if the length or prefix is wrong.
+    pub fn from_message(msg: &[u8]) -> Option<Self> {
+        if msg.len() != PERMIT_MESSAGE_LEN || &msg[..15] != PERMIT_MESSAGE_PREFIX {
+            return None;
+        }
+        let pubkey_at = |i: usize| Pubkey::try_from(&msg[i..i + 32]).ok();
+        let u64_at = |i: usize| msg[i..i + 8].try_into().ok().map(u64::from_le_bytes);
+        Some(Self {
+            vault: pubkey_at(15)?,
+            agent: pubkey_at(47)?,
+            amount_ceiling_usd: u64_at(79)?,
+            protocol: pubkey_at(87)?,
+            expires_at: u64_at(119)? as i64,
+            nonce: u64_at(127)?,
+        })
+    }
+}
+
+/// Replay protection for owner permits: a sliding 1024-nonce bitmap.
+/// Nonces below `base_nonce` are rejected as stale; a nonce past the window
+/// slides it forward, retiring the oldest unused nonces.
+/// Created by `initialize_permit_nonces`.
+///
+/// PDA seeds: `[b"permit_nonces", vault.key().as_ref()]`
+#[account]
+pub struct PermitNonces {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Nonce represented by bit 0 of `bitmap[0]`. Always a multiple of 64.
+    pub base_nonce: u64,
+
+    /// Used-nonce bits for `[base_nonce, base_nonce + PERMIT_NONCE_WINDOW)`.
+    pub bitmap: [u64; PERMIT_NONCE_WORDS],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PermitNonces {
+    /// 8 (discriminator) + 32 (vault) + 8 (base_nonce)
+    /// + 8 * PERMIT_NONCE_WORDS (bitmap) + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 8 + 8 * PERMIT_NONCE_WORDS + 1;
+
+    /// Mark `nonce` used, failing if it was already used or is stale.
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        require!(nonce >= self.base_nonce, SigilError::PermitNonceUsed);
+
+        let offset = nonce - self.base_nonce;
+        if offset >= PERMIT_NONCE_WINDOW {
+            let shift_words = (offset - PERMIT_NONCE_WINDOW) / 64 + 1;
+            if shift_words >= PERMIT_NONCE_WORDS as u64 {
+                self.bitmap = [0; PERMIT_NONCE_WORDS];
+            } else {
+                let shift = shift_words as usize;
+                self.bitmap.copy_within(shift.., 0);
+                self.bitmap[PERMIT_NONCE_WORDS - shift..].fill(0);
+            }
+            self.base_nonce = self
+                .base_nonce
+                .checked_add(shift_words * 64)
+                .ok_or(error!(SigilError::Overflow))?;
+        }
+
+        let offset = nonce - self.base_nonce;
+        let word = (offset / 64) as usize;
+        let bit = 1u64 << (offset % 64);
+        require!(self.bitmap[word] & bit == 0, SigilError::PermitNonceUsed);
+        self.bitmap[word] |= bit;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nonces() -> PermitNonces {
+        PermitNonces {
+            vault: Pubkey::default(),
+            base_nonce: 0,
+            bitmap: [0; PERMIT_NONCE_WORDS],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn nonce_cannot_be_reused() {
+        let mut n = nonces();
+        n.consume(7).unwrap();
+        n.consume(8).unwrap();
+        assert!(n.consume(7).is_err());
+    }
+
+    #[test]
+    fn window_slides_and_retires_old_nonces() {
+        let mut n = nonces();
+        n.consume(5).unwrap();
+        n.consume(PERMIT_NONCE_WINDOW + 10).unwrap();
+        assert_eq!(n.base_nonce, 64);
+        // Retired below the new base, even though never used.
+        assert!(n.consume(6).is_err());
+        // Still inside the window and unused.
+        n.consume(100).unwrap();
+        assert!(n.consume(PERMIT_NONCE_WINDOW + 10).is_err());
+    }
+
+    #[test]
+    fn large_jump_clears_bitmap() {
+        let mut n = nonces();
+        n.consume(1).unwrap();
+        n.consume(1_000_000).unwrap();
+        assert!(n.base_nonce > 1);
+        assert_eq!(n.bitmap.iter().map(|w| w.count_ones()).sum::<u32>(), 1);
+    }
+
+    #[test]
+    fn permit_message_round_trips() {
+        let permit = OwnerPermit {
+            vault: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            amount_ceiling_usd: 250_000_000_000,
+            protocol: Pubkey::new_unique(),
+            expires_at: 1_700_000_000,
+            nonce: 42,
+        };
+        let msg = permit.to_message();
+        assert_eq!(OwnerPermit::from_message(&msg), Some(permit));
+        assert_eq!(OwnerPermit::from_message(&msg[1..]), None);
+        let mut bad = msg;
+        bad[0] ^= 1;
+        assert_eq!(OwnerPermit::from_message(&bad), None);
+    }
+}
@@ -1,4 +1,7 @@
-use super::{MAX_ALLOWED_DESTINATIONS, MAX_ALLOWED_PROTOCOLS, SESSION_DURATION_SECONDS};
+use super::{
+    MAX_ALLOWED_DESTINATIONS, MAX_ALLOWED_PROTOCOLS, MAX_MINT_CAPS, SESSION_DURATION_SECONDS,
+    TRADING_HOURS_BYTES,
+};
 use anchor_lang::prelude::*;
 
 /// Protocol access control mode: all protocols allowed
@@ -15,6 +18,21 @@ pub const DESTINATION_MODE_RESTRICTED: u8 = 0;
 /// daily spending cap throttles drain blast radius. Owner must explicitly opt in.
 pub const DESTINATION_MODE_OPEN_WITH_CAP: u8 = 1;
 
+/// Rolling 24h cap on spend from a single stablecoin mint, checked next to
+/// the global `daily_spending_cap_usd` (e.g. a lower USDT cap during a depeg).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintSpendCap {
+    /// Stablecoin mint this cap applies to
+    pub mint: Pubkey,
+    /// Maximum spend from this mint per rolling 24h in USD (6 decimals)
+    pub daily_cap_usd: u64,
+}
+
+impl MintSpendCap {
+    /// mint (32) + daily_cap_usd (8)
+    pub const SIZE: usize = 32 + 8;
+}
+
 #[account]
 pub struct PolicyConfig {
     /// Associated vault pubkey
@@ -97,6 +115,62 @@ pub struct PolicyConfig {
     /// implies default-allow. Owners must explicitly opt into OpenWithCap via
     /// queue_policy_update / apply_pending_policy.
     pub destination_mode: u8,
+
+    /// Maximum aggregate spend per rolling 7-day period in USD (6 decimals).
+    /// 0 = no weekly cap. Tracked in `SpendTrackerExtension`.
+    pub weekly_spending_cap_usd: u64,
+
+    /// Maximum aggregate spend per rolling 30-day period in USD (6 decimals).
+    /// 0 = no monthly cap. Tracked in `SpendTrackerExtension`.
+    pub monthly_spending_cap_usd: u64,
+
+    /// Per-stablecoin-mint rolling 24h caps. Mints without an entry only
+    /// count against the global caps. Bounded to MAX_MINT_CAPS entries.
+    /// Tracked in `SpendTrackerExtension`.
+    pub mint_caps: Vec<MintSpendCap>,
+
+    /// Per-destination rolling 24h caps in USD (6 decimals) for
+    /// `agent_transfer`. Index-aligned with `allowed_destinations`; empty =
+    /// no per-destination caps. A value of 0 means no limit for that entry.
+    /// Tracked in `DestinationSpendTracker`.
+    pub destination_caps: Vec<u64>,
+
+    /// Weekly UTC schedule of hours in which agents may spend. Bit `h % 8`
+    /// of byte `h / 8` covers hour-of-week `h`, where 0 = Monday 00:00–01:00
+    /// UTC. All zero = no schedule (trading allowed at any hour).
+    pub trading_hours: [u8; TRADING_HOURS_BYTES],
+
+    /// When true, non-spending sessions (`amount == 0`) ignore `trading_hours`.
+    pub trading_hours_exempt_non_spending: bool,
+
+    /// Rolling 24h budget in USD (6 decimals) for fees charged by sessions
+    /// that finalize without real spend. 0 = charge those fees against
+    /// `daily_spending_cap_usd` instead. Tracked in `SpendTrackerExtension`;
+    /// going over it pauses the agent.
+    pub daily_fee_cap_usd: u64,
+
+    /// Abandoned spending sessions (finalized with no measured spend) an
+    /// agent may accumulate per rolling hour before it is paused.
+    /// 0 = no limit. Counted in `AgentSpendOverlay.abandon_counters`.
+    pub max_abandoned_sessions_per_hour: u16,
+
+    /// Opt-in for `KNOWN_ASYNC_FULFILLMENT_PROGRAMS`. When set, a spending
+    /// session may invoke one of them; its full amount is reserved against
+    /// the caps at validate time and released by `reconcile_fulfillment`.
+    pub allow_async_fulfillment: bool,
+
+    /// Advisory minimum-output check for non-Jupiter spending. When > 0,
+    /// such a session must declare an `ExpectedOutput` whose minimum gives
+    /// up at most this much of its quote; finalize verifies the output delta
+    /// against it. Quote and minimum are both the agent's, so this is not a
+    /// slippage guard on the vault. 0 = not required. Jupiter keeps
+    /// `max_slippage_bps`.
+    pub max_protocol_slippage_bps: u16,
+
+    /// Slot of the last `tighten_policy`. A pending update queued at or
+    /// before it would re-loosen the tightened fields, so it can no longer
+    /// be applied.
+    pub tightened_at_slot: u64,
 }
 
 impl PolicyConfig {
@@ -107,7 +181,13 @@ impl PolicyConfig {
     /// allowed_destinations vec (4 + 32 * MAX) + has_constraints (1) +
     /// has_pending_policy (1) + has_protocol_caps (1) +
     /// protocol_caps vec (4 + 8 * MAX) + session_expiry_seconds (8) + bump (1) +
-    /// policy_version (8) + has_post_assertions (1) + destination_mode (1)
+    /// policy_version (8) + has_post_assertions (1) + destination_mode (1) +
+    /// weekly_spending_cap_usd (8) + monthly_spending_cap_usd (8) +
+    /// mint_caps vec (4 + 40 * MAX) + destination_caps vec (4 + 8 * MAX) +
+    /// trading_hours (21) + trading_hours_exempt_non_spending (1) +
+    /// daily_fee_cap_usd (8) + max_abandoned_sessions_per_hour (2) +
+    /// allow_async_fulfillment (1) + max_protocol_slippage_bps (2) +
+    /// tightened_at_slot (8)
     pub const SIZE: usize = 8
         + 32
         + 8
@@ -126,16 +206,22 @@ impl PolicyConfig {
         + 1 // bump
         + 8 // policy_version
         + 1 // has_post_assertions
-        + 1; // destination_mode
+        + 1 // destination_mode
+        + 8 // weekly_spending_cap_usd
+        + 8 // monthly_spending_cap_usd
+        + (4 + MintSpendCap::SIZE * MAX_MINT_CAPS) // mint_caps
+        + (4 + 8 * MAX_ALLOWED_DESTINATIONS) // destination_caps
+        + TRADING_HOURS_BYTES // trading_hours
+        + 1 // trading_hours_exempt_non_spending
+        + 8 // daily_fee_cap_usd
+        + 2 // max_abandoned_sessions_per_hour
+        + 1 // allow_async_fulfillment
+        + 2 // max_protocol_slippage_bps
+        + 8; // tightened_at_slot
 
     /// Check if a protocol is allowed based on the protocol mode.
     pub fn is_protocol_allowed(&self, program_id: &Pubkey) -> bool {
-        match self.protocol_mode {
-            PROTOCOL_MODE_ALL => true,
-            PROTOCOL_MODE_ALLOWLIST => self.protocols.contains(program_id),
-            PROTOCOL_MODE_DENYLIST => !self.protocols.contains(program_id),
-            _ => false, // invalid mode = deny all
-        }
+        protocol_allowed(self.protocol_mode, &self.protocols, program_id)
     }
 
     /// Check if a destination is allowed for agent transfers.
@@ -171,6 +257,36 @@ impl PolicyConfig {
             .map(|i| self.protocol_caps.get(i).copied().unwrap_or(0))
     }
 
+    /// Whether any of `protocols` has a non-zero per-protocol cap.
+    pub fn any_protocol_capped<'a>(&self, mut protocols: impl Iterator<Item = &'a Pubkey>) -> bool {
+        protocols.any(|p| self.get_protocol_cap(p).is_some_and(|cap| cap > 0))
+    }
+
+    /// Per-destination daily cap for `destination_owner`. 0 = no limit
+    /// (not listed, or listed without a cap).
+    pub fn get_destination_cap(&self, destination_owner: &Pubkey) -> u64 {
+        self.allowed_destinations
+            .iter()
+            .position(|d| d == destination_owner)
+            .and_then(|i| self.destination_caps.get(i).copied())
+            .unwrap_or(0)
+    }
+
+    /// Whether `unix_timestamp` falls inside the trading-hours schedule.
+    /// Always true when no schedule is configured.
+    pub fn is_within_trading_hours(&self, unix_timestamp: i64) -> bool {
+        if self.trading_hours.iter().all(|b| *b == 0) {
+            return true;
+        }
+        let hour = hour_of_week(unix_timestamp);
+        self.trading_hours[hour / 8] & (1 << (hour % 8)) != 0
+    }
+
+    /// Whether a session of this kind must fall inside `trading_hours`.
+    pub fn is_trading_hours_enforced(&self, is_spending: bool) -> bool {
+        is_spending || !self.trading_hours_exempt_non_spending
+    }
+
     /// Returns the effective session duration in seconds.
     /// 0 = use default (`SESSION_DURATION_SECONDS` = 30s).
     pub fn effective_session_expiry_seconds(&self) -> u64 {
@@ -180,4 +296,385 @@ impl PolicyConfig {
             self.session_expiry_seconds
         }
     }
+
+    /// Whether a weekly, monthly or per-mint cap is configured, i.e. whether
+    /// spend paths must carry the `SpendTrackerExtension`.
+    pub fn requires_tracker_extension(&self) -> bool {
+        self.weekly_spending_cap_usd > 0
+            || self.monthly_spending_cap_usd > 0
+            || !self.mint_caps.is_empty()
+    }
+
+    /// True if `new_caps` keeps every current per-mint cap at the same or a
+    /// lower value. New mints may be added; removing one loosens.
+    pub fn mint_caps_tighten(&self, new_caps: &[MintSpendCap]) -> bool {
+        self.mint_caps.iter().all(|old| {
+            new_caps
+                .iter()
+                .any(|new| new.mint == old.mint && new.daily_cap_usd <= old.daily_cap_usd)
+        })
+    }
+
+    /// True if replacing `protocols` with `new_protocols` can only shrink the
+    /// set of reachable protocols: a subset in ALLOWLIST mode, a superset in
+    /// DENYLIST mode. ALL mode ignores the list, so no change is provably
+    /// tighter there.
+    pub fn protocols_tighten(&self, new_protocols: &[Pubkey]) -> bool {
+        match self.protocol_mode {
+            PROTOCOL_MODE_ALLOWLIST => new_protocols.iter().all(|p| self.protocols.contains(p)),
+            PROTOCOL_MODE_DENYLIST => self.protocols.iter().all(|p| new_protocols.contains(p)),
+            _ => false,
+        }
+    }
+
+    /// True if moving to (`new_mode`, `new_destinations`) can only shrink the
+    /// set of reachable destinations. Switching OpenWithCap → Restricted is
+    /// tighter with any list; otherwise the mode must not change and the list
+    /// must be a subset of the current one.
+    pub fn destinations_tighten(&self, new_mode: u8, new_destinations: &[Pubkey]) -> bool {
+        match (self.destination_mode, new_mode) {
+            (DESTINATION_MODE_OPEN_WITH_CAP, DESTINATION_MODE_RESTRICTED) => true,
+            (old, new) if old == new => new_destinations
+                .iter()
+                .all(|d| self.allowed_destinations.contains(d)),
+            _ => false,
+        }
+    }
+
+    /// True if an update queued at `queued_at_slot` postdates the last
+    /// `tighten_policy`.
+    pub fn accepts_pending_update(&self, queued_at_slot: u64) -> bool {
+        queued_at_slot > self.tightened_at_slot
+    }
+
+    /// True if `new_expiry` (0 = default) yields a session no longer than
+    /// the current effective expiry.
+    pub fn session_expiry_tightens(&self, new_expiry: u64) -> bool {
+        let new_effective = if new_expiry == 0 {
+            SESSION_DURATION_SECONDS as u64
+        } else {
+            new_expiry
+        };
+        new_effective <= self.effective_session_expiry_seconds()
+    }
+
+    /// Per-destination caps re-indexed against `new_destinations`, carrying
+    /// each surviving destination's cap. Stays empty if no caps are set.
+    pub fn realigned_destination_caps(&self, new_destinations: &[Pubkey]) -> Vec<u64> {
+        if self.destination_caps.is_empty() {
+            return Vec::new();
+        }
+        new_destinations
+            .iter()
+            .map(|d| self.get_destination_cap(d))
+            .collect()
+    }
+
+    /// Per-protocol caps re-indexed against `new_protocols`, carrying each
+    /// surviving protocol's current cap. Protocols not in the current list
+    /// get 0 (unlimited — the global cap still applies).
+    pub fn realigned_protocol_caps(&self, new_protocols: &[Pubkey]) -> Vec<u64> {
+        new_protocols
+            .iter()
+            .map(|p| {
+                self.protocols
+                    .iter()
+                    .position(|old| old == p)
+                    .and_then(|i| self.protocol_caps.get(i).copied())
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+/// Whether agents may invoke `program_id` under `protocol_mode` and `protocols`.
+pub fn protocol_allowed(protocol_mode: u8, protocols: &[Pubkey], program_id: &Pubkey) -> bool {
+    match protocol_mode {
+        PROTOCOL_MODE_ALL => true,
+        PROTOCOL_MODE_ALLOWLIST => protocols.contains(program_id),
+        PROTOCOL_MODE_DENYLIST => !protocols.contains(program_id),
+        _ => false, // invalid mode = deny all
+    }
+}
+
+/// Whether none of `cpi_callers` is a program agents may invoke under
+/// `protocol_mode` and `protocols`; such a program must not be able to drive
+/// owner instructions. Under DENYLIST every unlisted program is reachable,
+/// and under ALL every program is, so ALL admits no CPI callers.
+pub fn cpi_callers_unreachable(
+    cpi_callers: &[Pubkey],
+    protocol_mode: u8,
+    protocols: &[Pubkey],
+) -> bool {
+    !cpi_callers
+        .iter()
+        .any(|c| protocol_allowed(protocol_mode, protocols, c))
+}
+
+/// Hour of the UTC week for `unix_timestamp`, 0 = Monday 00:00–01:00.
+/// The Unix epoch (1970-01-01) was a Thursday, hence the 3-day offset.
+pub fn hour_of_week(unix_timestamp: i64) -> usize {
+    let day = (unix_timestamp.div_euclid(86_400) + 3).rem_euclid(7);
+    let hour = unix_timestamp.rem_euclid(86_400) / 3_600;
+    (day * 24 + hour) as usize
+}
+
+/// True if `caps` fits MAX_MINT_CAPS, names only stablecoin mints, and
+/// lists each mint at most once.
+pub fn mint_caps_valid(caps: &[MintSpendCap]) -> bool {
+    caps.len() <= MAX_MINT_CAPS
+        && caps.iter().enumerate().all(|(i, cap)| {
+            super::is_stablecoin_mint(&cap.mint)
+                && !caps[..i].iter().any(|prev| prev.mint == cap.mint)
+        })
+}
+
+/// True if a limit change lowers risk, for limits where 0 means unlimited
+/// (per-protocol, weekly and monthly caps). Any non-zero limit tightens an
+/// unlimited one, and 0 never tightens a limit.
+pub fn limit_tightens(old_cap: u64, new_cap: u64) -> bool {
+    match (old_cap, new_cap) {
+        (0, _) => true,
+        (_, 0) => false,
+        (old, new) => new <= old,
+    }
+}
+
+#[cfg(test)]
+mod tighten_tests {
+    use super::*;
+
+    fn policy(protocol_mode: u8, protocols: Vec<Pubkey>) -> PolicyConfig {
+        PolicyConfig {
+            vault: Pubkey::default(),
+            daily_spending_cap_usd: 500_000_000,
+            max_transaction_size_usd: 100_000_000,
+            protocol_mode,
+            protocols,
+            developer_fee_rate: 0,
+            max_slippage_bps: 100,
+            timelock_duration: 1800,
+            allowed_destinations: vec![],
+            has_constraints: false,
+            has_pending_policy: false,
+            has_protocol_caps: false,
+            protocol_caps: vec![],
+            session_expiry_seconds: 0,
+            bump: 255,
+            policy_version: 0,
+            has_post_assertions: 0,
+            destination_mode: DESTINATION_MODE_RESTRICTED,
+            weekly_spending_cap_usd: 0,
+            monthly_spending_cap_usd: 0,
+            mint_caps: vec![],
+            destination_caps: vec![],
+            trading_hours: [0; TRADING_HOURS_BYTES],
+            trading_hours_exempt_non_spending: false,
+            daily_fee_cap_usd: 0,
+            max_abandoned_sessions_per_hour: 0,
+            allow_async_fulfillment: false,
+            max_protocol_slippage_bps: 0,
+            tightened_at_slot: 0,
+        }
+    }
+
+    #[test]
+    fn allowlist_subset_tightens_superset_loosens() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let p = policy(PROTOCOL_MODE_ALLOWLIST, vec![a, b]);
+        assert!(p.protocols_tighten(&[a]));
+        assert!(p.protocols_tighten(&[]));
+        assert!(!p.protocols_tighten(&[a, c]));
+    }
+
+    #[test]
+    fn denylist_superset_tightens_subset_loosens() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let p = policy(PROTOCOL_MODE_DENYLIST, vec![a, b]);
+        assert!(p.protocols_tighten(&[a, b, c]));
+        assert!(!p.protocols_tighten(&[a]));
+    }
+
+    #[test]
+    fn cpi_caller_on_allowlist_is_reachable() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(cpi_callers_unreachable(&[b], PROTOCOL_MODE_ALLOWLIST, &[a]));
+        assert!(!cpi_callers_unreachable(
+            &[b],
+            PROTOCOL_MODE_ALLOWLIST,
+            &[a, b]
+        ));
+    }
+
+    #[test]
+    fn cpi_caller_off_denylist_is_reachable() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(!cpi_callers_unreachable(&[b], PROTOCOL_MODE_DENYLIST, &[a]));
+        assert!(cpi_callers_unreachable(
+            &[b],
+            PROTOCOL_MODE_DENYLIST,
+            &[a, b]
+        ));
+    }
+
+    #[test]
+    fn all_mode_admits_no_cpi_callers() {
+        let a = Pubkey::new_unique();
+        assert!(!cpi_callers_unreachable(&[a], PROTOCOL_MODE_ALL, &[]));
+        assert!(!cpi_callers_unreachable(&[a], PROTOCOL_MODE_ALL, &[a]));
+        assert!(cpi_callers_unreachable(&[], PROTOCOL_MODE_ALL, &[]));
+    }
+
+    #[test]
+    fn tighten_supersedes_earlier_pending_update() {
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        assert!(p.accepts_pending_update(1));
+        p.tightened_at_slot = 100;
+        assert!(!p.accepts_pending_update(99));
+        assert!(!p.accepts_pending_update(100));
+        assert!(p.accepts_pending_update(101));
+    }
+
+    #[test]
+    fn capped_second_leg_is_detected() {
+        use crate::state::SessionLeg;
+
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut p = policy(PROTOCOL_MODE_ALLOWLIST, vec![a, b]);
+        p.has_protocol_caps = true;
+        p.protocol_caps = vec![0, 100_000_000];
+        let legs = [
+            SessionLeg {
+                protocol: a,
+                constraint_entry: None,
+            },
+            SessionLeg {
+                protocol: b,
+                constraint_entry: None,
+            },
+        ];
+        assert!(!p.any_protocol_capped(legs[..1].iter().map(|l| &l.protocol)));
+        assert!(p.any_protocol_capped(legs.iter().map(|l| &l.protocol)));
+
+        p.has_protocol_caps = false;
+        assert!(!p.any_protocol_capped(legs.iter().map(|l| &l.protocol)));
+    }
+
+    #[test]
+    fn all_mode_never_tightens_via_list() {
+        let p = policy(PROTOCOL_MODE_ALL, vec![]);
+        assert!(!p.protocols_tighten(&[Pubkey::new_unique()]));
+    }
+
+    #[test]
+    fn destinations_restricted_subset_only() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        p.allowed_destinations = vec![a];
+        assert!(p.destinations_tighten(DESTINATION_MODE_RESTRICTED, &[]));
+        assert!(!p.destinations_tighten(DESTINATION_MODE_RESTRICTED, &[a, b]));
+        assert!(!p.destinations_tighten(DESTINATION_MODE_OPEN_WITH_CAP, &[a]));
+
+        p.destination_mode = DESTINATION_MODE_OPEN_WITH_CAP;
+        assert!(p.destinations_tighten(DESTINATION_MODE_RESTRICTED, &[a, b]));
+    }
+
+    #[test]
+    fn session_expiry_compares_effective_values() {
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        // Default (30s) → 20s tightens, 60s loosens, 0 is a no-op.
+        assert!(p.session_expiry_tightens(20));
+        assert!(p.session_expiry_tightens(0));
+        assert!(!p.session_expiry_tightens(60));
+
+        p.session_expiry_seconds = 10;
+        assert!(!p.session_expiry_tightens(0));
+    }
+
+    #[test]
+    fn realigned_caps_follow_surviving_protocols() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut p = policy(PROTOCOL_MODE_ALLOWLIST, vec![a, b, c]);
+        p.has_protocol_caps = true;
+        p.protocol_caps = vec![10, 20, 30];
+        assert_eq!(p.realigned_protocol_caps(&[c, a]), vec![30, 10]);
+    }
+
+    #[test]
+    fn limit_zero_is_unlimited() {
+        assert!(limit_tightens(0, 5));
+        assert!(limit_tightens(10, 5));
+        assert!(!limit_tightens(10, 0));
+        assert!(!limit_tightens(10, 11));
+    }
+
+    #[test]
+    fn mint_caps_lower_or_add_tightens_remove_loosens() {
+        let (usdc, usdt) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let cap = |mint, daily_cap_usd| MintSpendCap {
+            mint,
+            daily_cap_usd,
+        };
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        p.mint_caps = vec![cap(usdc, 100)];
+        assert!(p.mint_caps_tighten(&[cap(usdc, 50)]));
+        assert!(p.mint_caps_tighten(&[cap(usdc, 100), cap(usdt, 10)]));
+        assert!(!p.mint_caps_tighten(&[cap(usdc, 101)]));
+        assert!(!p.mint_caps_tighten(&[cap(usdt, 10)]));
+    }
+
+    #[test]
+    fn destination_caps_follow_their_destination() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        p.allowed_destinations = vec![a, b];
+        assert_eq!(p.realigned_destination_caps(&[b]), Vec::<u64>::new());
+
+        p.destination_caps = vec![200, 0];
+        assert_eq!(p.get_destination_cap(&a), 200);
+        assert_eq!(p.get_destination_cap(&b), 0);
+        assert_eq!(p.get_destination_cap(&c), 0);
+        assert_eq!(p.realigned_destination_caps(&[c, a]), vec![0, 200]);
+    }
+
+    #[test]
+    fn hour_of_week_starts_monday_utc() {
+        // 1970-01-01 00:00 UTC was a Thursday.
+        assert_eq!(hour_of_week(0), 3 * 24);
+        // 2024-01-01 00:00 UTC was a Monday.
+        assert_eq!(hour_of_week(1_704_067_200), 0);
+        assert_eq!(hour_of_week(1_704_067_200 + 7 * 86_400 - 1), 167);
+    }
+
+    #[test]
+    fn trading_hours_gate_by_bitmap() {
+        let mut p = policy(PROTOCOL_MODE_ALL, vec![]);
+        let monday = 1_704_067_200;
+        assert!(p.is_within_trading_hours(monday));
+
+        // Allow Monday 13:00–14:00 UTC only.
+        p.trading_hours[13 / 8] |= 1 << (13 % 8);
+        assert!(!p.is_within_trading_hours(monday));
+        assert!(p.is_within_trading_hours(monday + 13 * 3_600));
+        assert!(p.is_within_trading_hours(monday + 13 * 3_600 + 3_599));
+        assert!(!p.is_within_trading_hours(monday + 14 * 3_600));
+        // Same hour one day later is Tuesday, bit 37.
+        assert!(!p.is_within_trading_hours(monday + 86_400 + 13 * 3_600));
+    }
 }
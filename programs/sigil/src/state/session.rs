@@ -1,5 +1,54 @@
 use anchor_lang::prelude::*;
 
+/// One step of a multi-leg spending session, passed to
+/// `validate_and_authorize`. Legs are matched in order against the DeFi
+/// instructions between validate and finalize.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SessionLeg {
+    /// Program the leg's instruction must target
+    pub protocol: Pubkey,
+    /// Index of the `InstructionConstraints` entry the instruction must
+    /// match. None = any entry (or none, outside strict mode).
+    pub constraint_entry: Option<u8>,
+}
+
+/// Advisory minimum-output declaration passed to `validate_and_authorize`
+/// and verified against the measured balance delta in `finalize_session`.
+///
+/// The quote and minimum are the agent's own. Sigil has no price source and
+/// no owner-signed bound on them, so neither is checked against the market,
+/// for stablecoin or non-stablecoin input alike. It catches execution that
+/// falls short of what the agent itself expected; it is not a slippage guard
+/// and does nothing against a compromised agent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ExpectedOutput {
+    /// Mint the session must receive. The stablecoin itself for
+    /// non-stablecoin input.
+    pub mint: Pubkey,
+    /// Off-chain quote the minimum is derived from
+    pub quoted_amount: u64,
+    /// Smallest acceptable increase of the vault's output token account
+    pub min_amount: u64,
+}
+
+impl ExpectedOutput {
+    /// True when `min_amount` is non-zero, does not exceed the quote, and
+    /// gives up at most `max_slippage_bps` of it. `max_slippage_bps == 0`
+    /// (enforcement off) only requires a non-zero minimum.
+    pub fn within_slippage(&self, max_slippage_bps: u16) -> bool {
+        if self.min_amount == 0 || self.min_amount > self.quoted_amount {
+            return false;
+        }
+        if max_slippage_bps == 0 {
+            return true;
+        }
+        let floor = (self.quoted_amount as u128)
+            .saturating_mul(10_000u128.saturating_sub(max_slippage_bps as u128))
+            / 10_000;
+        self.min_amount as u128 >= floor
+    }
+}
+
 #[account]
 pub struct SessionAuthority {
     /// Associated vault
@@ -65,6 +114,47 @@ pub struct SessionAuthority {
     /// 0 = no snapshot captured (mode 0 entries). Non-zero = snapshot was captured.
     /// finalize_session cross-checks snapshot_lens[i] == entry.value_len.
     pub snapshot_lens: [u8; 4],
+
+    /// Agent's `AgentSettings.max_transaction_size_usd` at validate time
+    /// (0 = none). Snapshotted so finalize_session — which can be cranked
+    /// permissionlessly — enforces it without the settings account.
+    pub agent_max_transaction_size_usd: u64,
+
+    /// Amount ceiling of the owner permit consumed at validate time
+    /// (0 = no permit). When set it replaces the per-transaction limit.
+    pub permit_ceiling_usd: u64,
+
+    /// Agent-chosen session nonce (see `session_nonce_seed`).
+    pub nonce: u8,
+
+    /// Session invoked an async-fulfillment program; its spend was reserved
+    /// up front in a `PendingFulfillment`.
+    pub async_fulfillment: bool,
+
+    /// Declared `ExpectedOutput::mint`. Pubkey::default() = no minimum-output
+    /// check at finalize.
+    pub min_output_mint: Pubkey,
+
+    /// Declared `ExpectedOutput::min_amount`
+    pub min_output_amount: u64,
+
+    /// Vault token account whose increase is measured against the minimum.
+    /// For non-stablecoin input this is the output stablecoin account.
+    pub min_output_token_account: Pubkey,
+
+    /// Snapshot of `min_output_token_account` before the swap
+    pub min_output_balance_before: u64,
+}
+
+/// Trailing seed for a session PDA. Nonce 0 contributes no bytes, so the
+/// first session per (agent, mint) keeps the original
+/// `[b"session", vault, agent, token_mint]` address.
+pub fn session_nonce_seed(nonce: &u8) -> &[u8] {
+    if *nonce == 0 {
+        &[]
+    } else {
+        core::slice::from_ref(nonce)
+    }
 }
 
 impl SessionAuthority {
@@ -73,15 +163,51 @@ impl SessionAuthority {
     /// expires_at_timestamp i64 (8) + delegated (1) + delegation_token_account (32) +
     /// protocol_fee (8) + developer_fee (8) +
     /// output_mint (32) + stablecoin_balance_before (8) + bump (1) +
-    /// assertion_snapshots (128) + snapshot_lens (4)
-    pub const SIZE: usize =
-        8 + 32 + 32 + 1 + 8 + 32 + 32 + 1 + 8 + 1 + 32 + 8 + 8 + 32 + 8 + 1 + 128 + 4;
+    /// assertion_snapshots (128) + snapshot_lens (4) +
+    /// agent_max_transaction_size_usd (8) + permit_ceiling_usd (8) + nonce (1) +
+    /// async_fulfillment (1) + min_output_mint (32) + min_output_amount (8) +
+    /// min_output_token_account (32) + min_output_balance_before (8)
+    pub const SIZE: usize = 8
+        + 32
+        + 32
+        + 1
+        + 8
+        + 32
+        + 32
+        + 1
+        + 8
+        + 1
+        + 32
+        + 8
+        + 8
+        + 32
+        + 8
+        + 1
+        + 128
+        + 4
+        + 8
+        + 8
+        + 1
+        + 1
+        + 32
+        + 8
+        + 32
+        + 8;
 
     /// Returns true when wall-clock has passed the session's expiry timestamp.
     pub fn is_expired(&self, current_unix_ts: i64) -> bool {
         current_unix_ts > self.expires_at_timestamp
     }
 
+    /// Per-transaction ceiling for this session: the owner permit's ceiling
+    /// if one was consumed, else the vault's tightened by the agent's override.
+    pub fn effective_max_transaction_size_usd(&self, vault_max_usd: u64) -> u64 {
+        if self.permit_ceiling_usd > 0 {
+            return self.permit_ceiling_usd;
+        }
+        super::tighter_override(vault_max_usd, self.agent_max_transaction_size_usd)
+    }
+
     pub fn is_valid(&self, current_unix_ts: i64) -> bool {
         self.authorized && !self.is_expired(current_unix_ts)
     }
@@ -134,6 +260,14 @@ mod f5h1_tests {
             bump: 0,
             assertion_snapshots: [[0u8; 32]; 4],
             snapshot_lens: [0u8; 4],
+            agent_max_transaction_size_usd: 0,
+            permit_ceiling_usd: 0,
+            nonce: 0,
+            async_fulfillment: false,
+            min_output_mint: Pubkey::default(),
+            min_output_amount: 0,
+            min_output_token_account: Pubkey::default(),
+            min_output_balance_before: 0,
         }
     }
 
@@ -226,3 +360,70 @@ mod f5h1_tests {
         assert!(!session.is_valid(1_700_000_000)); // not authorized
     }
 }
+
+#[cfg(test)]
+mod nonce_seed_tests {
+    use super::*;
+
+    /// Nonce 0 must keep the original four-seed session address.
+    #[test]
+    fn zero_nonce_keeps_legacy_pda() {
+        let (vault, agent, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let legacy = Pubkey::find_program_address(
+            &[b"session", vault.as_ref(), agent.as_ref(), mint.as_ref()],
+            &crate::ID,
+        );
+        let derive = |nonce: u8| {
+            Pubkey::find_program_address(
+                &[
+                    b"session",
+                    vault.as_ref(),
+                    agent.as_ref(),
+                    mint.as_ref(),
+                    session_nonce_seed(&nonce),
+                ],
+                &crate::ID,
+            )
+        };
+        assert_eq!(derive(0), legacy);
+        assert_ne!(derive(1), legacy);
+        assert_ne!(derive(1), derive(2));
+    }
+}
+
+#[cfg(test)]
+mod expected_output_tests {
+    use super::*;
+
+    fn expected(quoted_amount: u64, min_amount: u64) -> ExpectedOutput {
+        ExpectedOutput {
+            mint: Pubkey::new_unique(),
+            quoted_amount,
+            min_amount,
+        }
+    }
+
+    #[test]
+    fn min_amount_bounded_by_policy_slippage() {
+        // 1% = 100 bps off a 1_000_000 quote → floor 990_000
+        assert!(expected(1_000_000, 990_000).within_slippage(100));
+        assert!(expected(1_000_000, 1_000_000).within_slippage(100));
+        assert!(!expected(1_000_000, 989_999).within_slippage(100));
+    }
+
+    #[test]
+    fn rejects_zero_or_above_quote_minimum() {
+        assert!(!expected(1_000_000, 0).within_slippage(100));
+        assert!(!expected(1_000_000, 1_000_001).within_slippage(100));
+        assert!(!expected(0, 0).within_slippage(0));
+    }
+
+    #[test]
+    fn zero_bps_only_requires_nonzero_minimum() {
+        assert!(expected(1_000_000, 1).within_slippage(0));
+    }
+}
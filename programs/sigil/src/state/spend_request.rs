@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum SpendRequestStatus {
+    Pending,
+    Approved,
+}
+
+/// Agent-created request to exceed the per-transaction limit once.
+/// Created by `create_spend_request`, approved by the owner or guardian via
+/// `approve_spend_request`, and consumed (closed) by the `agent_transfer`
+/// that executes it. `cancel_spend_request` closes it unused.
+///
+/// PDA seeds: `[b"spend_request", vault.key().as_ref(), agent.as_ref(), request_id.to_le_bytes().as_ref()]`
+#[account]
+pub struct SpendRequest {
+    /// Associated vault pubkey
+    pub vault: Pubkey,
+
+    /// Requesting agent (also the rent payer)
+    pub agent: Pubkey,
+
+    /// Agent-chosen id, unique among the agent's open requests
+    pub request_id: u64,
+
+    /// Stablecoin mint to transfer
+    pub token_mint: Pubkey,
+
+    /// Maximum token amount the executing transfer may move
+    pub amount: u64,
+
+    /// Destination wallet (owner of the destination token account)
+    pub destination: Pubkey,
+
+    /// Hash of the agent's off-chain justification, shown to the approver
+    pub reason_hash: [u8; 32],
+
+    pub created_at: i64,
+
+    /// Request can be neither approved nor executed after this timestamp
+    pub expires_at: i64,
+
+    pub status: SpendRequestStatus,
+
+    /// Owner or guardian that approved (Pubkey::default() while pending)
+    pub approved_by: Pubkey,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SpendRequest {
+    /// 8 (discriminator) + 32 (vault) + 32 (agent) + 8 (request_id)
+    /// + 32 (token_mint) + 8 (amount) + 32 (destination) + 32 (reason_hash)
+    /// + 8 (created_at) + 8 (expires_at) + 1 (status) + 32 (approved_by)
+    /// + 1 (bump)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 32 + 8 + 32 + 32 + 8 + 8 + 1 + 32 + 1;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expires_at
+    }
+
+    /// True if the request still holds the contents the approver reviewed.
+    /// A cancelled and re-created `request_id` differs at least in
+    /// `created_at`.
+    pub fn matches(
+        &self,
+        amount: u64,
+        destination: &Pubkey,
+        token_mint: &Pubkey,
+        reason_hash: &[u8; 32],
+        created_at: i64,
+    ) -> bool {
+        self.amount == amount
+            && self.destination == *destination
+            && self.token_mint == *token_mint
+            && self.reason_hash == *reason_hash
+            && self.created_at == created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> SpendRequest {
+        SpendRequest {
+            vault: Pubkey::new_unique(),
+            agent: Pubkey::new_unique(),
+            request_id: 7,
+            token_mint: Pubkey::new_unique(),
+            amount: 1_000_000,
+            destination: Pubkey::new_unique(),
+            reason_hash: [9; 32],
+            created_at: 1_700_000_000,
+            expires_at: 1_700_003_600,
+            status: SpendRequestStatus::Pending,
+            approved_by: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn matches_only_reviewed_contents() {
+        let r = request();
+        let (d, m, h, t) = (r.destination, r.token_mint, r.reason_hash, r.created_at);
+        assert!(r.matches(1_000_000, &d, &m, &h, t));
+        assert!(!r.matches(1_000_001, &d, &m, &h, t));
+        assert!(!r.matches(1_000_000, &Pubkey::new_unique(), &m, &h, t));
+        assert!(!r.matches(1_000_000, &d, &Pubkey::new_unique(), &h, t));
+        assert!(!r.matches(1_000_000, &d, &m, &[0; 32], t));
+        // Same contents re-created under the same id later
+        assert!(!r.matches(1_000_000, &d, &m, &h, t + 1));
+    }
+}
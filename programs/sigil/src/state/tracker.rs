@@ -41,7 +41,8 @@ pub struct SpendTracker {
 // Total data: 2,824 bytes + 8 (discriminator) = 2,832 bytes
 
 /// A single epoch bucket tracking aggregate USD spend.
-/// 16 bytes per bucket. USD-only — rate limiting stays client-side.
+/// 16 bytes per bucket. USD-only — per-agent session rate limiting lives in
+/// `AgentSpendOverlay::session_counters`.
 #[derive(Default)]
 #[zero_copy]
 pub struct EpochBucket {
@@ -52,6 +53,97 @@ pub struct EpochBucket {
     pub usd_amount: u64, // 8 bytes
 }
 
+// Bucket-window helpers shared by every rolling window: the 24h tracker,
+// the weekly / monthly / per-mint / fee windows in SpendTrackerExtension and
+// the per-destination windows. A window is a ring of `EpochBucket`s covering
+// `buckets.len() * epoch_duration` seconds; `epoch_duration` must be a
+// non-zero constant.
+
+/// Add `usd_amount` to the bucket for the epoch containing `now`,
+/// resetting the bucket first if it belongs to an older epoch.
+pub(crate) fn record_in_buckets(
+    buckets: &mut [EpochBucket],
+    epoch_duration: i64,
+    now: i64,
+    usd_amount: u64,
+) -> Result<()> {
+    // Safe: epoch_duration is a non-zero constant
+    let current_epoch = now.checked_div(epoch_duration).unwrap();
+    let idx = (current_epoch % buckets.len() as i64) as usize;
+
+    if buckets[idx].epoch_id != current_epoch {
+        buckets[idx] = EpochBucket {
+            epoch_id: current_epoch,
+            usd_amount: 0,
+        };
+    }
+
+    buckets[idx].usd_amount = buckets[idx]
+        .usd_amount
+        .checked_add(usd_amount)
+        .ok_or(error!(SigilError::Overflow))?;
+
+    Ok(())
+}
+
+/// Remove `usd_amount` recorded at `recorded_at` from that epoch's bucket.
+/// A bucket since reused for a newer epoch is left alone: the amount has
+/// already aged out of the window.
+pub(crate) fn release_from_buckets(
+    buckets: &mut [EpochBucket],
+    epoch_duration: i64,
+    recorded_at: i64,
+    usd_amount: u64,
+) {
+    if recorded_at <= 0 {
+        return;
+    }
+    // Safe: epoch_duration is a non-zero constant
+    let epoch = recorded_at.checked_div(epoch_duration).unwrap();
+    let idx = (epoch % buckets.len() as i64) as usize;
+    if buckets[idx].epoch_id == epoch {
+        buckets[idx].usd_amount = buckets[idx].usd_amount.saturating_sub(usd_amount);
+    }
+}
+
+/// Sum of buckets inside the window of `buckets.len() * epoch_duration`
+/// seconds ending at `now`. The oldest bucket that straddles the window
+/// start is scaled by its overlap.
+pub(crate) fn rolling_sum(buckets: &[EpochBucket], epoch_duration: i64, now: i64) -> u64 {
+    if now <= 0 {
+        return 0;
+    }
+    let current_epoch = now.checked_div(epoch_duration).unwrap();
+    let window_start_ts = now.saturating_sub(epoch_duration.saturating_mul(buckets.len() as i64));
+    let mut total: u128 = 0;
+
+    for bucket in buckets {
+        if bucket.usd_amount == 0 {
+            continue;
+        }
+
+        let bucket_start = bucket.epoch_id.saturating_mul(epoch_duration);
+        let bucket_end = bucket_start.saturating_add(epoch_duration);
+
+        if bucket_end <= window_start_ts || bucket.epoch_id > current_epoch {
+            continue;
+        }
+
+        if bucket_start >= window_start_ts {
+            total = total.saturating_add(bucket.usd_amount as u128);
+        } else {
+            let overlap = bucket_end.checked_sub(window_start_ts).unwrap() as u128;
+            let scaled = (bucket.usd_amount as u128)
+                .saturating_mul(overlap)
+                .checked_div(epoch_duration as u128)
+                .unwrap();
+            total = total.saturating_add(scaled);
+        }
+    }
+
+    u64::try_from(total).unwrap_or(u64::MAX)
+}
+
 /// Per-protocol spend counter using simple 24h window.
 /// When current_epoch - window_start >= 144, the window is expired and resets to 0.
 /// 48 bytes per entry (32 + 8 + 8).
@@ -73,24 +165,14 @@ impl SpendTracker {
     /// If the bucket is from a different epoch, reset it first.
     pub fn record_spend(&mut self, clock: &Clock, usd_amount: u64) -> Result<()> {
         require!(clock.unix_timestamp > 0, SigilError::Overflow);
+        record_in_buckets(
+            &mut self.buckets,
+            EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )?;
         // Safe: EPOCH_DURATION is a non-zero constant (600)
-        let current_epoch = clock.unix_timestamp.checked_div(EPOCH_DURATION).unwrap();
-        let idx = (current_epoch % NUM_EPOCHS as i64) as usize;
-
-        if self.buckets[idx].epoch_id != current_epoch {
-            self.buckets[idx] = EpochBucket {
-                epoch_id: current_epoch,
-                usd_amount: 0,
-            };
-        }
-
-        self.buckets[idx].usd_amount = self.buckets[idx]
-            .usd_amount
-            .checked_add(usd_amount)
-            .ok_or(error!(SigilError::Overflow))?;
-
-        self.last_write_epoch = current_epoch;
-
+        self.last_write_epoch = clock.unix_timestamp.checked_div(EPOCH_DURATION).unwrap();
         Ok(())
     }
 
@@ -112,42 +194,13 @@ impl SpendTracker {
             return 0;
         }
 
-        let window_start_ts = clock.unix_timestamp.saturating_sub(ROLLING_WINDOW_SECONDS);
-        let mut total: u128 = 0;
-
-        for bucket in &self.buckets {
-            if bucket.usd_amount == 0 {
-                continue;
-            }
-
-            let bucket_start = bucket.epoch_id.saturating_mul(EPOCH_DURATION);
-            let bucket_end = bucket_start.saturating_add(EPOCH_DURATION);
-
-            if bucket_end <= window_start_ts || bucket.epoch_id > current_epoch {
-                continue; // entirely outside window
-            }
-
-            if bucket_start >= window_start_ts {
-                // Fully inside window — count 100%
-                total = total.saturating_add(bucket.usd_amount as u128);
-            } else {
-                // Boundary bucket — proportional scaling
-                // Safe: bucket_end > window_start_ts (checked above), EPOCH_DURATION non-zero
-                let overlap = bucket_end.checked_sub(window_start_ts).unwrap() as u128;
-                let scaled = (bucket.usd_amount as u128)
-                    .saturating_mul(overlap)
-                    .checked_div(EPOCH_DURATION as u128)
-                    .unwrap();
-                total = total.saturating_add(scaled);
-            }
-        }
+        rolling_sum(&self.buckets, EPOCH_DURATION, clock.unix_timestamp)
+    }
 
-        // Cap at u64::MAX
-        if total > u64::MAX as u128 {
-            u64::MAX
-        } else {
-            total as u64
-        }
+    /// Release part of a spend recorded at `recorded_at` from the 24h
+    /// buckets. No-op once that epoch's bucket has been reused.
+    pub fn release_spend(&mut self, recorded_at: i64, usd_amount: u64) {
+        release_from_buckets(&mut self.buckets, EPOCH_DURATION, recorded_at, usd_amount);
     }
 
     /// Get per-protocol spend within the current simple 24h window.
@@ -227,4 +280,28 @@ impl SpendTracker {
             Err(error!(SigilError::ProtocolCapExceeded))
         }
     }
+
+    /// Release part of a per-protocol spend recorded at `recorded_at`.
+    /// No-op if the protocol's window has restarted since.
+    pub fn release_protocol_spend(
+        &mut self,
+        recorded_at: i64,
+        protocol_id: &Pubkey,
+        usd_amount: u64,
+    ) {
+        if recorded_at <= 0 {
+            return;
+        }
+        let recorded_epoch = recorded_at.checked_div(EPOCH_DURATION).unwrap();
+        let protocol_bytes = protocol_id.to_bytes();
+        if let Some(counter) = self
+            .protocol_counters
+            .iter_mut()
+            .find(|c| c.protocol == protocol_bytes)
+        {
+            if counter.window_start <= recorded_epoch {
+                counter.window_spend = counter.window_spend.saturating_sub(usd_amount);
+            }
+        }
+    }
 }
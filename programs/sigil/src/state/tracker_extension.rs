@@ -0,0 +1,431 @@
+use crate::errors::SigilError;
+use crate::state::{
+    record_in_buckets, release_from_buckets, rolling_sum, EpochBucket, MintSpendCap, MAX_MINT_CAPS,
+};
+use anchor_lang::prelude::*;
+
+/// 1-hour epoch duration for the weekly window
+pub const WEEKLY_EPOCH_DURATION: i64 = 3_600;
+
+/// Number of epochs in the 7-day window (168 × 1h = 7d)
+pub const NUM_WEEKLY_EPOCHS: usize = 168;
+
+/// 6-hour epoch duration for the monthly window
+pub const MONTHLY_EPOCH_DURATION: i64 = 21_600;
+
+/// Number of epochs in the 30-day window (120 × 6h = 30d)
+pub const NUM_MONTHLY_EPOCHS: usize = 120;
+
+/// 1-hour epoch duration for the per-mint 24h windows
+pub const MINT_EPOCH_DURATION: i64 = 3_600;
+
+/// Number of epochs in a per-mint 24h window (24 × 1h = 24h)
+pub const NUM_MINT_EPOCHS: usize = 24;
+
+/// 1-hour epoch duration for the 24h fee window
+pub const FEE_EPOCH_DURATION: i64 = 3_600;
+
+/// Number of epochs in the 24h fee window (24 × 1h = 24h)
+pub const NUM_FEE_EPOCHS: usize = 24;
+
+/// Rolling 24h spend for a single stablecoin mint.
+/// 416 bytes per window (32 + 24 × 16).
+#[zero_copy]
+pub struct MintSpendWindow {
+    /// Stablecoin mint stored as raw bytes. All-zero = unused slot.
+    pub mint: [u8; 32],
+
+    /// 24 one-hour buckets for the rolling 24h window
+    pub buckets: [EpochBucket; NUM_MINT_EPOCHS],
+}
+
+/// Zero-copy companion to `SpendTracker` holding coarser rolling windows
+/// for the optional weekly (7d) and monthly (30d) spending caps, plus
+/// per-stablecoin-mint 24h windows for `PolicyConfig::mint_caps` and the
+/// 24h fee window for `PolicyConfig::daily_fee_cap_usd`.
+/// Every window is an `EpochBucket` ring driven by the shared helpers in
+/// `tracker.rs`, just with longer epochs so the account stays small.
+///
+/// Seeds: `[b"tracker_ext", vault.key().as_ref()]`
+#[account(zero_copy)]
+pub struct SpendTrackerExtension {
+    /// Associated vault pubkey
+    pub vault: Pubkey, // 32 bytes
+
+    /// 168 one-hour buckets for the rolling 7-day window
+    pub weekly_buckets: [EpochBucket; NUM_WEEKLY_EPOCHS], // 2,688 bytes (168 × 16)
+
+    /// 120 six-hour buckets for the rolling 30-day window
+    pub monthly_buckets: [EpochBucket; NUM_MONTHLY_EPOCHS], // 1,920 bytes (120 × 16)
+
+    /// Bump seed for PDA
+    pub bump: u8, // 1 byte
+
+    /// Padding for 8-byte alignment
+    pub _padding: [u8; 7], // 7 bytes
+
+    /// Per-mint rolling 24h windows (one per capped stablecoin)
+    pub mint_windows: [MintSpendWindow; MAX_MINT_CAPS], // 832 bytes (2 × 416)
+
+    /// 24 one-hour buckets for fees charged by sessions without real spend.
+    /// Appended AFTER existing layout to preserve zero-copy byte offsets.
+    pub fee_buckets: [EpochBucket; NUM_FEE_EPOCHS], // 384 bytes (24 × 16)
+}
+// Total data: 5,864 bytes + 8 (discriminator) = 5,872 bytes
+
+impl SpendTrackerExtension {
+    /// Total account size including 8-byte discriminator
+    pub const SIZE: usize = 8
+        + 32
+        + (16 * NUM_WEEKLY_EPOCHS)
+        + (16 * NUM_MONTHLY_EPOCHS)
+        + 1
+        + 7
+        + ((32 + 16 * NUM_MINT_EPOCHS) * MAX_MINT_CAPS)
+        + (16 * NUM_FEE_EPOCHS);
+
+    /// Record a spend in both the weekly and monthly windows.
+    pub fn record_spend(&mut self, clock: &Clock, usd_amount: u64) -> Result<()> {
+        require!(clock.unix_timestamp > 0, SigilError::Overflow);
+        record_in_buckets(
+            &mut self.weekly_buckets,
+            WEEKLY_EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )?;
+        record_in_buckets(
+            &mut self.monthly_buckets,
+            MONTHLY_EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )
+    }
+
+    /// Rolling 7-day USD spend with boundary correction.
+    pub fn get_rolling_7d_usd(&self, clock: &Clock) -> u64 {
+        rolling_sum(
+            &self.weekly_buckets,
+            WEEKLY_EPOCH_DURATION,
+            clock.unix_timestamp,
+        )
+    }
+
+    /// Rolling 30-day USD spend with boundary correction.
+    pub fn get_rolling_30d_usd(&self, clock: &Clock) -> u64 {
+        rolling_sum(
+            &self.monthly_buckets,
+            MONTHLY_EPOCH_DURATION,
+            clock.unix_timestamp,
+        )
+    }
+
+    /// Rolling 24h USD spend from `mint`. 0 if the mint has no window.
+    pub fn get_mint_rolling_24h_usd(&self, clock: &Clock, mint: &Pubkey) -> u64 {
+        let mint_bytes = mint.to_bytes();
+        self.mint_windows
+            .iter()
+            .find(|w| w.mint == mint_bytes)
+            .map_or(0, |w| {
+                rolling_sum(&w.buckets, MINT_EPOCH_DURATION, clock.unix_timestamp)
+            })
+    }
+
+    /// Record a spend from `mint` in its 24h window. A mint without a window
+    /// takes an unused slot, or else recycles one whose mint is not in
+    /// `mint_caps`. If every slot holds a capped mint, `mint` itself is
+    /// uncapped (there are at most `MAX_MINT_CAPS` caps) and is not tracked.
+    pub fn record_mint_spend(
+        &mut self,
+        clock: &Clock,
+        mint_caps: &[MintSpendCap],
+        mint: &Pubkey,
+        usd_amount: u64,
+    ) -> Result<()> {
+        require!(clock.unix_timestamp > 0, SigilError::Overflow);
+        let mint_bytes = mint.to_bytes();
+        let slot = match self.mint_windows.iter().position(|w| w.mint == mint_bytes) {
+            Some(idx) => idx,
+            None => {
+                let capped =
+                    |w: &MintSpendWindow| mint_caps.iter().any(|c| c.mint.to_bytes() == w.mint);
+                let free = self
+                    .mint_windows
+                    .iter()
+                    .position(|w| w.mint == [0u8; 32])
+                    .or_else(|| self.mint_windows.iter().position(|w| !capped(w)));
+                let Some(idx) = free else {
+                    return Ok(());
+                };
+                self.mint_windows[idx] = MintSpendWindow {
+                    mint: mint_bytes,
+                    buckets: [EpochBucket::default(); NUM_MINT_EPOCHS],
+                };
+                idx
+            }
+        };
+        record_in_buckets(
+            &mut self.mint_windows[slot].buckets,
+            MINT_EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )
+    }
+
+    /// Check `usd_amount` spent from `mint` against the weekly, monthly and
+    /// per-mint caps, then record it. A weekly/monthly cap of 0 disables
+    /// that horizon; a mint without a `mint_caps` entry has no per-mint cap.
+    pub fn check_and_record(
+        &mut self,
+        clock: &Clock,
+        weekly_cap_usd: u64,
+        monthly_cap_usd: u64,
+        mint_caps: &[MintSpendCap],
+        mint: &Pubkey,
+        usd_amount: u64,
+    ) -> Result<()> {
+        if weekly_cap_usd > 0 {
+            let new_total = self
+                .get_rolling_7d_usd(clock)
+                .checked_add(usd_amount)
+                .ok_or(SigilError::Overflow)?;
+            require!(
+                new_total <= weekly_cap_usd,
+                SigilError::WeeklySpendingCapExceeded
+            );
+        }
+        if monthly_cap_usd > 0 {
+            let new_total = self
+                .get_rolling_30d_usd(clock)
+                .checked_add(usd_amount)
+                .ok_or(SigilError::Overflow)?;
+            require!(
+                new_total <= monthly_cap_usd,
+                SigilError::MonthlySpendingCapExceeded
+            );
+        }
+        if let Some(cap) = mint_caps.iter().find(|c| c.mint == *mint) {
+            let new_total = self
+                .get_mint_rolling_24h_usd(clock, mint)
+                .checked_add(usd_amount)
+                .ok_or(SigilError::Overflow)?;
+            require!(
+                new_total <= cap.daily_cap_usd,
+                SigilError::MintSpendingCapExceeded
+            );
+        }
+        self.record_spend(clock, usd_amount)?;
+        self.record_mint_spend(clock, mint_caps, mint, usd_amount)
+    }
+
+    /// Release part of a spend recorded at `recorded_at` from `mint` from the
+    /// weekly, monthly and per-mint windows.
+    pub fn release_spend(&mut self, recorded_at: i64, mint: &Pubkey, usd_amount: u64) {
+        release_from_buckets(
+            &mut self.weekly_buckets,
+            WEEKLY_EPOCH_DURATION,
+            recorded_at,
+            usd_amount,
+        );
+        release_from_buckets(
+            &mut self.monthly_buckets,
+            MONTHLY_EPOCH_DURATION,
+            recorded_at,
+            usd_amount,
+        );
+        let mint_bytes = mint.to_bytes();
+        if let Some(window) = self.mint_windows.iter_mut().find(|w| w.mint == mint_bytes) {
+            release_from_buckets(
+                &mut window.buckets,
+                MINT_EPOCH_DURATION,
+                recorded_at,
+                usd_amount,
+            );
+        }
+    }
+
+    /// Rolling 24h USD fees charged to the fee budget.
+    pub fn get_rolling_24h_fees_usd(&self, clock: &Clock) -> u64 {
+        rolling_sum(&self.fee_buckets, FEE_EPOCH_DURATION, clock.unix_timestamp)
+    }
+
+    /// Record `usd_amount` of fees and return whether the rolling total now
+    /// exceeds `daily_fee_cap_usd`. The fees were already transferred, so
+    /// they are recorded either way.
+    pub fn record_fees(
+        &mut self,
+        clock: &Clock,
+        daily_fee_cap_usd: u64,
+        usd_amount: u64,
+    ) -> Result<bool> {
+        require!(clock.unix_timestamp > 0, SigilError::Overflow);
+        let new_total = self
+            .get_rolling_24h_fees_usd(clock)
+            .checked_add(usd_amount)
+            .ok_or(SigilError::Overflow)?;
+        record_in_buckets(
+            &mut self.fee_buckets,
+            FEE_EPOCH_DURATION,
+            clock.unix_timestamp,
+            usd_amount,
+        )?;
+        Ok(new_total > daily_fee_cap_usd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clock_at(ts: i64) -> Clock {
+        Clock {
+            unix_timestamp: ts,
+            ..Clock::default()
+        }
+    }
+
+    fn empty() -> SpendTrackerExtension {
+        SpendTrackerExtension {
+            vault: Pubkey::default(),
+            weekly_buckets: [EpochBucket::default(); NUM_WEEKLY_EPOCHS],
+            monthly_buckets: [EpochBucket::default(); NUM_MONTHLY_EPOCHS],
+            bump: 0,
+            _padding: [0; 7],
+            mint_windows: [MintSpendWindow {
+                mint: [0; 32],
+                buckets: [EpochBucket::default(); NUM_MINT_EPOCHS],
+            }; MAX_MINT_CAPS],
+            fee_buckets: [EpochBucket::default(); NUM_FEE_EPOCHS],
+        }
+    }
+
+    const T0: i64 = 1_700_000_000;
+    const MINT: Pubkey = Pubkey::new_from_array([7; 32]);
+    const OTHER_MINT: Pubkey = Pubkey::new_from_array([9; 32]);
+
+    #[test]
+    fn size_matches_layout() {
+        assert_eq!(
+            SpendTrackerExtension::SIZE,
+            8 + core::mem::size_of::<SpendTrackerExtension>()
+        );
+    }
+
+    #[test]
+    fn spend_counts_in_both_windows() {
+        let mut ext = empty();
+        ext.record_spend(&clock_at(T0), 100).unwrap();
+        assert_eq!(ext.get_rolling_7d_usd(&clock_at(T0)), 100);
+        assert_eq!(ext.get_rolling_30d_usd(&clock_at(T0)), 100);
+    }
+
+    #[test]
+    fn weekly_window_drops_old_spend_monthly_keeps_it() {
+        let mut ext = empty();
+        ext.record_spend(&clock_at(T0), 100).unwrap();
+        let later = clock_at(T0 + 8 * 86_400);
+        assert_eq!(ext.get_rolling_7d_usd(&later), 0);
+        assert_eq!(ext.get_rolling_30d_usd(&later), 100);
+        let much_later = clock_at(T0 + 31 * 86_400);
+        assert_eq!(ext.get_rolling_30d_usd(&much_later), 0);
+    }
+
+    #[test]
+    fn caps_enforced_and_zero_disables() {
+        let mut ext = empty();
+        let clock = clock_at(T0);
+        ext.check_and_record(&clock, 1_000, 0, &[], &MINT, 600)
+            .unwrap();
+        assert!(ext
+            .check_and_record(&clock, 1_000, 0, &[], &MINT, 500)
+            .is_err());
+        assert!(ext
+            .check_and_record(&clock, 0, 1_000, &[], &MINT, 500)
+            .is_err());
+        ext.check_and_record(&clock, 0, 0, &[], &MINT, 500).unwrap();
+        assert_eq!(ext.get_rolling_7d_usd(&clock), 1_100);
+    }
+
+    #[test]
+    fn failed_check_does_not_record() {
+        let mut ext = empty();
+        let clock = clock_at(T0);
+        assert!(ext
+            .check_and_record(&clock, 100, 0, &[], &MINT, 101)
+            .is_err());
+        assert_eq!(ext.get_rolling_7d_usd(&clock), 0);
+    }
+
+    #[test]
+    fn mint_cap_only_counts_that_mint() {
+        let mut ext = empty();
+        let clock = clock_at(T0);
+        let caps = [MintSpendCap {
+            mint: MINT,
+            daily_cap_usd: 1_000,
+        }];
+        ext.check_and_record(&clock, 0, 0, &caps, &OTHER_MINT, 5_000)
+            .unwrap();
+        ext.check_and_record(&clock, 0, 0, &caps, &MINT, 1_000)
+            .unwrap();
+        assert!(ext.check_and_record(&clock, 0, 0, &caps, &MINT, 1).is_err());
+        assert_eq!(ext.get_mint_rolling_24h_usd(&clock, &MINT), 1_000);
+        assert_eq!(ext.get_mint_rolling_24h_usd(&clock, &OTHER_MINT), 5_000);
+    }
+
+    #[test]
+    fn mint_window_expires_after_24h() {
+        let mut ext = empty();
+        let caps = [MintSpendCap {
+            mint: MINT,
+            daily_cap_usd: 1_000,
+        }];
+        ext.check_and_record(&clock_at(T0), 0, 0, &caps, &MINT, 1_000)
+            .unwrap();
+        ext.check_and_record(&clock_at(T0 + 86_400 + 3_600), 0, 0, &caps, &MINT, 1_000)
+            .unwrap();
+    }
+
+    #[test]
+    fn uncapped_mint_slot_is_recycled_for_capped_mint() {
+        let mut ext = empty();
+        let clock = clock_at(T0);
+        let a = Pubkey::new_from_array([1; 32]);
+        let b = Pubkey::new_from_array([2; 32]);
+        ext.record_mint_spend(&clock, &[], &a, 10).unwrap();
+        ext.record_mint_spend(&clock, &[], &b, 10).unwrap();
+        let caps = [MintSpendCap {
+            mint: MINT,
+            daily_cap_usd: 1_000,
+        }];
+        ext.record_mint_spend(&clock, &caps, &MINT, 10).unwrap();
+        assert_eq!(ext.get_mint_rolling_24h_usd(&clock, &MINT), 10);
+    }
+
+    #[test]
+    fn fee_budget_is_separate_from_spend_windows() {
+        let mut ext = empty();
+        let clock = clock_at(T0);
+        assert!(!ext.record_fees(&clock, 1_000, 600).unwrap());
+        assert!(ext.record_fees(&clock, 1_000, 401).unwrap());
+        assert_eq!(ext.get_rolling_24h_fees_usd(&clock), 1_001);
+        assert_eq!(ext.get_rolling_7d_usd(&clock), 0);
+        let later = clock_at(T0 + 86_400 + 3_600);
+        assert!(!ext.record_fees(&later, 1_000, 1_000).unwrap());
+    }
+
+    #[test]
+    fn release_undoes_recent_spend_only() {
+        let mut ext = empty();
+        ext.check_and_record(&clock_at(T0), 0, 0, &[], &MINT, 1_000)
+            .unwrap();
+        ext.release_spend(T0, &MINT, 400);
+        assert_eq!(ext.get_rolling_7d_usd(&clock_at(T0)), 600);
+        assert_eq!(ext.get_mint_rolling_24h_usd(&clock_at(T0), &MINT), 600);
+
+        // Once the bucket is reused for a later epoch nothing is released.
+        let later = T0 + NUM_WEEKLY_EPOCHS as i64 * WEEKLY_EPOCH_DURATION;
+        ext.record_spend(&clock_at(later), 50).unwrap();
+        ext.release_spend(T0, &MINT, 400);
+        assert_eq!(ext.get_rolling_7d_usd(&clock_at(later)), 50);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{VaultStatus, MAX_AGENTS_PER_VAULT};
+use super::{VaultStatus, MAX_AGENTS_PER_VAULT, MAX_APPROVERS, MAX_CPI_CALLERS};
 use anchor_lang::prelude::*;
 
 /// Agent capability levels (replaces 21-bit ActionType bitmask).
@@ -15,9 +15,57 @@ pub struct AgentEntry {
     pub capability: u8, // 1 byte (was permissions: u64, 8 bytes)
     pub spending_limit_usd: u64, // 8 bytes — 0 = no per-agent limit
     pub paused: bool,   // 1 byte  — owner-controlled suspension
-    pub _reserved: [u8; 7], // 7 bytes — maintain layout size for account stability
+    /// Max sessions (validate_and_authorize + agent_transfer) per rolling hour.
+    /// 0 = unlimited. Counted in AgentSpendOverlay.session_counters.
+    pub max_sessions_per_hour: u16, // 2 bytes (carved from _reserved)
+    /// True when this agent's AgentSettings PDA narrows the vault policy
+    /// and must be passed to validate_and_authorize.
+    pub has_settings: bool, // 1 byte (carved from _reserved)
+    /// Unix timestamp after which this key is rejected. 0 = never expires.
+    /// u32 to fit the last 4 reserved bytes; covers timestamps until 2106.
+    pub valid_until: u32, // 4 bytes (carved from _reserved)
+    /// Concurrent session limit. 0 = one session per mint (session nonce
+    /// must be 0); N > 0 = nonces 0..N and at most N open sessions.
+    pub max_concurrent_sessions: u8, // 1 byte
+    /// Open sessions for this agent. Incremented in validate_and_authorize,
+    /// decremented in finalize_session.
+    pub active_sessions: u8, // 1 byte
+}
+// Total: 51 bytes per entry (32 + 1 + 8 + 1 + 2 + 1 + 4 + 1 + 1 = 51).
+// The last two bytes go beyond the original 49-byte entry (reserved bytes
+// exhausted); pre-migration vaults are rewritten by migrate_vault
+// (see `LEGACY_AGENT_ENTRY_SIZE`).
+
+impl AgentEntry {
+    pub const SIZE: usize = 51;
+
+    /// Whether a session with `nonce` may be opened now.
+    pub fn can_open_session(&self, nonce: u8) -> bool {
+        if self.max_concurrent_sessions == 0 {
+            nonce == 0
+        } else {
+            nonce < self.max_concurrent_sessions
+                && self.active_sessions < self.max_concurrent_sessions
+        }
+    }
+
+    /// Whether the key has passed its `valid_until` at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.valid_until != 0 && now > self.valid_until as i64
+    }
+}
+
+/// Convert a requested key expiry into `AgentEntry::valid_until`.
+/// 0 = never expires; otherwise it must lie in the future and fit in u32.
+pub fn pack_valid_until(valid_until: i64, now: i64) -> Option<u32> {
+    if valid_until == 0 {
+        return Some(0);
+    }
+    if valid_until <= now {
+        return None;
+    }
+    u32::try_from(valid_until).ok()
 }
-// Total: 49 bytes per entry (32 + 1 + 8 + 1 + 7 = 49, same as old layout with permissions: u64)
 
 #[account]
 pub struct AgentVault {
@@ -30,8 +78,10 @@ pub struct AgentVault {
     /// Registered agents with per-agent permission bitmasks (max 10)
     pub agents: Vec<AgentEntry>,
 
-    /// Developer fee destination — IMMUTABLE after initialization.
-    /// Prevents a compromised owner from redirecting fees.
+    /// Developer fee destination. Only changes through the timelocked
+    /// queue/apply_fee_destination_update flow, which emits events the
+    /// current recipient can watch — a compromised owner cannot redirect
+    /// fees silently.
     pub fee_destination: Pubkey,
 
     /// Vault status: Active, Frozen, or Closed
@@ -71,37 +121,122 @@ pub struct AgentVault {
     /// Informational only — never used in authorization decisions.
     pub total_failed_transactions: u64,
 
-    /// Number of active (not yet finalized) sessions for this vault.
+    /// Number of active (not yet finalized) sessions for this vault, summed
+    /// over agents (see `AgentEntry::active_sessions`). Kept vault-wide so a
+    /// revoked agent's open sessions still block close_vault.
     /// Incremented in validate_and_authorize, decremented in finalize_session.
     /// close_vault requires this to be 0.
     pub active_sessions: u8,
+
+    /// Optional guardian key (Pubkey::default() = none). Set via the
+    /// timelocked queue_policy_update path. May call freeze_vault,
+    /// pause_agent and tighten_policy, and approve agents' spend requests —
+    /// never anything that moves funds itself or loosens the policy.
+    pub guardian: Pubkey,
+
+    /// Key the vault PDA was derived from (`[b"vault", creator, vault_id]`).
+    /// Set once to the initial owner and never changed, so `owner` can be
+    /// transferred without moving the vault or any vault-scoped PDA.
+    pub creator: Pubkey,
+
+    /// Optional recovery key (Pubkey::default() = none). Set together with
+    /// `recovery_destination` via the timelocked queue_policy_update path.
+    /// Can only queue a sweep to `recovery_destination`, which waits out
+    /// `RECOVERY_TIMELOCK_SECONDS` and can be cancelled by the owner.
+    pub recovery_authority: Pubkey,
+
+    /// Wallet that receives a recovery sweep.
+    pub recovery_destination: Pubkey,
+
+    /// Unix timestamp of the last owner-signed instruction. Every owner
+    /// handler refreshes it via `record_owner_activity`.
+    pub last_owner_activity: i64,
+
+    /// Dead-man switch window in seconds (0 = disabled). Once
+    /// `last_owner_activity` is older than this, anyone may call
+    /// trigger_dead_man_switch. Set with `beneficiary` via queue_policy_update.
+    pub inactivity_window_seconds: u64,
+
+    /// Wallet that may claim vault funds after the dead-man switch fires.
+    pub beneficiary: Pubkey,
+
+    /// Set by trigger_dead_man_switch. Terminal: the vault stays frozen and
+    /// funds leave only through claim_beneficiary_funds.
+    pub dead_man_triggered: bool,
+
+    /// Optional native approver set (empty = none). Set with
+    /// `approval_threshold` via queue_policy_update. Bounded to MAX_APPROVERS.
+    pub approvers: Vec<Pubkey>,
+
+    /// Number of `approvers` that must co-sign the owner's governance
+    /// actions: the queue_* updates (policy, constraints, close constraints,
+    /// agent permissions, owner transfer, fee destination), register_agent,
+    /// rotate_agent_key, reactivate_vault with a new agent and
+    /// withdraw_funds (0 = disabled). The owner counts if listed.
+    pub approval_threshold: u8,
+
+    /// Programs whose CPIs are accepted for owner instructions when they are
+    /// the transaction's top-level program (empty = top-level calls only).
+    /// Set via queue_policy_update. Bounded to MAX_CPI_CALLERS.
+    pub cpi_callers: Vec<Pubkey>,
+
+    /// Open `PendingFulfillment` reservations. close_vault requires 0.
+    pub open_fulfillments: u8,
+
+    /// The `[b"tracker_ext", vault]` SpendTrackerExtension exists. Once set,
+    /// every spend path must pass it so its windows never miss a spend.
+    pub has_tracker_extension: bool,
+
+    /// A `GovernanceBundle` exists. Individual applies are rejected until it
+    /// is applied or cancelled, so a bundle cannot land half-way.
+    pub open_bundle: bool,
+
+    /// The `[b"destination_spend", vault]` DestinationSpendTracker exists.
+    /// Once set, agent_transfer must pass it on every transfer, capped or
+    /// not, so a destination cap added later starts from the full history.
+    pub has_destination_tracker: bool,
+
+    /// Slot of the last apply_owner_transfer (0 = never). Updates queued at
+    /// or before it were queued by a previous owner and are rejected at apply.
+    pub owner_changed_at_slot: u64,
 }
 
 // ARCHITECTURE DECISION: No on-chain viewer/delegate role
 //
-// The program has two roles: owner (full authority) and agent (execute within policy).
-// There is no "viewer" or "delegate" role because:
+// The program has two roles: owner (full authority) and agent (execute within policy),
+// plus an optional guardian limited to risk-reducing actions (freeze, pause,
+// tighten) and spend-request approval, so on-call responders can hit the kill
+// switch or unblock a large transfer without the owner key.
+// There is no "viewer" or general "delegate" role because:
 //   1. All Solana account data is publicly readable via RPC.
 //   2. Read-only access control is a dashboard/API concern, not on-chain.
 //   3. Adding viewer entries would bloat account size with zero security benefit.
-//   4. Delegate roles are handled by Squads V4 externally if the owner is a multisig.
+//   4. Delegate roles are handled by Squads V4 externally if the owner is a multisig;
+//      the native approver set only adds co-signers, it grants no authority alone.
 //
 // Found by: Persona test (Treasury Manager "David")
 // Decision: By design. Dashboard RBAC handles this.
 
 impl AgentVault {
     /// Account discriminator (8) + owner (32) + vault_id (8) +
-    /// agents vec prefix (4) + agents data (49 * 10) +
+    /// agents vec prefix (4) + agents data (51 * 10) +
     /// fee_destination (32) + status (1) + bump (1) +
     /// created_at (8) + total_transactions (8) + total_volume (8) +
     /// active_escrow_count (1) + total_fees_collected (8) +
     /// total_deposited_usd (8) + total_withdrawn_usd (8) + total_failed_transactions (8) +
-    /// active_sessions (1)
+    /// active_sessions (1) + guardian (32) + creator (32) +
+    /// recovery_authority (32) + recovery_destination (32) +
+    /// last_owner_activity (8) + inactivity_window_seconds (8) +
+    /// beneficiary (32) + dead_man_triggered (1) +
+    /// approvers vec (4 + 32 * MAX_APPROVERS) + approval_threshold (1) +
+    /// cpi_callers vec (4 + 32 * MAX_CPI_CALLERS) + open_fulfillments (1) +
+    /// has_tracker_extension (1) + open_bundle (1) +
+    /// has_destination_tracker (1) + owner_changed_at_slot (8)
     pub const SIZE: usize = 8
         + 32
         + 8
         + 4
-        + (49 * MAX_AGENTS_PER_VAULT)
+        + (AgentEntry::SIZE * MAX_AGENTS_PER_VAULT)
         + 32
         + 1
         + 1
@@ -113,8 +248,28 @@ impl AgentVault {
         + 8
         + 8
         + 8
-        + 1;
-    // = 634 (was 635; open_positions u8 removed)
+        + 1
+        + 32
+        + 32
+        + 32
+        + 32
+        + 8
+        + 8
+        + 32
+        + 1
+        + (4 + 32 * MAX_APPROVERS)
+        + 1
+        + (4 + 32 * MAX_CPI_CALLERS)
+        + 1
+        + 1
+        + 1
+        + 1
+        + 8;
+    // = 1140 (634 + guardian + creator + recovery authority/destination
+    //         + dead-man switch + approver set + CPI callers
+    //         + per-agent session counters + open fulfillments
+    //         + tracker extension, open bundle and destination tracker flags
+    //         + owner change slot)
 
     pub fn is_active(&self) -> bool {
         self.status == VaultStatus::Active
@@ -155,7 +310,141 @@ impl AgentVault {
         self.owner == *signer
     }
 
+    /// Owner or, when one is set, the guardian.
+    pub fn is_owner_or_guardian(&self, signer: &Pubkey) -> bool {
+        self.is_owner(signer) || (self.guardian != Pubkey::default() && self.guardian == *signer)
+    }
+
+    pub fn record_owner_activity(&mut self, now: i64) {
+        self.last_owner_activity = now;
+    }
+
+    /// Whether the dead-man switch is armed and the owner has been silent
+    /// for at least the configured window.
+    pub fn is_owner_inactive(&self, now: i64) -> bool {
+        self.inactivity_window_seconds > 0
+            && now.saturating_sub(self.last_owner_activity) >= self.inactivity_window_seconds as i64
+    }
+
+    /// Hand the vault to `new_owner` at `slot`. Keys the previous owner
+    /// appointed — approvers, guardian, recovery authority and dead-man
+    /// beneficiary — are dropped for the new owner to re-appoint through
+    /// queue_policy_update, and every update queued so far stops applying.
+    pub fn transfer_ownership(&mut self, new_owner: Pubkey, slot: u64) {
+        self.owner = new_owner;
+        self.owner_changed_at_slot = slot;
+        self.approvers = Vec::new();
+        self.approval_threshold = 0;
+        self.guardian = Pubkey::default();
+        self.recovery_authority = Pubkey::default();
+        self.recovery_destination = Pubkey::default();
+        self.inactivity_window_seconds = 0;
+        self.beneficiary = Pubkey::default();
+    }
+
+    /// Whether an update queued at `queued_at_slot` was queued by the
+    /// current owner, i.e. after the last ownership transfer.
+    pub fn accepts_queued_update(&self, queued_at_slot: u64) -> bool {
+        queued_at_slot > self.owner_changed_at_slot
+    }
+
+    pub fn has_recovery(&self) -> bool {
+        self.recovery_authority != Pubkey::default()
+    }
+
+    /// Number of distinct approvers among `signers`.
+    pub fn approval_count(&self, signers: &[Pubkey]) -> usize {
+        self.approvers
+            .iter()
+            .filter(|a| signers.contains(a))
+            .count()
+    }
+
     pub fn is_agent_paused(&self, signer: &Pubkey) -> bool {
         self.get_agent(signer).map(|a| a.paused).unwrap_or(false)
     }
+
+    pub fn is_agent_expired(&self, signer: &Pubkey, now: i64) -> bool {
+        self.get_agent(signer)
+            .map(|a| a.is_expired(now))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault(owner: Pubkey) -> AgentVault {
+        AgentVault {
+            owner,
+            vault_id: 0,
+            agents: Vec::new(),
+            fee_destination: Pubkey::new_unique(),
+            status: VaultStatus::Active,
+            bump: 255,
+            created_at: 0,
+            total_transactions: 0,
+            total_volume: 0,
+            active_escrow_count: 0,
+            total_fees_collected: 0,
+            total_deposited_usd: 0,
+            total_withdrawn_usd: 0,
+            total_failed_transactions: 0,
+            active_sessions: 0,
+            guardian: Pubkey::default(),
+            creator: owner,
+            recovery_authority: Pubkey::default(),
+            recovery_destination: Pubkey::default(),
+            last_owner_activity: 0,
+            inactivity_window_seconds: 0,
+            beneficiary: Pubkey::default(),
+            dead_man_triggered: false,
+            approvers: Vec::new(),
+            approval_threshold: 0,
+            cpi_callers: Vec::new(),
+            open_fulfillments: 0,
+            has_tracker_extension: true,
+            open_bundle: false,
+            has_destination_tracker: true,
+            owner_changed_at_slot: 0,
+        }
+    }
+
+    #[test]
+    fn transfer_drops_keys_the_previous_owner_appointed() {
+        let (old_owner, new_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut v = vault(old_owner);
+        v.approvers = vec![old_owner, Pubkey::new_unique()];
+        v.approval_threshold = 2;
+        v.guardian = Pubkey::new_unique();
+        v.recovery_authority = Pubkey::new_unique();
+        v.recovery_destination = Pubkey::new_unique();
+        v.inactivity_window_seconds = 30 * 86_400;
+        v.beneficiary = Pubkey::new_unique();
+
+        v.transfer_ownership(new_owner, 500);
+
+        assert!(v.is_owner(&new_owner));
+        assert_eq!(v.creator, old_owner);
+        assert!(v.approvers.is_empty());
+        assert_eq!(v.approval_threshold, 0);
+        assert_eq!(v.approval_count(&[old_owner]), 0);
+        assert!(!v.is_owner_or_guardian(&old_owner));
+        assert!(!v.has_recovery());
+        assert_eq!(v.recovery_destination, Pubkey::default());
+        assert!(!v.is_owner_inactive(i64::MAX));
+        assert_eq!(v.beneficiary, Pubkey::default());
+    }
+
+    #[test]
+    fn transfer_invalidates_updates_queued_before_it() {
+        let mut v = vault(Pubkey::new_unique());
+        assert!(v.accepts_queued_update(1));
+
+        v.transfer_ownership(Pubkey::new_unique(), 500);
+        assert!(!v.accepts_queued_update(499));
+        assert!(!v.accepts_queued_update(500));
+        assert!(v.accepts_queued_update(501));
+    }
 }
@@ -5,7 +5,7 @@
  * Every error includes a category, retryability flag, and
  * recovery actions that tell the agent exactly what to do next.
  *
- * Maps all 88 on-chain error codes (6000-6087) plus 34 SDK
+ * Maps all 148 on-chain error codes (6000-6147) plus 34 SDK
  * error codes (7000-7033) to AgentError with machine-readable metadata.
  *
  * Zero dependency on @solana/web3.js or @coral-xyz/anchor.
@@ -57,7 +57,7 @@ export interface AgentError {
 }
 
 // ---------------------------------------------------------------------------
-// On-chain error code mapping (6000-6087)
+// On-chain error code mapping (6000-6147)
 // ---------------------------------------------------------------------------
 
 interface ErrorMapping {
@@ -1362,6 +1362,879 @@ export const ON_CHAIN_ERROR_MAP: Record<number, ErrorMapping> = {
       },
     ],
   },
+  // --- Policy tightening errors ---
+  6088: {
+    name: "PolicyChangeNotTightening",
+    message:
+      "tighten_policy only accepts changes that strictly lower risk; use queue_policy_update for anything else",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_direct_update",
+        description:
+          "Queue the change with queue_policy_update; tighten_policy only accepts changes that strictly lower risk",
+      },
+    ],
+  },
+  // --- Rolling window cap errors ---
+  6089: {
+    name: "WeeklySpendingCapExceeded",
+    message: "Rolling 7-day spending cap would be exceeded",
+    category: "SPENDING_CAP",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "reduce_amount",
+        description: "Reduce the amount to fit within the remaining 7-day cap",
+      },
+      {
+        action: "check_spending",
+        description: "Check remaining spending capacity",
+        tool: "sigil_check_spending",
+      },
+      {
+        action: "wait",
+        description:
+          "Wait for the 7-day rolling window to release spent capacity",
+      },
+    ],
+  },
+  6090: {
+    name: "MonthlySpendingCapExceeded",
+    message: "Rolling 30-day spending cap would be exceeded",
+    category: "SPENDING_CAP",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "reduce_amount",
+        description: "Reduce the amount to fit within the remaining 30-day cap",
+      },
+      {
+        action: "check_spending",
+        description: "Check remaining spending capacity",
+        tool: "sigil_check_spending",
+      },
+      {
+        action: "wait",
+        description:
+          "Wait for the 30-day rolling window to release spent capacity",
+      },
+    ],
+  },
+  6091: {
+    name: "TrackerExtensionRequired",
+    message:
+      "SpendTrackerExtension account is required once the vault has one or sets a weekly, monthly, per-mint or fee cap",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_sdk",
+        description:
+          "Pass the vault's SpendTrackerExtension PDA (seeds: tracker_ext, vault)",
+      },
+    ],
+  },
+  6092: {
+    name: "MintSpendingCapExceeded",
+    message:
+      "Rolling 24h spending cap for this stablecoin mint would be exceeded",
+    category: "SPENDING_CAP",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "reduce_amount",
+        description:
+          "Reduce the amount to fit within this mint's remaining daily cap",
+      },
+      {
+        action: "use_stablecoin",
+        description:
+          "Spend a different stablecoin mint with remaining capacity",
+      },
+      {
+        action: "wait",
+        description:
+          "Wait for the 24h rolling window to release spent capacity",
+      },
+    ],
+  },
+  6093: {
+    name: "InvalidMintCaps",
+    message:
+      "Invalid per-mint caps: too many entries, duplicate mint, or non-stablecoin mint",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_policy",
+        description:
+          "Use at most one cap per stablecoin mint and only USDC/USDT mints",
+      },
+    ],
+  },
+  // --- Destination cap errors ---
+  6094: {
+    name: "DestinationSpendCapExceeded",
+    message: "Rolling 24h cap for this transfer destination would be exceeded",
+    category: "SPENDING_CAP",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "reduce_amount",
+        description:
+          "Reduce the amount to fit within this destination's remaining daily cap",
+      },
+      {
+        action: "wait",
+        description:
+          "Wait for the 24h rolling window to release spent capacity",
+      },
+    ],
+  },
+  6095: {
+    name: "DestinationCapsMismatch",
+    message:
+      "Destination caps must be empty or match allowed_destinations length",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_policy",
+        description:
+          "Pass no destination caps, or one cap per allowed destination in the same order",
+      },
+    ],
+  },
+  6096: {
+    name: "DestinationTrackerRequired",
+    message:
+      "DestinationSpendTracker account is required when the destination has a cap",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_sdk",
+        description:
+          "Pass the vault's DestinationSpendTracker PDA (seeds: destination_spend, vault)",
+      },
+    ],
+  },
+  // --- Agent rate, schedule and settings errors ---
+  6097: {
+    name: "AgentSessionRateExceeded",
+    message: "Agent has reached its max_sessions_per_hour limit",
+    category: "RATE_LIMIT",
+    retryable: true,
+    retry_after_ms: 600_000,
+    recovery_actions: [
+      {
+        action: "wait",
+        description: "Wait for earlier sessions to leave the rolling hour",
+      },
+      {
+        action: "request_permissions",
+        description: "Ask the vault owner to raise max_sessions_per_hour",
+      },
+    ],
+  },
+  6098: {
+    name: "OutsideTradingWindow",
+    message: "Current UTC hour is outside the policy's trading-hours schedule",
+    category: "POLICY_VIOLATION",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "wait",
+        description:
+          "Retry during an hour the policy's trading schedule allows",
+      },
+      {
+        action: "check_policy",
+        description: "Check the vault's trading-hours schedule",
+        tool: "sigil_check_vault",
+      },
+    ],
+  },
+  6099: {
+    name: "AgentSettingsRequired",
+    message: "AgentSettings account is required for this agent",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_sdk",
+        description:
+          "Pass the agent's AgentSettings PDA (seeds: agent_settings, vault, agent)",
+      },
+    ],
+  },
+  6100: {
+    name: "AgentProtocolNotAllowed",
+    message: "Protocol not in this agent's protocol subset",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "change_protocol",
+        description: "Use a protocol in this agent's protocol subset",
+      },
+      {
+        action: "request_permissions",
+        description:
+          "Ask the vault owner to add the protocol to the agent's subset",
+      },
+    ],
+  },
+  6101: {
+    name: "AgentOverrideNotTighter",
+    message: "Per-agent override must be at or below the vault policy value",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_permissions",
+        description:
+          "Set per-agent overrides at or below the vault policy values",
+      },
+    ],
+  },
+  6102: {
+    name: "AgentKeyExpired",
+    message: "Agent key has passed its valid_until timestamp",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_different_key",
+        description:
+          "Ask the vault owner to rotate the agent key or extend valid_until",
+      },
+    ],
+  },
+  6103: {
+    name: "InvalidAgentExpiry",
+    message:
+      "Agent key expiry must be 0 (never) or a future timestamp before 2106",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_expiry",
+        description:
+          "Pass valid_until = null (never) or a future timestamp before 2106",
+      },
+    ],
+  },
+  // --- Guardian, ownership and recovery errors ---
+  6104: {
+    name: "UnauthorizedOwnerOrGuardian",
+    message: "Only the vault owner or guardian can perform this action",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "verify_owner",
+        description:
+          "This operation requires the vault owner's or guardian's signature",
+      },
+    ],
+  },
+  6105: {
+    name: "InvalidGuardian",
+    message: "Guardian must differ from the owner and every registered agent",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Choose a guardian that is neither the owner nor a registered agent",
+      },
+    ],
+  },
+  6106: {
+    name: "InvalidNewOwner",
+    message:
+      "New owner must be a fresh key: not the current owner, guardian or an agent",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Transfer ownership to a fresh key that is not the owner, guardian or an agent",
+      },
+    ],
+  },
+  6107: {
+    name: "InvalidRecoveryConfig",
+    message:
+      "Recovery authority and destination must be set together; the authority must differ from the owner and every agent",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Set recovery authority and destination together, with an authority that is neither the owner nor an agent",
+      },
+    ],
+  },
+  6108: {
+    name: "UnauthorizedRecoveryAuthority",
+    message: "Signer is not the vault's recovery authority",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "verify_owner",
+        description:
+          "This operation requires the vault's recovery authority signature",
+      },
+    ],
+  },
+  6109: {
+    name: "InvalidDeadManSwitchConfig",
+    message:
+      "Inactivity window and beneficiary must be set together: 0 and no beneficiary, or at least 7 days with a non-agent beneficiary",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Disable the switch (window 0, no beneficiary) or set a window of at least 7 days with a non-agent beneficiary",
+      },
+    ],
+  },
+  6110: {
+    name: "OwnerNotInactive",
+    message:
+      "Dead-man switch is disabled or the owner has been active within the window",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "check_vault_status",
+        description:
+          "Check the vault's inactivity window and last owner activity",
+        tool: "sigil_check_vault",
+      },
+    ],
+  },
+  6111: {
+    name: "DeadManSwitchTriggered",
+    message: "Dead-man switch has fired; funds can only go to the beneficiary",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "escalate_to_human",
+        description:
+          "The dead-man switch has fired; only the beneficiary can claim the vault's funds",
+      },
+    ],
+  },
+  6112: {
+    name: "DeadManSwitchNotTriggered",
+    message: "Dead-man switch has not fired",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "check_vault_status",
+        description: "Trigger the dead-man switch before claiming funds",
+        tool: "sigil_check_vault",
+      },
+    ],
+  },
+  6113: {
+    name: "UnauthorizedBeneficiary",
+    message: "Signer is not the vault's beneficiary",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "verify_owner",
+        description:
+          "This operation requires the vault beneficiary's signature",
+      },
+    ],
+  },
+  // --- Owner permit errors ---
+  6114: {
+    name: "InvalidPermit",
+    message:
+      "No valid owner-signed permit for this vault, agent and protocol precedes this instruction",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_instruction_order",
+        description:
+          "Place an owner-signed Ed25519 permit for this vault, agent and protocol before validate_and_authorize",
+      },
+    ],
+  },
+  6115: {
+    name: "PermitExpired",
+    message: "Owner permit has expired",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "escalate_to_human",
+        description: "Ask the vault owner to sign a fresh permit",
+      },
+    ],
+  },
+  6116: {
+    name: "PermitNonceUsed",
+    message: "Permit nonce already used or below the nonce window",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "escalate_to_human",
+        description:
+          "Ask the vault owner to sign a permit with an unused nonce",
+      },
+    ],
+  },
+  6117: {
+    name: "PermitCeilingExceeded",
+    message: "Transaction exceeds the owner permit's amount ceiling",
+    category: "SPENDING_CAP",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "reduce_amount",
+        description: "Reduce the amount to the permit's ceiling",
+      },
+    ],
+  },
+  // --- Spend request errors ---
+  6118: {
+    name: "InvalidSpendRequestExpiry",
+    message: "Spend request expiry must be in the future and within 7 days",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_expiry",
+        description:
+          "Set the spend request expiry in the future and within 7 days",
+      },
+    ],
+  },
+  6119: {
+    name: "SpendRequestNotPending",
+    message: "Spend request is not pending",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "check_status",
+        description:
+          "The spend request was already approved, executed or cancelled",
+      },
+    ],
+  },
+  6120: {
+    name: "SpendRequestNotApproved",
+    message: "Spend request has not been approved",
+    category: "ESCALATION_REQUIRED",
+    retryable: true,
+    retry_after_ms: 60_000,
+    recovery_actions: [
+      {
+        action: "escalate_to_human",
+        description:
+          "Wait for the owner or approvers to approve the spend request",
+      },
+    ],
+  },
+  6121: {
+    name: "SpendRequestExpired",
+    message: "Spend request has expired",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "requeue",
+        description: "Cancel the expired request and create a new one",
+      },
+    ],
+  },
+  6122: {
+    name: "SpendRequestMismatch",
+    message: "Transfer does not match the approved spend request",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_transaction",
+        description:
+          "Transfer exactly the approved amount, mint and destination",
+      },
+    ],
+  },
+  6123: {
+    name: "UnauthorizedSpendRequestAction",
+    message:
+      "Only the requesting agent, owner or guardian can cancel a spend request",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "verify_owner",
+        description:
+          "Only the requesting agent, owner or guardian can cancel a spend request",
+      },
+    ],
+  },
+  // --- Governance bundle and approver errors ---
+  6124: {
+    name: "InvalidGovernanceBundle",
+    message:
+      "Governance bundle must reference at least two distinct pending updates",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Reference at least two distinct pending updates in the bundle",
+      },
+    ],
+  },
+  6125: {
+    name: "GovernanceBundleMismatch",
+    message:
+      "Pending update does not match the one recorded in the governance bundle",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "resolve_pending_policy",
+        description:
+          "Cancel the bundle and re-create it for the current pending updates",
+      },
+    ],
+  },
+  6126: {
+    name: "InvalidApproverConfig",
+    message:
+      "Approver set must be unique non-agent keys (max 5) with 1 <= threshold <= set size",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Use up to 5 unique non-agent approvers with 1 <= threshold <= approver count",
+      },
+    ],
+  },
+  6127: {
+    name: "InsufficientApprovals",
+    message: "Not enough approvers co-signed this instruction",
+    category: "ESCALATION_REQUIRED",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "escalate_to_human",
+        description:
+          "Collect co-signatures from enough approvers to meet the threshold",
+      },
+    ],
+  },
+  6128: {
+    name: "InvalidCpiCallers",
+    message:
+      "CPI caller allowlist must hold unique programs (max 4), excluding Sigil and allowed protocols",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_config",
+        description:
+          "Allow up to 4 unique caller programs, excluding Sigil and allowed protocols",
+      },
+    ],
+  },
+  6129: {
+    name: "CpiCallerNotAllowed",
+    message: "Top-level program is not on the vault's CPI caller allowlist",
+    category: "PERMISSION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "check_allowlist",
+        description:
+          "Call Sigil directly or from a program on the vault's CPI caller allowlist",
+        tool: "sigil_check_vault",
+      },
+    ],
+  },
+  // --- Multi-leg and concurrent session errors ---
+  6130: {
+    name: "InvalidSessionLegs",
+    message:
+      "Session legs must be 2-4 allowed protocols, starting with target_protocol, for a spending session",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_inputs",
+        description:
+          "Declare 2-4 allowed protocols, starting with target_protocol, or pass no legs",
+      },
+    ],
+  },
+  6131: {
+    name: "SessionLegMismatch",
+    message: "DeFi instructions do not match the declared session legs",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_transaction_shape",
+        description:
+          "Order the DeFi instructions to match the declared session legs",
+      },
+    ],
+  },
+  6132: {
+    name: "TooManyConcurrentSessions",
+    message: "Session nonce exceeds the agent's concurrent session limit",
+    category: "RATE_LIMIT",
+    retryable: true,
+    retry_after_ms: 10_000,
+    recovery_actions: [
+      {
+        action: "finalize_sessions",
+        description:
+          "Finalize open sessions or use a nonce below max_concurrent_sessions",
+      },
+    ],
+  },
+  6133: {
+    name: "InvalidConcurrentSessionLimit",
+    message: "max_concurrent_sessions exceeds MAX_CONCURRENT_SESSIONS",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_permissions",
+        description: "Lower max_concurrent_sessions to the program maximum",
+      },
+    ],
+  },
+  6134: {
+    name: "FeeCapExceeded",
+    message: "Abandoned-session fees would exceed the daily fee budget",
+    category: "SPENDING_CAP",
+    retryable: true,
+    retry_after_ms: 3_600_000,
+    recovery_actions: [
+      {
+        action: "finalize_sessions",
+        description: "Finalize sessions atomically so they are not abandoned",
+      },
+      {
+        action: "wait",
+        description: "Wait for the 24h rolling window to release fee budget",
+      },
+    ],
+  },
+  // --- Async fulfillment and advisory minimum-output errors ---
+  6135: {
+    name: "InvalidPendingFulfillment",
+    message:
+      "Pending fulfillment account missing, unexpected or mismatched for this session",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "use_sdk",
+        description:
+          "Pass the PendingFulfillment PDA exactly when the session invokes an async-fulfillment program",
+      },
+    ],
+  },
+  6136: {
+    name: "FulfillmentWindowOpen",
+    message:
+      "Fulfillment window has not elapsed; reservation cannot be reconciled yet",
+    category: "TRANSIENT",
+    retryable: true,
+    retry_after_ms: 60_000,
+    recovery_actions: [
+      {
+        action: "wait",
+        description:
+          "Wait for the fulfillment window to elapse before reconciling",
+      },
+    ],
+  },
+  6137: {
+    name: "OpenFulfillmentsExist",
+    message: "Vault has unreconciled async-fulfillment reservations",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "settle",
+        description:
+          "Reconcile open fulfillment reservations before closing the vault",
+      },
+    ],
+  },
+  6138: {
+    name: "ExpectedOutputRequired",
+    message: "Policy requires an expected output for non-Jupiter spending",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "fix_inputs",
+        description:
+          "Pass an expected output (mint, quote, minimum) for non-Jupiter spending",
+      },
+    ],
+  },
+  6139: {
+    name: "InvalidExpectedOutput",
+    message:
+      "Expected output mint, account or minimum is invalid or exceeds max_protocol_slippage_bps",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "reduce_slippage",
+        description:
+          "Set the minimum within max_protocol_slippage_bps of the quote and pass the matching output account",
+      },
+    ],
+  },
+  6140: {
+    name: "MinimumOutputNotMet",
+    message: "Session output is below the declared minimum",
+    category: "POLICY_VIOLATION",
+    retryable: true,
+    retry_after_ms: 3_000,
+    recovery_actions: [
+      {
+        action: "increase_slippage",
+        description:
+          "Re-quote and retry; the route returned less than the declared minimum",
+      },
+    ],
+  },
+  // --- Policy queue guard errors ---
+  6141: {
+    name: "PendingPolicySuperseded",
+    message:
+      "Policy was tightened after this update was queued; cancel and re-queue it",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "requeue",
+        description:
+          "Cancel the pending update and re-queue it against the tightened policy",
+      },
+    ],
+  },
+  6142: {
+    name: "SpendRequestChanged",
+    message: "Spend request contents differ from those the approver reviewed",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "requeue",
+        description:
+          "Cancel the spend request and create a new one for approval",
+      },
+    ],
+  },
+  6143: {
+    name: "GovernanceBundleOpen",
+    message:
+      "A governance bundle is open; apply or cancel it before applying its updates individually",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "resolve_pending_policy",
+        description: "Apply or cancel the open governance bundle first",
+      },
+    ],
+  },
+  6144: {
+    name: "CappedProtocolInMultiLegSession",
+    message:
+      "Multi-leg sessions cannot include a protocol with a per-protocol cap",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "simplify_route",
+        description:
+          "Route through uncapped protocols or split into single-protocol sessions",
+      },
+    ],
+  },
+  6145: {
+    name: "AgentSessionsOpen",
+    message: "Agent has open sessions; finalize them before rotating its key",
+    category: "INPUT_VALIDATION",
+    retryable: true,
+    retry_after_ms: 10_000,
+    recovery_actions: [
+      {
+        action: "finalize_sessions",
+        description:
+          "Finalize the agent's open sessions before rotating its key",
+      },
+    ],
+  },
+  6146: {
+    name: "QueuedBeforeOwnerTransfer",
+    message:
+      "Update was queued before the vault changed owner; cancel and re-queue it",
+    category: "POLICY_VIOLATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "requeue",
+        description: "Cancel the update and re-queue it as the new owner",
+      },
+    ],
+  },
+  6147: {
+    name: "NotLegacyVault",
+    message:
+      "Vault, policy or spend overlay is not in the pre-migration layout",
+    category: "INPUT_VALIDATION",
+    retryable: false,
+    recovery_actions: [
+      {
+        action: "skip_migration",
+        description:
+          "The vault already uses the current layout; no migration is needed",
+      },
+    ],
+  },
 };
 
 // ---------------------------------------------------------------------------
@@ -1883,7 +2756,7 @@ const SDK_ERRORS: Record<string, ErrorMapping> = {
  * Convert any error into a structured AgentError.
  *
  * Handles:
- * - On-chain Anchor errors (code 6000-6087)
+ * - On-chain Anchor errors (code 6000-6147)
  * - SDK errors (code 7000-7033)
  * - Network/RPC errors (from message patterns)
  * - Unknown errors (wrapped as FATAL)
@@ -2236,7 +3109,7 @@ function extractErrorCode(error: unknown): number | null {
   const e = error as Record<string, unknown>;
 
   // Direct code property
-  if (typeof e.code === "number" && e.code >= 6000 && e.code <= 6087)
+  if (typeof e.code === "number" && e.code >= 6000 && e.code <= 6147)
     return e.code;
 
   // Anchor error structure
@@ -2253,7 +3126,7 @@ function extractErrorCode(error: unknown): number | null {
     const match = e.message.match(/custom program error: 0x([0-9a-fA-F]+)/);
     if (match) {
       const code = parseInt(match[1], 16);
-      if (code >= 6000 && code <= 6087) return code;
+      if (code >= 6000 && code <= 6147) return code;
     }
   }
 
@@ -2494,7 +3367,7 @@ export class SigilSdkError extends Error implements AgentError {
  * Returns a SigilSdkError (extends Error) so instanceof Error checks still work.
  *
  * Processing order:
- * 1. Try on-chain error extraction via toAgentError() (numeric codes 6000-6087)
+ * 1. Try on-chain error extraction via toAgentError() (numeric codes 6000-6147)
  * 2. Pattern-match SDK error messages (11 patterns from seal.ts throw sites)
  * 3. Fallback to UNKNOWN/FATAL
  */
@@ -268,6 +268,8 @@ export async function createVault(
     agent: options.agent.address,
     capability: Number(options.permissions ?? FULL_PERMISSIONS),
     spendingLimitUsd: options.spendingLimitUsd,
+    maxSessionsPerHour: 0,
+    validUntil: null,
   });
 
   return {
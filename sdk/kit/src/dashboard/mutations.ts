@@ -37,6 +37,7 @@ import {
 import { AccountRole } from "../kit-adapter.js";
 import {
   getAgentOverlayPDA,
+  getDestinationSpendPDA,
   getPendingPolicyPDA,
   getPendingCloseConstraintsPDA,
   getPolicyPDA,
@@ -45,7 +46,10 @@ import { resolveVaultStateForOwner } from "../state-resolver.js";
 import { redactCause } from "../network-errors.js";
 import { SIGIL_PROGRAM_ADDRESS, MAX_ALLOWED_PROTOCOLS } from "../types.js";
 import type { Network } from "../types.js";
-import type { AgentVault } from "../generated/accounts/agentVault.js";
+import {
+  fetchAgentVault,
+  type AgentVault,
+} from "../generated/accounts/agentVault.js";
 
 // Phase 3: Simple mutations
 import { getFreezeVaultInstruction } from "../generated/instructions/freezeVault.js";
@@ -231,7 +235,7 @@ export async function freezeVault(
   network: "devnet" | "mainnet",
   opts?: TxOpts,
 ): Promise<TxResult> {
-  const ix = getFreezeVaultInstruction({ owner, vault });
+  const ix = getFreezeVaultInstruction({ authority: owner, vault });
   return run(rpc, owner, network, [ix], opts);
 }
 
@@ -274,10 +278,23 @@ export async function closeVault(
   const vaultData = state.vault as AgentVault;
 
   const [overlayPda] = await getAgentOverlayPDA(vault, 0);
+  // Vaults created before the tracker extension and destination tracker
+  // existed have neither; pass the program address (Anchor's None) for
+  // whichever is missing.
+  const [destinationSpendPda] = await getDestinationSpendPDA(vault);
+  const destinationSpendInfo = await rpc
+    .getAccountInfo(destinationSpendPda, { encoding: "base64" })
+    .send();
   const ix = await getCloseVaultInstructionAsync({
     owner,
     vault,
     agentSpendOverlay: overlayPda,
+    trackerExtension: vaultData.hasTrackerExtension
+      ? undefined
+      : SIGIL_PROGRAM_ADDRESS,
+    destinationSpend: destinationSpendInfo.value
+      ? destinationSpendPda
+      : SIGIL_PROGRAM_ADDRESS,
   });
 
   // Build remaining_accounts for pending PDA cleanup (close_vault.rs:68-142)
@@ -382,7 +399,11 @@ export async function pauseAgent(
   opts?: TxOpts,
 ): Promise<TxResult> {
   requireValidAddress(agent, "Agent address");
-  const ix = getPauseAgentInstruction({ owner, vault, agentToPause: agent });
+  const ix = getPauseAgentInstruction({
+    authority: owner,
+    vault,
+    agentToPause: agent,
+  });
   return run(rpc, owner, network, [ix], opts);
 }
 
@@ -442,6 +463,8 @@ export async function addAgent(
     agent,
     capability: Number(permissions),
     spendingLimitUsd: spendingLimit,
+    maxSessionsPerHour: 0,
+    validUntil: null,
   });
   return run(rpc, owner, network, [ix], opts);
 }
@@ -564,6 +587,24 @@ export async function queuePolicyUpdate(
     hasProtocolCaps: changes.hasProtocolCaps ?? null,
     protocolCaps: changes.protocolCaps ?? null,
     destinationMode: changes.destinationMode ?? null,
+    weeklySpendingCapUsd: null,
+    monthlySpendingCapUsd: null,
+    mintCaps: null,
+    destinationCaps: null,
+    tradingHours: null,
+    tradingHoursExemptNonSpending: null,
+    guardian: null,
+    recoveryAuthority: null,
+    recoveryDestination: null,
+    inactivityWindowSeconds: null,
+    beneficiary: null,
+    approvers: null,
+    approvalThreshold: null,
+    cpiCallers: null,
+    dailyFeeCapUsd: null,
+    maxAbandonedSessionsPerHour: null,
+    allowAsyncFulfillment: null,
+    maxProtocolSlippageBps: null,
   });
   return run(rpc, owner, network, [ix], opts);
 }
@@ -602,12 +643,22 @@ export async function queueAgentPermissions(
 ): Promise<TxResult> {
   requireValidAddress(agent, "Agent address");
   requireValidPermissions(permissions);
+  // max_sessions_per_hour is not optional in the update; carry the current
+  // value over so changing permissions does not lift an hourly limit.
+  const { data: vaultData } = await fetchAgentVault(rpc, vault);
+  const entry = vaultData.agents.find((a) => a.pubkey === agent);
   const ix = await getQueueAgentPermissionsUpdateInstructionAsync({
     owner,
     vault,
     agent,
     newCapability: Number(permissions),
     spendingLimitUsd: spendingLimit,
+    maxSessionsPerHour: entry?.maxSessionsPerHour ?? 0,
+    protocols: null,
+    maxTransactionSizeUsd: null,
+    sessionExpirySeconds: null,
+    validUntil: null,
+    maxConcurrentSessions: null,
   });
   return run(rpc, owner, network, [ix], opts);
 }
@@ -43,8 +43,12 @@ import {
 import {
   getAgentContributionEntryDecoder,
   getAgentContributionEntryEncoder,
+  getAgentSessionCounterDecoder,
+  getAgentSessionCounterEncoder,
   type AgentContributionEntry,
   type AgentContributionEntryArgs,
+  type AgentSessionCounter,
+  type AgentSessionCounterArgs,
 } from "../types/index.js";
 
 export const AGENT_SPEND_OVERLAY_DISCRIMINATOR = new Uint8Array([
@@ -84,6 +88,18 @@ export type AgentSpendOverlay = {
    * Used for: avg TX size (lifetime_spend / lifetime_tx_count), agent activity ranking.
    */
   lifetimeTxCount: Array<bigint>;
+  /**
+   * Per-agent session counters for `max_sessions_per_hour`. Index matches entries[i].
+   * Incremented in validate_and_authorize and agent_transfer.
+   * Appended AFTER existing layout to preserve zero-copy byte offsets.
+   */
+  sessionCounters: Array<AgentSessionCounter>;
+  /**
+   * Per-agent abandoned-session strikes for `max_abandoned_sessions_per_hour`.
+   * Index matches entries[i]. Incremented in finalize_session.
+   * Appended AFTER existing layout to preserve zero-copy byte offsets.
+   */
+  abandonCounters: Array<AgentSessionCounter>;
 };
 
 export type AgentSpendOverlayArgs = {
@@ -112,6 +128,18 @@ export type AgentSpendOverlayArgs = {
    * Used for: avg TX size (lifetime_spend / lifetime_tx_count), agent activity ranking.
    */
   lifetimeTxCount: Array<number | bigint>;
+  /**
+   * Per-agent session counters for `max_sessions_per_hour`. Index matches entries[i].
+   * Incremented in validate_and_authorize and agent_transfer.
+   * Appended AFTER existing layout to preserve zero-copy byte offsets.
+   */
+  sessionCounters: Array<AgentSessionCounterArgs>;
+  /**
+   * Per-agent abandoned-session strikes for `max_abandoned_sessions_per_hour`.
+   * Index matches entries[i]. Incremented in finalize_session.
+   * Appended AFTER existing layout to preserve zero-copy byte offsets.
+   */
+  abandonCounters: Array<AgentSessionCounterArgs>;
 };
 
 /** Gets the encoder for {@link AgentSpendOverlayArgs} account data. */
@@ -128,6 +156,14 @@ export function getAgentSpendOverlayEncoder(): FixedSizeEncoder<AgentSpendOverla
       ["padding", fixEncoderSize(getBytesEncoder(), 7)],
       ["lifetimeSpend", getArrayEncoder(getU64Encoder(), { size: 10 })],
       ["lifetimeTxCount", getArrayEncoder(getU64Encoder(), { size: 10 })],
+      [
+        "sessionCounters",
+        getArrayEncoder(getAgentSessionCounterEncoder(), { size: 10 }),
+      ],
+      [
+        "abandonCounters",
+        getArrayEncoder(getAgentSessionCounterEncoder(), { size: 10 }),
+      ],
     ]),
     (value) => ({ ...value, discriminator: AGENT_SPEND_OVERLAY_DISCRIMINATOR }),
   );
@@ -146,6 +182,14 @@ export function getAgentSpendOverlayDecoder(): FixedSizeDecoder<AgentSpendOverla
     ["padding", fixDecoderSize(getBytesDecoder(), 7)],
     ["lifetimeSpend", getArrayDecoder(getU64Decoder(), { size: 10 })],
     ["lifetimeTxCount", getArrayDecoder(getU64Decoder(), { size: 10 })],
+    [
+      "sessionCounters",
+      getArrayDecoder(getAgentSessionCounterDecoder(), { size: 10 }),
+    ],
+    [
+      "abandonCounters",
+      getArrayDecoder(getAgentSessionCounterDecoder(), { size: 10 }),
+    ],
   ]);
 }
 
@@ -224,5 +268,5 @@ export async function fetchAllMaybeAgentSpendOverlay(
 }
 
 export function getAgentSpendOverlaySize(): number {
-  return 2528;
+  return 2848;
 }
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -70,8 +72,10 @@ export type AgentVault = {
   /** Registered agents with per-agent permission bitmasks (max 10) */
   agents: Array<AgentEntry>;
   /**
-   * Developer fee destination — IMMUTABLE after initialization.
-   * Prevents a compromised owner from redirecting fees.
+   * Developer fee destination. Only changes through the timelocked
+   * queue/apply_fee_destination_update flow, which emits events the
+   * current recipient can watch — a compromised owner cannot redirect
+   * fees silently.
    */
   feeDestination: Address;
   /** Vault status: Active, Frozen, or Closed */
@@ -108,11 +112,95 @@ export type AgentVault = {
    */
   totalFailedTransactions: bigint;
   /**
-   * Number of active (not yet finalized) sessions for this vault.
+   * Number of active (not yet finalized) sessions for this vault, summed
+   * over agents (see `AgentEntry::active_sessions`). Kept vault-wide so a
+   * revoked agent's open sessions still block close_vault.
    * Incremented in validate_and_authorize, decremented in finalize_session.
    * close_vault requires this to be 0.
    */
   activeSessions: number;
+  /**
+   * Optional guardian key (Pubkey::default() = none). Set via the
+   * timelocked queue_policy_update path. May call freeze_vault,
+   * pause_agent and tighten_policy, and approve agents' spend requests —
+   * never anything that moves funds itself or loosens the policy.
+   */
+  guardian: Address;
+  /**
+   * Key the vault PDA was derived from (`[b"vault", creator, vault_id]`).
+   * Set once to the initial owner and never changed, so `owner` can be
+   * transferred without moving the vault or any vault-scoped PDA.
+   */
+  creator: Address;
+  /**
+   * Optional recovery key (Pubkey::default() = none). Set together with
+   * `recovery_destination` via the timelocked queue_policy_update path.
+   * Can only queue a sweep to `recovery_destination`, which waits out
+   * `RECOVERY_TIMELOCK_SECONDS` and can be cancelled by the owner.
+   */
+  recoveryAuthority: Address;
+  /** Wallet that receives a recovery sweep. */
+  recoveryDestination: Address;
+  /**
+   * Unix timestamp of the last owner-signed instruction. Every owner
+   * handler refreshes it via `record_owner_activity`.
+   */
+  lastOwnerActivity: bigint;
+  /**
+   * Dead-man switch window in seconds (0 = disabled). Once
+   * `last_owner_activity` is older than this, anyone may call
+   * trigger_dead_man_switch. Set with `beneficiary` via queue_policy_update.
+   */
+  inactivityWindowSeconds: bigint;
+  /** Wallet that may claim vault funds after the dead-man switch fires. */
+  beneficiary: Address;
+  /**
+   * Set by trigger_dead_man_switch. Terminal: the vault stays frozen and
+   * funds leave only through claim_beneficiary_funds.
+   */
+  deadManTriggered: boolean;
+  /**
+   * Optional native approver set (empty = none). Set with
+   * `approval_threshold` via queue_policy_update. Bounded to MAX_APPROVERS.
+   */
+  approvers: Array<Address>;
+  /**
+   * Number of `approvers` that must co-sign the owner's governance
+   * actions: the queue_* updates (policy, constraints, close constraints,
+   * agent permissions, owner transfer, fee destination), register_agent,
+   * rotate_agent_key, reactivate_vault with a new agent and
+   * withdraw_funds (0 = disabled). The owner counts if listed.
+   */
+  approvalThreshold: number;
+  /**
+   * Programs whose CPIs are accepted for owner instructions when they are
+   * the transaction's top-level program (empty = top-level calls only).
+   * Set via queue_policy_update. Bounded to MAX_CPI_CALLERS.
+   */
+  cpiCallers: Array<Address>;
+  /** Open `PendingFulfillment` reservations. close_vault requires 0. */
+  openFulfillments: number;
+  /**
+   * The `[b"tracker_ext", vault]` SpendTrackerExtension exists. Once set,
+   * every spend path must pass it so its windows never miss a spend.
+   */
+  hasTrackerExtension: boolean;
+  /**
+   * A `GovernanceBundle` exists. Individual applies are rejected until it
+   * is applied or cancelled, so a bundle cannot land half-way.
+   */
+  openBundle: boolean;
+  /**
+   * The `[b"destination_spend", vault]` DestinationSpendTracker exists.
+   * Once set, agent_transfer must pass it on every transfer, capped or
+   * not, so a destination cap added later starts from the full history.
+   */
+  hasDestinationTracker: boolean;
+  /**
+   * Slot of the last apply_owner_transfer (0 = never). Updates queued at
+   * or before it were queued by a previous owner and are rejected at apply.
+   */
+  ownerChangedAtSlot: bigint;
 };
 
 export type AgentVaultArgs = {
@@ -123,8 +211,10 @@ export type AgentVaultArgs = {
   /** Registered agents with per-agent permission bitmasks (max 10) */
   agents: Array<AgentEntryArgs>;
   /**
-   * Developer fee destination — IMMUTABLE after initialization.
-   * Prevents a compromised owner from redirecting fees.
+   * Developer fee destination. Only changes through the timelocked
+   * queue/apply_fee_destination_update flow, which emits events the
+   * current recipient can watch — a compromised owner cannot redirect
+   * fees silently.
    */
   feeDestination: Address;
   /** Vault status: Active, Frozen, or Closed */
@@ -161,11 +251,95 @@ export type AgentVaultArgs = {
    */
   totalFailedTransactions: number | bigint;
   /**
-   * Number of active (not yet finalized) sessions for this vault.
+   * Number of active (not yet finalized) sessions for this vault, summed
+   * over agents (see `AgentEntry::active_sessions`). Kept vault-wide so a
+   * revoked agent's open sessions still block close_vault.
    * Incremented in validate_and_authorize, decremented in finalize_session.
    * close_vault requires this to be 0.
    */
   activeSessions: number;
+  /**
+   * Optional guardian key (Pubkey::default() = none). Set via the
+   * timelocked queue_policy_update path. May call freeze_vault,
+   * pause_agent and tighten_policy, and approve agents' spend requests —
+   * never anything that moves funds itself or loosens the policy.
+   */
+  guardian: Address;
+  /**
+   * Key the vault PDA was derived from (`[b"vault", creator, vault_id]`).
+   * Set once to the initial owner and never changed, so `owner` can be
+   * transferred without moving the vault or any vault-scoped PDA.
+   */
+  creator: Address;
+  /**
+   * Optional recovery key (Pubkey::default() = none). Set together with
+   * `recovery_destination` via the timelocked queue_policy_update path.
+   * Can only queue a sweep to `recovery_destination`, which waits out
+   * `RECOVERY_TIMELOCK_SECONDS` and can be cancelled by the owner.
+   */
+  recoveryAuthority: Address;
+  /** Wallet that receives a recovery sweep. */
+  recoveryDestination: Address;
+  /**
+   * Unix timestamp of the last owner-signed instruction. Every owner
+   * handler refreshes it via `record_owner_activity`.
+   */
+  lastOwnerActivity: number | bigint;
+  /**
+   * Dead-man switch window in seconds (0 = disabled). Once
+   * `last_owner_activity` is older than this, anyone may call
+   * trigger_dead_man_switch. Set with `beneficiary` via queue_policy_update.
+   */
+  inactivityWindowSeconds: number | bigint;
+  /** Wallet that may claim vault funds after the dead-man switch fires. */
+  beneficiary: Address;
+  /**
+   * Set by trigger_dead_man_switch. Terminal: the vault stays frozen and
+   * funds leave only through claim_beneficiary_funds.
+   */
+  deadManTriggered: boolean;
+  /**
+   * Optional native approver set (empty = none). Set with
+   * `approval_threshold` via queue_policy_update. Bounded to MAX_APPROVERS.
+   */
+  approvers: Array<Address>;
+  /**
+   * Number of `approvers` that must co-sign the owner's governance
+   * actions: the queue_* updates (policy, constraints, close constraints,
+   * agent permissions, owner transfer, fee destination), register_agent,
+   * rotate_agent_key, reactivate_vault with a new agent and
+   * withdraw_funds (0 = disabled). The owner counts if listed.
+   */
+  approvalThreshold: number;
+  /**
+   * Programs whose CPIs are accepted for owner instructions when they are
+   * the transaction's top-level program (empty = top-level calls only).
+   * Set via queue_policy_update. Bounded to MAX_CPI_CALLERS.
+   */
+  cpiCallers: Array<Address>;
+  /** Open `PendingFulfillment` reservations. close_vault requires 0. */
+  openFulfillments: number;
+  /**
+   * The `[b"tracker_ext", vault]` SpendTrackerExtension exists. Once set,
+   * every spend path must pass it so its windows never miss a spend.
+   */
+  hasTrackerExtension: boolean;
+  /**
+   * A `GovernanceBundle` exists. Individual applies are rejected until it
+   * is applied or cancelled, so a bundle cannot land half-way.
+   */
+  openBundle: boolean;
+  /**
+   * The `[b"destination_spend", vault]` DestinationSpendTracker exists.
+   * Once set, agent_transfer must pass it on every transfer, capped or
+   * not, so a destination cap added later starts from the full history.
+   */
+  hasDestinationTracker: boolean;
+  /**
+   * Slot of the last apply_owner_transfer (0 = never). Updates queued at
+   * or before it were queued by a previous owner and are rejected at apply.
+   */
+  ownerChangedAtSlot: number | bigint;
 };
 
 /** Gets the encoder for {@link AgentVaultArgs} account data. */
@@ -188,6 +362,22 @@ export function getAgentVaultEncoder(): Encoder<AgentVaultArgs> {
       ["totalWithdrawnUsd", getU64Encoder()],
       ["totalFailedTransactions", getU64Encoder()],
       ["activeSessions", getU8Encoder()],
+      ["guardian", getAddressEncoder()],
+      ["creator", getAddressEncoder()],
+      ["recoveryAuthority", getAddressEncoder()],
+      ["recoveryDestination", getAddressEncoder()],
+      ["lastOwnerActivity", getI64Encoder()],
+      ["inactivityWindowSeconds", getU64Encoder()],
+      ["beneficiary", getAddressEncoder()],
+      ["deadManTriggered", getBooleanEncoder()],
+      ["approvers", getArrayEncoder(getAddressEncoder())],
+      ["approvalThreshold", getU8Encoder()],
+      ["cpiCallers", getArrayEncoder(getAddressEncoder())],
+      ["openFulfillments", getU8Encoder()],
+      ["hasTrackerExtension", getBooleanEncoder()],
+      ["openBundle", getBooleanEncoder()],
+      ["hasDestinationTracker", getBooleanEncoder()],
+      ["ownerChangedAtSlot", getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: AGENT_VAULT_DISCRIMINATOR }),
   );
@@ -212,6 +402,22 @@ export function getAgentVaultDecoder(): Decoder<AgentVault> {
     ["totalWithdrawnUsd", getU64Decoder()],
     ["totalFailedTransactions", getU64Decoder()],
     ["activeSessions", getU8Decoder()],
+    ["guardian", getAddressDecoder()],
+    ["creator", getAddressDecoder()],
+    ["recoveryAuthority", getAddressDecoder()],
+    ["recoveryDestination", getAddressDecoder()],
+    ["lastOwnerActivity", getI64Decoder()],
+    ["inactivityWindowSeconds", getU64Decoder()],
+    ["beneficiary", getAddressDecoder()],
+    ["deadManTriggered", getBooleanDecoder()],
+    ["approvers", getArrayDecoder(getAddressDecoder())],
+    ["approvalThreshold", getU8Decoder()],
+    ["cpiCallers", getArrayDecoder(getAddressDecoder())],
+    ["openFulfillments", getU8Decoder()],
+    ["hasTrackerExtension", getBooleanDecoder()],
+    ["openBundle", getBooleanDecoder()],
+    ["hasDestinationTracker", getBooleanDecoder()],
+    ["ownerChangedAtSlot", getU64Decoder()],
   ]);
 }
 
@@ -6,15 +6,25 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./agentSettings.js";
 export * from "./agentSpendOverlay.js";
 export * from "./agentVault.js";
+export * from "./destinationSpendTracker.js";
 export * from "./escrowDeposit.js";
+export * from "./governanceBundle.js";
 export * from "./instructionConstraints.js";
 export * from "./pendingAgentPermissionsUpdate.js";
 export * from "./pendingCloseConstraints.js";
 export * from "./pendingConstraintsUpdate.js";
+export * from "./pendingFeeDestinationUpdate.js";
+export * from "./pendingFulfillment.js";
+export * from "./pendingOwnerTransfer.js";
 export * from "./pendingPolicyUpdate.js";
+export * from "./pendingRecovery.js";
+export * from "./permitNonces.js";
 export * from "./policyConfig.js";
 export * from "./postExecutionAssertions.js";
 export * from "./sessionAuthority.js";
+export * from "./spendRequest.js";
 export * from "./spendTracker.js";
+export * from "./spendTrackerExtension.js";
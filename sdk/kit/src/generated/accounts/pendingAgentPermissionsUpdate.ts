@@ -17,12 +17,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -30,14 +36,16 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
@@ -56,6 +64,8 @@ export type PendingAgentPermissionsUpdate = {
   vault: Address;
   agent: Address;
   newCapability: number;
+  /** Replacement `AgentEntry::max_sessions_per_hour` (0 = unlimited). */
+  maxSessionsPerHour: number;
   reservedCap: ReadonlyUint8Array;
   spendingLimitUsd: bigint;
   queuedAt: bigint;
@@ -67,12 +77,29 @@ export type PendingAgentPermissionsUpdate = {
    */
   queuedAtSlot: bigint;
   bump: number;
+  /**
+   * Replacement per-agent protocol subset (AgentSettings.protocols).
+   * None = leave unchanged, Some(vec![]) = inherit the vault policy.
+   */
+  protocols: Option<Array<Address>>;
+  /**
+   * Replacement AgentSettings overrides. None = leave unchanged,
+   * Some(0) = inherit the vault value.
+   */
+  maxTransactionSizeUsd: Option<bigint>;
+  sessionExpirySeconds: Option<bigint>;
+  /** Replacement key expiry. None = leave unchanged, Some(0) = never expires. */
+  validUntil: Option<bigint>;
+  /** Replacement `AgentEntry::max_concurrent_sessions`. None = leave unchanged. */
+  maxConcurrentSessions: Option<number>;
 };
 
 export type PendingAgentPermissionsUpdateArgs = {
   vault: Address;
   agent: Address;
   newCapability: number;
+  /** Replacement `AgentEntry::max_sessions_per_hour` (0 = unlimited). */
+  maxSessionsPerHour: number;
   reservedCap: ReadonlyUint8Array;
   spendingLimitUsd: number | bigint;
   queuedAt: number | bigint;
@@ -84,22 +111,43 @@ export type PendingAgentPermissionsUpdateArgs = {
    */
   queuedAtSlot: number | bigint;
   bump: number;
+  /**
+   * Replacement per-agent protocol subset (AgentSettings.protocols).
+   * None = leave unchanged, Some(vec![]) = inherit the vault policy.
+   */
+  protocols: OptionOrNullable<Array<Address>>;
+  /**
+   * Replacement AgentSettings overrides. None = leave unchanged,
+   * Some(0) = inherit the vault value.
+   */
+  maxTransactionSizeUsd: OptionOrNullable<number | bigint>;
+  sessionExpirySeconds: OptionOrNullable<number | bigint>;
+  /** Replacement key expiry. None = leave unchanged, Some(0) = never expires. */
+  validUntil: OptionOrNullable<number | bigint>;
+  /** Replacement `AgentEntry::max_concurrent_sessions`. None = leave unchanged. */
+  maxConcurrentSessions: OptionOrNullable<number>;
 };
 
 /** Gets the encoder for {@link PendingAgentPermissionsUpdateArgs} account data. */
-export function getPendingAgentPermissionsUpdateEncoder(): FixedSizeEncoder<PendingAgentPermissionsUpdateArgs> {
+export function getPendingAgentPermissionsUpdateEncoder(): Encoder<PendingAgentPermissionsUpdateArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["vault", getAddressEncoder()],
       ["agent", getAddressEncoder()],
       ["newCapability", getU8Encoder()],
-      ["reservedCap", fixEncoderSize(getBytesEncoder(), 7)],
+      ["maxSessionsPerHour", getU16Encoder()],
+      ["reservedCap", fixEncoderSize(getBytesEncoder(), 5)],
       ["spendingLimitUsd", getU64Encoder()],
       ["queuedAt", getI64Encoder()],
       ["executesAt", getI64Encoder()],
       ["queuedAtSlot", getU64Encoder()],
       ["bump", getU8Encoder()],
+      ["protocols", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["maxTransactionSizeUsd", getOptionEncoder(getU64Encoder())],
+      ["sessionExpirySeconds", getOptionEncoder(getU64Encoder())],
+      ["validUntil", getOptionEncoder(getI64Encoder())],
+      ["maxConcurrentSessions", getOptionEncoder(getU8Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -109,23 +157,29 @@ export function getPendingAgentPermissionsUpdateEncoder(): FixedSizeEncoder<Pend
 }
 
 /** Gets the decoder for {@link PendingAgentPermissionsUpdate} account data. */
-export function getPendingAgentPermissionsUpdateDecoder(): FixedSizeDecoder<PendingAgentPermissionsUpdate> {
+export function getPendingAgentPermissionsUpdateDecoder(): Decoder<PendingAgentPermissionsUpdate> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["vault", getAddressDecoder()],
     ["agent", getAddressDecoder()],
     ["newCapability", getU8Decoder()],
-    ["reservedCap", fixDecoderSize(getBytesDecoder(), 7)],
+    ["maxSessionsPerHour", getU16Decoder()],
+    ["reservedCap", fixDecoderSize(getBytesDecoder(), 5)],
     ["spendingLimitUsd", getU64Decoder()],
     ["queuedAt", getI64Decoder()],
     ["executesAt", getI64Decoder()],
     ["queuedAtSlot", getU64Decoder()],
     ["bump", getU8Decoder()],
+    ["protocols", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["maxTransactionSizeUsd", getOptionDecoder(getU64Decoder())],
+    ["sessionExpirySeconds", getOptionDecoder(getU64Decoder())],
+    ["validUntil", getOptionDecoder(getI64Decoder())],
+    ["maxConcurrentSessions", getOptionDecoder(getU8Decoder())],
   ]);
 }
 
 /** Gets the codec for {@link PendingAgentPermissionsUpdate} account data. */
-export function getPendingAgentPermissionsUpdateCodec(): FixedSizeCodec<
+export function getPendingAgentPermissionsUpdateCodec(): Codec<
   PendingAgentPermissionsUpdateArgs,
   PendingAgentPermissionsUpdate
 > {
@@ -209,7 +263,3 @@ export async function fetchAllMaybePendingAgentPermissionsUpdate(
     decodePendingAgentPermissionsUpdate(maybeAccount),
   );
 }
-
-export function getPendingAgentPermissionsUpdateSize(): number {
-  return 113;
-}
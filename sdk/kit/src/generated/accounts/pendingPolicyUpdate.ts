@@ -50,6 +50,12 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
+import {
+  getMintSpendCapDecoder,
+  getMintSpendCapEncoder,
+  type MintSpendCap,
+  type MintSpendCapArgs,
+} from "../types/index.js";
 
 export const PENDING_POLICY_UPDATE_DISCRIMINATOR = new Uint8Array([
   77, 255, 2, 51, 79, 237, 183, 239,
@@ -91,6 +97,58 @@ export type PendingPolicyUpdate = {
    * Some(0) = Restricted, Some(1) = OpenWithCap, None = leave unchanged.
    */
   destinationMode: Option<number>;
+  /** Weekly / monthly cap updates. Some(0) disables that horizon. */
+  weeklySpendingCapUsd: Option<bigint>;
+  monthlySpendingCapUsd: Option<bigint>;
+  /** Per-mint cap list replacement. Some(vec![]) removes all per-mint caps. */
+  mintCaps: Option<Array<MintSpendCap>>;
+  /**
+   * Per-destination cap replacement, index-aligned with the resulting
+   * `allowed_destinations`. None = existing caps follow their destination.
+   */
+  destinationCaps: Option<Array<bigint>>;
+  /** Trading-hours schedule replacement. Some([0; 21]) removes the schedule. */
+  tradingHours: Option<ReadonlyUint8Array>;
+  tradingHoursExemptNonSpending: Option<boolean>;
+  /**
+   * Guardian replacement (lives on AgentVault). Some(Pubkey::default())
+   * removes the guardian.
+   */
+  guardian: Option<Address>;
+  /**
+   * Recovery key + destination replacement (lives on AgentVault).
+   * Always queued together; Some(Pubkey::default()) for both removes recovery.
+   */
+  recoveryAuthority: Option<Address>;
+  recoveryDestination: Option<Address>;
+  /**
+   * Dead-man switch window + beneficiary replacement (lives on AgentVault).
+   * Always queued together; Some(0) / Some(Pubkey::default()) disables it.
+   */
+  inactivityWindowSeconds: Option<bigint>;
+  beneficiary: Option<Address>;
+  /**
+   * Approver set + threshold replacement (lives on AgentVault).
+   * Always queued together; Some(vec![]) / Some(0) removes the set.
+   */
+  approvers: Option<Array<Address>>;
+  approvalThreshold: Option<number>;
+  /**
+   * CPI caller allowlist replacement (lives on AgentVault).
+   * Some(vec![]) restricts owner instructions to top-level calls again.
+   */
+  cpiCallers: Option<Array<Address>>;
+  /**
+   * Fee budget replacement. Some(0) charges abandoned-session fees to the
+   * daily spending cap again.
+   */
+  dailyFeeCapUsd: Option<bigint>;
+  /** Abandoned-session strike limit replacement. Some(0) disables it. */
+  maxAbandonedSessionsPerHour: Option<number>;
+  /** Async-fulfillment opt-in replacement. */
+  allowAsyncFulfillment: Option<boolean>;
+  /** Advisory minimum-output ceiling replacement. Some(0) disables it. */
+  maxProtocolSlippageBps: Option<number>;
   /** Bump seed for PDA */
   bump: number;
 };
@@ -124,6 +182,58 @@ export type PendingPolicyUpdateArgs = {
    * Some(0) = Restricted, Some(1) = OpenWithCap, None = leave unchanged.
    */
   destinationMode: OptionOrNullable<number>;
+  /** Weekly / monthly cap updates. Some(0) disables that horizon. */
+  weeklySpendingCapUsd: OptionOrNullable<number | bigint>;
+  monthlySpendingCapUsd: OptionOrNullable<number | bigint>;
+  /** Per-mint cap list replacement. Some(vec![]) removes all per-mint caps. */
+  mintCaps: OptionOrNullable<Array<MintSpendCapArgs>>;
+  /**
+   * Per-destination cap replacement, index-aligned with the resulting
+   * `allowed_destinations`. None = existing caps follow their destination.
+   */
+  destinationCaps: OptionOrNullable<Array<number | bigint>>;
+  /** Trading-hours schedule replacement. Some([0; 21]) removes the schedule. */
+  tradingHours: OptionOrNullable<ReadonlyUint8Array>;
+  tradingHoursExemptNonSpending: OptionOrNullable<boolean>;
+  /**
+   * Guardian replacement (lives on AgentVault). Some(Pubkey::default())
+   * removes the guardian.
+   */
+  guardian: OptionOrNullable<Address>;
+  /**
+   * Recovery key + destination replacement (lives on AgentVault).
+   * Always queued together; Some(Pubkey::default()) for both removes recovery.
+   */
+  recoveryAuthority: OptionOrNullable<Address>;
+  recoveryDestination: OptionOrNullable<Address>;
+  /**
+   * Dead-man switch window + beneficiary replacement (lives on AgentVault).
+   * Always queued together; Some(0) / Some(Pubkey::default()) disables it.
+   */
+  inactivityWindowSeconds: OptionOrNullable<number | bigint>;
+  beneficiary: OptionOrNullable<Address>;
+  /**
+   * Approver set + threshold replacement (lives on AgentVault).
+   * Always queued together; Some(vec![]) / Some(0) removes the set.
+   */
+  approvers: OptionOrNullable<Array<Address>>;
+  approvalThreshold: OptionOrNullable<number>;
+  /**
+   * CPI caller allowlist replacement (lives on AgentVault).
+   * Some(vec![]) restricts owner instructions to top-level calls again.
+   */
+  cpiCallers: OptionOrNullable<Array<Address>>;
+  /**
+   * Fee budget replacement. Some(0) charges abandoned-session fees to the
+   * daily spending cap again.
+   */
+  dailyFeeCapUsd: OptionOrNullable<number | bigint>;
+  /** Abandoned-session strike limit replacement. Some(0) disables it. */
+  maxAbandonedSessionsPerHour: OptionOrNullable<number>;
+  /** Async-fulfillment opt-in replacement. */
+  allowAsyncFulfillment: OptionOrNullable<boolean>;
+  /** Advisory minimum-output ceiling replacement. Some(0) disables it. */
+  maxProtocolSlippageBps: OptionOrNullable<number>;
   /** Bump seed for PDA */
   bump: number;
 };
@@ -152,6 +262,24 @@ export function getPendingPolicyUpdateEncoder(): Encoder<PendingPolicyUpdateArgs
       ["hasProtocolCaps", getOptionEncoder(getBooleanEncoder())],
       ["protocolCaps", getOptionEncoder(getArrayEncoder(getU64Encoder()))],
       ["destinationMode", getOptionEncoder(getU8Encoder())],
+      ["weeklySpendingCapUsd", getOptionEncoder(getU64Encoder())],
+      ["monthlySpendingCapUsd", getOptionEncoder(getU64Encoder())],
+      ["mintCaps", getOptionEncoder(getArrayEncoder(getMintSpendCapEncoder()))],
+      ["destinationCaps", getOptionEncoder(getArrayEncoder(getU64Encoder()))],
+      ["tradingHours", getOptionEncoder(fixEncoderSize(getBytesEncoder(), 21))],
+      ["tradingHoursExemptNonSpending", getOptionEncoder(getBooleanEncoder())],
+      ["guardian", getOptionEncoder(getAddressEncoder())],
+      ["recoveryAuthority", getOptionEncoder(getAddressEncoder())],
+      ["recoveryDestination", getOptionEncoder(getAddressEncoder())],
+      ["inactivityWindowSeconds", getOptionEncoder(getU64Encoder())],
+      ["beneficiary", getOptionEncoder(getAddressEncoder())],
+      ["approvers", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["approvalThreshold", getOptionEncoder(getU8Encoder())],
+      ["cpiCallers", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["dailyFeeCapUsd", getOptionEncoder(getU64Encoder())],
+      ["maxAbandonedSessionsPerHour", getOptionEncoder(getU16Encoder())],
+      ["allowAsyncFulfillment", getOptionEncoder(getBooleanEncoder())],
+      ["maxProtocolSlippageBps", getOptionEncoder(getU16Encoder())],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({
@@ -184,6 +312,24 @@ export function getPendingPolicyUpdateDecoder(): Decoder<PendingPolicyUpdate> {
     ["hasProtocolCaps", getOptionDecoder(getBooleanDecoder())],
     ["protocolCaps", getOptionDecoder(getArrayDecoder(getU64Decoder()))],
     ["destinationMode", getOptionDecoder(getU8Decoder())],
+    ["weeklySpendingCapUsd", getOptionDecoder(getU64Decoder())],
+    ["monthlySpendingCapUsd", getOptionDecoder(getU64Decoder())],
+    ["mintCaps", getOptionDecoder(getArrayDecoder(getMintSpendCapDecoder()))],
+    ["destinationCaps", getOptionDecoder(getArrayDecoder(getU64Decoder()))],
+    ["tradingHours", getOptionDecoder(fixDecoderSize(getBytesDecoder(), 21))],
+    ["tradingHoursExemptNonSpending", getOptionDecoder(getBooleanDecoder())],
+    ["guardian", getOptionDecoder(getAddressDecoder())],
+    ["recoveryAuthority", getOptionDecoder(getAddressDecoder())],
+    ["recoveryDestination", getOptionDecoder(getAddressDecoder())],
+    ["inactivityWindowSeconds", getOptionDecoder(getU64Decoder())],
+    ["beneficiary", getOptionDecoder(getAddressDecoder())],
+    ["approvers", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["approvalThreshold", getOptionDecoder(getU8Decoder())],
+    ["cpiCallers", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["dailyFeeCapUsd", getOptionDecoder(getU64Decoder())],
+    ["maxAbandonedSessionsPerHour", getOptionDecoder(getU16Decoder())],
+    ["allowAsyncFulfillment", getOptionDecoder(getBooleanDecoder())],
+    ["maxProtocolSlippageBps", getOptionDecoder(getU16Decoder())],
     ["bump", getU8Decoder()],
   ]);
 }
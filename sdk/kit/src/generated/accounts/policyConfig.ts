@@ -44,6 +44,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from "@solana/kit";
+import {
+  getMintSpendCapDecoder,
+  getMintSpendCapEncoder,
+  type MintSpendCap,
+  type MintSpendCapArgs,
+} from "../types/index.js";
 
 export const POLICY_CONFIG_DISCRIMINATOR = new Uint8Array([
   219, 7, 79, 84, 175, 51, 148, 146,
@@ -148,6 +154,71 @@ export type PolicyConfig = {
    * queue_policy_update / apply_pending_policy.
    */
   destinationMode: number;
+  /**
+   * Maximum aggregate spend per rolling 7-day period in USD (6 decimals).
+   * 0 = no weekly cap. Tracked in `SpendTrackerExtension`.
+   */
+  weeklySpendingCapUsd: bigint;
+  /**
+   * Maximum aggregate spend per rolling 30-day period in USD (6 decimals).
+   * 0 = no monthly cap. Tracked in `SpendTrackerExtension`.
+   */
+  monthlySpendingCapUsd: bigint;
+  /**
+   * Per-stablecoin-mint rolling 24h caps. Mints without an entry only
+   * count against the global caps. Bounded to MAX_MINT_CAPS entries.
+   * Tracked in `SpendTrackerExtension`.
+   */
+  mintCaps: Array<MintSpendCap>;
+  /**
+   * Per-destination rolling 24h caps in USD (6 decimals) for
+   * `agent_transfer`. Index-aligned with `allowed_destinations`; empty =
+   * no per-destination caps. A value of 0 means no limit for that entry.
+   * Tracked in `DestinationSpendTracker`.
+   */
+  destinationCaps: Array<bigint>;
+  /**
+   * Weekly UTC schedule of hours in which agents may spend. Bit `h % 8`
+   * of byte `h / 8` covers hour-of-week `h`, where 0 = Monday 00:00–01:00
+   * UTC. All zero = no schedule (trading allowed at any hour).
+   */
+  tradingHours: ReadonlyUint8Array;
+  /** When true, non-spending sessions (`amount == 0`) ignore `trading_hours`. */
+  tradingHoursExemptNonSpending: boolean;
+  /**
+   * Rolling 24h budget in USD (6 decimals) for fees charged by sessions
+   * that finalize without real spend. 0 = charge those fees against
+   * `daily_spending_cap_usd` instead. Tracked in `SpendTrackerExtension`;
+   * going over it pauses the agent.
+   */
+  dailyFeeCapUsd: bigint;
+  /**
+   * Abandoned spending sessions (finalized with no measured spend) an
+   * agent may accumulate per rolling hour before it is paused.
+   * 0 = no limit. Counted in `AgentSpendOverlay.abandon_counters`.
+   */
+  maxAbandonedSessionsPerHour: number;
+  /**
+   * Opt-in for `KNOWN_ASYNC_FULFILLMENT_PROGRAMS`. When set, a spending
+   * session may invoke one of them; its full amount is reserved against
+   * the caps at validate time and released by `reconcile_fulfillment`.
+   */
+  allowAsyncFulfillment: boolean;
+  /**
+   * Advisory minimum-output check for non-Jupiter spending. When > 0,
+   * such a session must declare an `ExpectedOutput` whose minimum gives
+   * up at most this much of its quote; finalize verifies the output delta
+   * against it. Quote and minimum are both the agent's, so this is not a
+   * slippage guard on the vault. 0 = not required. Jupiter keeps
+   * `max_slippage_bps`.
+   */
+  maxProtocolSlippageBps: number;
+  /**
+   * Slot of the last `tighten_policy`. A pending update queued at or
+   * before it would re-loosen the tightened fields, so it can no longer
+   * be applied.
+   */
+  tightenedAtSlot: bigint;
 };
 
 export type PolicyConfigArgs = {
@@ -242,6 +313,71 @@ export type PolicyConfigArgs = {
    * queue_policy_update / apply_pending_policy.
    */
   destinationMode: number;
+  /**
+   * Maximum aggregate spend per rolling 7-day period in USD (6 decimals).
+   * 0 = no weekly cap. Tracked in `SpendTrackerExtension`.
+   */
+  weeklySpendingCapUsd: number | bigint;
+  /**
+   * Maximum aggregate spend per rolling 30-day period in USD (6 decimals).
+   * 0 = no monthly cap. Tracked in `SpendTrackerExtension`.
+   */
+  monthlySpendingCapUsd: number | bigint;
+  /**
+   * Per-stablecoin-mint rolling 24h caps. Mints without an entry only
+   * count against the global caps. Bounded to MAX_MINT_CAPS entries.
+   * Tracked in `SpendTrackerExtension`.
+   */
+  mintCaps: Array<MintSpendCapArgs>;
+  /**
+   * Per-destination rolling 24h caps in USD (6 decimals) for
+   * `agent_transfer`. Index-aligned with `allowed_destinations`; empty =
+   * no per-destination caps. A value of 0 means no limit for that entry.
+   * Tracked in `DestinationSpendTracker`.
+   */
+  destinationCaps: Array<number | bigint>;
+  /**
+   * Weekly UTC schedule of hours in which agents may spend. Bit `h % 8`
+   * of byte `h / 8` covers hour-of-week `h`, where 0 = Monday 00:00–01:00
+   * UTC. All zero = no schedule (trading allowed at any hour).
+   */
+  tradingHours: ReadonlyUint8Array;
+  /** When true, non-spending sessions (`amount == 0`) ignore `trading_hours`. */
+  tradingHoursExemptNonSpending: boolean;
+  /**
+   * Rolling 24h budget in USD (6 decimals) for fees charged by sessions
+   * that finalize without real spend. 0 = charge those fees against
+   * `daily_spending_cap_usd` instead. Tracked in `SpendTrackerExtension`;
+   * going over it pauses the agent.
+   */
+  dailyFeeCapUsd: number | bigint;
+  /**
+   * Abandoned spending sessions (finalized with no measured spend) an
+   * agent may accumulate per rolling hour before it is paused.
+   * 0 = no limit. Counted in `AgentSpendOverlay.abandon_counters`.
+   */
+  maxAbandonedSessionsPerHour: number;
+  /**
+   * Opt-in for `KNOWN_ASYNC_FULFILLMENT_PROGRAMS`. When set, a spending
+   * session may invoke one of them; its full amount is reserved against
+   * the caps at validate time and released by `reconcile_fulfillment`.
+   */
+  allowAsyncFulfillment: boolean;
+  /**
+   * Advisory minimum-output check for non-Jupiter spending. When > 0,
+   * such a session must declare an `ExpectedOutput` whose minimum gives
+   * up at most this much of its quote; finalize verifies the output delta
+   * against it. Quote and minimum are both the agent's, so this is not a
+   * slippage guard on the vault. 0 = not required. Jupiter keeps
+   * `max_slippage_bps`.
+   */
+  maxProtocolSlippageBps: number;
+  /**
+   * Slot of the last `tighten_policy`. A pending update queued at or
+   * before it would re-loosen the tightened fields, so it can no longer
+   * be applied.
+   */
+  tightenedAtSlot: number | bigint;
 };
 
 /** Gets the encoder for {@link PolicyConfigArgs} account data. */
@@ -267,6 +403,17 @@ export function getPolicyConfigEncoder(): Encoder<PolicyConfigArgs> {
       ["policyVersion", getU64Encoder()],
       ["hasPostAssertions", getU8Encoder()],
       ["destinationMode", getU8Encoder()],
+      ["weeklySpendingCapUsd", getU64Encoder()],
+      ["monthlySpendingCapUsd", getU64Encoder()],
+      ["mintCaps", getArrayEncoder(getMintSpendCapEncoder())],
+      ["destinationCaps", getArrayEncoder(getU64Encoder())],
+      ["tradingHours", fixEncoderSize(getBytesEncoder(), 21)],
+      ["tradingHoursExemptNonSpending", getBooleanEncoder()],
+      ["dailyFeeCapUsd", getU64Encoder()],
+      ["maxAbandonedSessionsPerHour", getU16Encoder()],
+      ["allowAsyncFulfillment", getBooleanEncoder()],
+      ["maxProtocolSlippageBps", getU16Encoder()],
+      ["tightenedAtSlot", getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: POLICY_CONFIG_DISCRIMINATOR }),
   );
@@ -294,6 +441,17 @@ export function getPolicyConfigDecoder(): Decoder<PolicyConfig> {
     ["policyVersion", getU64Decoder()],
     ["hasPostAssertions", getU8Decoder()],
     ["destinationMode", getU8Decoder()],
+    ["weeklySpendingCapUsd", getU64Decoder()],
+    ["monthlySpendingCapUsd", getU64Decoder()],
+    ["mintCaps", getArrayDecoder(getMintSpendCapDecoder())],
+    ["destinationCaps", getArrayDecoder(getU64Decoder())],
+    ["tradingHours", fixDecoderSize(getBytesDecoder(), 21)],
+    ["tradingHoursExemptNonSpending", getBooleanDecoder()],
+    ["dailyFeeCapUsd", getU64Decoder()],
+    ["maxAbandonedSessionsPerHour", getU16Decoder()],
+    ["allowAsyncFulfillment", getBooleanDecoder()],
+    ["maxProtocolSlippageBps", getU16Decoder()],
+    ["tightenedAtSlot", getU64Decoder()],
   ]);
 }
 
@@ -120,6 +120,38 @@ export type SessionAuthority = {
    * finalize_session cross-checks snapshot_lens[i] == entry.value_len.
    */
   snapshotLens: ReadonlyUint8Array;
+  /**
+   * Agent's `AgentSettings.max_transaction_size_usd` at validate time
+   * (0 = none). Snapshotted so finalize_session — which can be cranked
+   * permissionlessly — enforces it without the settings account.
+   */
+  agentMaxTransactionSizeUsd: bigint;
+  /**
+   * Amount ceiling of the owner permit consumed at validate time
+   * (0 = no permit). When set it replaces the per-transaction limit.
+   */
+  permitCeilingUsd: bigint;
+  /** Agent-chosen session nonce (see `session_nonce_seed`). */
+  nonce: number;
+  /**
+   * Session invoked an async-fulfillment program; its spend was reserved
+   * up front in a `PendingFulfillment`.
+   */
+  asyncFulfillment: boolean;
+  /**
+   * Declared `ExpectedOutput::mint`. Pubkey::default() = no minimum-output
+   * check at finalize.
+   */
+  minOutputMint: Address;
+  /** Declared `ExpectedOutput::min_amount` */
+  minOutputAmount: bigint;
+  /**
+   * Vault token account whose increase is measured against the minimum.
+   * For non-stablecoin input this is the output stablecoin account.
+   */
+  minOutputTokenAccount: Address;
+  /** Snapshot of `min_output_token_account` before the swap */
+  minOutputBalanceBefore: bigint;
 };
 
 export type SessionAuthorityArgs = {
@@ -186,6 +218,38 @@ export type SessionAuthorityArgs = {
    * finalize_session cross-checks snapshot_lens[i] == entry.value_len.
    */
   snapshotLens: ReadonlyUint8Array;
+  /**
+   * Agent's `AgentSettings.max_transaction_size_usd` at validate time
+   * (0 = none). Snapshotted so finalize_session — which can be cranked
+   * permissionlessly — enforces it without the settings account.
+   */
+  agentMaxTransactionSizeUsd: number | bigint;
+  /**
+   * Amount ceiling of the owner permit consumed at validate time
+   * (0 = no permit). When set it replaces the per-transaction limit.
+   */
+  permitCeilingUsd: number | bigint;
+  /** Agent-chosen session nonce (see `session_nonce_seed`). */
+  nonce: number;
+  /**
+   * Session invoked an async-fulfillment program; its spend was reserved
+   * up front in a `PendingFulfillment`.
+   */
+  asyncFulfillment: boolean;
+  /**
+   * Declared `ExpectedOutput::mint`. Pubkey::default() = no minimum-output
+   * check at finalize.
+   */
+  minOutputMint: Address;
+  /** Declared `ExpectedOutput::min_amount` */
+  minOutputAmount: number | bigint;
+  /**
+   * Vault token account whose increase is measured against the minimum.
+   * For non-stablecoin input this is the output stablecoin account.
+   */
+  minOutputTokenAccount: Address;
+  /** Snapshot of `min_output_token_account` before the swap */
+  minOutputBalanceBefore: number | bigint;
 };
 
 /** Gets the encoder for {@link SessionAuthorityArgs} account data. */
@@ -213,6 +277,14 @@ export function getSessionAuthorityEncoder(): FixedSizeEncoder<SessionAuthorityA
         getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32), { size: 4 }),
       ],
       ["snapshotLens", fixEncoderSize(getBytesEncoder(), 4)],
+      ["agentMaxTransactionSizeUsd", getU64Encoder()],
+      ["permitCeilingUsd", getU64Encoder()],
+      ["nonce", getU8Encoder()],
+      ["asyncFulfillment", getBooleanEncoder()],
+      ["minOutputMint", getAddressEncoder()],
+      ["minOutputAmount", getU64Encoder()],
+      ["minOutputTokenAccount", getAddressEncoder()],
+      ["minOutputBalanceBefore", getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: SESSION_AUTHORITY_DISCRIMINATOR }),
   );
@@ -242,6 +314,14 @@ export function getSessionAuthorityDecoder(): FixedSizeDecoder<SessionAuthority>
       getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32), { size: 4 }),
     ],
     ["snapshotLens", fixDecoderSize(getBytesDecoder(), 4)],
+    ["agentMaxTransactionSizeUsd", getU64Decoder()],
+    ["permitCeilingUsd", getU64Decoder()],
+    ["nonce", getU8Decoder()],
+    ["asyncFulfillment", getBooleanDecoder()],
+    ["minOutputMint", getAddressDecoder()],
+    ["minOutputAmount", getU64Decoder()],
+    ["minOutputTokenAccount", getAddressDecoder()],
+    ["minOutputBalanceBefore", getU64Decoder()],
   ]);
 }
 
@@ -320,5 +400,5 @@ export async function fetchAllMaybeSessionAuthority(
 }
 
 export function getSessionAuthoritySize(): number {
-  return 376;
+  return 474;
 }
@@ -170,7 +170,7 @@ export const SIGIL_ERROR__QUEUED_UPDATE_EXPIRED = 0x17bb; // 6075
 export const SIGIL_ERROR__ACCOUNT_WRITABILITY_MISMATCH = 0x17bc; // 6076
 /** SysvarScanBoundExceeded: Sysvar instruction scan exceeded the per-tx safety bound */
 export const SIGIL_ERROR__SYSVAR_SCAN_BOUND_EXCEEDED = 0x17bd; // 6077
-/** AsyncFulfillmentNotPermitted: Async-fulfillment program is not permitted in V1 (Jupiter Perps, Drift, Drift JIT). Spending cannot be measured because keeper submits the actual transfer in a separate transaction after finalize_session returns. */
+/** AsyncFulfillmentNotPermitted: Async-fulfillment program is not permitted (Jupiter Perps, Drift, Drift JIT) unless the policy opts in, and then only for a single-leg stablecoin spending session. The keeper submits the actual transfer in a separate transaction after finalize_session returns. */
 export const SIGIL_ERROR__ASYNC_FULFILLMENT_NOT_PERMITTED = 0x17be; // 6078
 /** ConstraintsAlreadyPopulated: Cannot clean an active constraints PDA; use queue+apply_close_constraints */
 export const SIGIL_ERROR__CONSTRAINTS_ALREADY_POPULATED = 0x17bf; // 6079
@@ -190,6 +190,126 @@ export const SIGIL_ERROR__LAMPORT_DRAIN_BLOCKED = 0x17c5; // 6085
 export const SIGIL_ERROR__BATCH_INSTRUCTION_BLOCKED = 0x17c6; // 6086
 /** InvalidDestinationMode: Invalid destination mode (must be 0 = Restricted or 1 = OpenWithCap) */
 export const SIGIL_ERROR__INVALID_DESTINATION_MODE = 0x17c7; // 6087
+/** PolicyChangeNotTightening: tighten_policy only accepts changes that strictly lower risk; use queue_policy_update for anything else */
+export const SIGIL_ERROR__POLICY_CHANGE_NOT_TIGHTENING = 0x17c8; // 6088
+/** WeeklySpendingCapExceeded: Rolling 7-day spending cap would be exceeded */
+export const SIGIL_ERROR__WEEKLY_SPENDING_CAP_EXCEEDED = 0x17c9; // 6089
+/** MonthlySpendingCapExceeded: Rolling 30-day spending cap would be exceeded */
+export const SIGIL_ERROR__MONTHLY_SPENDING_CAP_EXCEEDED = 0x17ca; // 6090
+/** TrackerExtensionRequired: SpendTrackerExtension account is required once the vault has one or sets a weekly, monthly, per-mint or fee cap */
+export const SIGIL_ERROR__TRACKER_EXTENSION_REQUIRED = 0x17cb; // 6091
+/** MintSpendingCapExceeded: Rolling 24h spending cap for this stablecoin mint would be exceeded */
+export const SIGIL_ERROR__MINT_SPENDING_CAP_EXCEEDED = 0x17cc; // 6092
+/** InvalidMintCaps: Invalid per-mint caps: too many entries, duplicate mint, or non-stablecoin mint */
+export const SIGIL_ERROR__INVALID_MINT_CAPS = 0x17cd; // 6093
+/** DestinationSpendCapExceeded: Rolling 24h cap for this transfer destination would be exceeded */
+export const SIGIL_ERROR__DESTINATION_SPEND_CAP_EXCEEDED = 0x17ce; // 6094
+/** DestinationCapsMismatch: Destination caps must be empty or match allowed_destinations length */
+export const SIGIL_ERROR__DESTINATION_CAPS_MISMATCH = 0x17cf; // 6095
+/** DestinationTrackerRequired: DestinationSpendTracker account is required when the destination has a cap */
+export const SIGIL_ERROR__DESTINATION_TRACKER_REQUIRED = 0x17d0; // 6096
+/** AgentSessionRateExceeded: Agent has reached its max_sessions_per_hour limit */
+export const SIGIL_ERROR__AGENT_SESSION_RATE_EXCEEDED = 0x17d1; // 6097
+/** OutsideTradingWindow: Current UTC hour is outside the policy's trading-hours schedule */
+export const SIGIL_ERROR__OUTSIDE_TRADING_WINDOW = 0x17d2; // 6098
+/** AgentSettingsRequired: AgentSettings account is required for this agent */
+export const SIGIL_ERROR__AGENT_SETTINGS_REQUIRED = 0x17d3; // 6099
+/** AgentProtocolNotAllowed: Protocol not in this agent's protocol subset */
+export const SIGIL_ERROR__AGENT_PROTOCOL_NOT_ALLOWED = 0x17d4; // 6100
+/** AgentOverrideNotTighter: Per-agent override must be at or below the vault policy value */
+export const SIGIL_ERROR__AGENT_OVERRIDE_NOT_TIGHTER = 0x17d5; // 6101
+/** AgentKeyExpired: Agent key has passed its valid_until timestamp */
+export const SIGIL_ERROR__AGENT_KEY_EXPIRED = 0x17d6; // 6102
+/** InvalidAgentExpiry: Agent key expiry must be 0 (never) or a future timestamp before 2106 */
+export const SIGIL_ERROR__INVALID_AGENT_EXPIRY = 0x17d7; // 6103
+/** UnauthorizedOwnerOrGuardian: Only the vault owner or guardian can perform this action */
+export const SIGIL_ERROR__UNAUTHORIZED_OWNER_OR_GUARDIAN = 0x17d8; // 6104
+/** InvalidGuardian: Guardian must differ from the owner and every registered agent */
+export const SIGIL_ERROR__INVALID_GUARDIAN = 0x17d9; // 6105
+/** InvalidNewOwner: New owner must be a fresh key: not the current owner, guardian or an agent */
+export const SIGIL_ERROR__INVALID_NEW_OWNER = 0x17da; // 6106
+/** InvalidRecoveryConfig: Recovery authority and destination must be set together; the authority must differ from the owner and every agent */
+export const SIGIL_ERROR__INVALID_RECOVERY_CONFIG = 0x17db; // 6107
+/** UnauthorizedRecoveryAuthority: Signer is not the vault's recovery authority */
+export const SIGIL_ERROR__UNAUTHORIZED_RECOVERY_AUTHORITY = 0x17dc; // 6108
+/** InvalidDeadManSwitchConfig: Inactivity window and beneficiary must be set together: 0 and no beneficiary, or at least 7 days with a non-agent beneficiary */
+export const SIGIL_ERROR__INVALID_DEAD_MAN_SWITCH_CONFIG = 0x17dd; // 6109
+/** OwnerNotInactive: Dead-man switch is disabled or the owner has been active within the window */
+export const SIGIL_ERROR__OWNER_NOT_INACTIVE = 0x17de; // 6110
+/** DeadManSwitchTriggered: Dead-man switch has fired; funds can only go to the beneficiary */
+export const SIGIL_ERROR__DEAD_MAN_SWITCH_TRIGGERED = 0x17df; // 6111
+/** DeadManSwitchNotTriggered: Dead-man switch has not fired */
+export const SIGIL_ERROR__DEAD_MAN_SWITCH_NOT_TRIGGERED = 0x17e0; // 6112
+/** UnauthorizedBeneficiary: Signer is not the vault's beneficiary */
+export const SIGIL_ERROR__UNAUTHORIZED_BENEFICIARY = 0x17e1; // 6113
+/** InvalidPermit: No valid owner-signed permit for this vault, agent and protocol precedes this instruction */
+export const SIGIL_ERROR__INVALID_PERMIT = 0x17e2; // 6114
+/** PermitExpired: Owner permit has expired */
+export const SIGIL_ERROR__PERMIT_EXPIRED = 0x17e3; // 6115
+/** PermitNonceUsed: Permit nonce already used or below the nonce window */
+export const SIGIL_ERROR__PERMIT_NONCE_USED = 0x17e4; // 6116
+/** PermitCeilingExceeded: Transaction exceeds the owner permit's amount ceiling */
+export const SIGIL_ERROR__PERMIT_CEILING_EXCEEDED = 0x17e5; // 6117
+/** InvalidSpendRequestExpiry: Spend request expiry must be in the future and within 7 days */
+export const SIGIL_ERROR__INVALID_SPEND_REQUEST_EXPIRY = 0x17e6; // 6118
+/** SpendRequestNotPending: Spend request is not pending */
+export const SIGIL_ERROR__SPEND_REQUEST_NOT_PENDING = 0x17e7; // 6119
+/** SpendRequestNotApproved: Spend request has not been approved */
+export const SIGIL_ERROR__SPEND_REQUEST_NOT_APPROVED = 0x17e8; // 6120
+/** SpendRequestExpired: Spend request has expired */
+export const SIGIL_ERROR__SPEND_REQUEST_EXPIRED = 0x17e9; // 6121
+/** SpendRequestMismatch: Transfer does not match the approved spend request */
+export const SIGIL_ERROR__SPEND_REQUEST_MISMATCH = 0x17ea; // 6122
+/** UnauthorizedSpendRequestAction: Only the requesting agent, owner or guardian can cancel a spend request */
+export const SIGIL_ERROR__UNAUTHORIZED_SPEND_REQUEST_ACTION = 0x17eb; // 6123
+/** InvalidGovernanceBundle: Governance bundle must reference at least two distinct pending updates */
+export const SIGIL_ERROR__INVALID_GOVERNANCE_BUNDLE = 0x17ec; // 6124
+/** GovernanceBundleMismatch: Pending update does not match the one recorded in the governance bundle */
+export const SIGIL_ERROR__GOVERNANCE_BUNDLE_MISMATCH = 0x17ed; // 6125
+/** InvalidApproverConfig: Approver set must be unique non-agent keys (max 5) with 1 <= threshold <= set size */
+export const SIGIL_ERROR__INVALID_APPROVER_CONFIG = 0x17ee; // 6126
+/** InsufficientApprovals: Not enough approvers co-signed this instruction */
+export const SIGIL_ERROR__INSUFFICIENT_APPROVALS = 0x17ef; // 6127
+/** InvalidCpiCallers: CPI caller allowlist must hold unique programs (max 4), excluding Sigil and allowed protocols */
+export const SIGIL_ERROR__INVALID_CPI_CALLERS = 0x17f0; // 6128
+/** CpiCallerNotAllowed: Top-level program is not on the vault's CPI caller allowlist */
+export const SIGIL_ERROR__CPI_CALLER_NOT_ALLOWED = 0x17f1; // 6129
+/** InvalidSessionLegs: Session legs must be 2-4 allowed protocols, starting with target_protocol, for a spending session */
+export const SIGIL_ERROR__INVALID_SESSION_LEGS = 0x17f2; // 6130
+/** SessionLegMismatch: DeFi instructions do not match the declared session legs */
+export const SIGIL_ERROR__SESSION_LEG_MISMATCH = 0x17f3; // 6131
+/** TooManyConcurrentSessions: Session nonce exceeds the agent's concurrent session limit */
+export const SIGIL_ERROR__TOO_MANY_CONCURRENT_SESSIONS = 0x17f4; // 6132
+/** InvalidConcurrentSessionLimit: max_concurrent_sessions exceeds MAX_CONCURRENT_SESSIONS */
+export const SIGIL_ERROR__INVALID_CONCURRENT_SESSION_LIMIT = 0x17f5; // 6133
+/** FeeCapExceeded: Abandoned-session fees would exceed the daily fee budget */
+export const SIGIL_ERROR__FEE_CAP_EXCEEDED = 0x17f6; // 6134
+/** InvalidPendingFulfillment: Pending fulfillment account missing, unexpected or mismatched for this session */
+export const SIGIL_ERROR__INVALID_PENDING_FULFILLMENT = 0x17f7; // 6135
+/** FulfillmentWindowOpen: Fulfillment window has not elapsed; reservation cannot be reconciled yet */
+export const SIGIL_ERROR__FULFILLMENT_WINDOW_OPEN = 0x17f8; // 6136
+/** OpenFulfillmentsExist: Vault has unreconciled async-fulfillment reservations */
+export const SIGIL_ERROR__OPEN_FULFILLMENTS_EXIST = 0x17f9; // 6137
+/** ExpectedOutputRequired: Policy requires an expected output for non-Jupiter spending */
+export const SIGIL_ERROR__EXPECTED_OUTPUT_REQUIRED = 0x17fa; // 6138
+/** InvalidExpectedOutput: Expected output mint, account or minimum is invalid or exceeds max_protocol_slippage_bps */
+export const SIGIL_ERROR__INVALID_EXPECTED_OUTPUT = 0x17fb; // 6139
+/** MinimumOutputNotMet: Session output is below the declared minimum */
+export const SIGIL_ERROR__MINIMUM_OUTPUT_NOT_MET = 0x17fc; // 6140
+/** PendingPolicySuperseded: Policy was tightened after this update was queued; cancel and re-queue it */
+export const SIGIL_ERROR__PENDING_POLICY_SUPERSEDED = 0x17fd; // 6141
+/** SpendRequestChanged: Spend request contents differ from those the approver reviewed */
+export const SIGIL_ERROR__SPEND_REQUEST_CHANGED = 0x17fe; // 6142
+/** GovernanceBundleOpen: A governance bundle is open; apply or cancel it before applying its updates individually */
+export const SIGIL_ERROR__GOVERNANCE_BUNDLE_OPEN = 0x17ff; // 6143
+/** CappedProtocolInMultiLegSession: Multi-leg sessions cannot include a protocol with a per-protocol cap */
+export const SIGIL_ERROR__CAPPED_PROTOCOL_IN_MULTI_LEG_SESSION = 0x1800; // 6144
+/** AgentSessionsOpen: Agent has open sessions; finalize them before rotating its key */
+export const SIGIL_ERROR__AGENT_SESSIONS_OPEN = 0x1801; // 6145
+/** QueuedBeforeOwnerTransfer: Update was queued before the vault changed owner; cancel and re-queue it */
+export const SIGIL_ERROR__QUEUED_BEFORE_OWNER_TRANSFER = 0x1802; // 6146
+/** NotLegacyVault: Vault, policy or spend overlay is not in the pre-migration layout */
+export const SIGIL_ERROR__NOT_LEGACY_VAULT = 0x1803; // 6147
 
 export type SigilError =
   | typeof SIGIL_ERROR__ACCOUNT_WRITABILITY_MISMATCH
@@ -198,80 +318,139 @@ export type SigilError =
   | typeof SIGIL_ERROR__AGENT_ALREADY_PAUSED
   | typeof SIGIL_ERROR__AGENT_ALREADY_REGISTERED
   | typeof SIGIL_ERROR__AGENT_IS_OWNER
+  | typeof SIGIL_ERROR__AGENT_KEY_EXPIRED
   | typeof SIGIL_ERROR__AGENT_NOT_PAUSED
+  | typeof SIGIL_ERROR__AGENT_OVERRIDE_NOT_TIGHTER
   | typeof SIGIL_ERROR__AGENT_PAUSED
+  | typeof SIGIL_ERROR__AGENT_PROTOCOL_NOT_ALLOWED
+  | typeof SIGIL_ERROR__AGENT_SESSION_RATE_EXCEEDED
+  | typeof SIGIL_ERROR__AGENT_SESSIONS_OPEN
+  | typeof SIGIL_ERROR__AGENT_SETTINGS_REQUIRED
   | typeof SIGIL_ERROR__AGENT_SLOT_NOT_FOUND
   | typeof SIGIL_ERROR__AGENT_SPEND_LIMIT_EXCEEDED
   | typeof SIGIL_ERROR__ASYNC_FULFILLMENT_NOT_PERMITTED
   | typeof SIGIL_ERROR__BATCH_INSTRUCTION_BLOCKED
   | typeof SIGIL_ERROR__BLOCKED_SPL_OPCODE
+  | typeof SIGIL_ERROR__CAPPED_PROTOCOL_IN_MULTI_LEG_SESSION
   | typeof SIGIL_ERROR__CONFIDENTIAL_TRANSFER_BLOCKED
   | typeof SIGIL_ERROR__CONSTRAINTS_ALREADY_POPULATED
   | typeof SIGIL_ERROR__CONSTRAINTS_NOT_CLOSED
   | typeof SIGIL_ERROR__CONSTRAINTS_VAULT_MISMATCH
   | typeof SIGIL_ERROR__CONSTRAINT_VIOLATED
+  | typeof SIGIL_ERROR__CPI_CALLER_NOT_ALLOWED
   | typeof SIGIL_ERROR__CPI_CALL_NOT_ALLOWED
+  | typeof SIGIL_ERROR__DEAD_MAN_SWITCH_NOT_TRIGGERED
+  | typeof SIGIL_ERROR__DEAD_MAN_SWITCH_TRIGGERED
+  | typeof SIGIL_ERROR__DESTINATION_CAPS_MISMATCH
   | typeof SIGIL_ERROR__DESTINATION_NOT_ALLOWED
+  | typeof SIGIL_ERROR__DESTINATION_SPEND_CAP_EXCEEDED
+  | typeof SIGIL_ERROR__DESTINATION_TRACKER_REQUIRED
   | typeof SIGIL_ERROR__DEVELOPER_FEE_TOO_HIGH
   | typeof SIGIL_ERROR__ESCROW_CONDITIONS_NOT_MET
   | typeof SIGIL_ERROR__ESCROW_DURATION_EXCEEDED
   | typeof SIGIL_ERROR__ESCROW_EXPIRED
   | typeof SIGIL_ERROR__ESCROW_NOT_ACTIVE
   | typeof SIGIL_ERROR__ESCROW_NOT_EXPIRED
+  | typeof SIGIL_ERROR__EXPECTED_OUTPUT_REQUIRED
+  | typeof SIGIL_ERROR__FEE_CAP_EXCEEDED
+  | typeof SIGIL_ERROR__FULFILLMENT_WINDOW_OPEN
+  | typeof SIGIL_ERROR__GOVERNANCE_BUNDLE_MISMATCH
+  | typeof SIGIL_ERROR__GOVERNANCE_BUNDLE_OPEN
+  | typeof SIGIL_ERROR__INSUFFICIENT_APPROVALS
   | typeof SIGIL_ERROR__INSUFFICIENT_BALANCE
   | typeof SIGIL_ERROR__INSUFFICIENT_PERMISSIONS
+  | typeof SIGIL_ERROR__INVALID_AGENT_EXPIRY
   | typeof SIGIL_ERROR__INVALID_AGENT_KEY
+  | typeof SIGIL_ERROR__INVALID_APPROVER_CONFIG
+  | typeof SIGIL_ERROR__INVALID_CONCURRENT_SESSION_LIMIT
   | typeof SIGIL_ERROR__INVALID_CONSTRAINT_CONFIG
   | typeof SIGIL_ERROR__INVALID_CONSTRAINT_OPERATOR
   | typeof SIGIL_ERROR__INVALID_CONSTRAINTS_PDA
+  | typeof SIGIL_ERROR__INVALID_CPI_CALLERS
+  | typeof SIGIL_ERROR__INVALID_DEAD_MAN_SWITCH_CONFIG
   | typeof SIGIL_ERROR__INVALID_DESTINATION_MODE
   | typeof SIGIL_ERROR__INVALID_ESCROW_VAULT
+  | typeof SIGIL_ERROR__INVALID_EXPECTED_OUTPUT
   | typeof SIGIL_ERROR__INVALID_FEE_DESTINATION
+  | typeof SIGIL_ERROR__INVALID_GOVERNANCE_BUNDLE
+  | typeof SIGIL_ERROR__INVALID_GUARDIAN
   | typeof SIGIL_ERROR__INVALID_JUPITER_INSTRUCTION
+  | typeof SIGIL_ERROR__INVALID_MINT_CAPS
+  | typeof SIGIL_ERROR__INVALID_NEW_OWNER
   | typeof SIGIL_ERROR__INVALID_PENDING_CONSTRAINTS_PDA
+  | typeof SIGIL_ERROR__INVALID_PENDING_FULFILLMENT
   | typeof SIGIL_ERROR__INVALID_PERMISSIONS
+  | typeof SIGIL_ERROR__INVALID_PERMIT
   | typeof SIGIL_ERROR__INVALID_POST_ASSERTION_INDEX
   | typeof SIGIL_ERROR__INVALID_PROTOCOL_MODE
   | typeof SIGIL_ERROR__INVALID_PROTOCOL_TREASURY
+  | typeof SIGIL_ERROR__INVALID_RECOVERY_CONFIG
   | typeof SIGIL_ERROR__INVALID_SESSION
   | typeof SIGIL_ERROR__INVALID_SESSION_EXPIRY
+  | typeof SIGIL_ERROR__INVALID_SESSION_LEGS
+  | typeof SIGIL_ERROR__INVALID_SPEND_REQUEST_EXPIRY
   | typeof SIGIL_ERROR__INVALID_TOKEN_ACCOUNT
   | typeof SIGIL_ERROR__LAMPORT_DRAIN_BLOCKED
   | typeof SIGIL_ERROR__MAX_AGENTS_REACHED
+  | typeof SIGIL_ERROR__MINIMUM_OUTPUT_NOT_MET
+  | typeof SIGIL_ERROR__MINT_SPENDING_CAP_EXCEEDED
   | typeof SIGIL_ERROR__MISSING_FINALIZE_INSTRUCTION
+  | typeof SIGIL_ERROR__MONTHLY_SPENDING_CAP_EXCEEDED
   | typeof SIGIL_ERROR__NO_AGENT_REGISTERED
   | typeof SIGIL_ERROR__NON_TRACKED_SWAP_MUST_RETURN_STABLECOIN
   | typeof SIGIL_ERROR__NO_TIMELOCK_CONFIGURED
+  | typeof SIGIL_ERROR__NOT_LEGACY_VAULT
+  | typeof SIGIL_ERROR__OPEN_FULFILLMENTS_EXIST
   | typeof SIGIL_ERROR__ORPHAN_PDA_POPULATED
   | typeof SIGIL_ERROR__ORPHAN_PDA_WRONG_OWNER
+  | typeof SIGIL_ERROR__OUTSIDE_TRADING_WINDOW
   | typeof SIGIL_ERROR__OVERFLOW
   | typeof SIGIL_ERROR__OVERLAY_SLOT_EXHAUSTED
+  | typeof SIGIL_ERROR__OWNER_NOT_INACTIVE
   | typeof SIGIL_ERROR__PENDING_POLICY_EXISTS
+  | typeof SIGIL_ERROR__PENDING_POLICY_SUPERSEDED
   | typeof SIGIL_ERROR__PERMANENT_DELEGATE_BLOCKED
+  | typeof SIGIL_ERROR__PERMIT_CEILING_EXCEEDED
+  | typeof SIGIL_ERROR__PERMIT_EXPIRED
+  | typeof SIGIL_ERROR__PERMIT_NONCE_USED
+  | typeof SIGIL_ERROR__POLICY_CHANGE_NOT_TIGHTENING
   | typeof SIGIL_ERROR__POLICY_VERSION_MISMATCH
   | typeof SIGIL_ERROR__POST_ASSERTION_FAILED
   | typeof SIGIL_ERROR__PROTOCOL_CAP_EXCEEDED
   | typeof SIGIL_ERROR__PROTOCOL_CAPS_MISMATCH
   | typeof SIGIL_ERROR__PROTOCOL_MISMATCH
   | typeof SIGIL_ERROR__PROTOCOL_NOT_ALLOWED
+  | typeof SIGIL_ERROR__QUEUED_BEFORE_OWNER_TRANSFER
   | typeof SIGIL_ERROR__QUEUED_UPDATE_EXPIRED
+  | typeof SIGIL_ERROR__SESSION_LEG_MISMATCH
   | typeof SIGIL_ERROR__SESSION_NOT_AUTHORIZED
   | typeof SIGIL_ERROR__SLIPPAGE_BPS_TOO_HIGH
   | typeof SIGIL_ERROR__SNAPSHOT_NOT_CAPTURED
   | typeof SIGIL_ERROR__SPENDING_CAP_EXCEEDED
+  | typeof SIGIL_ERROR__SPEND_REQUEST_CHANGED
+  | typeof SIGIL_ERROR__SPEND_REQUEST_EXPIRED
+  | typeof SIGIL_ERROR__SPEND_REQUEST_MISMATCH
+  | typeof SIGIL_ERROR__SPEND_REQUEST_NOT_APPROVED
+  | typeof SIGIL_ERROR__SPEND_REQUEST_NOT_PENDING
   | typeof SIGIL_ERROR__SWAP_SLIPPAGE_EXCEEDED
   | typeof SIGIL_ERROR__SYSVAR_SCAN_BOUND_EXCEEDED
   | typeof SIGIL_ERROR__TIMELOCK_NOT_EXPIRED
   | typeof SIGIL_ERROR__TIMELOCK_TOO_SHORT
   | typeof SIGIL_ERROR__TOO_MANY_ALLOWED_PROTOCOLS
+  | typeof SIGIL_ERROR__TOO_MANY_CONCURRENT_SESSIONS
   | typeof SIGIL_ERROR__TOO_MANY_DE_FI_INSTRUCTIONS
   | typeof SIGIL_ERROR__TOO_MANY_DESTINATIONS
+  | typeof SIGIL_ERROR__TRACKER_EXTENSION_REQUIRED
   | typeof SIGIL_ERROR__TRANSACTION_TOO_LARGE
   | typeof SIGIL_ERROR__TRANSFER_HOOK_BLOCKED
   | typeof SIGIL_ERROR__UNAUTHORIZED_AGENT
+  | typeof SIGIL_ERROR__UNAUTHORIZED_BENEFICIARY
   | typeof SIGIL_ERROR__UNAUTHORIZED_OWNER
+  | typeof SIGIL_ERROR__UNAUTHORIZED_OWNER_OR_GUARDIAN
   | typeof SIGIL_ERROR__UNAUTHORIZED_POST_FINALIZE_INSTRUCTION
   | typeof SIGIL_ERROR__UNAUTHORIZED_PRE_VALIDATE_INSTRUCTION
+  | typeof SIGIL_ERROR__UNAUTHORIZED_RECOVERY_AUTHORITY
+  | typeof SIGIL_ERROR__UNAUTHORIZED_SPEND_REQUEST_ACTION
   | typeof SIGIL_ERROR__UNAUTHORIZED_TOKEN_APPROVAL
   | typeof SIGIL_ERROR__UNAUTHORIZED_TOKEN_TRANSFER
   | typeof SIGIL_ERROR__UNCONSTRAINED_PROGRAM_BLOCKED
@@ -279,7 +458,8 @@ export type SigilError =
   | typeof SIGIL_ERROR__UNSUPPORTED_TOKEN
   | typeof SIGIL_ERROR__VAULT_ALREADY_CLOSED
   | typeof SIGIL_ERROR__VAULT_NOT_ACTIVE
-  | typeof SIGIL_ERROR__VAULT_NOT_FROZEN;
+  | typeof SIGIL_ERROR__VAULT_NOT_FROZEN
+  | typeof SIGIL_ERROR__WEEKLY_SPENDING_CAP_EXCEEDED;
 
 let sigilErrorMessages: Record<SigilError, string> | undefined;
 if (process.env.NODE_ENV !== "production") {
@@ -290,80 +470,139 @@ if (process.env.NODE_ENV !== "production") {
     [SIGIL_ERROR__AGENT_ALREADY_PAUSED]: `Agent is already paused`,
     [SIGIL_ERROR__AGENT_ALREADY_REGISTERED]: `Agent already registered for this vault`,
     [SIGIL_ERROR__AGENT_IS_OWNER]: `Invalid agent: agent cannot be the vault owner`,
+    [SIGIL_ERROR__AGENT_KEY_EXPIRED]: `Agent key has passed its valid_until timestamp`,
     [SIGIL_ERROR__AGENT_NOT_PAUSED]: `Agent is not paused`,
+    [SIGIL_ERROR__AGENT_OVERRIDE_NOT_TIGHTER]: `Per-agent override must be at or below the vault policy value`,
     [SIGIL_ERROR__AGENT_PAUSED]: `Agent is paused and cannot execute actions`,
+    [SIGIL_ERROR__AGENT_PROTOCOL_NOT_ALLOWED]: `Protocol not in this agent's protocol subset`,
+    [SIGIL_ERROR__AGENT_SESSION_RATE_EXCEEDED]: `Agent has reached its max_sessions_per_hour limit`,
+    [SIGIL_ERROR__AGENT_SESSIONS_OPEN]: `Agent has open sessions; finalize them before rotating its key`,
+    [SIGIL_ERROR__AGENT_SETTINGS_REQUIRED]: `AgentSettings account is required for this agent`,
     [SIGIL_ERROR__AGENT_SLOT_NOT_FOUND]: `Agent has per-agent spending limit but no overlay tracking slot`,
     [SIGIL_ERROR__AGENT_SPEND_LIMIT_EXCEEDED]: `Agent rolling 24h spend exceeds per-agent spending limit`,
-    [SIGIL_ERROR__ASYNC_FULFILLMENT_NOT_PERMITTED]: `Async-fulfillment program is not permitted in V1 (Jupiter Perps, Drift, Drift JIT). Spending cannot be measured because keeper submits the actual transfer in a separate transaction after finalize_session returns.`,
+    [SIGIL_ERROR__ASYNC_FULFILLMENT_NOT_PERMITTED]: `Async-fulfillment program is not permitted (Jupiter Perps, Drift, Drift JIT) unless the policy opts in, and then only for a single-leg stablecoin spending session. The keeper submits the actual transfer in a separate transaction after finalize_session returns.`,
     [SIGIL_ERROR__BATCH_INSTRUCTION_BLOCKED]: `Token-2022 Batch instruction (opcode 255) is blocked outright — wraps inner instructions and bypasses byte-0 blocklist`,
     [SIGIL_ERROR__BLOCKED_SPL_OPCODE]: `SPL opcode is blocked at runtime and cannot be used in constraints`,
+    [SIGIL_ERROR__CAPPED_PROTOCOL_IN_MULTI_LEG_SESSION]: `Multi-leg sessions cannot include a protocol with a per-protocol cap`,
     [SIGIL_ERROR__CONFIDENTIAL_TRANSFER_BLOCKED]: `Token-2022 ConfidentialTransfer not permitted between validate and finalize`,
     [SIGIL_ERROR__CONSTRAINTS_ALREADY_POPULATED]: `Cannot clean an active constraints PDA; use queue+apply_close_constraints`,
     [SIGIL_ERROR__CONSTRAINTS_NOT_CLOSED]: `Instruction constraints must be closed before closing vault`,
     [SIGIL_ERROR__CONSTRAINTS_VAULT_MISMATCH]: `Zero-copy constraints account has wrong vault`,
     [SIGIL_ERROR__CONSTRAINT_VIOLATED]: `Instruction constraint violated`,
+    [SIGIL_ERROR__CPI_CALLER_NOT_ALLOWED]: `Top-level program is not on the vault's CPI caller allowlist`,
     [SIGIL_ERROR__CPI_CALL_NOT_ALLOWED]: `Instruction must be top-level (CPI calls not allowed)`,
+    [SIGIL_ERROR__DEAD_MAN_SWITCH_NOT_TRIGGERED]: `Dead-man switch has not fired`,
+    [SIGIL_ERROR__DEAD_MAN_SWITCH_TRIGGERED]: `Dead-man switch has fired; funds can only go to the beneficiary`,
+    [SIGIL_ERROR__DESTINATION_CAPS_MISMATCH]: `Destination caps must be empty or match allowed_destinations length`,
     [SIGIL_ERROR__DESTINATION_NOT_ALLOWED]: `Destination not in allowed list`,
+    [SIGIL_ERROR__DESTINATION_SPEND_CAP_EXCEEDED]: `Rolling 24h cap for this transfer destination would be exceeded`,
+    [SIGIL_ERROR__DESTINATION_TRACKER_REQUIRED]: `DestinationSpendTracker account is required when the destination has a cap`,
     [SIGIL_ERROR__DEVELOPER_FEE_TOO_HIGH]: `Developer fee rate exceeds maximum (500 / 1,000,000 = 5 BPS)`,
     [SIGIL_ERROR__ESCROW_CONDITIONS_NOT_MET]: `Escrow conditions not met`,
     [SIGIL_ERROR__ESCROW_DURATION_EXCEEDED]: `Escrow duration exceeds maximum (30 days)`,
     [SIGIL_ERROR__ESCROW_EXPIRED]: `Escrow has expired`,
     [SIGIL_ERROR__ESCROW_NOT_ACTIVE]: `Escrow is not in Active status`,
     [SIGIL_ERROR__ESCROW_NOT_EXPIRED]: `Escrow has not expired yet`,
+    [SIGIL_ERROR__EXPECTED_OUTPUT_REQUIRED]: `Policy requires an expected output for non-Jupiter spending`,
+    [SIGIL_ERROR__FEE_CAP_EXCEEDED]: `Abandoned-session fees would exceed the daily fee budget`,
+    [SIGIL_ERROR__FULFILLMENT_WINDOW_OPEN]: `Fulfillment window has not elapsed; reservation cannot be reconciled yet`,
+    [SIGIL_ERROR__GOVERNANCE_BUNDLE_MISMATCH]: `Pending update does not match the one recorded in the governance bundle`,
+    [SIGIL_ERROR__GOVERNANCE_BUNDLE_OPEN]: `A governance bundle is open; apply or cancel it before applying its updates individually`,
+    [SIGIL_ERROR__INSUFFICIENT_APPROVALS]: `Not enough approvers co-signed this instruction`,
     [SIGIL_ERROR__INSUFFICIENT_BALANCE]: `Insufficient vault balance for withdrawal`,
     [SIGIL_ERROR__INSUFFICIENT_PERMISSIONS]: `Agent lacks permission for this action type`,
+    [SIGIL_ERROR__INVALID_AGENT_EXPIRY]: `Agent key expiry must be 0 (never) or a future timestamp before 2106`,
     [SIGIL_ERROR__INVALID_AGENT_KEY]: `Invalid agent: cannot be the zero address`,
+    [SIGIL_ERROR__INVALID_APPROVER_CONFIG]: `Approver set must be unique non-agent keys (max 5) with 1 <= threshold <= set size`,
+    [SIGIL_ERROR__INVALID_CONCURRENT_SESSION_LIMIT]: `max_concurrent_sessions exceeds MAX_CONCURRENT_SESSIONS`,
     [SIGIL_ERROR__INVALID_CONSTRAINT_CONFIG]: `Invalid constraint configuration: bounds exceeded`,
     [SIGIL_ERROR__INVALID_CONSTRAINT_OPERATOR]: `Constraint operator value is not a valid ConstraintOperator discriminant`,
     [SIGIL_ERROR__INVALID_CONSTRAINTS_PDA]: `Invalid constraints PDA: wrong owner or vault`,
+    [SIGIL_ERROR__INVALID_CPI_CALLERS]: `CPI caller allowlist must hold unique programs (max 4), excluding Sigil and allowed protocols`,
+    [SIGIL_ERROR__INVALID_DEAD_MAN_SWITCH_CONFIG]: `Inactivity window and beneficiary must be set together: 0 and no beneficiary, or at least 7 days with a non-agent beneficiary`,
     [SIGIL_ERROR__INVALID_DESTINATION_MODE]: `Invalid destination mode (must be 0 = Restricted or 1 = OpenWithCap)`,
     [SIGIL_ERROR__INVALID_ESCROW_VAULT]: `Invalid escrow vault`,
+    [SIGIL_ERROR__INVALID_EXPECTED_OUTPUT]: `Expected output mint, account or minimum is invalid or exceeds max_protocol_slippage_bps`,
     [SIGIL_ERROR__INVALID_FEE_DESTINATION]: `Fee destination account invalid`,
+    [SIGIL_ERROR__INVALID_GOVERNANCE_BUNDLE]: `Governance bundle must reference at least two distinct pending updates`,
+    [SIGIL_ERROR__INVALID_GUARDIAN]: `Guardian must differ from the owner and every registered agent`,
     [SIGIL_ERROR__INVALID_JUPITER_INSTRUCTION]: `Cannot parse Jupiter swap instruction data`,
+    [SIGIL_ERROR__INVALID_MINT_CAPS]: `Invalid per-mint caps: too many entries, duplicate mint, or non-stablecoin mint`,
+    [SIGIL_ERROR__INVALID_NEW_OWNER]: `New owner must be a fresh key: not the current owner, guardian or an agent`,
     [SIGIL_ERROR__INVALID_PENDING_CONSTRAINTS_PDA]: `Invalid pending constraints PDA: wrong owner or vault`,
+    [SIGIL_ERROR__INVALID_PENDING_FULFILLMENT]: `Pending fulfillment account missing, unexpected or mismatched for this session`,
     [SIGIL_ERROR__INVALID_PERMISSIONS]: `Permission bitmask contains invalid bits`,
+    [SIGIL_ERROR__INVALID_PERMIT]: `No valid owner-signed permit for this vault, agent and protocol precedes this instruction`,
     [SIGIL_ERROR__INVALID_POST_ASSERTION_INDEX]: `Post-assertion constraint references invalid instruction index`,
     [SIGIL_ERROR__INVALID_PROTOCOL_MODE]: `Invalid protocol mode (must be 0, 1, or 2)`,
     [SIGIL_ERROR__INVALID_PROTOCOL_TREASURY]: `Protocol treasury account does not match expected address`,
+    [SIGIL_ERROR__INVALID_RECOVERY_CONFIG]: `Recovery authority and destination must be set together; the authority must differ from the owner and every agent`,
     [SIGIL_ERROR__INVALID_SESSION]: `Invalid session: does not belong to this vault`,
     [SIGIL_ERROR__INVALID_SESSION_EXPIRY]: `Session expiry slots out of range (10-450)`,
+    [SIGIL_ERROR__INVALID_SESSION_LEGS]: `Session legs must be 2-4 allowed protocols, starting with target_protocol, for a spending session`,
+    [SIGIL_ERROR__INVALID_SPEND_REQUEST_EXPIRY]: `Spend request expiry must be in the future and within 7 days`,
     [SIGIL_ERROR__INVALID_TOKEN_ACCOUNT]: `Token account does not belong to vault or has wrong mint`,
     [SIGIL_ERROR__LAMPORT_DRAIN_BLOCKED]: `Token-2022 destructive-balance ix (opcodes 38/45/46) not permitted between validate and finalize`,
     [SIGIL_ERROR__MAX_AGENTS_REACHED]: `Maximum agents per vault reached (limit: 10)`,
+    [SIGIL_ERROR__MINIMUM_OUTPUT_NOT_MET]: `Session output is below the declared minimum`,
+    [SIGIL_ERROR__MINT_SPENDING_CAP_EXCEEDED]: `Rolling 24h spending cap for this stablecoin mint would be exceeded`,
     [SIGIL_ERROR__MISSING_FINALIZE_INSTRUCTION]: `Transaction must include finalize_session after validate`,
+    [SIGIL_ERROR__MONTHLY_SPENDING_CAP_EXCEEDED]: `Rolling 30-day spending cap would be exceeded`,
     [SIGIL_ERROR__NO_AGENT_REGISTERED]: `No agent registered for this vault`,
     [SIGIL_ERROR__NON_TRACKED_SWAP_MUST_RETURN_STABLECOIN]: `Non-stablecoin swap must return stablecoin (balance did not increase)`,
     [SIGIL_ERROR__NO_TIMELOCK_CONFIGURED]: `No timelock configured on this vault`,
+    [SIGIL_ERROR__NOT_LEGACY_VAULT]: `Vault, policy or spend overlay is not in the pre-migration layout`,
+    [SIGIL_ERROR__OPEN_FULFILLMENTS_EXIST]: `Vault has unreconciled async-fulfillment reservations`,
     [SIGIL_ERROR__ORPHAN_PDA_POPULATED]: `PDA is fully populated; not an orphan`,
     [SIGIL_ERROR__ORPHAN_PDA_WRONG_OWNER]: `PDA at constraints seeds is not program-owned`,
+    [SIGIL_ERROR__OUTSIDE_TRADING_WINDOW]: `Current UTC hour is outside the policy's trading-hours schedule`,
     [SIGIL_ERROR__OVERFLOW]: `Arithmetic overflow`,
     [SIGIL_ERROR__OVERLAY_SLOT_EXHAUSTED]: `Per-agent overlay is full; cannot register agent with spending limit`,
+    [SIGIL_ERROR__OWNER_NOT_INACTIVE]: `Dead-man switch is disabled or the owner has been active within the window`,
     [SIGIL_ERROR__PENDING_POLICY_EXISTS]: `Pending policy update must be applied or cancelled before closing vault`,
+    [SIGIL_ERROR__PENDING_POLICY_SUPERSEDED]: `Policy was tightened after this update was queued; cancel and re-queue it`,
     [SIGIL_ERROR__PERMANENT_DELEGATE_BLOCKED]: `Token-2022 PermanentDelegate not permitted between validate and finalize`,
+    [SIGIL_ERROR__PERMIT_CEILING_EXCEEDED]: `Transaction exceeds the owner permit's amount ceiling`,
+    [SIGIL_ERROR__PERMIT_EXPIRED]: `Owner permit has expired`,
+    [SIGIL_ERROR__PERMIT_NONCE_USED]: `Permit nonce already used or below the nonce window`,
+    [SIGIL_ERROR__POLICY_CHANGE_NOT_TIGHTENING]: `tighten_policy only accepts changes that strictly lower risk; use queue_policy_update for anything else`,
     [SIGIL_ERROR__POLICY_VERSION_MISMATCH]: `Policy version mismatch — policy changed since agent's last RPC read`,
     [SIGIL_ERROR__POST_ASSERTION_FAILED]: `Post-execution assertion failed: account state did not satisfy constraint`,
     [SIGIL_ERROR__PROTOCOL_CAP_EXCEEDED]: `Per-protocol rolling 24h spending cap would be exceeded`,
     [SIGIL_ERROR__PROTOCOL_CAPS_MISMATCH]: `protocol_caps length must match protocols length when has_protocol_caps is true`,
     [SIGIL_ERROR__PROTOCOL_MISMATCH]: `DeFi instruction program does not match declared target_protocol`,
     [SIGIL_ERROR__PROTOCOL_NOT_ALLOWED]: `Protocol not allowed by policy`,
+    [SIGIL_ERROR__QUEUED_BEFORE_OWNER_TRANSFER]: `Update was queued before the vault changed owner; cancel and re-queue it`,
     [SIGIL_ERROR__QUEUED_UPDATE_EXPIRED]: `Queued update is too old (>MAX_APPLY_AGE_SLOTS) — re-queue to apply. Defends against durable-nonce pre-signing.`,
+    [SIGIL_ERROR__SESSION_LEG_MISMATCH]: `DeFi instructions do not match the declared session legs`,
     [SIGIL_ERROR__SESSION_NOT_AUTHORIZED]: `Session not authorized`,
     [SIGIL_ERROR__SLIPPAGE_BPS_TOO_HIGH]: `Slippage BPS exceeds maximum (5000 = 50%)`,
     [SIGIL_ERROR__SNAPSHOT_NOT_CAPTURED]: `Delta assertion snapshot was not captured in validate_and_authorize`,
     [SIGIL_ERROR__SPENDING_CAP_EXCEEDED]: `Rolling 24h spending cap would be exceeded`,
+    [SIGIL_ERROR__SPEND_REQUEST_CHANGED]: `Spend request contents differ from those the approver reviewed`,
+    [SIGIL_ERROR__SPEND_REQUEST_EXPIRED]: `Spend request has expired`,
+    [SIGIL_ERROR__SPEND_REQUEST_MISMATCH]: `Transfer does not match the approved spend request`,
+    [SIGIL_ERROR__SPEND_REQUEST_NOT_APPROVED]: `Spend request has not been approved`,
+    [SIGIL_ERROR__SPEND_REQUEST_NOT_PENDING]: `Spend request is not pending`,
     [SIGIL_ERROR__SWAP_SLIPPAGE_EXCEEDED]: `Swap slippage exceeds policy max_slippage_bps or quoted output is zero`,
     [SIGIL_ERROR__SYSVAR_SCAN_BOUND_EXCEEDED]: `Sysvar instruction scan exceeded the per-tx safety bound`,
     [SIGIL_ERROR__TIMELOCK_NOT_EXPIRED]: `Timelock period has not expired yet`,
     [SIGIL_ERROR__TIMELOCK_TOO_SHORT]: `Timelock duration below minimum (1800 seconds / 30 minutes)`,
     [SIGIL_ERROR__TOO_MANY_ALLOWED_PROTOCOLS]: `Policy configuration invalid: too many allowed protocols`,
+    [SIGIL_ERROR__TOO_MANY_CONCURRENT_SESSIONS]: `Session nonce exceeds the agent's concurrent session limit`,
     [SIGIL_ERROR__TOO_MANY_DE_FI_INSTRUCTIONS]: `Spending allows at most one DeFi instruction`,
     [SIGIL_ERROR__TOO_MANY_DESTINATIONS]: `Too many destinations (max 10)`,
+    [SIGIL_ERROR__TRACKER_EXTENSION_REQUIRED]: `SpendTrackerExtension account is required once the vault has one or sets a weekly, monthly, per-mint or fee cap`,
     [SIGIL_ERROR__TRANSACTION_TOO_LARGE]: `Transaction exceeds maximum single transaction size`,
     [SIGIL_ERROR__TRANSFER_HOOK_BLOCKED]: `Token-2022 TransferHook not permitted between validate and finalize`,
     [SIGIL_ERROR__UNAUTHORIZED_AGENT]: `Unauthorized: signer is not the registered agent`,
+    [SIGIL_ERROR__UNAUTHORIZED_BENEFICIARY]: `Signer is not the vault's beneficiary`,
     [SIGIL_ERROR__UNAUTHORIZED_OWNER]: `Unauthorized: signer is not the vault owner`,
+    [SIGIL_ERROR__UNAUTHORIZED_OWNER_OR_GUARDIAN]: `Only the vault owner or guardian can perform this action`,
     [SIGIL_ERROR__UNAUTHORIZED_POST_FINALIZE_INSTRUCTION]: `Instructions after finalize_session must be ComputeBudget or SystemProgram only`,
     [SIGIL_ERROR__UNAUTHORIZED_PRE_VALIDATE_INSTRUCTION]: `Non-infrastructure instruction detected before validate_and_authorize`,
+    [SIGIL_ERROR__UNAUTHORIZED_RECOVERY_AUTHORITY]: `Signer is not the vault's recovery authority`,
+    [SIGIL_ERROR__UNAUTHORIZED_SPEND_REQUEST_ACTION]: `Only the requesting agent, owner or guardian can cancel a spend request`,
     [SIGIL_ERROR__UNAUTHORIZED_TOKEN_APPROVAL]: `Unauthorized SPL Token Approve between validate and finalize`,
     [SIGIL_ERROR__UNAUTHORIZED_TOKEN_TRANSFER]: `Top-level SPL Token transfer not allowed between validate and finalize`,
     [SIGIL_ERROR__UNCONSTRAINED_PROGRAM_BLOCKED]: `Program has no constraint entry and strict mode is enabled`,
@@ -372,6 +611,7 @@ if (process.env.NODE_ENV !== "production") {
     [SIGIL_ERROR__VAULT_ALREADY_CLOSED]: `Vault is already closed`,
     [SIGIL_ERROR__VAULT_NOT_ACTIVE]: `Vault is not active`,
     [SIGIL_ERROR__VAULT_NOT_FROZEN]: `Vault is not frozen (expected frozen for reactivation)`,
+    [SIGIL_ERROR__WEEKLY_SPENDING_CAP_EXCEEDED]: `Rolling 7-day spending cap would be exceeded`,
   };
 }
 
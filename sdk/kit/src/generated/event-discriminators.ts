@@ -1,11 +1,13 @@
 // AUTO-GENERATED by codama.mjs — do not edit manually.
 // Re-run `pnpm run codama` after any Rust event changes.
 //
-// Source: target/idl/sigil.json (38 events)
+// Source: target/idl/sigil.json (61 events)
 // Discriminator = SHA256("event:<EventName>")[0..8]
 
 export const EVENT_DISCRIMINATOR_MAP: Record<string, string> = {
   "555a3bda7e08b33f": "ActionAuthorized",
+  "4f628afbedc8867b": "AgentAutoPaused",
+  "111e41ab1ca75578": "AgentKeyRotated",
   "274a945ec6a67917": "AgentPausedEvent",
   "e9f7671e82adc4b7": "AgentPermissionsChangeApplied",
   "6b15814d018844d8": "AgentPermissionsChangeCancelled",
@@ -15,6 +17,7 @@ export const EVENT_DISCRIMINATOR_MAP: Record<string, string> = {
   "6b803c90a3532dd7": "AgentSpendLimitChecked",
   "583475457098a728": "AgentTransferExecuted",
   "dabbfd7c4fc02ab5": "AgentUnpausedEvent",
+  "cdbc7d55b48fd770": "BeneficiaryFundsClaimed",
   "ba3e196d90cf530d": "CloseConstraintsApplied",
   "66e2abbf6362ff86": "CloseConstraintsCancelled",
   "4d17e8996c2ef335": "CloseConstraintsQueued",
@@ -25,22 +28,42 @@ export const EVENT_DISCRIMINATOR_MAP: Record<string, string> = {
   "467f69665c6107ad": "EscrowCreated",
   "84d1316d878a1c51": "EscrowRefunded",
   "611b9637cbb3ad17": "EscrowSettled",
+  "0476fbbf1d651b86": "FeeDestinationChangeApplied",
+  "5c238fc794266180": "FeeDestinationChangeCancelled",
+  "efa68d83616550d5": "FeeDestinationChangeQueued",
   "e91775e16bb2fe08": "FeesCollected",
+  "7c1d63da1a95dd01": "FulfillmentReconciled",
+  "9824883997c6e314": "FulfillmentReserved",
   "9dd1645f3b640344": "FundsDeposited",
   "3882e69a235c0b76": "FundsWithdrawn",
+  "4f7530101231378e": "GovernanceBundleApplied",
+  "29ff4cb9a85dc49b": "GovernanceBundleCancelled",
+  "9a84984327fea45a": "GovernanceBundleCreated",
   "08aa63e81fd8391a": "InstructionConstraintsCreated",
   "a1ea93831fa39119": "OrphanConstraintsPdaCleaned",
+  "652b8717b02b2219": "OwnerTransferApplied",
+  "ac4d1113df5854c3": "OwnerTransferCancelled",
+  "8b763d230094a888": "OwnerTransferQueued",
   "1b63c3c6ee3503b5": "PdaAllocated",
   "43975f4f0c0b33f2": "PdaExtended",
   "68590564b4ca3449": "PolicyChangeApplied",
   "c89ee2ff19d31e97": "PolicyChangeCancelled",
   "49e7b6888d78204f": "PolicyChangeQueued",
+  "d3a27f6b452980d3": "PolicyTightened",
   "a66a5c0ac33cf77d": "PostAssertionChecked",
   "0714e066503c4e0b": "PostAssertionsClosed",
   "3159986e3a14441f": "PostAssertionsCreated",
+  "bf19ec56194d7560": "RecoveryCancelled",
+  "494d0d5f62dd54da": "RecoveryQueued",
+  "fe4033ca14f023cd": "RecoverySwept",
   "210cf25bce2aa3eb": "SessionFinalized",
+  "e39acc59ddcd2e2e": "SpendRequestApproved",
+  "2327a0c6a821a8c1": "SpendRequestCancelled",
+  "ea8a8ecb89b0c2ef": "SpendRequestCreated",
+  "826ce6dc2a782407": "SpendRequestExecuted",
   "ee8126e4e376f9d7": "VaultClosed",
   "751978fe4bec4e73": "VaultCreated",
   "0dc7ac6f580a97f7": "VaultFrozen",
+  "b80d213419efbd51": "VaultMigrated",
   "c534a0939f595a1c": "VaultReactivated",
 };
@@ -60,6 +60,13 @@ export type AgentTransferInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
+  TAccountDestinationSpend extends string | AccountMeta<string> = string,
+  TAccountAgentSettings extends string | AccountMeta<string> = string,
+  TAccountPermitNonces extends string | AccountMeta<string> = string,
+  TAccountSpendRequest extends string | AccountMeta<string> = string,
+  TAccountInstructionsSysvar extends string | AccountMeta<string> =
+    "Sysvar1nstructions1111111111111111111111111",
   TAccountVaultTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenMintAccount extends string | AccountMeta<string> = string,
   TAccountDestinationTokenAccount extends string | AccountMeta<string> = string,
@@ -90,6 +97,24 @@ export type AgentTransferInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
+      TAccountDestinationSpend extends string
+        ? WritableAccount<TAccountDestinationSpend>
+        : TAccountDestinationSpend,
+      TAccountAgentSettings extends string
+        ? ReadonlyAccount<TAccountAgentSettings>
+        : TAccountAgentSettings,
+      TAccountPermitNonces extends string
+        ? WritableAccount<TAccountPermitNonces>
+        : TAccountPermitNonces,
+      TAccountSpendRequest extends string
+        ? WritableAccount<TAccountSpendRequest>
+        : TAccountSpendRequest,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
       TAccountVaultTokenAccount extends string
         ? WritableAccount<TAccountVaultTokenAccount>
         : TAccountVaultTokenAccount,
@@ -158,6 +183,12 @@ export type AgentTransferAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountPermitNonces extends string = string,
+  TAccountSpendRequest extends string = string,
+  TAccountInstructionsSysvar extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountTokenMintAccount extends string = string,
   TAccountDestinationTokenAccount extends string = string,
@@ -172,6 +203,30 @@ export type AgentTransferAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /**
+   * Zero-copy DestinationSpendTracker — per-destination rolling spend.
+   * Required when the vault has one (`AgentVault::has_destination_tracker`).
+   */
+  destinationSpend?: Address<TAccountDestinationSpend>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
+  /**
+   * Permit nonce bitmap. Required, with `instructions_sysvar`, when the
+   * amount exceeds the per-transaction limit and an owner permit is used.
+   */
+  permitNonces?: Address<TAccountPermitNonces>;
+  /**
+   * Approved spend request authorizing this over-limit transfer.
+   * Consumed (closed) by this instruction.
+   */
+  spendRequest?: Address<TAccountSpendRequest>;
+  /** Instructions sysvar, for locating the owner permit. */
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   /** Vault's PDA-owned token account (source) */
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
   /** Token mint account for decimals validation */
@@ -193,6 +248,12 @@ export async function getAgentTransferInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
+  TAccountAgentSettings extends string,
+  TAccountPermitNonces extends string,
+  TAccountSpendRequest extends string,
+  TAccountInstructionsSysvar extends string,
   TAccountVaultTokenAccount extends string,
   TAccountTokenMintAccount extends string,
   TAccountDestinationTokenAccount extends string,
@@ -207,6 +268,12 @@ export async function getAgentTransferInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
+    TAccountSpendRequest,
+    TAccountInstructionsSysvar,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountDestinationTokenAccount,
@@ -223,6 +290,12 @@ export async function getAgentTransferInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
+    TAccountSpendRequest,
+    TAccountInstructionsSysvar,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountDestinationTokenAccount,
@@ -244,6 +317,21 @@ export async function getAgentTransferInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
+    permitNonces: { value: input.permitNonces ?? null, isWritable: true },
+    spendRequest: { value: input.spendRequest ?? null, isWritable: true },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -305,6 +393,87 @@ export async function getAgentTransferInstructionAsync<
       ],
     });
   }
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.destinationSpend.value) {
+    accounts.destinationSpend.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 95, 115, 112,
+            101, 110, 100,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.agentSettings.value) {
+    accounts.agentSettings.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            97, 103, 101, 110, 116, 95, 115, 101, 116, 116, 105, 110, 103, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "agent",
+            accounts.agent.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.permitNonces.value) {
+    accounts.permitNonces.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 101, 114, 109, 105, 116, 95, 110, 111, 110, 99, 101, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      "Sysvar1nstructions1111111111111111111111111" as Address<"Sysvar1nstructions1111111111111111111111111">;
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
@@ -318,6 +487,12 @@ export async function getAgentTransferInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("permitNonces", accounts.permitNonces),
+      getAccountMeta("spendRequest", accounts.spendRequest),
+      getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta("tokenMintAccount", accounts.tokenMintAccount),
       getAccountMeta(
@@ -345,6 +520,12 @@ export async function getAgentTransferInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
+    TAccountSpendRequest,
+    TAccountInstructionsSysvar,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountDestinationTokenAccount,
@@ -360,6 +541,12 @@ export type AgentTransferInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountPermitNonces extends string = string,
+  TAccountSpendRequest extends string = string,
+  TAccountInstructionsSysvar extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountTokenMintAccount extends string = string,
   TAccountDestinationTokenAccount extends string = string,
@@ -374,6 +561,30 @@ export type AgentTransferInput<
   tracker: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /**
+   * Zero-copy DestinationSpendTracker — per-destination rolling spend.
+   * Required when the vault has one (`AgentVault::has_destination_tracker`).
+   */
+  destinationSpend?: Address<TAccountDestinationSpend>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
+  /**
+   * Permit nonce bitmap. Required, with `instructions_sysvar`, when the
+   * amount exceeds the per-transaction limit and an owner permit is used.
+   */
+  permitNonces?: Address<TAccountPermitNonces>;
+  /**
+   * Approved spend request authorizing this over-limit transfer.
+   * Consumed (closed) by this instruction.
+   */
+  spendRequest?: Address<TAccountSpendRequest>;
+  /** Instructions sysvar, for locating the owner permit. */
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   /** Vault's PDA-owned token account (source) */
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
   /** Token mint account for decimals validation */
@@ -395,6 +606,12 @@ export function getAgentTransferInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
+  TAccountAgentSettings extends string,
+  TAccountPermitNonces extends string,
+  TAccountSpendRequest extends string,
+  TAccountInstructionsSysvar extends string,
   TAccountVaultTokenAccount extends string,
   TAccountTokenMintAccount extends string,
   TAccountDestinationTokenAccount extends string,
@@ -409,6 +626,12 @@ export function getAgentTransferInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
+    TAccountSpendRequest,
+    TAccountInstructionsSysvar,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountDestinationTokenAccount,
@@ -424,6 +647,12 @@ export function getAgentTransferInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
+  TAccountDestinationSpend,
+  TAccountAgentSettings,
+  TAccountPermitNonces,
+  TAccountSpendRequest,
+  TAccountInstructionsSysvar,
   TAccountVaultTokenAccount,
   TAccountTokenMintAccount,
   TAccountDestinationTokenAccount,
@@ -444,6 +673,21 @@ export function getAgentTransferInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
+    permitNonces: { value: input.permitNonces ?? null, isWritable: true },
+    spendRequest: { value: input.spendRequest ?? null, isWritable: true },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -475,6 +719,10 @@ export function getAgentTransferInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.instructionsSysvar.value) {
+    accounts.instructionsSysvar.value =
+      "Sysvar1nstructions1111111111111111111111111" as Address<"Sysvar1nstructions1111111111111111111111111">;
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
@@ -488,6 +736,12 @@ export function getAgentTransferInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("permitNonces", accounts.permitNonces),
+      getAccountMeta("spendRequest", accounts.spendRequest),
+      getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta("tokenMintAccount", accounts.tokenMintAccount),
       getAccountMeta(
@@ -515,6 +769,12 @@ export function getAgentTransferInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
+    TAccountSpendRequest,
+    TAccountInstructionsSysvar,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountDestinationTokenAccount,
@@ -537,17 +797,41 @@ export type ParsedAgentTransferInstruction<
     tracker: TAccountMetas[3];
     /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
     agentSpendOverlay: TAccountMetas[4];
+    /**
+     * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+     * Required when the vault has one (`AgentVault::has_tracker_extension`).
+     */
+    trackerExtension?: TAccountMetas[5] | undefined;
+    /**
+     * Zero-copy DestinationSpendTracker — per-destination rolling spend.
+     * Required when the vault has one (`AgentVault::has_destination_tracker`).
+     */
+    destinationSpend?: TAccountMetas[6] | undefined;
+    /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+    agentSettings?: TAccountMetas[7] | undefined;
+    /**
+     * Permit nonce bitmap. Required, with `instructions_sysvar`, when the
+     * amount exceeds the per-transaction limit and an owner permit is used.
+     */
+    permitNonces?: TAccountMetas[8] | undefined;
+    /**
+     * Approved spend request authorizing this over-limit transfer.
+     * Consumed (closed) by this instruction.
+     */
+    spendRequest?: TAccountMetas[9] | undefined;
+    /** Instructions sysvar, for locating the owner permit. */
+    instructionsSysvar?: TAccountMetas[10] | undefined;
     /** Vault's PDA-owned token account (source) */
-    vaultTokenAccount: TAccountMetas[5];
+    vaultTokenAccount: TAccountMetas[11];
     /** Token mint account for decimals validation */
-    tokenMintAccount: TAccountMetas[6];
+    tokenMintAccount: TAccountMetas[12];
     /** Destination token account (must be in allowed destinations) */
-    destinationTokenAccount: TAccountMetas[7];
+    destinationTokenAccount: TAccountMetas[13];
     /** Developer fee destination token account */
-    feeDestinationTokenAccount?: TAccountMetas[8] | undefined;
+    feeDestinationTokenAccount?: TAccountMetas[14] | undefined;
     /** Protocol treasury token account */
-    protocolTreasuryTokenAccount?: TAccountMetas[9] | undefined;
-    tokenProgram: TAccountMetas[10];
+    protocolTreasuryTokenAccount?: TAccountMetas[15] | undefined;
+    tokenProgram: TAccountMetas[16];
   };
   data: AgentTransferInstructionData;
 };
@@ -560,12 +844,12 @@ export function parseAgentTransferInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedAgentTransferInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 17) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 11,
+        expectedAccountMetas: 17,
       },
     );
   }
@@ -589,6 +873,12 @@ export function parseAgentTransferInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextOptionalAccount(),
+      destinationSpend: getNextOptionalAccount(),
+      agentSettings: getNextOptionalAccount(),
+      permitNonces: getNextOptionalAccount(),
+      spendRequest: getNextOptionalAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
       vaultTokenAccount: getNextAccount(),
       tokenMintAccount: getNextAccount(),
       destinationTokenAccount: getNextAccount(),
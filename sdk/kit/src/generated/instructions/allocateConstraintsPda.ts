@@ -69,7 +69,7 @@ export type AllocateConstraintsPdaInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? ReadonlyAccount<TAccountPolicy>
@@ -163,7 +163,7 @@ export async function getAllocateConstraintsPdaInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     constraints: { value: input.constraints ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -275,7 +275,7 @@ export function getAllocateConstraintsPdaInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     constraints: { value: input.constraints ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
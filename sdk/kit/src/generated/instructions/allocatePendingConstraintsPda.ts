@@ -70,7 +70,7 @@ export type AllocatePendingConstraintsPdaInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? ReadonlyAccount<TAccountPolicy>
@@ -172,7 +172,7 @@ export async function getAllocatePendingConstraintsPdaInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     constraints: { value: input.constraints ?? null, isWritable: false },
     pendingConstraints: {
@@ -314,7 +314,7 @@ export function getAllocatePendingConstraintsPdaInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     constraints: { value: input.constraints ?? null, isWritable: false },
     pendingConstraints: {
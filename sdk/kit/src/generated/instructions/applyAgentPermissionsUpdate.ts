@@ -28,6 +28,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -57,6 +58,9 @@ export type ApplyAgentPermissionsUpdateInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountPendingAgentPerms extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountAgentSettings extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    "11111111111111111111111111111111",
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -78,6 +82,12 @@ export type ApplyAgentPermissionsUpdateInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountAgentSettings extends string
+        ? WritableAccount<TAccountAgentSettings>
+        : TAccountAgentSettings,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -120,6 +130,8 @@ export type ApplyAgentPermissionsUpdateAsyncInput<
   TAccountPolicy extends string = string,
   TAccountPendingAgentPerms extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
   vault: Address<TAccountVault>;
@@ -127,6 +139,12 @@ export type ApplyAgentPermissionsUpdateAsyncInput<
   pendingAgentPerms: Address<TAccountPendingAgentPerms>;
   /** Agent spend overlay — per-agent tracking slot. */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Per-agent settings. Required when the pending update carries a
+   * protocol subset or an override; created on first use.
+   */
+  agentSettings?: Address<TAccountAgentSettings>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export async function getApplyAgentPermissionsUpdateInstructionAsync<
@@ -135,6 +153,8 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
   TAccountPolicy extends string,
   TAccountPendingAgentPerms extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountAgentSettings extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
   input: ApplyAgentPermissionsUpdateAsyncInput<
@@ -142,7 +162,9 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
     TAccountVault,
     TAccountPolicy,
     TAccountPendingAgentPerms,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -152,7 +174,9 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
     TAccountVault,
     TAccountPolicy,
     TAccountPendingAgentPerms,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings,
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -171,6 +195,8 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -192,6 +218,10 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
       ],
     });
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -201,6 +231,8 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("pendingAgentPerms", accounts.pendingAgentPerms),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("systemProgram", accounts.systemProgram),
     ],
     data: getApplyAgentPermissionsUpdateInstructionDataEncoder().encode({}),
     programAddress,
@@ -210,7 +242,9 @@ export async function getApplyAgentPermissionsUpdateInstructionAsync<
     TAccountVault,
     TAccountPolicy,
     TAccountPendingAgentPerms,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings,
+    TAccountSystemProgram
   >);
 }
 
@@ -220,6 +254,8 @@ export type ApplyAgentPermissionsUpdateInput<
   TAccountPolicy extends string = string,
   TAccountPendingAgentPerms extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
   vault: Address<TAccountVault>;
@@ -227,6 +263,12 @@ export type ApplyAgentPermissionsUpdateInput<
   pendingAgentPerms: Address<TAccountPendingAgentPerms>;
   /** Agent spend overlay — per-agent tracking slot. */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Per-agent settings. Required when the pending update carries a
+   * protocol subset or an override; created on first use.
+   */
+  agentSettings?: Address<TAccountAgentSettings>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getApplyAgentPermissionsUpdateInstruction<
@@ -235,6 +277,8 @@ export function getApplyAgentPermissionsUpdateInstruction<
   TAccountPolicy extends string,
   TAccountPendingAgentPerms extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountAgentSettings extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
   input: ApplyAgentPermissionsUpdateInput<
@@ -242,7 +286,9 @@ export function getApplyAgentPermissionsUpdateInstruction<
     TAccountVault,
     TAccountPolicy,
     TAccountPendingAgentPerms,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): ApplyAgentPermissionsUpdateInstruction<
@@ -251,7 +297,9 @@ export function getApplyAgentPermissionsUpdateInstruction<
   TAccountVault,
   TAccountPolicy,
   TAccountPendingAgentPerms,
-  TAccountAgentSpendOverlay
+  TAccountAgentSpendOverlay,
+  TAccountAgentSettings,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? SIGIL_PROGRAM_ADDRESS;
@@ -269,12 +317,20 @@ export function getApplyAgentPermissionsUpdateInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedInstructionAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
@@ -283,6 +339,8 @@ export function getApplyAgentPermissionsUpdateInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("pendingAgentPerms", accounts.pendingAgentPerms),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("systemProgram", accounts.systemProgram),
     ],
     data: getApplyAgentPermissionsUpdateInstructionDataEncoder().encode({}),
     programAddress,
@@ -292,7 +350,9 @@ export function getApplyAgentPermissionsUpdateInstruction<
     TAccountVault,
     TAccountPolicy,
     TAccountPendingAgentPerms,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings,
+    TAccountSystemProgram
   >);
 }
 
@@ -308,6 +368,12 @@ export type ParsedApplyAgentPermissionsUpdateInstruction<
     pendingAgentPerms: TAccountMetas[3];
     /** Agent spend overlay — per-agent tracking slot. */
     agentSpendOverlay: TAccountMetas[4];
+    /**
+     * Per-agent settings. Required when the pending update carries a
+     * protocol subset or an override; created on first use.
+     */
+    agentSettings?: TAccountMetas[5] | undefined;
+    systemProgram: TAccountMetas[6];
   };
   data: ApplyAgentPermissionsUpdateInstructionData;
 };
@@ -320,12 +386,12 @@ export function parseApplyAgentPermissionsUpdateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedApplyAgentPermissionsUpdateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 5,
+        expectedAccountMetas: 7,
       },
     );
   }
@@ -335,6 +401,12 @@ export function parseApplyAgentPermissionsUpdateInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SIGIL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -343,6 +415,8 @@ export function parseApplyAgentPermissionsUpdateInstruction<
       policy: getNextAccount(),
       pendingAgentPerms: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      agentSettings: getNextOptionalAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getApplyAgentPermissionsUpdateInstructionDataDecoder().decode(
       instruction.data,
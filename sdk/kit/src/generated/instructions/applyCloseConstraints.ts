@@ -28,7 +28,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -68,7 +67,7 @@ export type ApplyCloseConstraintsInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? WritableAccount<TAccountPolicy>
@@ -161,7 +160,7 @@ export async function getApplyCloseConstraintsInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     constraints: { value: input.constraints ?? null, isWritable: true },
     pendingCloseConstraints: {
@@ -293,7 +292,7 @@ export function getApplyCloseConstraintsInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     constraints: { value: input.constraints ?? null, isWritable: true },
     pendingCloseConstraints: {
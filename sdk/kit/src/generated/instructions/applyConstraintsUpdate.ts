@@ -28,7 +28,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -68,7 +67,7 @@ export type ApplyConstraintsUpdateInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? WritableAccount<TAccountPolicy>
@@ -162,7 +161,7 @@ export async function getApplyConstraintsUpdateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     constraints: { value: input.constraints ?? null, isWritable: true },
     pendingConstraints: {
@@ -292,7 +291,7 @@ export function getApplyConstraintsUpdateInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     constraints: { value: input.constraints ?? null, isWritable: true },
     pendingConstraints: {
@@ -26,7 +26,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -63,7 +62,7 @@ export type CancelAgentPermissionsUpdateInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPendingAgentPerms extends string
         ? WritableAccount<TAccountPendingAgentPerms>
@@ -138,7 +137,7 @@ export function getCancelAgentPermissionsUpdateInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     pendingAgentPerms: {
       value: input.pendingAgentPerms ?? null,
       isWritable: true,
@@ -28,7 +28,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -66,7 +65,7 @@ export type CancelCloseConstraintsInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPendingCloseConstraints extends string
         ? WritableAccount<TAccountPendingCloseConstraints>
@@ -143,7 +142,7 @@ export async function getCancelCloseConstraintsInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     pendingCloseConstraints: {
       value: input.pendingCloseConstraints ?? null,
       isWritable: true,
@@ -229,7 +228,7 @@ export function getCancelCloseConstraintsInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     pendingCloseConstraints: {
       value: input.pendingCloseConstraints ?? null,
       isWritable: true,
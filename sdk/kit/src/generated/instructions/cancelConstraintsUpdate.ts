@@ -28,7 +28,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -66,7 +65,7 @@ export type CancelConstraintsUpdateInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPendingConstraints extends string
         ? WritableAccount<TAccountPendingConstraints>
@@ -143,7 +142,7 @@ export async function getCancelConstraintsUpdateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     pendingConstraints: {
       value: input.pendingConstraints ?? null,
       isWritable: true,
@@ -226,7 +225,7 @@ export function getCancelConstraintsUpdateInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     pendingConstraints: {
       value: input.pendingConstraints ?? null,
       isWritable: true,
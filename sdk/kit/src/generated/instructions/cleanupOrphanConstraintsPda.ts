@@ -67,7 +67,7 @@ export type CleanupOrphanConstraintsPdaInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? ReadonlyAccount<TAccountPolicy>
@@ -156,7 +156,7 @@ export async function getCleanupOrphanConstraintsPdaInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     orphanPda: { value: input.orphanPda ?? null, isWritable: true },
   };
@@ -259,7 +259,7 @@ export function getCleanupOrphanConstraintsPdaInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     orphanPda: { value: input.orphanPda ?? null, isWritable: true },
   };
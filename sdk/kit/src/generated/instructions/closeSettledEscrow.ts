@@ -70,7 +70,7 @@ export type CloseSettledEscrowInstruction<
             AccountSignerMeta<TAccountSigner>
         : TAccountSigner,
       TAccountSourceVault extends string
-        ? ReadonlyAccount<TAccountSourceVault>
+        ? WritableAccount<TAccountSourceVault>
         : TAccountSourceVault,
       TAccountDestinationVaultKey extends string
         ? ReadonlyAccount<TAccountDestinationVaultKey>
@@ -167,7 +167,7 @@ export async function getCloseSettledEscrowInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
-    sourceVault: { value: input.sourceVault ?? null, isWritable: false },
+    sourceVault: { value: input.sourceVault ?? null, isWritable: true },
     destinationVaultKey: {
       value: input.destinationVaultKey ?? null,
       isWritable: false,
@@ -272,7 +272,7 @@ export function getCloseSettledEscrowInstruction<
   // Original accounts.
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
-    sourceVault: { value: input.sourceVault ?? null, isWritable: false },
+    sourceVault: { value: input.sourceVault ?? null, isWritable: true },
     destinationVaultKey: {
       value: input.destinationVaultKey ?? null,
       isWritable: false,
@@ -56,6 +56,8 @@ export type CloseVaultInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
+  TAccountDestinationSpend extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -79,6 +81,12 @@ export type CloseVaultInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
+      TAccountDestinationSpend extends string
+        ? WritableAccount<TAccountDestinationSpend>
+        : TAccountDestinationSpend,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -119,6 +127,8 @@ export type CloseVaultAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
@@ -128,6 +138,17 @@ export type CloseVaultAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — close returns rent to owner */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — close returns rent to owner.
+   * Optional: a vault rewritten by migrate_vault has none until the owner
+   * calls initialize_tracker_extension.
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /**
+   * Zero-copy DestinationSpendTracker — close returns rent to owner.
+   * Optional for the same reason as `tracker_extension`.
+   */
+  destinationSpend?: Address<TAccountDestinationSpend>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -137,6 +158,8 @@ export async function getCloseVaultInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
@@ -146,6 +169,8 @@ export async function getCloseVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
@@ -157,6 +182,8 @@ export async function getCloseVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountSystemProgram
   >
 > {
@@ -173,6 +200,14 @@ export async function getCloseVaultInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -211,6 +246,41 @@ export async function getCloseVaultInstructionAsync<
       ],
     });
   }
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.destinationSpend.value) {
+    accounts.destinationSpend.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 95, 115, 112,
+            101, 110, 100,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
@@ -224,6 +294,8 @@ export async function getCloseVaultInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
       getAccountMeta("systemProgram", accounts.systemProgram),
     ],
     data: getCloseVaultInstructionDataEncoder().encode({}),
@@ -235,6 +307,8 @@ export async function getCloseVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountSystemProgram
   >);
 }
@@ -245,6 +319,8 @@ export type CloseVaultInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
@@ -254,6 +330,17 @@ export type CloseVaultInput<
   tracker: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — close returns rent to owner */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — close returns rent to owner.
+   * Optional: a vault rewritten by migrate_vault has none until the owner
+   * calls initialize_tracker_extension.
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /**
+   * Zero-copy DestinationSpendTracker — close returns rent to owner.
+   * Optional for the same reason as `tracker_extension`.
+   */
+  destinationSpend?: Address<TAccountDestinationSpend>;
   systemProgram?: Address<TAccountSystemProgram>;
 };
 
@@ -263,6 +350,8 @@ export function getCloseVaultInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
@@ -272,6 +361,8 @@ export function getCloseVaultInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
@@ -282,6 +373,8 @@ export function getCloseVaultInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
+  TAccountDestinationSpend,
   TAccountSystemProgram
 > {
   // Program address.
@@ -297,6 +390,14 @@ export function getCloseVaultInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -318,6 +419,8 @@ export function getCloseVaultInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
       getAccountMeta("systemProgram", accounts.systemProgram),
     ],
     data: getCloseVaultInstructionDataEncoder().encode({}),
@@ -329,6 +432,8 @@ export function getCloseVaultInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountSystemProgram
   >);
 }
@@ -346,7 +451,18 @@ export type ParsedCloseVaultInstruction<
     tracker: TAccountMetas[3];
     /** Zero-copy AgentSpendOverlay — close returns rent to owner */
     agentSpendOverlay: TAccountMetas[4];
-    systemProgram: TAccountMetas[5];
+    /**
+     * Zero-copy SpendTrackerExtension — close returns rent to owner.
+     * Optional: a vault rewritten by migrate_vault has none until the owner
+     * calls initialize_tracker_extension.
+     */
+    trackerExtension?: TAccountMetas[5] | undefined;
+    /**
+     * Zero-copy DestinationSpendTracker — close returns rent to owner.
+     * Optional for the same reason as `tracker_extension`.
+     */
+    destinationSpend?: TAccountMetas[6] | undefined;
+    systemProgram: TAccountMetas[7];
   };
   data: CloseVaultInstructionData;
 };
@@ -359,12 +475,12 @@ export function parseCloseVaultInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCloseVaultInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 8) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 6,
+        expectedAccountMetas: 8,
       },
     );
   }
@@ -374,6 +490,12 @@ export function parseCloseVaultInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SIGIL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -382,6 +504,8 @@ export function parseCloseVaultInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextOptionalAccount(),
+      destinationSpend: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getCloseVaultInstructionDataDecoder().decode(instruction.data),
@@ -63,6 +63,8 @@ export type CreateEscrowInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
+  TAccountAgentSettings extends string | AccountMeta<string> = string,
   TAccountDestinationVault extends string | AccountMeta<string> = string,
   TAccountEscrow extends string | AccountMeta<string> = string,
   TAccountSourceVaultAta extends string | AccountMeta<string> = string,
@@ -97,6 +99,12 @@ export type CreateEscrowInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
+      TAccountAgentSettings extends string
+        ? ReadonlyAccount<TAccountAgentSettings>
+        : TAccountAgentSettings,
       TAccountDestinationVault extends string
         ? ReadonlyAccount<TAccountDestinationVault>
         : TAccountDestinationVault,
@@ -185,6 +193,8 @@ export type CreateEscrowAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountAgentSettings extends string = string,
   TAccountDestinationVault extends string = string,
   TAccountEscrow extends string = string,
   TAccountSourceVaultAta extends string = string,
@@ -202,6 +212,13 @@ export type CreateEscrowAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
   destinationVault: Address<TAccountDestinationVault>;
   escrow?: Address<TAccountEscrow>;
   /** Source vault's token account (vault PDA is authority) */
@@ -228,6 +245,8 @@ export async function getCreateEscrowInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountAgentSettings extends string,
   TAccountDestinationVault extends string,
   TAccountEscrow extends string,
   TAccountSourceVaultAta extends string,
@@ -246,6 +265,8 @@ export async function getCreateEscrowInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
     TAccountDestinationVault,
     TAccountEscrow,
     TAccountSourceVaultAta,
@@ -266,6 +287,8 @@ export async function getCreateEscrowInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
     TAccountDestinationVault,
     TAccountEscrow,
     TAccountSourceVaultAta,
@@ -291,6 +314,11 @@ export async function getCreateEscrowInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
     destinationVault: {
       value: input.destinationVault ?? null,
       isWritable: false,
@@ -353,6 +381,46 @@ export async function getCreateEscrowInstructionAsync<
       ],
     });
   }
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "sourceVault",
+            accounts.sourceVault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.agentSettings.value) {
+    accounts.agentSettings.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            97, 103, 101, 110, 116, 95, 115, 101, 116, 116, 105, 110, 103, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "sourceVault",
+            accounts.sourceVault.value,
+          ),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "agent",
+            accounts.agent.value,
+          ),
+        ),
+      ],
+    });
+  }
   if (!accounts.escrow.value) {
     accounts.escrow.value = await getProgramDerivedAddress({
       programAddress,
@@ -424,6 +492,8 @@ export async function getCreateEscrowInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("agentSettings", accounts.agentSettings),
       getAccountMeta("destinationVault", accounts.destinationVault),
       getAccountMeta("escrow", accounts.escrow),
       getAccountMeta("sourceVaultAta", accounts.sourceVaultAta),
@@ -446,6 +516,8 @@ export async function getCreateEscrowInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
     TAccountDestinationVault,
     TAccountEscrow,
     TAccountSourceVaultAta,
@@ -465,6 +537,8 @@ export type CreateEscrowInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountAgentSettings extends string = string,
   TAccountDestinationVault extends string = string,
   TAccountEscrow extends string = string,
   TAccountSourceVaultAta extends string = string,
@@ -482,6 +556,13 @@ export type CreateEscrowInput<
   tracker: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
   destinationVault: Address<TAccountDestinationVault>;
   escrow: Address<TAccountEscrow>;
   /** Source vault's token account (vault PDA is authority) */
@@ -508,6 +589,8 @@ export function getCreateEscrowInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountAgentSettings extends string,
   TAccountDestinationVault extends string,
   TAccountEscrow extends string,
   TAccountSourceVaultAta extends string,
@@ -526,6 +609,8 @@ export function getCreateEscrowInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
     TAccountDestinationVault,
     TAccountEscrow,
     TAccountSourceVaultAta,
@@ -545,6 +630,8 @@ export function getCreateEscrowInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
+  TAccountAgentSettings,
   TAccountDestinationVault,
   TAccountEscrow,
   TAccountSourceVaultAta,
@@ -569,6 +656,11 @@ export function getCreateEscrowInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
     destinationVault: {
       value: input.destinationVault ?? null,
       isWritable: false,
@@ -622,6 +714,8 @@ export function getCreateEscrowInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("agentSettings", accounts.agentSettings),
       getAccountMeta("destinationVault", accounts.destinationVault),
       getAccountMeta("escrow", accounts.escrow),
       getAccountMeta("sourceVaultAta", accounts.sourceVaultAta),
@@ -644,6 +738,8 @@ export function getCreateEscrowInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
     TAccountDestinationVault,
     TAccountEscrow,
     TAccountSourceVaultAta,
@@ -669,20 +765,27 @@ export type ParsedCreateEscrowInstruction<
     tracker: TAccountMetas[3];
     /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
     agentSpendOverlay: TAccountMetas[4];
-    destinationVault: TAccountMetas[5];
-    escrow: TAccountMetas[6];
+    /**
+     * Zero-copy SpendTrackerExtension — weekly/monthly windows.
+     * Required when the vault has one (`AgentVault::has_tracker_extension`).
+     */
+    trackerExtension?: TAccountMetas[5] | undefined;
+    /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+    agentSettings?: TAccountMetas[6] | undefined;
+    destinationVault: TAccountMetas[7];
+    escrow: TAccountMetas[8];
     /** Source vault's token account (vault PDA is authority) */
-    sourceVaultAta: TAccountMetas[7];
+    sourceVaultAta: TAccountMetas[9];
     /** Escrow-owned ATA — init_if_needed because escrow PDA is created in same ix */
-    escrowAta: TAccountMetas[8];
+    escrowAta: TAccountMetas[10];
     /** Protocol treasury token account (needed when protocol_fee > 0) */
-    protocolTreasuryAta?: TAccountMetas[9] | undefined;
+    protocolTreasuryAta?: TAccountMetas[11] | undefined;
     /** Developer fee destination token account (needed when developer_fee > 0) */
-    feeDestinationAta?: TAccountMetas[10] | undefined;
-    tokenMint: TAccountMetas[11];
-    tokenProgram: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
-    associatedTokenProgram: TAccountMetas[14];
+    feeDestinationAta?: TAccountMetas[12] | undefined;
+    tokenMint: TAccountMetas[13];
+    tokenProgram: TAccountMetas[14];
+    systemProgram: TAccountMetas[15];
+    associatedTokenProgram: TAccountMetas[16];
   };
   data: CreateEscrowInstructionData;
 };
@@ -695,12 +798,12 @@ export function parseCreateEscrowInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateEscrowInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 15) {
+  if (instruction.accounts.length < 17) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 15,
+        expectedAccountMetas: 17,
       },
     );
   }
@@ -724,6 +827,8 @@ export function parseCreateEscrowInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextOptionalAccount(),
+      agentSettings: getNextOptionalAccount(),
       destinationVault: getNextAccount(),
       escrow: getNextAccount(),
       sourceVaultAta: getNextAccount(),
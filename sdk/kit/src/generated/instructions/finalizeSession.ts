@@ -60,8 +60,11 @@ export type FinalizeSessionInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
   TAccountVaultTokenAccount extends string | AccountMeta<string> = string,
   TAccountOutputStablecoinAccount extends string | AccountMeta<string> = string,
+  TAccountOutputTokenAccount extends string | AccountMeta<string> = string,
+  TAccountPendingFulfillment extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountSystemProgram extends string | AccountMeta<string> =
@@ -95,12 +98,21 @@ export type FinalizeSessionInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
       TAccountVaultTokenAccount extends string
         ? WritableAccount<TAccountVaultTokenAccount>
         : TAccountVaultTokenAccount,
       TAccountOutputStablecoinAccount extends string
         ? WritableAccount<TAccountOutputStablecoinAccount>
         : TAccountOutputStablecoinAccount,
+      TAccountOutputTokenAccount extends string
+        ? ReadonlyAccount<TAccountOutputTokenAccount>
+        : TAccountOutputTokenAccount,
+      TAccountPendingFulfillment extends string
+        ? WritableAccount<TAccountPendingFulfillment>
+        : TAccountPendingFulfillment,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -151,8 +163,11 @@ export type FinalizeSessionAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountOutputStablecoinAccount extends string = string,
+  TAccountOutputTokenAccount extends string = string,
+  TAccountPendingFulfillment extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -171,6 +186,11 @@ export type FinalizeSessionAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows and fee budget.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
   /** Vault's PDA token account for the session's token */
   vaultTokenAccount?: Address<TAccountVaultTokenAccount>;
   /**
@@ -178,6 +198,16 @@ export type FinalizeSessionAsyncInput<
    * Required when session.output_mint != Pubkey::default() (all spending).
    */
   outputStablecoinAccount?: Address<TAccountOutputStablecoinAccount>;
+  /**
+   * Vault's token account for the declared expected output. Required when
+   * a stablecoin-input session declared one.
+   */
+  outputTokenAccount?: Address<TAccountOutputTokenAccount>;
+  /**
+   * Spend reservation for async-fulfillment sessions. Required exactly when
+   * session.async_fulfillment is set; stays open until reconcile_fulfillment.
+   */
+  pendingFulfillment?: Address<TAccountPendingFulfillment>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   /** Instructions sysvar for post-finalize instruction verification. */
@@ -192,8 +222,11 @@ export async function getFinalizeSessionInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
   TAccountVaultTokenAccount extends string,
   TAccountOutputStablecoinAccount extends string,
+  TAccountOutputTokenAccount extends string,
+  TAccountPendingFulfillment extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
@@ -207,8 +240,11 @@ export async function getFinalizeSessionInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
     TAccountVaultTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
+    TAccountPendingFulfillment,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -224,8 +260,11 @@ export async function getFinalizeSessionInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
     TAccountVaultTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
+    TAccountPendingFulfillment,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -249,6 +288,10 @@ export async function getFinalizeSessionInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -257,6 +300,14 @@ export async function getFinalizeSessionInstructionAsync<
       value: input.outputStablecoinAccount ?? null,
       isWritable: true,
     },
+    outputTokenAccount: {
+      value: input.outputTokenAccount ?? null,
+      isWritable: false,
+    },
+    pendingFulfillment: {
+      value: input.pendingFulfillment ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
@@ -300,6 +351,47 @@ export async function getFinalizeSessionInstructionAsync<
       ],
     });
   }
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.pendingFulfillment.value) {
+    accounts.pendingFulfillment.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 101, 110, 100, 105, 110, 103, 95, 102, 117, 108, 102, 105, 108,
+            108, 109, 101, 110, 116,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "session",
+            accounts.session.value,
+          ),
+        ),
+      ],
+    });
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
@@ -323,11 +415,14 @@ export async function getFinalizeSessionInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta(
         "outputStablecoinAccount",
         accounts.outputStablecoinAccount,
       ),
+      getAccountMeta("outputTokenAccount", accounts.outputTokenAccount),
+      getAccountMeta("pendingFulfillment", accounts.pendingFulfillment),
       getAccountMeta("tokenProgram", accounts.tokenProgram),
       getAccountMeta("systemProgram", accounts.systemProgram),
       getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
@@ -343,8 +438,11 @@ export async function getFinalizeSessionInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
     TAccountVaultTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
+    TAccountPendingFulfillment,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -359,8 +457,11 @@ export type FinalizeSessionInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountOutputStablecoinAccount extends string = string,
+  TAccountOutputTokenAccount extends string = string,
+  TAccountPendingFulfillment extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -379,6 +480,11 @@ export type FinalizeSessionInput<
   tracker: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows and fee budget.
+   * Required when the vault has one (`AgentVault::has_tracker_extension`).
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
   /** Vault's PDA token account for the session's token */
   vaultTokenAccount?: Address<TAccountVaultTokenAccount>;
   /**
@@ -386,6 +492,16 @@ export type FinalizeSessionInput<
    * Required when session.output_mint != Pubkey::default() (all spending).
    */
   outputStablecoinAccount?: Address<TAccountOutputStablecoinAccount>;
+  /**
+   * Vault's token account for the declared expected output. Required when
+   * a stablecoin-input session declared one.
+   */
+  outputTokenAccount?: Address<TAccountOutputTokenAccount>;
+  /**
+   * Spend reservation for async-fulfillment sessions. Required exactly when
+   * session.async_fulfillment is set; stays open until reconcile_fulfillment.
+   */
+  pendingFulfillment?: Address<TAccountPendingFulfillment>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   /** Instructions sysvar for post-finalize instruction verification. */
@@ -400,8 +516,11 @@ export function getFinalizeSessionInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
   TAccountVaultTokenAccount extends string,
   TAccountOutputStablecoinAccount extends string,
+  TAccountOutputTokenAccount extends string,
+  TAccountPendingFulfillment extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
@@ -415,8 +534,11 @@ export function getFinalizeSessionInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
     TAccountVaultTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
+    TAccountPendingFulfillment,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -431,8 +553,11 @@ export function getFinalizeSessionInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
   TAccountVaultTokenAccount,
   TAccountOutputStablecoinAccount,
+  TAccountOutputTokenAccount,
+  TAccountPendingFulfillment,
   TAccountTokenProgram,
   TAccountSystemProgram,
   TAccountInstructionsSysvar
@@ -455,6 +580,10 @@ export function getFinalizeSessionInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -463,6 +592,14 @@ export function getFinalizeSessionInstruction<
       value: input.outputStablecoinAccount ?? null,
       isWritable: true,
     },
+    outputTokenAccount: {
+      value: input.outputTokenAccount ?? null,
+      isWritable: false,
+    },
+    pendingFulfillment: {
+      value: input.pendingFulfillment ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
@@ -499,11 +636,14 @@ export function getFinalizeSessionInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta(
         "outputStablecoinAccount",
         accounts.outputStablecoinAccount,
       ),
+      getAccountMeta("outputTokenAccount", accounts.outputTokenAccount),
+      getAccountMeta("pendingFulfillment", accounts.pendingFulfillment),
       getAccountMeta("tokenProgram", accounts.tokenProgram),
       getAccountMeta("systemProgram", accounts.systemProgram),
       getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
@@ -519,8 +659,11 @@ export function getFinalizeSessionInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
     TAccountVaultTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
+    TAccountPendingFulfillment,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -547,17 +690,32 @@ export type ParsedFinalizeSessionInstruction<
     tracker: TAccountMetas[5];
     /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
     agentSpendOverlay: TAccountMetas[6];
+    /**
+     * Zero-copy SpendTrackerExtension — weekly/monthly windows and fee budget.
+     * Required when the vault has one (`AgentVault::has_tracker_extension`).
+     */
+    trackerExtension?: TAccountMetas[7] | undefined;
     /** Vault's PDA token account for the session's token */
-    vaultTokenAccount?: TAccountMetas[7] | undefined;
+    vaultTokenAccount?: TAccountMetas[8] | undefined;
     /**
      * Vault's stablecoin ATA for outcome-based spending verification.
      * Required when session.output_mint != Pubkey::default() (all spending).
      */
-    outputStablecoinAccount?: TAccountMetas[8] | undefined;
-    tokenProgram: TAccountMetas[9];
-    systemProgram: TAccountMetas[10];
+    outputStablecoinAccount?: TAccountMetas[9] | undefined;
+    /**
+     * Vault's token account for the declared expected output. Required when
+     * a stablecoin-input session declared one.
+     */
+    outputTokenAccount?: TAccountMetas[10] | undefined;
+    /**
+     * Spend reservation for async-fulfillment sessions. Required exactly when
+     * session.async_fulfillment is set; stays open until reconcile_fulfillment.
+     */
+    pendingFulfillment?: TAccountMetas[11] | undefined;
+    tokenProgram: TAccountMetas[12];
+    systemProgram: TAccountMetas[13];
     /** Instructions sysvar for post-finalize instruction verification. */
-    instructionsSysvar: TAccountMetas[11];
+    instructionsSysvar: TAccountMetas[14];
   };
   data: FinalizeSessionInstructionData;
 };
@@ -570,12 +728,12 @@ export function parseFinalizeSessionInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedFinalizeSessionInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 15) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 12,
+        expectedAccountMetas: 15,
       },
     );
   }
@@ -601,8 +759,11 @@ export function parseFinalizeSessionInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextOptionalAccount(),
       vaultTokenAccount: getNextOptionalAccount(),
       outputStablecoinAccount: getNextOptionalAccount(),
+      outputTokenAccount: getNextOptionalAccount(),
+      pendingFulfillment: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       instructionsSysvar: getNextAccount(),
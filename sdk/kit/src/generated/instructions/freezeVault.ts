@@ -50,7 +50,7 @@ export function getFreezeVaultDiscriminatorBytes() {
 
 export type FreezeVaultInstruction<
   TProgram extends string = typeof SIGIL_PROGRAM_ADDRESS,
-  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
@@ -59,10 +59,10 @@ export type FreezeVaultInstruction<
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
-            AccountSignerMeta<TAccountOwner>
-        : TAccountOwner,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            AccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -101,26 +101,31 @@ export function getFreezeVaultInstructionDataCodec(): FixedSizeCodec<
 }
 
 export type FreezeVaultInput<
-  TAccountOwner extends string = string,
+  TAccountAuthority extends string = string,
   TAccountVault extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
-  owner: TransactionSigner<TAccountOwner>;
+  /** Vault owner or guardian */
+  authority: TransactionSigner<TAccountAuthority>;
   vault: Address<TAccountVault>;
   tokenProgram?: Address<TAccountTokenProgram>;
 };
 
 export function getFreezeVaultInstruction<
-  TAccountOwner extends string,
+  TAccountAuthority extends string,
   TAccountVault extends string,
   TAccountTokenProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
-  input: FreezeVaultInput<TAccountOwner, TAccountVault, TAccountTokenProgram>,
+  input: FreezeVaultInput<
+    TAccountAuthority,
+    TAccountVault,
+    TAccountTokenProgram
+  >,
   config?: { programAddress?: TProgramAddress },
 ): FreezeVaultInstruction<
   TProgramAddress,
-  TAccountOwner,
+  TAccountAuthority,
   TAccountVault,
   TAccountTokenProgram
 > {
@@ -129,7 +134,7 @@ export function getFreezeVaultInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    owner: { value: input.owner ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
@@ -147,7 +152,7 @@ export function getFreezeVaultInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta("owner", accounts.owner),
+      getAccountMeta("authority", accounts.authority),
       getAccountMeta("vault", accounts.vault),
       getAccountMeta("tokenProgram", accounts.tokenProgram),
     ],
@@ -155,7 +160,7 @@ export function getFreezeVaultInstruction<
     programAddress,
   } as FreezeVaultInstruction<
     TProgramAddress,
-    TAccountOwner,
+    TAccountAuthority,
     TAccountVault,
     TAccountTokenProgram
   >);
@@ -167,7 +172,8 @@ export type ParsedFreezeVaultInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    owner: TAccountMetas[0];
+    /** Vault owner or guardian */
+    authority: TAccountMetas[0];
     vault: TAccountMetas[1];
     tokenProgram: TAccountMetas[2];
   };
@@ -200,7 +206,7 @@ export function parseFreezeVaultInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: {
-      owner: getNextAccount(),
+      authority: getNextAccount(),
       vault: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
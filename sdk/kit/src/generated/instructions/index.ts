@@ -10,35 +10,59 @@ export * from "./agentTransfer.js";
 export * from "./allocateConstraintsPda.js";
 export * from "./allocatePendingConstraintsPda.js";
 export * from "./applyAgentPermissionsUpdate.js";
+export * from "./applyBundle.js";
 export * from "./applyCloseConstraints.js";
 export * from "./applyConstraintsUpdate.js";
+export * from "./applyFeeDestinationUpdate.js";
+export * from "./applyOwnerTransfer.js";
 export * from "./applyPendingPolicy.js";
+export * from "./approveSpendRequest.js";
 export * from "./cancelAgentPermissionsUpdate.js";
 export * from "./cancelCloseConstraints.js";
 export * from "./cancelConstraintsUpdate.js";
+export * from "./cancelFeeDestinationUpdate.js";
+export * from "./cancelGovernanceBundle.js";
+export * from "./cancelOwnerTransfer.js";
 export * from "./cancelPendingPolicy.js";
+export * from "./cancelRecovery.js";
+export * from "./cancelSpendRequest.js";
+export * from "./claimBeneficiaryFunds.js";
 export * from "./cleanupOrphanConstraintsPda.js";
 export * from "./closePostAssertions.js";
 export * from "./closeSettledEscrow.js";
 export * from "./closeVault.js";
 export * from "./createEscrow.js";
+export * from "./createGovernanceBundle.js";
 export * from "./createInstructionConstraints.js";
 export * from "./createPostAssertions.js";
+export * from "./createSpendRequest.js";
 export * from "./depositFunds.js";
+export * from "./executeRecovery.js";
 export * from "./extendPda.js";
 export * from "./finalizeSession.js";
 export * from "./freezeVault.js";
+export * from "./initializeDestinationTracker.js";
+export * from "./initializePermitNonces.js";
+export * from "./initializeTrackerExtension.js";
 export * from "./initializeVault.js";
+export * from "./migrateVault.js";
 export * from "./pauseAgent.js";
 export * from "./queueAgentPermissionsUpdate.js";
 export * from "./queueCloseConstraints.js";
 export * from "./queueConstraintsUpdate.js";
+export * from "./queueFeeDestinationUpdate.js";
+export * from "./queueOwnerTransfer.js";
 export * from "./queuePolicyUpdate.js";
+export * from "./queueRecovery.js";
 export * from "./reactivateVault.js";
+export * from "./reconcileFulfillment.js";
 export * from "./refundEscrow.js";
 export * from "./registerAgent.js";
 export * from "./revokeAgent.js";
+export * from "./rotateAgentKey.js";
 export * from "./settleEscrow.js";
+export * from "./tightenPolicy.js";
+export * from "./triggerDeadManSwitch.js";
 export * from "./unpauseAgent.js";
 export * from "./validateAndAuthorize.js";
 export * from "./withdrawFunds.js";
@@ -68,6 +68,8 @@ export type InitializeVaultInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
+  TAccountDestinationSpend extends string | AccountMeta<string> = string,
   TAccountFeeDestination extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
@@ -92,6 +94,12 @@ export type InitializeVaultInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
+      TAccountDestinationSpend extends string
+        ? WritableAccount<TAccountDestinationSpend>
+        : TAccountDestinationSpend,
       TAccountFeeDestination extends string
         ? ReadonlyAccount<TAccountFeeDestination>
         : TAccountFeeDestination,
@@ -180,6 +188,8 @@ export type InitializeVaultAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
   TAccountFeeDestination extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -190,6 +200,10 @@ export type InitializeVaultAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Agent spend overlay — per-agent contribution tracking */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /** Zero-copy weekly/monthly spend windows */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /** Zero-copy per-destination spend windows for agent_transfer */
+  destinationSpend?: Address<TAccountDestinationSpend>;
   feeDestination: Address<TAccountFeeDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
   vaultId: InitializeVaultInstructionDataArgs["vaultId"];
@@ -210,6 +224,8 @@ export async function getInitializeVaultInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
   TAccountFeeDestination extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
@@ -220,6 +236,8 @@ export async function getInitializeVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountFeeDestination,
     TAccountSystemProgram
   >,
@@ -232,6 +250,8 @@ export async function getInitializeVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountFeeDestination,
     TAccountSystemProgram
   >
@@ -249,6 +269,14 @@ export async function getInitializeVaultInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
     feeDestination: { value: input.feeDestination ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -308,6 +336,41 @@ export async function getInitializeVaultInstructionAsync<
       ],
     });
   }
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.destinationSpend.value) {
+    accounts.destinationSpend.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 95, 115, 112,
+            101, 110, 100,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
@@ -321,6 +384,8 @@ export async function getInitializeVaultInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
       getAccountMeta("feeDestination", accounts.feeDestination),
       getAccountMeta("systemProgram", accounts.systemProgram),
     ],
@@ -335,6 +400,8 @@ export async function getInitializeVaultInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountFeeDestination,
     TAccountSystemProgram
   >);
@@ -346,6 +413,8 @@ export type InitializeVaultInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountDestinationSpend extends string = string,
   TAccountFeeDestination extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -356,6 +425,10 @@ export type InitializeVaultInput<
   tracker: Address<TAccountTracker>;
   /** Agent spend overlay — per-agent contribution tracking */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /** Zero-copy weekly/monthly spend windows */
+  trackerExtension: Address<TAccountTrackerExtension>;
+  /** Zero-copy per-destination spend windows for agent_transfer */
+  destinationSpend: Address<TAccountDestinationSpend>;
   feeDestination: Address<TAccountFeeDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
   vaultId: InitializeVaultInstructionDataArgs["vaultId"];
@@ -376,6 +449,8 @@ export function getInitializeVaultInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountDestinationSpend extends string,
   TAccountFeeDestination extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
@@ -386,6 +461,8 @@ export function getInitializeVaultInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountFeeDestination,
     TAccountSystemProgram
   >,
@@ -397,6 +474,8 @@ export function getInitializeVaultInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
+  TAccountDestinationSpend,
   TAccountFeeDestination,
   TAccountSystemProgram
 > {
@@ -413,6 +492,14 @@ export function getInitializeVaultInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    destinationSpend: {
+      value: input.destinationSpend ?? null,
+      isWritable: true,
+    },
     feeDestination: { value: input.feeDestination ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -438,6 +525,8 @@ export function getInitializeVaultInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("destinationSpend", accounts.destinationSpend),
       getAccountMeta("feeDestination", accounts.feeDestination),
       getAccountMeta("systemProgram", accounts.systemProgram),
     ],
@@ -452,6 +541,8 @@ export function getInitializeVaultInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountDestinationSpend,
     TAccountFeeDestination,
     TAccountSystemProgram
   >);
@@ -470,8 +561,12 @@ export type ParsedInitializeVaultInstruction<
     tracker: TAccountMetas[3];
     /** Agent spend overlay — per-agent contribution tracking */
     agentSpendOverlay: TAccountMetas[4];
-    feeDestination: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    /** Zero-copy weekly/monthly spend windows */
+    trackerExtension: TAccountMetas[5];
+    /** Zero-copy per-destination spend windows for agent_transfer */
+    destinationSpend: TAccountMetas[6];
+    feeDestination: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
   };
   data: InitializeVaultInstructionData;
 };
@@ -484,12 +579,12 @@ export function parseInitializeVaultInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedInitializeVaultInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 7,
+        expectedAccountMetas: 9,
       },
     );
   }
@@ -507,6 +602,8 @@ export function parseInitializeVaultInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextAccount(),
+      destinationSpend: getNextAccount(),
       feeDestination: getNextAccount(),
       systemProgram: getNextAccount(),
     },
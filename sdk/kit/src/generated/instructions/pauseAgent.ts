@@ -49,17 +49,17 @@ export function getPauseAgentDiscriminatorBytes() {
 
 export type PauseAgentInstruction<
   TProgram extends string = typeof SIGIL_PROGRAM_ADDRESS,
-  TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountAuthority extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
-            AccountSignerMeta<TAccountOwner>
-        : TAccountOwner,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            AccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -102,28 +102,29 @@ export function getPauseAgentInstructionDataCodec(): FixedSizeCodec<
 }
 
 export type PauseAgentInput<
-  TAccountOwner extends string = string,
+  TAccountAuthority extends string = string,
   TAccountVault extends string = string,
 > = {
-  owner: TransactionSigner<TAccountOwner>;
+  /** Vault owner or guardian */
+  authority: TransactionSigner<TAccountAuthority>;
   vault: Address<TAccountVault>;
   agentToPause: PauseAgentInstructionDataArgs["agentToPause"];
 };
 
 export function getPauseAgentInstruction<
-  TAccountOwner extends string,
+  TAccountAuthority extends string,
   TAccountVault extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
-  input: PauseAgentInput<TAccountOwner, TAccountVault>,
+  input: PauseAgentInput<TAccountAuthority, TAccountVault>,
   config?: { programAddress?: TProgramAddress },
-): PauseAgentInstruction<TProgramAddress, TAccountOwner, TAccountVault> {
+): PauseAgentInstruction<TProgramAddress, TAccountAuthority, TAccountVault> {
   // Program address.
   const programAddress = config?.programAddress ?? SIGIL_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
-    owner: { value: input.owner ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
@@ -137,14 +138,18 @@ export function getPauseAgentInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta("owner", accounts.owner),
+      getAccountMeta("authority", accounts.authority),
       getAccountMeta("vault", accounts.vault),
     ],
     data: getPauseAgentInstructionDataEncoder().encode(
       args as PauseAgentInstructionDataArgs,
     ),
     programAddress,
-  } as PauseAgentInstruction<TProgramAddress, TAccountOwner, TAccountVault>);
+  } as PauseAgentInstruction<
+    TProgramAddress,
+    TAccountAuthority,
+    TAccountVault
+  >);
 }
 
 export type ParsedPauseAgentInstruction<
@@ -153,7 +158,8 @@ export type ParsedPauseAgentInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    owner: TAccountMetas[0];
+    /** Vault owner or guardian */
+    authority: TAccountMetas[0];
     vault: TAccountMetas[1];
   };
   data: PauseAgentInstructionData;
@@ -184,7 +190,7 @@ export function parsePauseAgentInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { owner: getNextAccount(), vault: getNextAccount() },
+    accounts: { authority: getNextAccount(), vault: getNextAccount() },
     data: getPauseAgentInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -12,11 +12,19 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -27,12 +35,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -75,7 +85,7 @@ export type QueueAgentPermissionsUpdateInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? ReadonlyAccount<TAccountPolicy>
@@ -95,21 +105,39 @@ export type QueueAgentPermissionsUpdateInstructionData = {
   agent: Address;
   newCapability: number;
   spendingLimitUsd: bigint;
+  maxSessionsPerHour: number;
+  protocols: Option<Array<Address>>;
+  maxTransactionSizeUsd: Option<bigint>;
+  sessionExpirySeconds: Option<bigint>;
+  validUntil: Option<bigint>;
+  maxConcurrentSessions: Option<number>;
 };
 
 export type QueueAgentPermissionsUpdateInstructionDataArgs = {
   agent: Address;
   newCapability: number;
   spendingLimitUsd: number | bigint;
+  maxSessionsPerHour: number;
+  protocols: OptionOrNullable<Array<Address>>;
+  maxTransactionSizeUsd: OptionOrNullable<number | bigint>;
+  sessionExpirySeconds: OptionOrNullable<number | bigint>;
+  validUntil: OptionOrNullable<number | bigint>;
+  maxConcurrentSessions: OptionOrNullable<number>;
 };
 
-export function getQueueAgentPermissionsUpdateInstructionDataEncoder(): FixedSizeEncoder<QueueAgentPermissionsUpdateInstructionDataArgs> {
+export function getQueueAgentPermissionsUpdateInstructionDataEncoder(): Encoder<QueueAgentPermissionsUpdateInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["agent", getAddressEncoder()],
       ["newCapability", getU8Encoder()],
       ["spendingLimitUsd", getU64Encoder()],
+      ["maxSessionsPerHour", getU16Encoder()],
+      ["protocols", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["maxTransactionSizeUsd", getOptionEncoder(getU64Encoder())],
+      ["sessionExpirySeconds", getOptionEncoder(getU64Encoder())],
+      ["validUntil", getOptionEncoder(getI64Encoder())],
+      ["maxConcurrentSessions", getOptionEncoder(getU8Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -118,16 +146,22 @@ export function getQueueAgentPermissionsUpdateInstructionDataEncoder(): FixedSiz
   );
 }
 
-export function getQueueAgentPermissionsUpdateInstructionDataDecoder(): FixedSizeDecoder<QueueAgentPermissionsUpdateInstructionData> {
+export function getQueueAgentPermissionsUpdateInstructionDataDecoder(): Decoder<QueueAgentPermissionsUpdateInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["agent", getAddressDecoder()],
     ["newCapability", getU8Decoder()],
     ["spendingLimitUsd", getU64Decoder()],
+    ["maxSessionsPerHour", getU16Decoder()],
+    ["protocols", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["maxTransactionSizeUsd", getOptionDecoder(getU64Decoder())],
+    ["sessionExpirySeconds", getOptionDecoder(getU64Decoder())],
+    ["validUntil", getOptionDecoder(getI64Decoder())],
+    ["maxConcurrentSessions", getOptionDecoder(getU8Decoder())],
   ]);
 }
 
-export function getQueueAgentPermissionsUpdateInstructionDataCodec(): FixedSizeCodec<
+export function getQueueAgentPermissionsUpdateInstructionDataCodec(): Codec<
   QueueAgentPermissionsUpdateInstructionDataArgs,
   QueueAgentPermissionsUpdateInstructionData
 > {
@@ -152,6 +186,12 @@ export type QueueAgentPermissionsUpdateAsyncInput<
   agent: QueueAgentPermissionsUpdateInstructionDataArgs["agent"];
   newCapability: QueueAgentPermissionsUpdateInstructionDataArgs["newCapability"];
   spendingLimitUsd: QueueAgentPermissionsUpdateInstructionDataArgs["spendingLimitUsd"];
+  maxSessionsPerHour: QueueAgentPermissionsUpdateInstructionDataArgs["maxSessionsPerHour"];
+  protocols: QueueAgentPermissionsUpdateInstructionDataArgs["protocols"];
+  maxTransactionSizeUsd: QueueAgentPermissionsUpdateInstructionDataArgs["maxTransactionSizeUsd"];
+  sessionExpirySeconds: QueueAgentPermissionsUpdateInstructionDataArgs["sessionExpirySeconds"];
+  validUntil: QueueAgentPermissionsUpdateInstructionDataArgs["validUntil"];
+  maxConcurrentSessions: QueueAgentPermissionsUpdateInstructionDataArgs["maxConcurrentSessions"];
 };
 
 export async function getQueueAgentPermissionsUpdateInstructionAsync<
@@ -186,7 +226,7 @@ export async function getQueueAgentPermissionsUpdateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     pendingAgentPerms: {
       value: input.pendingAgentPerms ?? null,
@@ -282,6 +322,12 @@ export type QueueAgentPermissionsUpdateInput<
   agent: QueueAgentPermissionsUpdateInstructionDataArgs["agent"];
   newCapability: QueueAgentPermissionsUpdateInstructionDataArgs["newCapability"];
   spendingLimitUsd: QueueAgentPermissionsUpdateInstructionDataArgs["spendingLimitUsd"];
+  maxSessionsPerHour: QueueAgentPermissionsUpdateInstructionDataArgs["maxSessionsPerHour"];
+  protocols: QueueAgentPermissionsUpdateInstructionDataArgs["protocols"];
+  maxTransactionSizeUsd: QueueAgentPermissionsUpdateInstructionDataArgs["maxTransactionSizeUsd"];
+  sessionExpirySeconds: QueueAgentPermissionsUpdateInstructionDataArgs["sessionExpirySeconds"];
+  validUntil: QueueAgentPermissionsUpdateInstructionDataArgs["validUntil"];
+  maxConcurrentSessions: QueueAgentPermissionsUpdateInstructionDataArgs["maxConcurrentSessions"];
 };
 
 export function getQueueAgentPermissionsUpdateInstruction<
@@ -314,7 +360,7 @@ export function getQueueAgentPermissionsUpdateInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: false },
     pendingAgentPerms: {
       value: input.pendingAgentPerms ?? null,
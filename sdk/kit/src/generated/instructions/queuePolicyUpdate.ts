@@ -55,6 +55,12 @@ import {
   type ResolvedInstructionAccount,
 } from "@solana/program-client-core";
 import { SIGIL_PROGRAM_ADDRESS } from "../programs/index.js";
+import {
+  getMintSpendCapDecoder,
+  getMintSpendCapEncoder,
+  type MintSpendCap,
+  type MintSpendCapArgs,
+} from "../types/index.js";
 
 export const QUEUE_POLICY_UPDATE_DISCRIMINATOR = new Uint8Array([
   149, 18, 76, 197, 179, 193, 91, 77,
@@ -84,7 +90,7 @@ export type QueuePolicyUpdateInstruction<
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPolicy extends string
         ? WritableAccount<TAccountPolicy>
@@ -113,6 +119,24 @@ export type QueuePolicyUpdateInstructionData = {
   hasProtocolCaps: Option<boolean>;
   protocolCaps: Option<Array<bigint>>;
   destinationMode: Option<number>;
+  weeklySpendingCapUsd: Option<bigint>;
+  monthlySpendingCapUsd: Option<bigint>;
+  mintCaps: Option<Array<MintSpendCap>>;
+  destinationCaps: Option<Array<bigint>>;
+  tradingHours: Option<ReadonlyUint8Array>;
+  tradingHoursExemptNonSpending: Option<boolean>;
+  guardian: Option<Address>;
+  recoveryAuthority: Option<Address>;
+  recoveryDestination: Option<Address>;
+  inactivityWindowSeconds: Option<bigint>;
+  beneficiary: Option<Address>;
+  approvers: Option<Array<Address>>;
+  approvalThreshold: Option<number>;
+  cpiCallers: Option<Array<Address>>;
+  dailyFeeCapUsd: Option<bigint>;
+  maxAbandonedSessionsPerHour: Option<number>;
+  allowAsyncFulfillment: Option<boolean>;
+  maxProtocolSlippageBps: Option<number>;
 };
 
 export type QueuePolicyUpdateInstructionDataArgs = {
@@ -128,6 +152,24 @@ export type QueuePolicyUpdateInstructionDataArgs = {
   hasProtocolCaps: OptionOrNullable<boolean>;
   protocolCaps: OptionOrNullable<Array<number | bigint>>;
   destinationMode: OptionOrNullable<number>;
+  weeklySpendingCapUsd: OptionOrNullable<number | bigint>;
+  monthlySpendingCapUsd: OptionOrNullable<number | bigint>;
+  mintCaps: OptionOrNullable<Array<MintSpendCapArgs>>;
+  destinationCaps: OptionOrNullable<Array<number | bigint>>;
+  tradingHours: OptionOrNullable<ReadonlyUint8Array>;
+  tradingHoursExemptNonSpending: OptionOrNullable<boolean>;
+  guardian: OptionOrNullable<Address>;
+  recoveryAuthority: OptionOrNullable<Address>;
+  recoveryDestination: OptionOrNullable<Address>;
+  inactivityWindowSeconds: OptionOrNullable<number | bigint>;
+  beneficiary: OptionOrNullable<Address>;
+  approvers: OptionOrNullable<Array<Address>>;
+  approvalThreshold: OptionOrNullable<number>;
+  cpiCallers: OptionOrNullable<Array<Address>>;
+  dailyFeeCapUsd: OptionOrNullable<number | bigint>;
+  maxAbandonedSessionsPerHour: OptionOrNullable<number>;
+  allowAsyncFulfillment: OptionOrNullable<boolean>;
+  maxProtocolSlippageBps: OptionOrNullable<number>;
 };
 
 export function getQueuePolicyUpdateInstructionDataEncoder(): Encoder<QueuePolicyUpdateInstructionDataArgs> {
@@ -149,6 +191,24 @@ export function getQueuePolicyUpdateInstructionDataEncoder(): Encoder<QueuePolic
       ["hasProtocolCaps", getOptionEncoder(getBooleanEncoder())],
       ["protocolCaps", getOptionEncoder(getArrayEncoder(getU64Encoder()))],
       ["destinationMode", getOptionEncoder(getU8Encoder())],
+      ["weeklySpendingCapUsd", getOptionEncoder(getU64Encoder())],
+      ["monthlySpendingCapUsd", getOptionEncoder(getU64Encoder())],
+      ["mintCaps", getOptionEncoder(getArrayEncoder(getMintSpendCapEncoder()))],
+      ["destinationCaps", getOptionEncoder(getArrayEncoder(getU64Encoder()))],
+      ["tradingHours", getOptionEncoder(fixEncoderSize(getBytesEncoder(), 21))],
+      ["tradingHoursExemptNonSpending", getOptionEncoder(getBooleanEncoder())],
+      ["guardian", getOptionEncoder(getAddressEncoder())],
+      ["recoveryAuthority", getOptionEncoder(getAddressEncoder())],
+      ["recoveryDestination", getOptionEncoder(getAddressEncoder())],
+      ["inactivityWindowSeconds", getOptionEncoder(getU64Encoder())],
+      ["beneficiary", getOptionEncoder(getAddressEncoder())],
+      ["approvers", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["approvalThreshold", getOptionEncoder(getU8Encoder())],
+      ["cpiCallers", getOptionEncoder(getArrayEncoder(getAddressEncoder()))],
+      ["dailyFeeCapUsd", getOptionEncoder(getU64Encoder())],
+      ["maxAbandonedSessionsPerHour", getOptionEncoder(getU16Encoder())],
+      ["allowAsyncFulfillment", getOptionEncoder(getBooleanEncoder())],
+      ["maxProtocolSlippageBps", getOptionEncoder(getU16Encoder())],
     ]),
     (value) => ({ ...value, discriminator: QUEUE_POLICY_UPDATE_DISCRIMINATOR }),
   );
@@ -172,6 +232,24 @@ export function getQueuePolicyUpdateInstructionDataDecoder(): Decoder<QueuePolic
     ["hasProtocolCaps", getOptionDecoder(getBooleanDecoder())],
     ["protocolCaps", getOptionDecoder(getArrayDecoder(getU64Decoder()))],
     ["destinationMode", getOptionDecoder(getU8Decoder())],
+    ["weeklySpendingCapUsd", getOptionDecoder(getU64Decoder())],
+    ["monthlySpendingCapUsd", getOptionDecoder(getU64Decoder())],
+    ["mintCaps", getOptionDecoder(getArrayDecoder(getMintSpendCapDecoder()))],
+    ["destinationCaps", getOptionDecoder(getArrayDecoder(getU64Decoder()))],
+    ["tradingHours", getOptionDecoder(fixDecoderSize(getBytesDecoder(), 21))],
+    ["tradingHoursExemptNonSpending", getOptionDecoder(getBooleanDecoder())],
+    ["guardian", getOptionDecoder(getAddressDecoder())],
+    ["recoveryAuthority", getOptionDecoder(getAddressDecoder())],
+    ["recoveryDestination", getOptionDecoder(getAddressDecoder())],
+    ["inactivityWindowSeconds", getOptionDecoder(getU64Decoder())],
+    ["beneficiary", getOptionDecoder(getAddressDecoder())],
+    ["approvers", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["approvalThreshold", getOptionDecoder(getU8Decoder())],
+    ["cpiCallers", getOptionDecoder(getArrayDecoder(getAddressDecoder()))],
+    ["dailyFeeCapUsd", getOptionDecoder(getU64Decoder())],
+    ["maxAbandonedSessionsPerHour", getOptionDecoder(getU16Decoder())],
+    ["allowAsyncFulfillment", getOptionDecoder(getBooleanDecoder())],
+    ["maxProtocolSlippageBps", getOptionDecoder(getU16Decoder())],
   ]);
 }
 
@@ -209,6 +287,24 @@ export type QueuePolicyUpdateAsyncInput<
   hasProtocolCaps: QueuePolicyUpdateInstructionDataArgs["hasProtocolCaps"];
   protocolCaps: QueuePolicyUpdateInstructionDataArgs["protocolCaps"];
   destinationMode: QueuePolicyUpdateInstructionDataArgs["destinationMode"];
+  weeklySpendingCapUsd: QueuePolicyUpdateInstructionDataArgs["weeklySpendingCapUsd"];
+  monthlySpendingCapUsd: QueuePolicyUpdateInstructionDataArgs["monthlySpendingCapUsd"];
+  mintCaps: QueuePolicyUpdateInstructionDataArgs["mintCaps"];
+  destinationCaps: QueuePolicyUpdateInstructionDataArgs["destinationCaps"];
+  tradingHours: QueuePolicyUpdateInstructionDataArgs["tradingHours"];
+  tradingHoursExemptNonSpending: QueuePolicyUpdateInstructionDataArgs["tradingHoursExemptNonSpending"];
+  guardian: QueuePolicyUpdateInstructionDataArgs["guardian"];
+  recoveryAuthority: QueuePolicyUpdateInstructionDataArgs["recoveryAuthority"];
+  recoveryDestination: QueuePolicyUpdateInstructionDataArgs["recoveryDestination"];
+  inactivityWindowSeconds: QueuePolicyUpdateInstructionDataArgs["inactivityWindowSeconds"];
+  beneficiary: QueuePolicyUpdateInstructionDataArgs["beneficiary"];
+  approvers: QueuePolicyUpdateInstructionDataArgs["approvers"];
+  approvalThreshold: QueuePolicyUpdateInstructionDataArgs["approvalThreshold"];
+  cpiCallers: QueuePolicyUpdateInstructionDataArgs["cpiCallers"];
+  dailyFeeCapUsd: QueuePolicyUpdateInstructionDataArgs["dailyFeeCapUsd"];
+  maxAbandonedSessionsPerHour: QueuePolicyUpdateInstructionDataArgs["maxAbandonedSessionsPerHour"];
+  allowAsyncFulfillment: QueuePolicyUpdateInstructionDataArgs["allowAsyncFulfillment"];
+  maxProtocolSlippageBps: QueuePolicyUpdateInstructionDataArgs["maxProtocolSlippageBps"];
 };
 
 export async function getQueuePolicyUpdateInstructionAsync<
@@ -243,7 +339,7 @@ export async function getQueuePolicyUpdateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     pendingPolicy: { value: input.pendingPolicy ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -341,6 +437,24 @@ export type QueuePolicyUpdateInput<
   hasProtocolCaps: QueuePolicyUpdateInstructionDataArgs["hasProtocolCaps"];
   protocolCaps: QueuePolicyUpdateInstructionDataArgs["protocolCaps"];
   destinationMode: QueuePolicyUpdateInstructionDataArgs["destinationMode"];
+  weeklySpendingCapUsd: QueuePolicyUpdateInstructionDataArgs["weeklySpendingCapUsd"];
+  monthlySpendingCapUsd: QueuePolicyUpdateInstructionDataArgs["monthlySpendingCapUsd"];
+  mintCaps: QueuePolicyUpdateInstructionDataArgs["mintCaps"];
+  destinationCaps: QueuePolicyUpdateInstructionDataArgs["destinationCaps"];
+  tradingHours: QueuePolicyUpdateInstructionDataArgs["tradingHours"];
+  tradingHoursExemptNonSpending: QueuePolicyUpdateInstructionDataArgs["tradingHoursExemptNonSpending"];
+  guardian: QueuePolicyUpdateInstructionDataArgs["guardian"];
+  recoveryAuthority: QueuePolicyUpdateInstructionDataArgs["recoveryAuthority"];
+  recoveryDestination: QueuePolicyUpdateInstructionDataArgs["recoveryDestination"];
+  inactivityWindowSeconds: QueuePolicyUpdateInstructionDataArgs["inactivityWindowSeconds"];
+  beneficiary: QueuePolicyUpdateInstructionDataArgs["beneficiary"];
+  approvers: QueuePolicyUpdateInstructionDataArgs["approvers"];
+  approvalThreshold: QueuePolicyUpdateInstructionDataArgs["approvalThreshold"];
+  cpiCallers: QueuePolicyUpdateInstructionDataArgs["cpiCallers"];
+  dailyFeeCapUsd: QueuePolicyUpdateInstructionDataArgs["dailyFeeCapUsd"];
+  maxAbandonedSessionsPerHour: QueuePolicyUpdateInstructionDataArgs["maxAbandonedSessionsPerHour"];
+  allowAsyncFulfillment: QueuePolicyUpdateInstructionDataArgs["allowAsyncFulfillment"];
+  maxProtocolSlippageBps: QueuePolicyUpdateInstructionDataArgs["maxProtocolSlippageBps"];
 };
 
 export function getQueuePolicyUpdateInstruction<
@@ -373,7 +487,7 @@ export function getQueuePolicyUpdateInstruction<
   // Original accounts.
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: true },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     policy: { value: input.policy ?? null, isWritable: true },
     pendingPolicy: { value: input.pendingPolicy ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -14,8 +14,14 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -26,12 +32,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -82,36 +90,44 @@ export type RegisterAgentInstructionData = {
   agent: Address;
   capability: number;
   spendingLimitUsd: bigint;
+  maxSessionsPerHour: number;
+  validUntil: Option<bigint>;
 };
 
 export type RegisterAgentInstructionDataArgs = {
   agent: Address;
   capability: number;
   spendingLimitUsd: number | bigint;
+  maxSessionsPerHour: number;
+  validUntil: OptionOrNullable<number | bigint>;
 };
 
-export function getRegisterAgentInstructionDataEncoder(): FixedSizeEncoder<RegisterAgentInstructionDataArgs> {
+export function getRegisterAgentInstructionDataEncoder(): Encoder<RegisterAgentInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["agent", getAddressEncoder()],
       ["capability", getU8Encoder()],
       ["spendingLimitUsd", getU64Encoder()],
+      ["maxSessionsPerHour", getU16Encoder()],
+      ["validUntil", getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: REGISTER_AGENT_DISCRIMINATOR }),
   );
 }
 
-export function getRegisterAgentInstructionDataDecoder(): FixedSizeDecoder<RegisterAgentInstructionData> {
+export function getRegisterAgentInstructionDataDecoder(): Decoder<RegisterAgentInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["agent", getAddressDecoder()],
     ["capability", getU8Decoder()],
     ["spendingLimitUsd", getU64Decoder()],
+    ["maxSessionsPerHour", getU16Decoder()],
+    ["validUntil", getOptionDecoder(getI64Decoder())],
   ]);
 }
 
-export function getRegisterAgentInstructionDataCodec(): FixedSizeCodec<
+export function getRegisterAgentInstructionDataCodec(): Codec<
   RegisterAgentInstructionDataArgs,
   RegisterAgentInstructionData
 > {
@@ -133,6 +149,8 @@ export type RegisterAgentInput<
   agent: RegisterAgentInstructionDataArgs["agent"];
   capability: RegisterAgentInstructionDataArgs["capability"];
   spendingLimitUsd: RegisterAgentInstructionDataArgs["spendingLimitUsd"];
+  maxSessionsPerHour: RegisterAgentInstructionDataArgs["maxSessionsPerHour"];
+  validUntil: RegisterAgentInstructionDataArgs["validUntil"];
 };
 
 export function getRegisterAgentInstruction<
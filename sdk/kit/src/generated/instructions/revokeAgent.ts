@@ -14,6 +14,7 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
@@ -28,13 +29,15 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from "@solana/kit";
 import {
   getAccountMetaFactory,
+  getAddressFromResolvedInstructionAccount,
+  getNonNullResolvedInstructionInput,
   type ResolvedInstructionAccount,
 } from "@solana/program-client-core";
 import { SIGIL_PROGRAM_ADDRESS } from "../programs/index.js";
@@ -54,13 +57,14 @@ export type RevokeAgentInstruction<
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountAgentSettings extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
+        ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
       TAccountVault extends string
@@ -69,6 +73,9 @@ export type RevokeAgentInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountAgentSettings extends string
+        ? WritableAccount<TAccountAgentSettings>
+        : TAccountAgentSettings,
       ...TRemainingAccounts,
     ]
   >;
@@ -107,15 +114,130 @@ export function getRevokeAgentInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type RevokeAgentAsyncInput<
+  TAccountOwner extends string = string,
+  TAccountVault extends string = string,
+  TAccountAgentSpendOverlay extends string = string,
+  TAccountAgentSettings extends string = string,
+> = {
+  owner: TransactionSigner<TAccountOwner>;
+  vault: Address<TAccountVault>;
+  /** Agent spend overlay — release slot on revocation. */
+  agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Per-agent settings — closed with the agent so a re-registered key
+   * starts from the vault policy. Optional for agents that never had one.
+   */
+  agentSettings?: Address<TAccountAgentSettings>;
+  agentToRemove: RevokeAgentInstructionDataArgs["agentToRemove"];
+};
+
+export async function getRevokeAgentInstructionAsync<
+  TAccountOwner extends string,
+  TAccountVault extends string,
+  TAccountAgentSpendOverlay extends string,
+  TAccountAgentSettings extends string,
+  TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
+>(
+  input: RevokeAgentAsyncInput<
+    TAccountOwner,
+    TAccountVault,
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings
+  >,
+  config?: { programAddress?: TProgramAddress },
+): Promise<
+  RevokeAgentInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountVault,
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings
+  >
+> {
+  // Program address.
+  const programAddress = config?.programAddress ?? SIGIL_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    owner: { value: input.owner ?? null, isWritable: true },
+    vault: { value: input.vault ?? null, isWritable: true },
+    agentSpendOverlay: {
+      value: input.agentSpendOverlay ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedInstructionAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.agentSettings.value) {
+    accounts.agentSettings.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            97, 103, 101, 110, 116, 95, 115, 101, 116, 116, 105, 110, 103, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+        getAddressEncoder().encode(
+          getNonNullResolvedInstructionInput(
+            "agentToRemove",
+            args.agentToRemove,
+          ),
+        ),
+      ],
+    });
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
+  return Object.freeze({
+    accounts: [
+      getAccountMeta("owner", accounts.owner),
+      getAccountMeta("vault", accounts.vault),
+      getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+    ],
+    data: getRevokeAgentInstructionDataEncoder().encode(
+      args as RevokeAgentInstructionDataArgs,
+    ),
+    programAddress,
+  } as RevokeAgentInstruction<
+    TProgramAddress,
+    TAccountOwner,
+    TAccountVault,
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings
+  >);
+}
+
 export type RevokeAgentInput<
   TAccountOwner extends string = string,
   TAccountVault extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountAgentSettings extends string = string,
 > = {
   owner: TransactionSigner<TAccountOwner>;
   vault: Address<TAccountVault>;
   /** Agent spend overlay — release slot on revocation. */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Per-agent settings — closed with the agent so a re-registered key
+   * starts from the vault policy. Optional for agents that never had one.
+   */
+  agentSettings?: Address<TAccountAgentSettings>;
   agentToRemove: RevokeAgentInstructionDataArgs["agentToRemove"];
 };
 
@@ -123,31 +245,35 @@ export function getRevokeAgentInstruction<
   TAccountOwner extends string,
   TAccountVault extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountAgentSettings extends string,
   TProgramAddress extends Address = typeof SIGIL_PROGRAM_ADDRESS,
 >(
   input: RevokeAgentInput<
     TAccountOwner,
     TAccountVault,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings
   >,
   config?: { programAddress?: TProgramAddress },
 ): RevokeAgentInstruction<
   TProgramAddress,
   TAccountOwner,
   TAccountVault,
-  TAccountAgentSpendOverlay
+  TAccountAgentSpendOverlay,
+  TAccountAgentSettings
 > {
   // Program address.
   const programAddress = config?.programAddress ?? SIGIL_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
-    owner: { value: input.owner ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
     agentSpendOverlay: {
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -163,6 +289,7 @@ export function getRevokeAgentInstruction<
       getAccountMeta("owner", accounts.owner),
       getAccountMeta("vault", accounts.vault),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("agentSettings", accounts.agentSettings),
     ],
     data: getRevokeAgentInstructionDataEncoder().encode(
       args as RevokeAgentInstructionDataArgs,
@@ -172,7 +299,8 @@ export function getRevokeAgentInstruction<
     TProgramAddress,
     TAccountOwner,
     TAccountVault,
-    TAccountAgentSpendOverlay
+    TAccountAgentSpendOverlay,
+    TAccountAgentSettings
   >);
 }
 
@@ -186,6 +314,11 @@ export type ParsedRevokeAgentInstruction<
     vault: TAccountMetas[1];
     /** Agent spend overlay — release slot on revocation. */
     agentSpendOverlay: TAccountMetas[2];
+    /**
+     * Per-agent settings — closed with the agent so a re-registered key
+     * starts from the vault policy. Optional for agents that never had one.
+     */
+    agentSettings?: TAccountMetas[3] | undefined;
   };
   data: RevokeAgentInstructionData;
 };
@@ -198,12 +331,12 @@ export function parseRevokeAgentInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedRevokeAgentInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 3,
+        expectedAccountMetas: 4,
       },
     );
   }
@@ -213,12 +346,19 @@ export function parseRevokeAgentInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SIGIL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
       vault: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      agentSettings: getNextOptionalAccount(),
     },
     data: getRevokeAgentInstructionDataDecoder().decode(instruction.data),
   };
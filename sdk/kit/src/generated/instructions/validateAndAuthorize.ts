@@ -12,25 +12,33 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
   SolanaError,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -40,10 +48,19 @@ import {
 import {
   getAccountMetaFactory,
   getAddressFromResolvedInstructionAccount,
-  getNonNullResolvedInstructionInput,
   type ResolvedInstructionAccount,
 } from "@solana/program-client-core";
 import { SIGIL_PROGRAM_ADDRESS } from "../programs/index.js";
+import {
+  getExpectedOutputDecoder,
+  getExpectedOutputEncoder,
+  getSessionLegDecoder,
+  getSessionLegEncoder,
+  type ExpectedOutput,
+  type ExpectedOutputArgs,
+  type SessionLeg,
+  type SessionLegArgs,
+} from "../types/index.js";
 
 export const VALIDATE_AND_AUTHORIZE_DISCRIMINATOR = new Uint8Array([
   22, 183, 48, 222, 218, 11, 197, 152,
@@ -62,7 +79,11 @@ export type ValidateAndAuthorizeInstruction<
   TAccountPolicy extends string | AccountMeta<string> = string,
   TAccountTracker extends string | AccountMeta<string> = string,
   TAccountAgentSpendOverlay extends string | AccountMeta<string> = string,
+  TAccountTrackerExtension extends string | AccountMeta<string> = string,
+  TAccountAgentSettings extends string | AccountMeta<string> = string,
+  TAccountPermitNonces extends string | AccountMeta<string> = string,
   TAccountSession extends string | AccountMeta<string> = string,
+  TAccountPendingFulfillment extends string | AccountMeta<string> = string,
   TAccountVaultTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenMintAccount extends string | AccountMeta<string> = string,
   TAccountProtocolTreasuryTokenAccount extends string | AccountMeta<string> =
@@ -70,6 +91,7 @@ export type ValidateAndAuthorizeInstruction<
   TAccountFeeDestinationTokenAccount extends string | AccountMeta<string> =
     string,
   TAccountOutputStablecoinAccount extends string | AccountMeta<string> = string,
+  TAccountOutputTokenAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountSystemProgram extends string | AccountMeta<string> =
@@ -97,9 +119,21 @@ export type ValidateAndAuthorizeInstruction<
       TAccountAgentSpendOverlay extends string
         ? WritableAccount<TAccountAgentSpendOverlay>
         : TAccountAgentSpendOverlay,
+      TAccountTrackerExtension extends string
+        ? WritableAccount<TAccountTrackerExtension>
+        : TAccountTrackerExtension,
+      TAccountAgentSettings extends string
+        ? ReadonlyAccount<TAccountAgentSettings>
+        : TAccountAgentSettings,
+      TAccountPermitNonces extends string
+        ? WritableAccount<TAccountPermitNonces>
+        : TAccountPermitNonces,
       TAccountSession extends string
         ? WritableAccount<TAccountSession>
         : TAccountSession,
+      TAccountPendingFulfillment extends string
+        ? WritableAccount<TAccountPendingFulfillment>
+        : TAccountPendingFulfillment,
       TAccountVaultTokenAccount extends string
         ? WritableAccount<TAccountVaultTokenAccount>
         : TAccountVaultTokenAccount,
@@ -115,6 +149,9 @@ export type ValidateAndAuthorizeInstruction<
       TAccountOutputStablecoinAccount extends string
         ? WritableAccount<TAccountOutputStablecoinAccount>
         : TAccountOutputStablecoinAccount,
+      TAccountOutputTokenAccount extends string
+        ? ReadonlyAccount<TAccountOutputTokenAccount>
+        : TAccountOutputTokenAccount,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -134,6 +171,9 @@ export type ValidateAndAuthorizeInstructionData = {
   amount: bigint;
   targetProtocol: Address;
   expectedPolicyVersion: bigint;
+  legs: Array<SessionLeg>;
+  sessionNonce: number;
+  expectedOutput: Option<ExpectedOutput>;
 };
 
 export type ValidateAndAuthorizeInstructionDataArgs = {
@@ -141,9 +181,12 @@ export type ValidateAndAuthorizeInstructionDataArgs = {
   amount: number | bigint;
   targetProtocol: Address;
   expectedPolicyVersion: number | bigint;
+  legs: Array<SessionLegArgs>;
+  sessionNonce: number;
+  expectedOutput: OptionOrNullable<ExpectedOutputArgs>;
 };
 
-export function getValidateAndAuthorizeInstructionDataEncoder(): FixedSizeEncoder<ValidateAndAuthorizeInstructionDataArgs> {
+export function getValidateAndAuthorizeInstructionDataEncoder(): Encoder<ValidateAndAuthorizeInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
@@ -151,6 +194,9 @@ export function getValidateAndAuthorizeInstructionDataEncoder(): FixedSizeEncode
       ["amount", getU64Encoder()],
       ["targetProtocol", getAddressEncoder()],
       ["expectedPolicyVersion", getU64Encoder()],
+      ["legs", getArrayEncoder(getSessionLegEncoder())],
+      ["sessionNonce", getU8Encoder()],
+      ["expectedOutput", getOptionEncoder(getExpectedOutputEncoder())],
     ]),
     (value) => ({
       ...value,
@@ -159,17 +205,20 @@ export function getValidateAndAuthorizeInstructionDataEncoder(): FixedSizeEncode
   );
 }
 
-export function getValidateAndAuthorizeInstructionDataDecoder(): FixedSizeDecoder<ValidateAndAuthorizeInstructionData> {
+export function getValidateAndAuthorizeInstructionDataDecoder(): Decoder<ValidateAndAuthorizeInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["tokenMint", getAddressDecoder()],
     ["amount", getU64Decoder()],
     ["targetProtocol", getAddressDecoder()],
     ["expectedPolicyVersion", getU64Decoder()],
+    ["legs", getArrayDecoder(getSessionLegDecoder())],
+    ["sessionNonce", getU8Decoder()],
+    ["expectedOutput", getOptionDecoder(getExpectedOutputDecoder())],
   ]);
 }
 
-export function getValidateAndAuthorizeInstructionDataCodec(): FixedSizeCodec<
+export function getValidateAndAuthorizeInstructionDataCodec(): Codec<
   ValidateAndAuthorizeInstructionDataArgs,
   ValidateAndAuthorizeInstructionData
 > {
@@ -185,12 +234,17 @@ export type ValidateAndAuthorizeAsyncInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountPermitNonces extends string = string,
   TAccountSession extends string = string,
+  TAccountPendingFulfillment extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountTokenMintAccount extends string = string,
   TAccountProtocolTreasuryTokenAccount extends string = string,
   TAccountFeeDestinationTokenAccount extends string = string,
   TAccountOutputStablecoinAccount extends string = string,
+  TAccountOutputTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -202,11 +256,30 @@ export type ValidateAndAuthorizeAsyncInput<
   tracker?: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows. Only read
+   * when an async-fulfillment session reserves its amount here; then
+   * required if the vault has one.
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
+  /**
+   * Permit nonce bitmap. Passing it opts this session into an owner
+   * permit, which must then precede this instruction.
+   */
+  permitNonces?: Address<TAccountPermitNonces>;
   /**
    * Ephemeral session PDA — `init` ensures no double-authorization.
-   * Seeds include token_mint for per-token concurrent sessions.
+   * Seeds include token_mint for per-token concurrent sessions, and the
+   * session nonce for concurrent sessions on the same mint.
    */
-  session?: Address<TAccountSession>;
+  session: Address<TAccountSession>;
+  /**
+   * Cap reservation for a session that invokes an async-fulfillment
+   * program. Required exactly when one is invoked.
+   */
+  pendingFulfillment?: Address<TAccountPendingFulfillment>;
   /** Vault's PDA-owned token account for the spend token */
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
   /** The token mint being spent — constrained to match token_mint arg */
@@ -220,6 +293,11 @@ export type ValidateAndAuthorizeAsyncInput<
    * Required when input token is NOT a stablecoin (output verification in finalize).
    */
   outputStablecoinAccount?: Address<TAccountOutputStablecoinAccount>;
+  /**
+   * Vault's token account for `ExpectedOutput::mint`. Required when a
+   * stablecoin-input session declares an expected output.
+   */
+  outputTokenAccount?: Address<TAccountOutputTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   /**
@@ -231,6 +309,9 @@ export type ValidateAndAuthorizeAsyncInput<
   amount: ValidateAndAuthorizeInstructionDataArgs["amount"];
   targetProtocol: ValidateAndAuthorizeInstructionDataArgs["targetProtocol"];
   expectedPolicyVersion: ValidateAndAuthorizeInstructionDataArgs["expectedPolicyVersion"];
+  legs: ValidateAndAuthorizeInstructionDataArgs["legs"];
+  sessionNonce: ValidateAndAuthorizeInstructionDataArgs["sessionNonce"];
+  expectedOutput: ValidateAndAuthorizeInstructionDataArgs["expectedOutput"];
 };
 
 export async function getValidateAndAuthorizeInstructionAsync<
@@ -239,12 +320,17 @@ export async function getValidateAndAuthorizeInstructionAsync<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountAgentSettings extends string,
+  TAccountPermitNonces extends string,
   TAccountSession extends string,
+  TAccountPendingFulfillment extends string,
   TAccountVaultTokenAccount extends string,
   TAccountTokenMintAccount extends string,
   TAccountProtocolTreasuryTokenAccount extends string,
   TAccountFeeDestinationTokenAccount extends string,
   TAccountOutputStablecoinAccount extends string,
+  TAccountOutputTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
@@ -256,12 +342,17 @@ export async function getValidateAndAuthorizeInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
     TAccountSession,
+    TAccountPendingFulfillment,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountProtocolTreasuryTokenAccount,
     TAccountFeeDestinationTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -275,12 +366,17 @@ export async function getValidateAndAuthorizeInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
     TAccountSession,
+    TAccountPendingFulfillment,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountProtocolTreasuryTokenAccount,
     TAccountFeeDestinationTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -299,7 +395,17 @@ export async function getValidateAndAuthorizeInstructionAsync<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
+    permitNonces: { value: input.permitNonces ?? null, isWritable: true },
     session: { value: input.session ?? null, isWritable: true },
+    pendingFulfillment: {
+      value: input.pendingFulfillment ?? null,
+      isWritable: true,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -320,6 +426,10 @@ export async function getValidateAndAuthorizeInstructionAsync<
       value: input.outputStablecoinAccount ?? null,
       isWritable: true,
     },
+    outputTokenAccount: {
+      value: input.outputTokenAccount ?? null,
+      isWritable: false,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
@@ -366,12 +476,30 @@ export async function getValidateAndAuthorizeInstructionAsync<
       ],
     });
   }
-  if (!accounts.session.value) {
-    accounts.session.value = await getProgramDerivedAddress({
+  if (!accounts.trackerExtension.value) {
+    accounts.trackerExtension.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([116, 114, 97, 99, 107, 101, 114, 95, 101, 120, 116]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.agentSettings.value) {
+    accounts.agentSettings.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
-          new Uint8Array([115, 101, 115, 115, 105, 111, 110]),
+          new Uint8Array([
+            97, 103, 101, 110, 116, 95, 115, 101, 116, 116, 105, 110, 103, 115,
+          ]),
         ),
         getAddressEncoder().encode(
           getAddressFromResolvedInstructionAccount(
@@ -385,8 +513,48 @@ export async function getValidateAndAuthorizeInstructionAsync<
             accounts.agent.value,
           ),
         ),
+      ],
+    });
+  }
+  if (!accounts.permitNonces.value) {
+    accounts.permitNonces.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 101, 114, 109, 105, 116, 95, 110, 111, 110, 99, 101, 115,
+          ]),
+        ),
         getAddressEncoder().encode(
-          getNonNullResolvedInstructionInput("tokenMint", args.tokenMint),
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+      ],
+    });
+  }
+  if (!accounts.pendingFulfillment.value) {
+    accounts.pendingFulfillment.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 101, 110, 100, 105, 110, 103, 95, 102, 117, 108, 102, 105, 108,
+            108, 109, 101, 110, 116,
+          ]),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "vault",
+            accounts.vault.value,
+          ),
+        ),
+        getAddressEncoder().encode(
+          getAddressFromResolvedInstructionAccount(
+            "session",
+            accounts.session.value,
+          ),
         ),
       ],
     });
@@ -412,7 +580,11 @@ export async function getValidateAndAuthorizeInstructionAsync<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("permitNonces", accounts.permitNonces),
       getAccountMeta("session", accounts.session),
+      getAccountMeta("pendingFulfillment", accounts.pendingFulfillment),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta("tokenMintAccount", accounts.tokenMintAccount),
       getAccountMeta(
@@ -427,6 +599,7 @@ export async function getValidateAndAuthorizeInstructionAsync<
         "outputStablecoinAccount",
         accounts.outputStablecoinAccount,
       ),
+      getAccountMeta("outputTokenAccount", accounts.outputTokenAccount),
       getAccountMeta("tokenProgram", accounts.tokenProgram),
       getAccountMeta("systemProgram", accounts.systemProgram),
       getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
@@ -442,12 +615,17 @@ export async function getValidateAndAuthorizeInstructionAsync<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
     TAccountSession,
+    TAccountPendingFulfillment,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountProtocolTreasuryTokenAccount,
     TAccountFeeDestinationTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -460,12 +638,17 @@ export type ValidateAndAuthorizeInput<
   TAccountPolicy extends string = string,
   TAccountTracker extends string = string,
   TAccountAgentSpendOverlay extends string = string,
+  TAccountTrackerExtension extends string = string,
+  TAccountAgentSettings extends string = string,
+  TAccountPermitNonces extends string = string,
   TAccountSession extends string = string,
+  TAccountPendingFulfillment extends string = string,
   TAccountVaultTokenAccount extends string = string,
   TAccountTokenMintAccount extends string = string,
   TAccountProtocolTreasuryTokenAccount extends string = string,
   TAccountFeeDestinationTokenAccount extends string = string,
   TAccountOutputStablecoinAccount extends string = string,
+  TAccountOutputTokenAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
@@ -477,11 +660,30 @@ export type ValidateAndAuthorizeInput<
   tracker: Address<TAccountTracker>;
   /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
   agentSpendOverlay: Address<TAccountAgentSpendOverlay>;
+  /**
+   * Zero-copy SpendTrackerExtension — weekly/monthly windows. Only read
+   * when an async-fulfillment session reserves its amount here; then
+   * required if the vault has one.
+   */
+  trackerExtension?: Address<TAccountTrackerExtension>;
+  /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+  agentSettings?: Address<TAccountAgentSettings>;
+  /**
+   * Permit nonce bitmap. Passing it opts this session into an owner
+   * permit, which must then precede this instruction.
+   */
+  permitNonces?: Address<TAccountPermitNonces>;
   /**
    * Ephemeral session PDA — `init` ensures no double-authorization.
-   * Seeds include token_mint for per-token concurrent sessions.
+   * Seeds include token_mint for per-token concurrent sessions, and the
+   * session nonce for concurrent sessions on the same mint.
    */
   session: Address<TAccountSession>;
+  /**
+   * Cap reservation for a session that invokes an async-fulfillment
+   * program. Required exactly when one is invoked.
+   */
+  pendingFulfillment?: Address<TAccountPendingFulfillment>;
   /** Vault's PDA-owned token account for the spend token */
   vaultTokenAccount: Address<TAccountVaultTokenAccount>;
   /** The token mint being spent — constrained to match token_mint arg */
@@ -495,6 +697,11 @@ export type ValidateAndAuthorizeInput<
    * Required when input token is NOT a stablecoin (output verification in finalize).
    */
   outputStablecoinAccount?: Address<TAccountOutputStablecoinAccount>;
+  /**
+   * Vault's token account for `ExpectedOutput::mint`. Required when a
+   * stablecoin-input session declares an expected output.
+   */
+  outputTokenAccount?: Address<TAccountOutputTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   /**
@@ -506,6 +713,9 @@ export type ValidateAndAuthorizeInput<
   amount: ValidateAndAuthorizeInstructionDataArgs["amount"];
   targetProtocol: ValidateAndAuthorizeInstructionDataArgs["targetProtocol"];
   expectedPolicyVersion: ValidateAndAuthorizeInstructionDataArgs["expectedPolicyVersion"];
+  legs: ValidateAndAuthorizeInstructionDataArgs["legs"];
+  sessionNonce: ValidateAndAuthorizeInstructionDataArgs["sessionNonce"];
+  expectedOutput: ValidateAndAuthorizeInstructionDataArgs["expectedOutput"];
 };
 
 export function getValidateAndAuthorizeInstruction<
@@ -514,12 +724,17 @@ export function getValidateAndAuthorizeInstruction<
   TAccountPolicy extends string,
   TAccountTracker extends string,
   TAccountAgentSpendOverlay extends string,
+  TAccountTrackerExtension extends string,
+  TAccountAgentSettings extends string,
+  TAccountPermitNonces extends string,
   TAccountSession extends string,
+  TAccountPendingFulfillment extends string,
   TAccountVaultTokenAccount extends string,
   TAccountTokenMintAccount extends string,
   TAccountProtocolTreasuryTokenAccount extends string,
   TAccountFeeDestinationTokenAccount extends string,
   TAccountOutputStablecoinAccount extends string,
+  TAccountOutputTokenAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
@@ -531,12 +746,17 @@ export function getValidateAndAuthorizeInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
     TAccountSession,
+    TAccountPendingFulfillment,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountProtocolTreasuryTokenAccount,
     TAccountFeeDestinationTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -549,12 +769,17 @@ export function getValidateAndAuthorizeInstruction<
   TAccountPolicy,
   TAccountTracker,
   TAccountAgentSpendOverlay,
+  TAccountTrackerExtension,
+  TAccountAgentSettings,
+  TAccountPermitNonces,
   TAccountSession,
+  TAccountPendingFulfillment,
   TAccountVaultTokenAccount,
   TAccountTokenMintAccount,
   TAccountProtocolTreasuryTokenAccount,
   TAccountFeeDestinationTokenAccount,
   TAccountOutputStablecoinAccount,
+  TAccountOutputTokenAccount,
   TAccountTokenProgram,
   TAccountSystemProgram,
   TAccountInstructionsSysvar
@@ -572,7 +797,17 @@ export function getValidateAndAuthorizeInstruction<
       value: input.agentSpendOverlay ?? null,
       isWritable: true,
     },
+    trackerExtension: {
+      value: input.trackerExtension ?? null,
+      isWritable: true,
+    },
+    agentSettings: { value: input.agentSettings ?? null, isWritable: false },
+    permitNonces: { value: input.permitNonces ?? null, isWritable: true },
     session: { value: input.session ?? null, isWritable: true },
+    pendingFulfillment: {
+      value: input.pendingFulfillment ?? null,
+      isWritable: true,
+    },
     vaultTokenAccount: {
       value: input.vaultTokenAccount ?? null,
       isWritable: true,
@@ -593,6 +828,10 @@ export function getValidateAndAuthorizeInstruction<
       value: input.outputStablecoinAccount ?? null,
       isWritable: true,
     },
+    outputTokenAccount: {
+      value: input.outputTokenAccount ?? null,
+      isWritable: false,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
@@ -630,7 +869,11 @@ export function getValidateAndAuthorizeInstruction<
       getAccountMeta("policy", accounts.policy),
       getAccountMeta("tracker", accounts.tracker),
       getAccountMeta("agentSpendOverlay", accounts.agentSpendOverlay),
+      getAccountMeta("trackerExtension", accounts.trackerExtension),
+      getAccountMeta("agentSettings", accounts.agentSettings),
+      getAccountMeta("permitNonces", accounts.permitNonces),
       getAccountMeta("session", accounts.session),
+      getAccountMeta("pendingFulfillment", accounts.pendingFulfillment),
       getAccountMeta("vaultTokenAccount", accounts.vaultTokenAccount),
       getAccountMeta("tokenMintAccount", accounts.tokenMintAccount),
       getAccountMeta(
@@ -645,6 +888,7 @@ export function getValidateAndAuthorizeInstruction<
         "outputStablecoinAccount",
         accounts.outputStablecoinAccount,
       ),
+      getAccountMeta("outputTokenAccount", accounts.outputTokenAccount),
       getAccountMeta("tokenProgram", accounts.tokenProgram),
       getAccountMeta("systemProgram", accounts.systemProgram),
       getAccountMeta("instructionsSysvar", accounts.instructionsSysvar),
@@ -660,12 +904,17 @@ export function getValidateAndAuthorizeInstruction<
     TAccountPolicy,
     TAccountTracker,
     TAccountAgentSpendOverlay,
+    TAccountTrackerExtension,
+    TAccountAgentSettings,
+    TAccountPermitNonces,
     TAccountSession,
+    TAccountPendingFulfillment,
     TAccountVaultTokenAccount,
     TAccountTokenMintAccount,
     TAccountProtocolTreasuryTokenAccount,
     TAccountFeeDestinationTokenAccount,
     TAccountOutputStablecoinAccount,
+    TAccountOutputTokenAccount,
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar
@@ -685,31 +934,55 @@ export type ParsedValidateAndAuthorizeInstruction<
     tracker: TAccountMetas[3];
     /** Zero-copy AgentSpendOverlay — per-agent rolling spend */
     agentSpendOverlay: TAccountMetas[4];
+    /**
+     * Zero-copy SpendTrackerExtension — weekly/monthly windows. Only read
+     * when an async-fulfillment session reserves its amount here; then
+     * required if the vault has one.
+     */
+    trackerExtension?: TAccountMetas[5] | undefined;
+    /** Per-agent settings. Required when `AgentEntry::has_settings` is set. */
+    agentSettings?: TAccountMetas[6] | undefined;
+    /**
+     * Permit nonce bitmap. Passing it opts this session into an owner
+     * permit, which must then precede this instruction.
+     */
+    permitNonces?: TAccountMetas[7] | undefined;
     /**
      * Ephemeral session PDA — `init` ensures no double-authorization.
-     * Seeds include token_mint for per-token concurrent sessions.
+     * Seeds include token_mint for per-token concurrent sessions, and the
+     * session nonce for concurrent sessions on the same mint.
      */
-    session: TAccountMetas[5];
+    session: TAccountMetas[8];
+    /**
+     * Cap reservation for a session that invokes an async-fulfillment
+     * program. Required exactly when one is invoked.
+     */
+    pendingFulfillment?: TAccountMetas[9] | undefined;
     /** Vault's PDA-owned token account for the spend token */
-    vaultTokenAccount: TAccountMetas[6];
+    vaultTokenAccount: TAccountMetas[10];
     /** The token mint being spent — constrained to match token_mint arg */
-    tokenMintAccount: TAccountMetas[7];
+    tokenMintAccount: TAccountMetas[11];
     /** Protocol treasury token account (needed when protocol_fee > 0) */
-    protocolTreasuryTokenAccount?: TAccountMetas[8] | undefined;
+    protocolTreasuryTokenAccount?: TAccountMetas[12] | undefined;
     /** Developer fee destination token account (needed when developer_fee > 0) */
-    feeDestinationTokenAccount?: TAccountMetas[9] | undefined;
+    feeDestinationTokenAccount?: TAccountMetas[13] | undefined;
     /**
      * Vault's stablecoin ATA to snapshot (for non-stablecoin input spending).
      * Required when input token is NOT a stablecoin (output verification in finalize).
      */
-    outputStablecoinAccount?: TAccountMetas[10] | undefined;
-    tokenProgram: TAccountMetas[11];
-    systemProgram: TAccountMetas[12];
+    outputStablecoinAccount?: TAccountMetas[14] | undefined;
+    /**
+     * Vault's token account for `ExpectedOutput::mint`. Required when a
+     * stablecoin-input session declares an expected output.
+     */
+    outputTokenAccount?: TAccountMetas[15] | undefined;
+    tokenProgram: TAccountMetas[16];
+    systemProgram: TAccountMetas[17];
     /**
      * Instructions sysvar for verifying DeFi instruction program_id
      * and protocol slippage enforcement.
      */
-    instructionsSysvar: TAccountMetas[13];
+    instructionsSysvar: TAccountMetas[18];
   };
   data: ValidateAndAuthorizeInstructionData;
 };
@@ -722,12 +995,12 @@ export function parseValidateAndAuthorizeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedValidateAndAuthorizeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 19) {
     throw new SolanaError(
       SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
       {
         actualAccountMetas: instruction.accounts.length,
-        expectedAccountMetas: 14,
+        expectedAccountMetas: 19,
       },
     );
   }
@@ -751,12 +1024,17 @@ export function parseValidateAndAuthorizeInstruction<
       policy: getNextAccount(),
       tracker: getNextAccount(),
       agentSpendOverlay: getNextAccount(),
+      trackerExtension: getNextOptionalAccount(),
+      agentSettings: getNextOptionalAccount(),
+      permitNonces: getNextOptionalAccount(),
       session: getNextAccount(),
+      pendingFulfillment: getNextOptionalAccount(),
       vaultTokenAccount: getNextAccount(),
       tokenMintAccount: getNextAccount(),
       protocolTreasuryTokenAccount: getNextOptionalAccount(),
       feeDestinationTokenAccount: getNextOptionalAccount(),
       outputStablecoinAccount: getNextOptionalAccount(),
+      outputTokenAccount: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       instructionsSysvar: getNextAccount(),
@@ -33,24 +33,40 @@ import {
   type SelfPlanAndSendFunctions,
 } from "@solana/program-client-core";
 import {
+  getAgentSettingsCodec,
   getAgentSpendOverlayCodec,
   getAgentVaultCodec,
+  getDestinationSpendTrackerCodec,
   getEscrowDepositCodec,
+  getGovernanceBundleCodec,
   getInstructionConstraintsCodec,
   getPendingAgentPermissionsUpdateCodec,
   getPendingCloseConstraintsCodec,
   getPendingConstraintsUpdateCodec,
+  getPendingFeeDestinationUpdateCodec,
+  getPendingFulfillmentCodec,
+  getPendingOwnerTransferCodec,
   getPendingPolicyUpdateCodec,
+  getPendingRecoveryCodec,
+  getPermitNoncesCodec,
   getPolicyConfigCodec,
   getPostExecutionAssertionsCodec,
   getSessionAuthorityCodec,
+  getSpendRequestCodec,
   getSpendTrackerCodec,
+  getSpendTrackerExtensionCodec,
+  type AgentSettings,
+  type AgentSettingsArgs,
   type AgentSpendOverlay,
   type AgentSpendOverlayArgs,
   type AgentVault,
   type AgentVaultArgs,
+  type DestinationSpendTracker,
+  type DestinationSpendTrackerArgs,
   type EscrowDeposit,
   type EscrowDepositArgs,
+  type GovernanceBundle,
+  type GovernanceBundleArgs,
   type InstructionConstraints,
   type InstructionConstraintsArgs,
   type PendingAgentPermissionsUpdate,
@@ -59,51 +75,89 @@ import {
   type PendingCloseConstraintsArgs,
   type PendingConstraintsUpdate,
   type PendingConstraintsUpdateArgs,
+  type PendingFeeDestinationUpdate,
+  type PendingFeeDestinationUpdateArgs,
+  type PendingFulfillment,
+  type PendingFulfillmentArgs,
+  type PendingOwnerTransfer,
+  type PendingOwnerTransferArgs,
   type PendingPolicyUpdate,
   type PendingPolicyUpdateArgs,
+  type PendingRecovery,
+  type PendingRecoveryArgs,
+  type PermitNonces,
+  type PermitNoncesArgs,
   type PolicyConfig,
   type PolicyConfigArgs,
   type PostExecutionAssertions,
   type PostExecutionAssertionsArgs,
   type SessionAuthority,
   type SessionAuthorityArgs,
+  type SpendRequest,
+  type SpendRequestArgs,
   type SpendTracker,
   type SpendTrackerArgs,
+  type SpendTrackerExtension,
+  type SpendTrackerExtensionArgs,
 } from "../accounts/index.js";
 import {
   getAgentTransferInstructionAsync,
   getAllocateConstraintsPdaInstructionAsync,
   getAllocatePendingConstraintsPdaInstructionAsync,
   getApplyAgentPermissionsUpdateInstructionAsync,
+  getApplyBundleInstructionAsync,
   getApplyCloseConstraintsInstructionAsync,
   getApplyConstraintsUpdateInstructionAsync,
+  getApplyFeeDestinationUpdateInstructionAsync,
+  getApplyOwnerTransferInstructionAsync,
   getApplyPendingPolicyInstructionAsync,
+  getApproveSpendRequestInstruction,
   getCancelAgentPermissionsUpdateInstruction,
   getCancelCloseConstraintsInstructionAsync,
   getCancelConstraintsUpdateInstructionAsync,
+  getCancelFeeDestinationUpdateInstructionAsync,
+  getCancelGovernanceBundleInstructionAsync,
+  getCancelOwnerTransferInstructionAsync,
   getCancelPendingPolicyInstructionAsync,
+  getCancelRecoveryInstructionAsync,
+  getCancelSpendRequestInstruction,
+  getClaimBeneficiaryFundsInstructionAsync,
   getCleanupOrphanConstraintsPdaInstructionAsync,
   getClosePostAssertionsInstructionAsync,
   getCloseSettledEscrowInstructionAsync,
   getCloseVaultInstructionAsync,
   getCreateEscrowInstructionAsync,
+  getCreateGovernanceBundleInstructionAsync,
   getCreateInstructionConstraintsInstructionAsync,
   getCreatePostAssertionsInstructionAsync,
+  getCreateSpendRequestInstructionAsync,
   getDepositFundsInstructionAsync,
+  getExecuteRecoveryInstructionAsync,
   getExtendPdaInstruction,
   getFinalizeSessionInstructionAsync,
   getFreezeVaultInstruction,
+  getInitializeDestinationTrackerInstructionAsync,
+  getInitializePermitNoncesInstructionAsync,
+  getInitializeTrackerExtensionInstructionAsync,
   getInitializeVaultInstructionAsync,
+  getMigrateVaultInstructionAsync,
   getPauseAgentInstruction,
   getQueueAgentPermissionsUpdateInstructionAsync,
   getQueueCloseConstraintsInstructionAsync,
   getQueueConstraintsUpdateInstructionAsync,
+  getQueueFeeDestinationUpdateInstructionAsync,
+  getQueueOwnerTransferInstructionAsync,
   getQueuePolicyUpdateInstructionAsync,
+  getQueueRecoveryInstructionAsync,
   getReactivateVaultInstruction,
+  getReconcileFulfillmentInstructionAsync,
   getRefundEscrowInstructionAsync,
   getRegisterAgentInstruction,
-  getRevokeAgentInstruction,
+  getRevokeAgentInstructionAsync,
+  getRotateAgentKeyInstructionAsync,
   getSettleEscrowInstructionAsync,
+  getTightenPolicyInstructionAsync,
+  getTriggerDeadManSwitchInstruction,
   getUnpauseAgentInstruction,
   getValidateAndAuthorizeInstructionAsync,
   getWithdrawFundsInstructionAsync,
@@ -111,35 +165,59 @@ import {
   parseAllocateConstraintsPdaInstruction,
   parseAllocatePendingConstraintsPdaInstruction,
   parseApplyAgentPermissionsUpdateInstruction,
+  parseApplyBundleInstruction,
   parseApplyCloseConstraintsInstruction,
   parseApplyConstraintsUpdateInstruction,
+  parseApplyFeeDestinationUpdateInstruction,
+  parseApplyOwnerTransferInstruction,
   parseApplyPendingPolicyInstruction,
+  parseApproveSpendRequestInstruction,
   parseCancelAgentPermissionsUpdateInstruction,
   parseCancelCloseConstraintsInstruction,
   parseCancelConstraintsUpdateInstruction,
+  parseCancelFeeDestinationUpdateInstruction,
+  parseCancelGovernanceBundleInstruction,
+  parseCancelOwnerTransferInstruction,
   parseCancelPendingPolicyInstruction,
+  parseCancelRecoveryInstruction,
+  parseCancelSpendRequestInstruction,
+  parseClaimBeneficiaryFundsInstruction,
   parseCleanupOrphanConstraintsPdaInstruction,
   parseClosePostAssertionsInstruction,
   parseCloseSettledEscrowInstruction,
   parseCloseVaultInstruction,
   parseCreateEscrowInstruction,
+  parseCreateGovernanceBundleInstruction,
   parseCreateInstructionConstraintsInstruction,
   parseCreatePostAssertionsInstruction,
+  parseCreateSpendRequestInstruction,
   parseDepositFundsInstruction,
+  parseExecuteRecoveryInstruction,
   parseExtendPdaInstruction,
   parseFinalizeSessionInstruction,
   parseFreezeVaultInstruction,
+  parseInitializeDestinationTrackerInstruction,
+  parseInitializePermitNoncesInstruction,
+  parseInitializeTrackerExtensionInstruction,
   parseInitializeVaultInstruction,
+  parseMigrateVaultInstruction,
   parsePauseAgentInstruction,
   parseQueueAgentPermissionsUpdateInstruction,
   parseQueueCloseConstraintsInstruction,
   parseQueueConstraintsUpdateInstruction,
+  parseQueueFeeDestinationUpdateInstruction,
+  parseQueueOwnerTransferInstruction,
   parseQueuePolicyUpdateInstruction,
+  parseQueueRecoveryInstruction,
   parseReactivateVaultInstruction,
+  parseReconcileFulfillmentInstruction,
   parseRefundEscrowInstruction,
   parseRegisterAgentInstruction,
   parseRevokeAgentInstruction,
+  parseRotateAgentKeyInstruction,
   parseSettleEscrowInstruction,
+  parseTightenPolicyInstruction,
+  parseTriggerDeadManSwitchInstruction,
   parseUnpauseAgentInstruction,
   parseValidateAndAuthorizeInstruction,
   parseWithdrawFundsInstruction,
@@ -147,58 +225,99 @@ import {
   type AllocateConstraintsPdaAsyncInput,
   type AllocatePendingConstraintsPdaAsyncInput,
   type ApplyAgentPermissionsUpdateAsyncInput,
+  type ApplyBundleAsyncInput,
   type ApplyCloseConstraintsAsyncInput,
   type ApplyConstraintsUpdateAsyncInput,
+  type ApplyFeeDestinationUpdateAsyncInput,
+  type ApplyOwnerTransferAsyncInput,
   type ApplyPendingPolicyAsyncInput,
+  type ApproveSpendRequestInput,
   type CancelAgentPermissionsUpdateInput,
   type CancelCloseConstraintsAsyncInput,
   type CancelConstraintsUpdateAsyncInput,
+  type CancelFeeDestinationUpdateAsyncInput,
+  type CancelGovernanceBundleAsyncInput,
+  type CancelOwnerTransferAsyncInput,
   type CancelPendingPolicyAsyncInput,
+  type CancelRecoveryAsyncInput,
+  type CancelSpendRequestInput,
+  type ClaimBeneficiaryFundsAsyncInput,
   type CleanupOrphanConstraintsPdaAsyncInput,
   type ClosePostAssertionsAsyncInput,
   type CloseSettledEscrowAsyncInput,
   type CloseVaultAsyncInput,
   type CreateEscrowAsyncInput,
+  type CreateGovernanceBundleAsyncInput,
   type CreateInstructionConstraintsAsyncInput,
   type CreatePostAssertionsAsyncInput,
+  type CreateSpendRequestAsyncInput,
   type DepositFundsAsyncInput,
+  type ExecuteRecoveryAsyncInput,
   type ExtendPdaInput,
   type FinalizeSessionAsyncInput,
   type FreezeVaultInput,
+  type InitializeDestinationTrackerAsyncInput,
+  type InitializePermitNoncesAsyncInput,
+  type InitializeTrackerExtensionAsyncInput,
   type InitializeVaultAsyncInput,
+  type MigrateVaultAsyncInput,
   type ParsedAgentTransferInstruction,
   type ParsedAllocateConstraintsPdaInstruction,
   type ParsedAllocatePendingConstraintsPdaInstruction,
   type ParsedApplyAgentPermissionsUpdateInstruction,
+  type ParsedApplyBundleInstruction,
   type ParsedApplyCloseConstraintsInstruction,
   type ParsedApplyConstraintsUpdateInstruction,
+  type ParsedApplyFeeDestinationUpdateInstruction,
+  type ParsedApplyOwnerTransferInstruction,
   type ParsedApplyPendingPolicyInstruction,
+  type ParsedApproveSpendRequestInstruction,
   type ParsedCancelAgentPermissionsUpdateInstruction,
   type ParsedCancelCloseConstraintsInstruction,
   type ParsedCancelConstraintsUpdateInstruction,
+  type ParsedCancelFeeDestinationUpdateInstruction,
+  type ParsedCancelGovernanceBundleInstruction,
+  type ParsedCancelOwnerTransferInstruction,
   type ParsedCancelPendingPolicyInstruction,
+  type ParsedCancelRecoveryInstruction,
+  type ParsedCancelSpendRequestInstruction,
+  type ParsedClaimBeneficiaryFundsInstruction,
   type ParsedCleanupOrphanConstraintsPdaInstruction,
   type ParsedClosePostAssertionsInstruction,
   type ParsedCloseSettledEscrowInstruction,
   type ParsedCloseVaultInstruction,
   type ParsedCreateEscrowInstruction,
+  type ParsedCreateGovernanceBundleInstruction,
   type ParsedCreateInstructionConstraintsInstruction,
   type ParsedCreatePostAssertionsInstruction,
+  type ParsedCreateSpendRequestInstruction,
   type ParsedDepositFundsInstruction,
+  type ParsedExecuteRecoveryInstruction,
   type ParsedExtendPdaInstruction,
   type ParsedFinalizeSessionInstruction,
   type ParsedFreezeVaultInstruction,
+  type ParsedInitializeDestinationTrackerInstruction,
+  type ParsedInitializePermitNoncesInstruction,
+  type ParsedInitializeTrackerExtensionInstruction,
   type ParsedInitializeVaultInstruction,
+  type ParsedMigrateVaultInstruction,
   type ParsedPauseAgentInstruction,
   type ParsedQueueAgentPermissionsUpdateInstruction,
   type ParsedQueueCloseConstraintsInstruction,
   type ParsedQueueConstraintsUpdateInstruction,
+  type ParsedQueueFeeDestinationUpdateInstruction,
+  type ParsedQueueOwnerTransferInstruction,
   type ParsedQueuePolicyUpdateInstruction,
+  type ParsedQueueRecoveryInstruction,
   type ParsedReactivateVaultInstruction,
+  type ParsedReconcileFulfillmentInstruction,
   type ParsedRefundEscrowInstruction,
   type ParsedRegisterAgentInstruction,
   type ParsedRevokeAgentInstruction,
+  type ParsedRotateAgentKeyInstruction,
   type ParsedSettleEscrowInstruction,
+  type ParsedTightenPolicyInstruction,
+  type ParsedTriggerDeadManSwitchInstruction,
   type ParsedUnpauseAgentInstruction,
   type ParsedValidateAndAuthorizeInstruction,
   type ParsedWithdrawFundsInstruction,
@@ -206,12 +325,19 @@ import {
   type QueueAgentPermissionsUpdateAsyncInput,
   type QueueCloseConstraintsAsyncInput,
   type QueueConstraintsUpdateAsyncInput,
+  type QueueFeeDestinationUpdateAsyncInput,
+  type QueueOwnerTransferAsyncInput,
   type QueuePolicyUpdateAsyncInput,
+  type QueueRecoveryAsyncInput,
   type ReactivateVaultInput,
+  type ReconcileFulfillmentAsyncInput,
   type RefundEscrowAsyncInput,
   type RegisterAgentInput,
-  type RevokeAgentInput,
+  type RevokeAgentAsyncInput,
+  type RotateAgentKeyAsyncInput,
   type SettleEscrowAsyncInput,
+  type TightenPolicyAsyncInput,
+  type TriggerDeadManSwitchInput,
   type UnpauseAgentInput,
   type ValidateAndAuthorizeAsyncInput,
   type WithdrawFundsAsyncInput,
@@ -221,24 +347,45 @@ export const SIGIL_PROGRAM_ADDRESS =
   "4ZeVCqnjUgUtFrHHPG7jELUxvJeoVGHhGNgPrhBPwrHL" as Address<"4ZeVCqnjUgUtFrHHPG7jELUxvJeoVGHhGNgPrhBPwrHL">;
 
 export enum SigilAccount {
+  AgentSettings,
   AgentSpendOverlay,
   AgentVault,
+  DestinationSpendTracker,
   EscrowDeposit,
+  GovernanceBundle,
   InstructionConstraints,
   PendingAgentPermissionsUpdate,
   PendingCloseConstraints,
   PendingConstraintsUpdate,
+  PendingFeeDestinationUpdate,
+  PendingFulfillment,
+  PendingOwnerTransfer,
   PendingPolicyUpdate,
+  PendingRecovery,
+  PermitNonces,
   PolicyConfig,
   PostExecutionAssertions,
   SessionAuthority,
+  SpendRequest,
   SpendTracker,
+  SpendTrackerExtension,
 }
 
 export function identifySigilAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): SigilAccount {
   const data = "data" in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([51, 223, 153, 209, 118, 28, 132, 102]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.AgentSettings;
+  }
   if (
     containsBytes(
       data,
@@ -261,6 +408,17 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.AgentVault;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([3, 25, 207, 179, 80, 242, 248, 75]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.DestinationSpendTracker;
+  }
   if (
     containsBytes(
       data,
@@ -272,6 +430,17 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.EscrowDeposit;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([252, 62, 203, 166, 241, 182, 177, 48]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.GovernanceBundle;
+  }
   if (
     containsBytes(
       data,
@@ -316,6 +485,39 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.PendingConstraintsUpdate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 210, 237, 4, 111, 45, 161, 137]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.PendingFeeDestinationUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([177, 202, 188, 16, 207, 10, 132, 29]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.PendingFulfillment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([154, 201, 58, 27, 21, 49, 33, 71]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.PendingOwnerTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -327,6 +529,28 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.PendingPolicyUpdate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([137, 93, 162, 156, 59, 69, 165, 163]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.PendingRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 82, 46, 73, 22, 22, 24, 239]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.PermitNonces;
+  }
   if (
     containsBytes(
       data,
@@ -360,6 +584,17 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.SessionAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([71, 251, 215, 71, 98, 153, 90, 25]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.SpendRequest;
+  }
   if (
     containsBytes(
       data,
@@ -371,6 +606,17 @@ export function identifySigilAccount(
   ) {
     return SigilAccount.SpendTracker;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 246, 92, 32, 92, 88, 145, 118]),
+      ),
+      0,
+    )
+  ) {
+    return SigilAccount.SpendTrackerExtension;
+  }
   throw new SolanaError(
     SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_ACCOUNT,
     { accountData: data, programName: "sigil" },
@@ -382,35 +628,59 @@ export enum SigilInstruction {
   AllocateConstraintsPda,
   AllocatePendingConstraintsPda,
   ApplyAgentPermissionsUpdate,
+  ApplyBundle,
   ApplyCloseConstraints,
   ApplyConstraintsUpdate,
+  ApplyFeeDestinationUpdate,
+  ApplyOwnerTransfer,
   ApplyPendingPolicy,
+  ApproveSpendRequest,
   CancelAgentPermissionsUpdate,
   CancelCloseConstraints,
   CancelConstraintsUpdate,
+  CancelFeeDestinationUpdate,
+  CancelGovernanceBundle,
+  CancelOwnerTransfer,
   CancelPendingPolicy,
+  CancelRecovery,
+  CancelSpendRequest,
+  ClaimBeneficiaryFunds,
   CleanupOrphanConstraintsPda,
   ClosePostAssertions,
   CloseSettledEscrow,
   CloseVault,
   CreateEscrow,
+  CreateGovernanceBundle,
   CreateInstructionConstraints,
   CreatePostAssertions,
+  CreateSpendRequest,
   DepositFunds,
+  ExecuteRecovery,
   ExtendPda,
   FinalizeSession,
   FreezeVault,
+  InitializeDestinationTracker,
+  InitializePermitNonces,
+  InitializeTrackerExtension,
   InitializeVault,
+  MigrateVault,
   PauseAgent,
   QueueAgentPermissionsUpdate,
   QueueCloseConstraints,
   QueueConstraintsUpdate,
+  QueueFeeDestinationUpdate,
+  QueueOwnerTransfer,
   QueuePolicyUpdate,
+  QueueRecovery,
   ReactivateVault,
+  ReconcileFulfillment,
   RefundEscrow,
   RegisterAgent,
   RevokeAgent,
+  RotateAgentKey,
   SettleEscrow,
+  TightenPolicy,
+  TriggerDeadManSwitch,
   UnpauseAgent,
   ValidateAndAuthorize,
   WithdrawFunds,
@@ -464,6 +734,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.ApplyAgentPermissionsUpdate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 196, 137, 165, 196, 255, 177, 227]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ApplyBundle;
+  }
   if (
     containsBytes(
       data,
@@ -486,6 +767,28 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.ApplyConstraintsUpdate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 92, 114, 199, 153, 11, 221, 228]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ApplyFeeDestinationUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([94, 1, 212, 10, 100, 165, 168, 153]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ApplyOwnerTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -497,6 +800,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.ApplyPendingPolicy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 62, 30, 147, 223, 247, 155, 140]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ApproveSpendRequest;
+  }
   if (
     containsBytes(
       data,
@@ -530,6 +844,39 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.CancelConstraintsUpdate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([213, 212, 253, 238, 81, 8, 162, 89]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CancelFeeDestinationUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 48, 191, 109, 228, 111, 59, 204]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CancelGovernanceBundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 15, 239, 95, 119, 251, 21, 149]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CancelOwnerTransfer;
+  }
   if (
     containsBytes(
       data,
@@ -541,6 +888,39 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.CancelPendingPolicy;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([176, 23, 203, 37, 121, 251, 227, 83]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CancelRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([2, 31, 50, 75, 145, 147, 114, 235]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CancelSpendRequest;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([152, 16, 235, 51, 251, 107, 54, 59]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ClaimBeneficiaryFunds;
+  }
   if (
     containsBytes(
       data,
@@ -596,6 +976,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.CreateEscrow;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([12, 205, 89, 3, 10, 132, 187, 119]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CreateGovernanceBundle;
+  }
   if (
     containsBytes(
       data,
@@ -618,6 +1009,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.CreatePostAssertions;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 129, 195, 145, 55, 97, 39, 33]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.CreateSpendRequest;
+  }
   if (
     containsBytes(
       data,
@@ -629,6 +1031,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.DepositFunds;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([203, 133, 133, 228, 153, 121, 182, 237]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ExecuteRecovery;
+  }
   if (
     containsBytes(
       data,
@@ -662,6 +1075,39 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.FreezeVault;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([229, 112, 195, 11, 195, 27, 201, 245]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.InitializeDestinationTracker;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 188, 229, 48, 58, 69, 204, 121]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.InitializePermitNonces;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([82, 134, 118, 51, 50, 41, 30, 26]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.InitializeTrackerExtension;
+  }
   if (
     containsBytes(
       data,
@@ -673,6 +1119,17 @@ export function identifySigilInstruction(
   ) {
     return SigilInstruction.InitializeVault;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 151, 25, 211, 120, 164, 24, 215]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.MigrateVault;
+  }
   if (
     containsBytes(
       data,
@@ -710,78 +1167,155 @@ export function identifySigilInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([247, 253, 233, 93, 233, 54, 53, 131]),
+        new Uint8Array([247, 253, 233, 93, 233, 54, 53, 131]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.QueueConstraintsUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([158, 134, 133, 227, 37, 187, 180, 252]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.QueueFeeDestinationUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([247, 65, 33, 210, 184, 141, 204, 60]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.QueueOwnerTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([149, 18, 76, 197, 179, 193, 91, 77]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.QueuePolicyUpdate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([50, 198, 176, 156, 253, 183, 135, 26]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.QueueRecovery;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 50, 143, 70, 114, 220, 25, 251]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ReactivateVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 34, 122, 74, 199, 1, 85, 6]),
+      ),
+      0,
+    )
+  ) {
+    return SigilInstruction.ReconcileFulfillment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 186, 89, 99, 26, 194, 23, 204]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.QueueConstraintsUpdate;
+    return SigilInstruction.RefundEscrow;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([149, 18, 76, 197, 179, 193, 91, 77]),
+        new Uint8Array([135, 157, 66, 195, 2, 113, 175, 30]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.QueuePolicyUpdate;
+    return SigilInstruction.RegisterAgent;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([245, 50, 143, 70, 114, 220, 25, 251]),
+        new Uint8Array([227, 60, 209, 125, 240, 117, 163, 73]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.ReactivateVault;
+    return SigilInstruction.RevokeAgent;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([107, 186, 89, 99, 26, 194, 23, 204]),
+        new Uint8Array([85, 31, 17, 212, 162, 53, 153, 115]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.RefundEscrow;
+    return SigilInstruction.RotateAgentKey;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([135, 157, 66, 195, 2, 113, 175, 30]),
+        new Uint8Array([22, 135, 160, 194, 23, 186, 124, 110]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.RegisterAgent;
+    return SigilInstruction.SettleEscrow;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([227, 60, 209, 125, 240, 117, 163, 73]),
+        new Uint8Array([163, 53, 51, 242, 144, 123, 81, 79]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.RevokeAgent;
+    return SigilInstruction.TightenPolicy;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([22, 135, 160, 194, 23, 186, 124, 110]),
+        new Uint8Array([245, 27, 71, 121, 121, 74, 214, 54]),
       ),
       0,
     )
   ) {
-    return SigilInstruction.SettleEscrow;
+    return SigilInstruction.TriggerDeadManSwitch;
   }
   if (
     containsBytes(
@@ -837,15 +1371,27 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.ApplyAgentPermissionsUpdate;
     } & ParsedApplyAgentPermissionsUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ApplyBundle;
+    } & ParsedApplyBundleInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.ApplyCloseConstraints;
     } & ParsedApplyCloseConstraintsInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.ApplyConstraintsUpdate;
     } & ParsedApplyConstraintsUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ApplyFeeDestinationUpdate;
+    } & ParsedApplyFeeDestinationUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ApplyOwnerTransfer;
+    } & ParsedApplyOwnerTransferInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.ApplyPendingPolicy;
     } & ParsedApplyPendingPolicyInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ApproveSpendRequest;
+    } & ParsedApproveSpendRequestInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.CancelAgentPermissionsUpdate;
     } & ParsedCancelAgentPermissionsUpdateInstruction<TProgram>)
@@ -855,9 +1401,27 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.CancelConstraintsUpdate;
     } & ParsedCancelConstraintsUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CancelFeeDestinationUpdate;
+    } & ParsedCancelFeeDestinationUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CancelGovernanceBundle;
+    } & ParsedCancelGovernanceBundleInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CancelOwnerTransfer;
+    } & ParsedCancelOwnerTransferInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.CancelPendingPolicy;
     } & ParsedCancelPendingPolicyInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CancelRecovery;
+    } & ParsedCancelRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CancelSpendRequest;
+    } & ParsedCancelSpendRequestInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ClaimBeneficiaryFunds;
+    } & ParsedClaimBeneficiaryFundsInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.CleanupOrphanConstraintsPda;
     } & ParsedCleanupOrphanConstraintsPdaInstruction<TProgram>)
@@ -873,15 +1437,24 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.CreateEscrow;
     } & ParsedCreateEscrowInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CreateGovernanceBundle;
+    } & ParsedCreateGovernanceBundleInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.CreateInstructionConstraints;
     } & ParsedCreateInstructionConstraintsInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.CreatePostAssertions;
     } & ParsedCreatePostAssertionsInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.CreateSpendRequest;
+    } & ParsedCreateSpendRequestInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.DepositFunds;
     } & ParsedDepositFundsInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ExecuteRecovery;
+    } & ParsedExecuteRecoveryInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.ExtendPda;
     } & ParsedExtendPdaInstruction<TProgram>)
@@ -891,9 +1464,21 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.FreezeVault;
     } & ParsedFreezeVaultInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.InitializeDestinationTracker;
+    } & ParsedInitializeDestinationTrackerInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.InitializePermitNonces;
+    } & ParsedInitializePermitNoncesInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.InitializeTrackerExtension;
+    } & ParsedInitializeTrackerExtensionInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.InitializeVault;
     } & ParsedInitializeVaultInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.MigrateVault;
+    } & ParsedMigrateVaultInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.PauseAgent;
     } & ParsedPauseAgentInstruction<TProgram>)
@@ -906,12 +1491,24 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.QueueConstraintsUpdate;
     } & ParsedQueueConstraintsUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.QueueFeeDestinationUpdate;
+    } & ParsedQueueFeeDestinationUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.QueueOwnerTransfer;
+    } & ParsedQueueOwnerTransferInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.QueuePolicyUpdate;
     } & ParsedQueuePolicyUpdateInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.QueueRecovery;
+    } & ParsedQueueRecoveryInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.ReactivateVault;
     } & ParsedReactivateVaultInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.ReconcileFulfillment;
+    } & ParsedReconcileFulfillmentInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.RefundEscrow;
     } & ParsedRefundEscrowInstruction<TProgram>)
@@ -921,9 +1518,18 @@ export type ParsedSigilInstruction<
   | ({
       instructionType: SigilInstruction.RevokeAgent;
     } & ParsedRevokeAgentInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.RotateAgentKey;
+    } & ParsedRotateAgentKeyInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.SettleEscrow;
     } & ParsedSettleEscrowInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.TightenPolicy;
+    } & ParsedTightenPolicyInstruction<TProgram>)
+  | ({
+      instructionType: SigilInstruction.TriggerDeadManSwitch;
+    } & ParsedTriggerDeadManSwitchInstruction<TProgram>)
   | ({
       instructionType: SigilInstruction.UnpauseAgent;
     } & ParsedUnpauseAgentInstruction<TProgram>)
@@ -967,6 +1573,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseApplyAgentPermissionsUpdateInstruction(instruction),
       };
     }
+    case SigilInstruction.ApplyBundle: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ApplyBundle,
+        ...parseApplyBundleInstruction(instruction),
+      };
+    }
     case SigilInstruction.ApplyCloseConstraints: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -981,6 +1594,20 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseApplyConstraintsUpdateInstruction(instruction),
       };
     }
+    case SigilInstruction.ApplyFeeDestinationUpdate: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ApplyFeeDestinationUpdate,
+        ...parseApplyFeeDestinationUpdateInstruction(instruction),
+      };
+    }
+    case SigilInstruction.ApplyOwnerTransfer: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ApplyOwnerTransfer,
+        ...parseApplyOwnerTransferInstruction(instruction),
+      };
+    }
     case SigilInstruction.ApplyPendingPolicy: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -988,6 +1615,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseApplyPendingPolicyInstruction(instruction),
       };
     }
+    case SigilInstruction.ApproveSpendRequest: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ApproveSpendRequest,
+        ...parseApproveSpendRequestInstruction(instruction),
+      };
+    }
     case SigilInstruction.CancelAgentPermissionsUpdate: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1009,6 +1643,27 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseCancelConstraintsUpdateInstruction(instruction),
       };
     }
+    case SigilInstruction.CancelFeeDestinationUpdate: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CancelFeeDestinationUpdate,
+        ...parseCancelFeeDestinationUpdateInstruction(instruction),
+      };
+    }
+    case SigilInstruction.CancelGovernanceBundle: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CancelGovernanceBundle,
+        ...parseCancelGovernanceBundleInstruction(instruction),
+      };
+    }
+    case SigilInstruction.CancelOwnerTransfer: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CancelOwnerTransfer,
+        ...parseCancelOwnerTransferInstruction(instruction),
+      };
+    }
     case SigilInstruction.CancelPendingPolicy: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1016,6 +1671,27 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseCancelPendingPolicyInstruction(instruction),
       };
     }
+    case SigilInstruction.CancelRecovery: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CancelRecovery,
+        ...parseCancelRecoveryInstruction(instruction),
+      };
+    }
+    case SigilInstruction.CancelSpendRequest: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CancelSpendRequest,
+        ...parseCancelSpendRequestInstruction(instruction),
+      };
+    }
+    case SigilInstruction.ClaimBeneficiaryFunds: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ClaimBeneficiaryFunds,
+        ...parseClaimBeneficiaryFundsInstruction(instruction),
+      };
+    }
     case SigilInstruction.CleanupOrphanConstraintsPda: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1051,6 +1727,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseCreateEscrowInstruction(instruction),
       };
     }
+    case SigilInstruction.CreateGovernanceBundle: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CreateGovernanceBundle,
+        ...parseCreateGovernanceBundleInstruction(instruction),
+      };
+    }
     case SigilInstruction.CreateInstructionConstraints: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1065,6 +1748,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseCreatePostAssertionsInstruction(instruction),
       };
     }
+    case SigilInstruction.CreateSpendRequest: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.CreateSpendRequest,
+        ...parseCreateSpendRequestInstruction(instruction),
+      };
+    }
     case SigilInstruction.DepositFunds: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1072,6 +1762,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseDepositFundsInstruction(instruction),
       };
     }
+    case SigilInstruction.ExecuteRecovery: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ExecuteRecovery,
+        ...parseExecuteRecoveryInstruction(instruction),
+      };
+    }
     case SigilInstruction.ExtendPda: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1093,6 +1790,27 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseFreezeVaultInstruction(instruction),
       };
     }
+    case SigilInstruction.InitializeDestinationTracker: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.InitializeDestinationTracker,
+        ...parseInitializeDestinationTrackerInstruction(instruction),
+      };
+    }
+    case SigilInstruction.InitializePermitNonces: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.InitializePermitNonces,
+        ...parseInitializePermitNoncesInstruction(instruction),
+      };
+    }
+    case SigilInstruction.InitializeTrackerExtension: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.InitializeTrackerExtension,
+        ...parseInitializeTrackerExtensionInstruction(instruction),
+      };
+    }
     case SigilInstruction.InitializeVault: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1100,6 +1818,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseInitializeVaultInstruction(instruction),
       };
     }
+    case SigilInstruction.MigrateVault: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.MigrateVault,
+        ...parseMigrateVaultInstruction(instruction),
+      };
+    }
     case SigilInstruction.PauseAgent: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1128,6 +1853,20 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseQueueConstraintsUpdateInstruction(instruction),
       };
     }
+    case SigilInstruction.QueueFeeDestinationUpdate: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.QueueFeeDestinationUpdate,
+        ...parseQueueFeeDestinationUpdateInstruction(instruction),
+      };
+    }
+    case SigilInstruction.QueueOwnerTransfer: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.QueueOwnerTransfer,
+        ...parseQueueOwnerTransferInstruction(instruction),
+      };
+    }
     case SigilInstruction.QueuePolicyUpdate: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1135,6 +1874,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseQueuePolicyUpdateInstruction(instruction),
       };
     }
+    case SigilInstruction.QueueRecovery: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.QueueRecovery,
+        ...parseQueueRecoveryInstruction(instruction),
+      };
+    }
     case SigilInstruction.ReactivateVault: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1142,6 +1888,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseReactivateVaultInstruction(instruction),
       };
     }
+    case SigilInstruction.ReconcileFulfillment: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.ReconcileFulfillment,
+        ...parseReconcileFulfillmentInstruction(instruction),
+      };
+    }
     case SigilInstruction.RefundEscrow: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1163,6 +1916,13 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseRevokeAgentInstruction(instruction),
       };
     }
+    case SigilInstruction.RotateAgentKey: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.RotateAgentKey,
+        ...parseRotateAgentKeyInstruction(instruction),
+      };
+    }
     case SigilInstruction.SettleEscrow: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1170,6 +1930,20 @@ export function parseSigilInstruction<TProgram extends string>(
         ...parseSettleEscrowInstruction(instruction),
       };
     }
+    case SigilInstruction.TightenPolicy: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.TightenPolicy,
+        ...parseTightenPolicyInstruction(instruction),
+      };
+    }
+    case SigilInstruction.TriggerDeadManSwitch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: SigilInstruction.TriggerDeadManSwitch,
+        ...parseTriggerDeadManSwitchInstruction(instruction),
+      };
+    }
     case SigilInstruction.UnpauseAgent: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -1205,12 +1979,18 @@ export type SigilPlugin = {
 };
 
 export type SigilPluginAccounts = {
+  agentSettings: ReturnType<typeof getAgentSettingsCodec> &
+    SelfFetchFunctions<AgentSettingsArgs, AgentSettings>;
   agentSpendOverlay: ReturnType<typeof getAgentSpendOverlayCodec> &
     SelfFetchFunctions<AgentSpendOverlayArgs, AgentSpendOverlay>;
   agentVault: ReturnType<typeof getAgentVaultCodec> &
     SelfFetchFunctions<AgentVaultArgs, AgentVault>;
+  destinationSpendTracker: ReturnType<typeof getDestinationSpendTrackerCodec> &
+    SelfFetchFunctions<DestinationSpendTrackerArgs, DestinationSpendTracker>;
   escrowDeposit: ReturnType<typeof getEscrowDepositCodec> &
     SelfFetchFunctions<EscrowDepositArgs, EscrowDeposit>;
+  governanceBundle: ReturnType<typeof getGovernanceBundleCodec> &
+    SelfFetchFunctions<GovernanceBundleArgs, GovernanceBundle>;
   instructionConstraints: ReturnType<typeof getInstructionConstraintsCodec> &
     SelfFetchFunctions<InstructionConstraintsArgs, InstructionConstraints>;
   pendingAgentPermissionsUpdate: ReturnType<
@@ -1226,16 +2006,35 @@ export type SigilPluginAccounts = {
     typeof getPendingConstraintsUpdateCodec
   > &
     SelfFetchFunctions<PendingConstraintsUpdateArgs, PendingConstraintsUpdate>;
+  pendingFeeDestinationUpdate: ReturnType<
+    typeof getPendingFeeDestinationUpdateCodec
+  > &
+    SelfFetchFunctions<
+      PendingFeeDestinationUpdateArgs,
+      PendingFeeDestinationUpdate
+    >;
+  pendingFulfillment: ReturnType<typeof getPendingFulfillmentCodec> &
+    SelfFetchFunctions<PendingFulfillmentArgs, PendingFulfillment>;
+  pendingOwnerTransfer: ReturnType<typeof getPendingOwnerTransferCodec> &
+    SelfFetchFunctions<PendingOwnerTransferArgs, PendingOwnerTransfer>;
   pendingPolicyUpdate: ReturnType<typeof getPendingPolicyUpdateCodec> &
     SelfFetchFunctions<PendingPolicyUpdateArgs, PendingPolicyUpdate>;
+  pendingRecovery: ReturnType<typeof getPendingRecoveryCodec> &
+    SelfFetchFunctions<PendingRecoveryArgs, PendingRecovery>;
+  permitNonces: ReturnType<typeof getPermitNoncesCodec> &
+    SelfFetchFunctions<PermitNoncesArgs, PermitNonces>;
   policyConfig: ReturnType<typeof getPolicyConfigCodec> &
     SelfFetchFunctions<PolicyConfigArgs, PolicyConfig>;
   postExecutionAssertions: ReturnType<typeof getPostExecutionAssertionsCodec> &
     SelfFetchFunctions<PostExecutionAssertionsArgs, PostExecutionAssertions>;
   sessionAuthority: ReturnType<typeof getSessionAuthorityCodec> &
     SelfFetchFunctions<SessionAuthorityArgs, SessionAuthority>;
+  spendRequest: ReturnType<typeof getSpendRequestCodec> &
+    SelfFetchFunctions<SpendRequestArgs, SpendRequest>;
   spendTracker: ReturnType<typeof getSpendTrackerCodec> &
     SelfFetchFunctions<SpendTrackerArgs, SpendTracker>;
+  spendTrackerExtension: ReturnType<typeof getSpendTrackerExtensionCodec> &
+    SelfFetchFunctions<SpendTrackerExtensionArgs, SpendTrackerExtension>;
 };
 
 export type SigilPluginInstructions = {
@@ -1255,6 +2054,10 @@ export type SigilPluginInstructions = {
     input: ApplyAgentPermissionsUpdateAsyncInput,
   ) => ReturnType<typeof getApplyAgentPermissionsUpdateInstructionAsync> &
     SelfPlanAndSendFunctions;
+  applyBundle: (
+    input: ApplyBundleAsyncInput,
+  ) => ReturnType<typeof getApplyBundleInstructionAsync> &
+    SelfPlanAndSendFunctions;
   applyCloseConstraints: (
     input: ApplyCloseConstraintsAsyncInput,
   ) => ReturnType<typeof getApplyCloseConstraintsInstructionAsync> &
@@ -1263,10 +2066,22 @@ export type SigilPluginInstructions = {
     input: ApplyConstraintsUpdateAsyncInput,
   ) => ReturnType<typeof getApplyConstraintsUpdateInstructionAsync> &
     SelfPlanAndSendFunctions;
+  applyFeeDestinationUpdate: (
+    input: ApplyFeeDestinationUpdateAsyncInput,
+  ) => ReturnType<typeof getApplyFeeDestinationUpdateInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  applyOwnerTransfer: (
+    input: ApplyOwnerTransferAsyncInput,
+  ) => ReturnType<typeof getApplyOwnerTransferInstructionAsync> &
+    SelfPlanAndSendFunctions;
   applyPendingPolicy: (
     input: ApplyPendingPolicyAsyncInput,
   ) => ReturnType<typeof getApplyPendingPolicyInstructionAsync> &
     SelfPlanAndSendFunctions;
+  approveSpendRequest: (
+    input: ApproveSpendRequestInput,
+  ) => ReturnType<typeof getApproveSpendRequestInstruction> &
+    SelfPlanAndSendFunctions;
   cancelAgentPermissionsUpdate: (
     input: CancelAgentPermissionsUpdateInput,
   ) => ReturnType<typeof getCancelAgentPermissionsUpdateInstruction> &
@@ -1279,10 +2094,34 @@ export type SigilPluginInstructions = {
     input: CancelConstraintsUpdateAsyncInput,
   ) => ReturnType<typeof getCancelConstraintsUpdateInstructionAsync> &
     SelfPlanAndSendFunctions;
+  cancelFeeDestinationUpdate: (
+    input: CancelFeeDestinationUpdateAsyncInput,
+  ) => ReturnType<typeof getCancelFeeDestinationUpdateInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  cancelGovernanceBundle: (
+    input: CancelGovernanceBundleAsyncInput,
+  ) => ReturnType<typeof getCancelGovernanceBundleInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  cancelOwnerTransfer: (
+    input: CancelOwnerTransferAsyncInput,
+  ) => ReturnType<typeof getCancelOwnerTransferInstructionAsync> &
+    SelfPlanAndSendFunctions;
   cancelPendingPolicy: (
     input: CancelPendingPolicyAsyncInput,
   ) => ReturnType<typeof getCancelPendingPolicyInstructionAsync> &
     SelfPlanAndSendFunctions;
+  cancelRecovery: (
+    input: CancelRecoveryAsyncInput,
+  ) => ReturnType<typeof getCancelRecoveryInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  cancelSpendRequest: (
+    input: CancelSpendRequestInput,
+  ) => ReturnType<typeof getCancelSpendRequestInstruction> &
+    SelfPlanAndSendFunctions;
+  claimBeneficiaryFunds: (
+    input: ClaimBeneficiaryFundsAsyncInput,
+  ) => ReturnType<typeof getClaimBeneficiaryFundsInstructionAsync> &
+    SelfPlanAndSendFunctions;
   cleanupOrphanConstraintsPda: (
     input: CleanupOrphanConstraintsPdaAsyncInput,
   ) => ReturnType<typeof getCleanupOrphanConstraintsPdaInstructionAsync> &
@@ -1303,6 +2142,10 @@ export type SigilPluginInstructions = {
     input: CreateEscrowAsyncInput,
   ) => ReturnType<typeof getCreateEscrowInstructionAsync> &
     SelfPlanAndSendFunctions;
+  createGovernanceBundle: (
+    input: CreateGovernanceBundleAsyncInput,
+  ) => ReturnType<typeof getCreateGovernanceBundleInstructionAsync> &
+    SelfPlanAndSendFunctions;
   createInstructionConstraints: (
     input: CreateInstructionConstraintsAsyncInput,
   ) => ReturnType<typeof getCreateInstructionConstraintsInstructionAsync> &
@@ -1311,10 +2154,18 @@ export type SigilPluginInstructions = {
     input: CreatePostAssertionsAsyncInput,
   ) => ReturnType<typeof getCreatePostAssertionsInstructionAsync> &
     SelfPlanAndSendFunctions;
+  createSpendRequest: (
+    input: CreateSpendRequestAsyncInput,
+  ) => ReturnType<typeof getCreateSpendRequestInstructionAsync> &
+    SelfPlanAndSendFunctions;
   depositFunds: (
     input: DepositFundsAsyncInput,
   ) => ReturnType<typeof getDepositFundsInstructionAsync> &
     SelfPlanAndSendFunctions;
+  executeRecovery: (
+    input: ExecuteRecoveryAsyncInput,
+  ) => ReturnType<typeof getExecuteRecoveryInstructionAsync> &
+    SelfPlanAndSendFunctions;
   extendPda: (
     input: ExtendPdaInput,
   ) => ReturnType<typeof getExtendPdaInstruction> & SelfPlanAndSendFunctions;
@@ -1325,10 +2176,26 @@ export type SigilPluginInstructions = {
   freezeVault: (
     input: FreezeVaultInput,
   ) => ReturnType<typeof getFreezeVaultInstruction> & SelfPlanAndSendFunctions;
+  initializeDestinationTracker: (
+    input: InitializeDestinationTrackerAsyncInput,
+  ) => ReturnType<typeof getInitializeDestinationTrackerInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  initializePermitNonces: (
+    input: InitializePermitNoncesAsyncInput,
+  ) => ReturnType<typeof getInitializePermitNoncesInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  initializeTrackerExtension: (
+    input: InitializeTrackerExtensionAsyncInput,
+  ) => ReturnType<typeof getInitializeTrackerExtensionInstructionAsync> &
+    SelfPlanAndSendFunctions;
   initializeVault: (
     input: InitializeVaultAsyncInput,
   ) => ReturnType<typeof getInitializeVaultInstructionAsync> &
     SelfPlanAndSendFunctions;
+  migrateVault: (
+    input: MigrateVaultAsyncInput,
+  ) => ReturnType<typeof getMigrateVaultInstructionAsync> &
+    SelfPlanAndSendFunctions;
   pauseAgent: (
     input: PauseAgentInput,
   ) => ReturnType<typeof getPauseAgentInstruction> & SelfPlanAndSendFunctions;
@@ -1344,14 +2211,30 @@ export type SigilPluginInstructions = {
     input: QueueConstraintsUpdateAsyncInput,
   ) => ReturnType<typeof getQueueConstraintsUpdateInstructionAsync> &
     SelfPlanAndSendFunctions;
+  queueFeeDestinationUpdate: (
+    input: QueueFeeDestinationUpdateAsyncInput,
+  ) => ReturnType<typeof getQueueFeeDestinationUpdateInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  queueOwnerTransfer: (
+    input: QueueOwnerTransferAsyncInput,
+  ) => ReturnType<typeof getQueueOwnerTransferInstructionAsync> &
+    SelfPlanAndSendFunctions;
   queuePolicyUpdate: (
     input: QueuePolicyUpdateAsyncInput,
   ) => ReturnType<typeof getQueuePolicyUpdateInstructionAsync> &
     SelfPlanAndSendFunctions;
+  queueRecovery: (
+    input: QueueRecoveryAsyncInput,
+  ) => ReturnType<typeof getQueueRecoveryInstructionAsync> &
+    SelfPlanAndSendFunctions;
   reactivateVault: (
     input: ReactivateVaultInput,
   ) => ReturnType<typeof getReactivateVaultInstruction> &
     SelfPlanAndSendFunctions;
+  reconcileFulfillment: (
+    input: ReconcileFulfillmentAsyncInput,
+  ) => ReturnType<typeof getReconcileFulfillmentInstructionAsync> &
+    SelfPlanAndSendFunctions;
   refundEscrow: (
     input: RefundEscrowAsyncInput,
   ) => ReturnType<typeof getRefundEscrowInstructionAsync> &
@@ -1361,12 +2244,25 @@ export type SigilPluginInstructions = {
   ) => ReturnType<typeof getRegisterAgentInstruction> &
     SelfPlanAndSendFunctions;
   revokeAgent: (
-    input: RevokeAgentInput,
-  ) => ReturnType<typeof getRevokeAgentInstruction> & SelfPlanAndSendFunctions;
+    input: RevokeAgentAsyncInput,
+  ) => ReturnType<typeof getRevokeAgentInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  rotateAgentKey: (
+    input: RotateAgentKeyAsyncInput,
+  ) => ReturnType<typeof getRotateAgentKeyInstructionAsync> &
+    SelfPlanAndSendFunctions;
   settleEscrow: (
     input: SettleEscrowAsyncInput,
   ) => ReturnType<typeof getSettleEscrowInstructionAsync> &
     SelfPlanAndSendFunctions;
+  tightenPolicy: (
+    input: TightenPolicyAsyncInput,
+  ) => ReturnType<typeof getTightenPolicyInstructionAsync> &
+    SelfPlanAndSendFunctions;
+  triggerDeadManSwitch: (
+    input: TriggerDeadManSwitchInput,
+  ) => ReturnType<typeof getTriggerDeadManSwitchInstruction> &
+    SelfPlanAndSendFunctions;
   unpauseAgent: (
     input: UnpauseAgentInput,
   ) => ReturnType<typeof getUnpauseAgentInstruction> & SelfPlanAndSendFunctions;
@@ -1393,12 +2289,21 @@ export function sigilProgram() {
       ...client,
       sigil: <SigilPlugin>{
         accounts: {
+          agentSettings: addSelfFetchFunctions(client, getAgentSettingsCodec()),
           agentSpendOverlay: addSelfFetchFunctions(
             client,
             getAgentSpendOverlayCodec(),
           ),
           agentVault: addSelfFetchFunctions(client, getAgentVaultCodec()),
+          destinationSpendTracker: addSelfFetchFunctions(
+            client,
+            getDestinationSpendTrackerCodec(),
+          ),
           escrowDeposit: addSelfFetchFunctions(client, getEscrowDepositCodec()),
+          governanceBundle: addSelfFetchFunctions(
+            client,
+            getGovernanceBundleCodec(),
+          ),
           instructionConstraints: addSelfFetchFunctions(
             client,
             getInstructionConstraintsCodec(),
@@ -1415,10 +2320,27 @@ export function sigilProgram() {
             client,
             getPendingConstraintsUpdateCodec(),
           ),
+          pendingFeeDestinationUpdate: addSelfFetchFunctions(
+            client,
+            getPendingFeeDestinationUpdateCodec(),
+          ),
+          pendingFulfillment: addSelfFetchFunctions(
+            client,
+            getPendingFulfillmentCodec(),
+          ),
+          pendingOwnerTransfer: addSelfFetchFunctions(
+            client,
+            getPendingOwnerTransferCodec(),
+          ),
           pendingPolicyUpdate: addSelfFetchFunctions(
             client,
             getPendingPolicyUpdateCodec(),
           ),
+          pendingRecovery: addSelfFetchFunctions(
+            client,
+            getPendingRecoveryCodec(),
+          ),
+          permitNonces: addSelfFetchFunctions(client, getPermitNoncesCodec()),
           policyConfig: addSelfFetchFunctions(client, getPolicyConfigCodec()),
           postExecutionAssertions: addSelfFetchFunctions(
             client,
@@ -1428,7 +2350,12 @@ export function sigilProgram() {
             client,
             getSessionAuthorityCodec(),
           ),
+          spendRequest: addSelfFetchFunctions(client, getSpendRequestCodec()),
           spendTracker: addSelfFetchFunctions(client, getSpendTrackerCodec()),
+          spendTrackerExtension: addSelfFetchFunctions(
+            client,
+            getSpendTrackerExtensionCodec(),
+          ),
         },
         instructions: {
           agentTransfer: (input) =>
@@ -1451,6 +2378,11 @@ export function sigilProgram() {
               client,
               getApplyAgentPermissionsUpdateInstructionAsync(input),
             ),
+          applyBundle: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getApplyBundleInstructionAsync(input),
+            ),
           applyCloseConstraints: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1461,11 +2393,26 @@ export function sigilProgram() {
               client,
               getApplyConstraintsUpdateInstructionAsync(input),
             ),
+          applyFeeDestinationUpdate: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getApplyFeeDestinationUpdateInstructionAsync(input),
+            ),
+          applyOwnerTransfer: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getApplyOwnerTransferInstructionAsync(input),
+            ),
           applyPendingPolicy: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getApplyPendingPolicyInstructionAsync(input),
             ),
+          approveSpendRequest: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getApproveSpendRequestInstruction(input),
+            ),
           cancelAgentPermissionsUpdate: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1481,11 +2428,41 @@ export function sigilProgram() {
               client,
               getCancelConstraintsUpdateInstructionAsync(input),
             ),
+          cancelFeeDestinationUpdate: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCancelFeeDestinationUpdateInstructionAsync(input),
+            ),
+          cancelGovernanceBundle: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCancelGovernanceBundleInstructionAsync(input),
+            ),
+          cancelOwnerTransfer: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCancelOwnerTransferInstructionAsync(input),
+            ),
           cancelPendingPolicy: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getCancelPendingPolicyInstructionAsync(input),
             ),
+          cancelRecovery: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCancelRecoveryInstructionAsync(input),
+            ),
+          cancelSpendRequest: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCancelSpendRequestInstruction(input),
+            ),
+          claimBeneficiaryFunds: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getClaimBeneficiaryFundsInstructionAsync(input),
+            ),
           cleanupOrphanConstraintsPda: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1511,6 +2488,11 @@ export function sigilProgram() {
               client,
               getCreateEscrowInstructionAsync(input),
             ),
+          createGovernanceBundle: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCreateGovernanceBundleInstructionAsync(input),
+            ),
           createInstructionConstraints: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1521,11 +2503,21 @@ export function sigilProgram() {
               client,
               getCreatePostAssertionsInstructionAsync(input),
             ),
+          createSpendRequest: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getCreateSpendRequestInstructionAsync(input),
+            ),
           depositFunds: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getDepositFundsInstructionAsync(input),
             ),
+          executeRecovery: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getExecuteRecoveryInstructionAsync(input),
+            ),
           extendPda: (input) =>
             addSelfPlanAndSendFunctions(client, getExtendPdaInstruction(input)),
           finalizeSession: (input) =>
@@ -1541,11 +2533,31 @@ export function sigilProgram() {
               client,
               getFreezeVaultInstruction(input),
             ),
+          initializeDestinationTracker: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getInitializeDestinationTrackerInstructionAsync(input),
+            ),
+          initializePermitNonces: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getInitializePermitNoncesInstructionAsync(input),
+            ),
+          initializeTrackerExtension: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getInitializeTrackerExtensionInstructionAsync(input),
+            ),
           initializeVault: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getInitializeVaultInstructionAsync(input),
             ),
+          migrateVault: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getMigrateVaultInstructionAsync(input),
+            ),
           pauseAgent: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1566,16 +2578,36 @@ export function sigilProgram() {
               client,
               getQueueConstraintsUpdateInstructionAsync(input),
             ),
+          queueFeeDestinationUpdate: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getQueueFeeDestinationUpdateInstructionAsync(input),
+            ),
+          queueOwnerTransfer: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getQueueOwnerTransferInstructionAsync(input),
+            ),
           queuePolicyUpdate: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getQueuePolicyUpdateInstructionAsync(input),
             ),
+          queueRecovery: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getQueueRecoveryInstructionAsync(input),
+            ),
           reactivateVault: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getReactivateVaultInstruction(input),
             ),
+          reconcileFulfillment: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getReconcileFulfillmentInstructionAsync(input),
+            ),
           refundEscrow: (input) =>
             addSelfPlanAndSendFunctions(
               client,
@@ -1589,13 +2621,28 @@ export function sigilProgram() {
           revokeAgent: (input) =>
             addSelfPlanAndSendFunctions(
               client,
-              getRevokeAgentInstruction(input),
+              getRevokeAgentInstructionAsync(input),
+            ),
+          rotateAgentKey: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getRotateAgentKeyInstructionAsync(input),
             ),
           settleEscrow: (input) =>
             addSelfPlanAndSendFunctions(
               client,
               getSettleEscrowInstructionAsync(input),
             ),
+          tightenPolicy: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getTightenPolicyInstructionAsync(input),
+            ),
+          triggerDeadManSwitch: (input) =>
+            addSelfPlanAndSendFunctions(
+              client,
+              getTriggerDeadManSwitchInstruction(input),
+            ),
           unpauseAgent: (input) =>
             addSelfPlanAndSendFunctions(
               client,
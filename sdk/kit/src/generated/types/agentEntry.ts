@@ -8,16 +8,16 @@
 
 import {
   combineCodec,
-  fixDecoderSize,
-  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
-  getBytesDecoder,
-  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -26,7 +26,6 @@ import {
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
-  type ReadonlyUint8Array,
 } from "@solana/kit";
 
 export type AgentEntry = {
@@ -38,7 +37,31 @@ export type AgentEntry = {
   capability: number;
   spendingLimitUsd: bigint;
   paused: boolean;
-  reserved: ReadonlyUint8Array;
+  /**
+   * Max sessions (validate_and_authorize + agent_transfer) per rolling hour.
+   * 0 = unlimited. Counted in AgentSpendOverlay.session_counters.
+   */
+  maxSessionsPerHour: number;
+  /**
+   * True when this agent's AgentSettings PDA narrows the vault policy
+   * and must be passed to validate_and_authorize.
+   */
+  hasSettings: boolean;
+  /**
+   * Unix timestamp after which this key is rejected. 0 = never expires.
+   * u32 to fit the last 4 reserved bytes; covers timestamps until 2106.
+   */
+  validUntil: number;
+  /**
+   * Concurrent session limit. 0 = one session per mint (session nonce
+   * must be 0); N > 0 = nonces 0..N and at most N open sessions.
+   */
+  maxConcurrentSessions: number;
+  /**
+   * Open sessions for this agent. Incremented in validate_and_authorize,
+   * decremented in finalize_session.
+   */
+  activeSessions: number;
 };
 
 export type AgentEntryArgs = {
@@ -50,7 +73,31 @@ export type AgentEntryArgs = {
   capability: number;
   spendingLimitUsd: number | bigint;
   paused: boolean;
-  reserved: ReadonlyUint8Array;
+  /**
+   * Max sessions (validate_and_authorize + agent_transfer) per rolling hour.
+   * 0 = unlimited. Counted in AgentSpendOverlay.session_counters.
+   */
+  maxSessionsPerHour: number;
+  /**
+   * True when this agent's AgentSettings PDA narrows the vault policy
+   * and must be passed to validate_and_authorize.
+   */
+  hasSettings: boolean;
+  /**
+   * Unix timestamp after which this key is rejected. 0 = never expires.
+   * u32 to fit the last 4 reserved bytes; covers timestamps until 2106.
+   */
+  validUntil: number;
+  /**
+   * Concurrent session limit. 0 = one session per mint (session nonce
+   * must be 0); N > 0 = nonces 0..N and at most N open sessions.
+   */
+  maxConcurrentSessions: number;
+  /**
+   * Open sessions for this agent. Incremented in validate_and_authorize,
+   * decremented in finalize_session.
+   */
+  activeSessions: number;
 };
 
 export function getAgentEntryEncoder(): FixedSizeEncoder<AgentEntryArgs> {
@@ -59,7 +106,11 @@ export function getAgentEntryEncoder(): FixedSizeEncoder<AgentEntryArgs> {
     ["capability", getU8Encoder()],
     ["spendingLimitUsd", getU64Encoder()],
     ["paused", getBooleanEncoder()],
-    ["reserved", fixEncoderSize(getBytesEncoder(), 7)],
+    ["maxSessionsPerHour", getU16Encoder()],
+    ["hasSettings", getBooleanEncoder()],
+    ["validUntil", getU32Encoder()],
+    ["maxConcurrentSessions", getU8Encoder()],
+    ["activeSessions", getU8Encoder()],
   ]);
 }
 
@@ -69,7 +120,11 @@ export function getAgentEntryDecoder(): FixedSizeDecoder<AgentEntry> {
     ["capability", getU8Decoder()],
     ["spendingLimitUsd", getU64Decoder()],
     ["paused", getBooleanDecoder()],
-    ["reserved", fixDecoderSize(getBytesDecoder(), 7)],
+    ["maxSessionsPerHour", getU16Decoder()],
+    ["hasSettings", getBooleanDecoder()],
+    ["validUntil", getU32Decoder()],
+    ["maxConcurrentSessions", getU8Decoder()],
+    ["activeSessions", getU8Decoder()],
   ]);
 }
 
@@ -14,6 +14,8 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -29,6 +31,8 @@ export type AgentRegistered = {
   agent: Address;
   capability: number;
   spendingLimitUsd: bigint;
+  maxSessionsPerHour: number;
+  validUntil: bigint;
   timestamp: bigint;
 };
 
@@ -37,6 +41,8 @@ export type AgentRegisteredArgs = {
   agent: Address;
   capability: number;
   spendingLimitUsd: number | bigint;
+  maxSessionsPerHour: number;
+  validUntil: number | bigint;
   timestamp: number | bigint;
 };
 
@@ -46,6 +52,8 @@ export function getAgentRegisteredEncoder(): FixedSizeEncoder<AgentRegisteredArg
     ["agent", getAddressEncoder()],
     ["capability", getU8Encoder()],
     ["spendingLimitUsd", getU64Encoder()],
+    ["maxSessionsPerHour", getU16Encoder()],
+    ["validUntil", getI64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
 }
@@ -56,6 +64,8 @@ export function getAgentRegisteredDecoder(): FixedSizeDecoder<AgentRegistered> {
     ["agent", getAddressDecoder()],
     ["capability", getU8Decoder()],
     ["spendingLimitUsd", getU64Decoder()],
+    ["maxSessionsPerHour", getU16Decoder()],
+    ["validUntil", getI64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
 }
@@ -25,6 +25,13 @@ export type AgentTransferExecuted = {
   destination: Address;
   amount: bigint;
   mint: Address;
+  /** Per-destination rolling 24h cap in USD (0 = no per-destination cap) */
+  destinationCapUsd: bigint;
+  /**
+   * Destination's rolling 24h spend in USD after this transfer
+   * (0 when the destination tracker was not passed)
+   */
+  destinationRollingSpendUsd: bigint;
 };
 
 export type AgentTransferExecutedArgs = {
@@ -32,6 +39,13 @@ export type AgentTransferExecutedArgs = {
   destination: Address;
   amount: number | bigint;
   mint: Address;
+  /** Per-destination rolling 24h cap in USD (0 = no per-destination cap) */
+  destinationCapUsd: number | bigint;
+  /**
+   * Destination's rolling 24h spend in USD after this transfer
+   * (0 when the destination tracker was not passed)
+   */
+  destinationRollingSpendUsd: number | bigint;
 };
 
 export function getAgentTransferExecutedEncoder(): FixedSizeEncoder<AgentTransferExecutedArgs> {
@@ -40,6 +54,8 @@ export function getAgentTransferExecutedEncoder(): FixedSizeEncoder<AgentTransfe
     ["destination", getAddressEncoder()],
     ["amount", getU64Encoder()],
     ["mint", getAddressEncoder()],
+    ["destinationCapUsd", getU64Encoder()],
+    ["destinationRollingSpendUsd", getU64Encoder()],
   ]);
 }
 
@@ -49,6 +65,8 @@ export function getAgentTransferExecutedDecoder(): FixedSizeDecoder<AgentTransfe
     ["destination", getAddressDecoder()],
     ["amount", getU64Decoder()],
     ["mint", getAddressDecoder()],
+    ["destinationCapUsd", getU64Decoder()],
+    ["destinationRollingSpendUsd", getU64Decoder()],
   ]);
 }
 
@@ -21,7 +21,8 @@ import {
 
 /**
  * A single epoch bucket tracking aggregate USD spend.
- * 16 bytes per bucket. USD-only — rate limiting stays client-side.
+ * 16 bytes per bucket. USD-only — per-agent session rate limiting lives in
+ * `AgentSpendOverlay::session_counters`.
  */
 export type EpochBucket = {
   /** Epoch identifier: unix_timestamp / EPOCH_DURATION */
@@ -9,17 +9,22 @@
 export * from "./accountConstraint.js";
 export * from "./accountConstraintZC.js";
 export * from "./actionAuthorized.js";
+export * from "./agentAutoPaused.js";
 export * from "./agentContributionEntry.js";
 export * from "./agentEntry.js";
+export * from "./agentKeyRotated.js";
 export * from "./agentPausedEvent.js";
 export * from "./agentPermissionsChangeApplied.js";
 export * from "./agentPermissionsChangeCancelled.js";
 export * from "./agentPermissionsChangeQueued.js";
 export * from "./agentRegistered.js";
 export * from "./agentRevoked.js";
+export * from "./agentSessionCounter.js";
 export * from "./agentSpendLimitChecked.js";
 export * from "./agentTransferExecuted.js";
 export * from "./agentUnpausedEvent.js";
+export * from "./beneficiaryFundsClaimed.js";
+export * from "./bundledAgentUpdate.js";
 export * from "./closeConstraintsApplied.js";
 export * from "./closeConstraintsCancelled.js";
 export * from "./closeConstraintsQueued.js";
@@ -32,31 +37,57 @@ export * from "./constraintsChangeQueued.js";
 export * from "./dataConstraint.js";
 export * from "./dataConstraintZC.js";
 export * from "./delegationRevoked.js";
+export * from "./destinationSpendEntry.js";
 export * from "./discriminatorFormat.js";
 export * from "./epochBucket.js";
 export * from "./escrowCreated.js";
 export * from "./escrowRefunded.js";
 export * from "./escrowSettled.js";
 export * from "./escrowStatus.js";
+export * from "./expectedOutput.js";
+export * from "./feeDestinationChangeApplied.js";
+export * from "./feeDestinationChangeCancelled.js";
+export * from "./feeDestinationChangeQueued.js";
 export * from "./feesCollected.js";
+export * from "./fulfillmentReconciled.js";
+export * from "./fulfillmentReserved.js";
 export * from "./fundsDeposited.js";
 export * from "./fundsWithdrawn.js";
+export * from "./governanceBundleApplied.js";
+export * from "./governanceBundleCancelled.js";
+export * from "./governanceBundleCreated.js";
 export * from "./instructionConstraintsCreated.js";
+export * from "./mintSpendCap.js";
+export * from "./mintSpendWindow.js";
 export * from "./orphanConstraintsPdaCleaned.js";
+export * from "./ownerTransferApplied.js";
+export * from "./ownerTransferCancelled.js";
+export * from "./ownerTransferQueued.js";
 export * from "./pdaAllocated.js";
 export * from "./pdaExtended.js";
 export * from "./policyChangeApplied.js";
 export * from "./policyChangeCancelled.js";
 export * from "./policyChangeQueued.js";
+export * from "./policyTightened.js";
 export * from "./postAssertionChecked.js";
 export * from "./postAssertionEntry.js";
 export * from "./postAssertionEntryZC.js";
 export * from "./postAssertionsClosed.js";
 export * from "./postAssertionsCreated.js";
 export * from "./protocolSpendCounter.js";
+export * from "./recoveryCancelled.js";
+export * from "./recoveryQueued.js";
+export * from "./recoverySwept.js";
 export * from "./sessionFinalized.js";
+export * from "./sessionLeg.js";
+export * from "./spendRequestApproved.js";
+export * from "./spendRequestCancelled.js";
+export * from "./spendRequestCreated.js";
+export * from "./spendRequestExecuted.js";
+export * from "./spendRequestStatus.js";
 export * from "./vaultClosed.js";
 export * from "./vaultCreated.js";
 export * from "./vaultFrozen.js";
+export * from "./vaultMigrated.js";
 export * from "./vaultReactivated.js";
 export * from "./vaultStatus.js";
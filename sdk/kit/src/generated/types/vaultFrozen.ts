@@ -27,6 +27,8 @@ import {
 export type VaultFrozen = {
   vault: Address;
   owner: Address;
+  /** Signer that froze the vault: the owner or the guardian. */
+  frozenBy: Address;
   agentsPreserved: number;
   /**
    * Number of active session SPL delegations revoked during freeze (F2-H1 fix).
@@ -34,12 +36,19 @@ export type VaultFrozen = {
    * each pair whose session_pda matches the expected derivation is revoked.
    */
   sessionsRevoked: number;
+  /**
+   * FREEZE_REASON_* constant: manual kill switch, recovery queued, or
+   * dead-man switch.
+   */
+  reason: number;
   timestamp: bigint;
 };
 
 export type VaultFrozenArgs = {
   vault: Address;
   owner: Address;
+  /** Signer that froze the vault: the owner or the guardian. */
+  frozenBy: Address;
   agentsPreserved: number;
   /**
    * Number of active session SPL delegations revoked during freeze (F2-H1 fix).
@@ -47,6 +56,11 @@ export type VaultFrozenArgs = {
    * each pair whose session_pda matches the expected derivation is revoked.
    */
   sessionsRevoked: number;
+  /**
+   * FREEZE_REASON_* constant: manual kill switch, recovery queued, or
+   * dead-man switch.
+   */
+  reason: number;
   timestamp: number | bigint;
 };
 
@@ -54,8 +68,10 @@ export function getVaultFrozenEncoder(): FixedSizeEncoder<VaultFrozenArgs> {
   return getStructEncoder([
     ["vault", getAddressEncoder()],
     ["owner", getAddressEncoder()],
+    ["frozenBy", getAddressEncoder()],
     ["agentsPreserved", getU8Encoder()],
     ["sessionsRevoked", getU32Encoder()],
+    ["reason", getU8Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
 }
@@ -64,8 +80,10 @@ export function getVaultFrozenDecoder(): FixedSizeDecoder<VaultFrozen> {
   return getStructDecoder([
     ["vault", getAddressDecoder()],
     ["owner", getAddressDecoder()],
+    ["frozenBy", getAddressDecoder()],
     ["agentsPreserved", getU8Decoder()],
     ["sessionsRevoked", getU32Decoder()],
+    ["reason", getU8Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
 }
@@ -69,6 +69,17 @@ export async function getTrackerPDA(
   return [pda, bump];
 }
 
+export async function getDestinationSpendPDA(
+  vault: Address,
+  programAddress: Address = SIGIL_PROGRAM_ADDRESS,
+): Promise<[Address, number]> {
+  const [pda, bump] = await getProgramDerivedAddress({
+    programAddress,
+    seeds: [seedString("destination_spend"), seedAddress(vault)],
+  });
+  return [pda, bump];
+}
+
 export async function getSessionPDA(
   vault: Address,
   agent: Address,
@@ -36,6 +36,7 @@ import {
   type SigilPolicyPlugin,
 } from "./plugin.js";
 
+import { SIGIL_PROGRAM_ADDRESS } from "./generated/programs/sigil.js";
 import { VaultStatus } from "./generated/types/vaultStatus.js";
 import { getValidateAndAuthorizeInstructionAsync } from "./generated/instructions/validateAndAuthorize.js";
 import { getFinalizeSessionInstructionAsync } from "./generated/instructions/finalizeSession.js";
@@ -730,11 +731,22 @@ export async function seal(params: SealParams): Promise<SealResult> {
     ataReplacements,
   );
 
-  // Step 8: Build validate_and_authorize instruction
+  // Step 8: Build validate_and_authorize instruction. Optional PDAs the
+  // session does not use are passed as the program address (Anchor's None);
+  // left unset, the generated builder would derive them and the program
+  // would try to load accounts that were never created.
+  const trackerExtension = state.vault.hasTrackerExtension
+    ? undefined
+    : SIGIL_PROGRAM_ADDRESS;
   const validateIxBase = await getValidateAndAuthorizeInstructionAsync({
     agent: params.agent,
     vault: params.vault,
     agentSpendOverlay: agentOverlayPda,
+    trackerExtension,
+    agentSettings: agentEntry.hasSettings ? undefined : SIGIL_PROGRAM_ADDRESS,
+    permitNonces: SIGIL_PROGRAM_ADDRESS,
+    session: sessionPda,
+    pendingFulfillment: SIGIL_PROGRAM_ADDRESS,
     vaultTokenAccount,
     tokenMintAccount: params.tokenMint,
     protocolTreasuryTokenAccount,
@@ -744,6 +756,9 @@ export async function seal(params: SealParams): Promise<SealResult> {
     amount: params.amount,
     targetProtocol,
     expectedPolicyVersion: state.policy.policyVersion ?? 0n,
+    legs: [],
+    sessionNonce: 0,
+    expectedOutput: null,
   });
 
   // Step 8b: When the vault has instruction constraints configured, the on-chain
@@ -777,8 +792,10 @@ export async function seal(params: SealParams): Promise<SealResult> {
     session: sessionPda,
     sessionRentRecipient: params.agent.address,
     agentSpendOverlay: agentOverlayPda,
+    trackerExtension,
     vaultTokenAccount,
     outputStablecoinAccount,
+    pendingFulfillment: SIGIL_PROGRAM_ADDRESS,
   });
 
   // Step 10: Compose + compile + measure
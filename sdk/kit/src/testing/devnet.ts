@@ -289,6 +289,8 @@ export async function provisionVault(
     agent: agent.address,
     capability: Number(permissions),
     spendingLimitUsd,
+    maxSessionsPerHour: 0,
+    validUntil: null,
   });
 
   await sendKitTransaction(rpc, owner, [registerIx as Instruction]);
@@ -14,7 +14,7 @@
  */
 
 // ────────────────────────────────────────────────────────────────
-// Sigil program errors (6000-6087)
+// Sigil program errors (6000-6147)
 // ────────────────────────────────────────────────────────────────
 
 export const SIGIL_ERRORS = {
@@ -106,6 +106,66 @@ export const SIGIL_ERRORS = {
   LamportDrainBlocked: 6085,
   BatchInstructionBlocked: 6086,
   InvalidDestinationMode: 6087,
+  PolicyChangeNotTightening: 6088,
+  WeeklySpendingCapExceeded: 6089,
+  MonthlySpendingCapExceeded: 6090,
+  TrackerExtensionRequired: 6091,
+  MintSpendingCapExceeded: 6092,
+  InvalidMintCaps: 6093,
+  DestinationSpendCapExceeded: 6094,
+  DestinationCapsMismatch: 6095,
+  DestinationTrackerRequired: 6096,
+  AgentSessionRateExceeded: 6097,
+  OutsideTradingWindow: 6098,
+  AgentSettingsRequired: 6099,
+  AgentProtocolNotAllowed: 6100,
+  AgentOverrideNotTighter: 6101,
+  AgentKeyExpired: 6102,
+  InvalidAgentExpiry: 6103,
+  UnauthorizedOwnerOrGuardian: 6104,
+  InvalidGuardian: 6105,
+  InvalidNewOwner: 6106,
+  InvalidRecoveryConfig: 6107,
+  UnauthorizedRecoveryAuthority: 6108,
+  InvalidDeadManSwitchConfig: 6109,
+  OwnerNotInactive: 6110,
+  DeadManSwitchTriggered: 6111,
+  DeadManSwitchNotTriggered: 6112,
+  UnauthorizedBeneficiary: 6113,
+  InvalidPermit: 6114,
+  PermitExpired: 6115,
+  PermitNonceUsed: 6116,
+  PermitCeilingExceeded: 6117,
+  InvalidSpendRequestExpiry: 6118,
+  SpendRequestNotPending: 6119,
+  SpendRequestNotApproved: 6120,
+  SpendRequestExpired: 6121,
+  SpendRequestMismatch: 6122,
+  UnauthorizedSpendRequestAction: 6123,
+  InvalidGovernanceBundle: 6124,
+  GovernanceBundleMismatch: 6125,
+  InvalidApproverConfig: 6126,
+  InsufficientApprovals: 6127,
+  InvalidCpiCallers: 6128,
+  CpiCallerNotAllowed: 6129,
+  InvalidSessionLegs: 6130,
+  SessionLegMismatch: 6131,
+  TooManyConcurrentSessions: 6132,
+  InvalidConcurrentSessionLimit: 6133,
+  FeeCapExceeded: 6134,
+  InvalidPendingFulfillment: 6135,
+  FulfillmentWindowOpen: 6136,
+  OpenFulfillmentsExist: 6137,
+  ExpectedOutputRequired: 6138,
+  InvalidExpectedOutput: 6139,
+  MinimumOutputNotMet: 6140,
+  PendingPolicySuperseded: 6141,
+  SpendRequestChanged: 6142,
+  GovernanceBundleOpen: 6143,
+  CappedProtocolInMultiLegSession: 6144,
+  AgentSessionsOpen: 6145,
+  QueuedBeforeOwnerTransfer: 6146,
+  NotLegacyVault: 6147,
 } as const;
 
 /**
@@ -27,11 +27,13 @@ import { TransactionExecutor } from "../../src/transaction-executor.js";
 import { getValidateAndAuthorizeInstructionAsync } from "../../src/generated/instructions/validateAndAuthorize.js";
 import { getFinalizeSessionInstructionAsync } from "../../src/generated/instructions/finalizeSession.js";
 import { resolveVaultState } from "../../src/state-resolver.js";
+import { getSessionPDA } from "../../src/resolve-accounts.js";
 import { deriveAta } from "../../src/x402/transfer-builder.js";
 import {
   USDC_MINT_DEVNET,
   PROTOCOL_TREASURY,
   JUPITER_PROGRAM_ADDRESS,
+  SIGIL_PROGRAM_ADDRESS,
 } from "../../src/types.js";
 
 // Skip entire file if no devnet env
@@ -45,10 +47,19 @@ async function buildSwapInstructions(
   protocolTreasuryAta: Address,
   amount: bigint,
 ) {
+  const [sessionPDA] = await getSessionPDA(
+    vault.vaultAddress,
+    agent.address,
+    USDC_MINT_DEVNET,
+  );
   const validateIx = await getValidateAndAuthorizeInstructionAsync({
     agent,
     vault: vault.vaultAddress,
     agentSpendOverlay: vault.overlayPDA,
+    agentSettings: SIGIL_PROGRAM_ADDRESS,
+    permitNonces: SIGIL_PROGRAM_ADDRESS,
+    session: sessionPDA,
+    pendingFulfillment: SIGIL_PROGRAM_ADDRESS,
     vaultTokenAccount: vaultTokenAta,
     tokenMintAccount: USDC_MINT_DEVNET,
     protocolTreasuryTokenAccount: protocolTreasuryAta,
@@ -56,15 +67,19 @@ async function buildSwapInstructions(
     amount,
     targetProtocol: JUPITER_PROGRAM_ADDRESS,
     expectedPolicyVersion: 0n,
+    legs: [],
+    sessionNonce: 0,
+    expectedOutput: null,
   });
 
   const finalizeIx = await getFinalizeSessionInstructionAsync({
     payer: agent,
     vault: vault.vaultAddress,
-    session: (validateIx as any).accounts[5].address, // session PDA auto-derived by validate
+    session: sessionPDA,
     sessionRentRecipient: agent.address,
     agentSpendOverlay: vault.overlayPDA,
     vaultTokenAccount: vaultTokenAta,
+    pendingFulfillment: SIGIL_PROGRAM_ADDRESS,
   });
 
   return {
@@ -137,6 +137,24 @@ describe("Kit SDK Devnet — Vault Lifecycle", function () {
       hasProtocolCaps: null,
       protocolCaps: null,
       destinationMode: null,
+      weeklySpendingCapUsd: null,
+      monthlySpendingCapUsd: null,
+      mintCaps: null,
+      destinationCaps: null,
+      tradingHours: null,
+      tradingHoursExemptNonSpending: null,
+      guardian: null,
+      recoveryAuthority: null,
+      recoveryDestination: null,
+      inactivityWindowSeconds: null,
+      beneficiary: null,
+      approvers: null,
+      approvalThreshold: null,
+      cpiCallers: null,
+      dailyFeeCapUsd: null,
+      maxAbandonedSessionsPerHour: null,
+      allowAsyncFulfillment: null,
+      maxProtocolSlippageBps: null,
     });
 
     await sendKitTransaction(rpc, owner, [queueIx as Instruction]);
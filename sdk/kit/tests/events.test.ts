@@ -140,8 +140,8 @@ describe("events", () => {
   });
 
   describe("getEventNames", () => {
-    it("returns 38 names", () => {
-      expect(getEventNames()).to.have.length(38);
+    it("returns 61 names", () => {
+      expect(getEventNames()).to.have.length(61);
     });
 
     it("includes known names", () => {
@@ -155,7 +155,13 @@ describe("analytics-counters", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -195,7 +201,15 @@ describe("analytics-counters", () => {
 
   async function buildValidateIx(amount: BN) {
     return program.methods
-      .validateAndAuthorize(usdcMint, amount, jupiterProgramId, new BN(0))
+      .validateAndAuthorize(
+        usdcMint,
+        amount,
+        jupiterProgramId,
+        new BN(0),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accountsPartial({
         agent: agent.publicKey,
         vault: vaultPda,
@@ -211,6 +225,10 @@ describe("analytics-counters", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
   }
@@ -231,6 +249,8 @@ describe("analytics-counters", () => {
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         outputStablecoinAccount: null,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
   }
@@ -334,7 +354,13 @@ describe("analytics-counters", () => {
     airdropSol(svm, agent2.publicKey, 5 * LAMPORTS_PER_SOL);
 
     await program.methods
-      .registerAgent(agent2.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent2.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -357,6 +383,7 @@ describe("analytics-counters", () => {
         owner: owner.publicKey,
         vault: vaultPda,
         agentSpendOverlay: overlayPda,
+        agentSettings: null,
       })
       .rpc();
 
@@ -364,7 +364,13 @@ describe("cu-budget", () => {
       .rpc();
 
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault,
@@ -424,7 +430,15 @@ describe("cu-budget", () => {
       program.programId,
     );
     let builder = program.methods
-      .validateAndAuthorize(usdcMint, amount, targetProtocol, new BN(0))
+      .validateAndAuthorize(
+        usdcMint,
+        amount,
+        targetProtocol,
+        new BN(0),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accountsPartial({
         agent: agent.publicKey,
         vault: ctx.vault,
@@ -440,6 +454,10 @@ describe("cu-budget", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       });
     if (remainingAccounts)
       builder = builder.remainingAccounts(remainingAccounts);
@@ -472,6 +490,8 @@ describe("cu-budget", () => {
         outputStablecoinAccount: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
   }
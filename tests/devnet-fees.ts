@@ -357,6 +357,9 @@ describe("devnet-fees", () => {
         feeDestinationTokenAccount: vaultA.feeDestinationAta,
         protocolTreasuryTokenAccount: vaultA.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agentA])
       .rpc();
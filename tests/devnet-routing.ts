@@ -628,6 +628,9 @@ describe("devnet-routing", () => {
         feeDestinationTokenAccount: null,
         protocolTreasuryTokenAccount: vault.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -669,6 +672,9 @@ describe("devnet-routing", () => {
         feeDestinationTokenAccount: null,
         protocolTreasuryTokenAccount: vault.usdtTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -743,6 +749,9 @@ describe("devnet-routing", () => {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agent])
         .rpc();
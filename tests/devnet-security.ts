@@ -119,6 +119,25 @@ describe("devnet-security", () => {
           null, // sessionExpirySeconds
           null, // hasProtocolCaps
           null, // protocolCaps
+          null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: attacker.publicKey,
@@ -144,6 +163,7 @@ describe("devnet-security", () => {
           owner: attacker.publicKey,
           vault: vault.vaultPda,
           agentSpendOverlay: vault.overlayPda,
+          agentSettings: null,
         } as any)
         .signers([attacker])
         .rpc();
@@ -217,6 +237,9 @@ describe("devnet-security", () => {
           new BN(10_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: attacker.publicKey,
@@ -233,6 +256,10 @@ describe("devnet-security", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([attacker])
         .rpc();
@@ -258,6 +285,25 @@ describe("devnet-security", () => {
           null, // sessionExpirySeconds
           null, // hasProtocolCaps
           null, // protocolCaps
+          null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: agent.publicKey,
@@ -516,6 +562,7 @@ describe("devnet-security", () => {
         owner: owner.publicKey,
         vault: freshVault.vaultPda,
         agentSpendOverlay: freshVault.overlayPda,
+        agentSettings: null,
       } as any)
       .rpc();
 
@@ -582,6 +629,7 @@ describe("devnet-security", () => {
         owner: owner.publicKey,
         vault: freshVault.vaultPda,
         agentSpendOverlay: freshVault.overlayPda,
+        agentSettings: null,
       } as any)
       .rpc();
 
@@ -742,6 +790,7 @@ describe("devnet-security", () => {
         owner: owner.publicKey,
         vault: freshVault.vaultPda,
         agentSpendOverlay: freshVault.overlayPda,
+        agentSettings: null,
       } as any)
       .rpc();
 
@@ -769,6 +818,9 @@ describe("devnet-security", () => {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: freshVault.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([freshAgent])
         .rpc();
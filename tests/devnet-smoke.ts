@@ -194,7 +194,13 @@ describe("devnet-smoke-test", () => {
       program.programId,
     );
     await program.methods
-      .registerAgent(agent.publicKey, 2, new BN(0)) // FULL_CAPABILITY
+      .registerAgent(
+        agent.publicKey,
+        2,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      ) // FULL_CAPABILITY
       .accounts({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -225,6 +231,25 @@ describe("devnet-smoke-test", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
@@ -263,6 +288,9 @@ describe("devnet-smoke-test", () => {
         new BN(50_000_000), // 50 tokens
         jupiterProgramId,
         new BN(0),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
       )
       .accounts({
         agent: agent.publicKey,
@@ -279,6 +307,10 @@ describe("devnet-smoke-test", () => {
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         outputStablecoinAccount: null,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       } as any)
       .instruction();
 
@@ -300,6 +332,8 @@ describe("devnet-smoke-test", () => {
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         outputStablecoinAccount: null,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       } as any)
       .instruction();
 
@@ -357,6 +391,7 @@ describe("devnet-smoke-test", () => {
         owner: owner.publicKey,
         vault: vaultPda,
         agentSpendOverlay: overlayPda,
+        agentSettings: null,
       } as any)
       .rpc();
 
@@ -397,6 +397,9 @@ describe("devnet-spending", () => {
         feeDestinationTokenAccount: null,
         protocolTreasuryTokenAccount: vault.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -478,6 +481,25 @@ describe("devnet-spending", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
@@ -93,6 +93,25 @@ describe("devnet-timelock", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
@@ -142,6 +161,25 @@ describe("devnet-timelock", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
@@ -198,6 +236,25 @@ describe("devnet-timelock", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
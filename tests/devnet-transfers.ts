@@ -133,6 +133,9 @@ describe("devnet-transfers", () => {
         feeDestinationTokenAccount: vaultAllowlist.feeDestinationAta,
         protocolTreasuryTokenAccount: vaultAllowlist.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -159,6 +162,9 @@ describe("devnet-transfers", () => {
           feeDestinationTokenAccount: vaultAllowlist.feeDestinationAta,
           protocolTreasuryTokenAccount: vaultAllowlist.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agent])
         .rpc();
@@ -192,6 +198,9 @@ describe("devnet-transfers", () => {
         feeDestinationTokenAccount: null,
         protocolTreasuryTokenAccount: vaultAnyDest.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -229,6 +238,9 @@ describe("devnet-transfers", () => {
         feeDestinationTokenAccount: vaultAllowlist.feeDestinationAta,
         protocolTreasuryTokenAccount: vaultAllowlist.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -267,6 +279,9 @@ describe("devnet-transfers", () => {
           feeDestinationTokenAccount: vaultAllowlist.feeDestinationAta,
           protocolTreasuryTokenAccount: vaultAllowlist.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([attacker])
         .rpc();
@@ -309,6 +324,9 @@ describe("devnet-transfers", () => {
         feeDestinationTokenAccount: null,
         protocolTreasuryTokenAccount: smallCapVault.protocolTreasuryAta,
         tokenProgram: TOKEN_PROGRAM_ID,
+        agentSettings: null,
+        permitNonces: null,
+        spendRequest: null,
       } as any)
       .signers([agent])
       .rpc();
@@ -329,6 +347,9 @@ describe("devnet-transfers", () => {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: smallCapVault.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agent])
         .rpc();
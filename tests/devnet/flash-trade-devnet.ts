@@ -240,7 +240,13 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
 
       // Register agent
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: vaultPda,
@@ -319,6 +325,9 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           new BN(500_000), // $0.50
           FLASH_TRADE_DEVNET,
           new BN(0), // expectedPolicyVersion
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -335,6 +344,10 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -352,6 +365,8 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -504,6 +519,9 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           amount,
           FLASH_TRADE_DEVNET,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -520,6 +538,10 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -537,6 +559,8 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -610,7 +634,13 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: vaultPda,
@@ -825,7 +855,13 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: pdas.vaultPda,
@@ -869,6 +905,9 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           new BN(100_000),
           FLASH_TRADE_DEVNET,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -885,6 +924,10 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -908,6 +951,8 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -963,7 +1008,13 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: pdas.vaultPda,
@@ -1006,6 +1057,9 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
             new BN(100_000),
             randomProgram, // NOT in allowlist
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1022,6 +1076,10 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .instruction();
 
@@ -1044,6 +1102,8 @@ describe("⚡ FLASH TRADE DEVNET — Real Perpetuals Through Sigil", function ()
             outputStablecoinAccount: null,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           } as any)
           .instruction();
 
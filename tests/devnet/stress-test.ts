@@ -117,6 +117,8 @@ async function createVault(opts: {
       opts.agent.publicKey,
       FULL_CAPABILITY,
       opts.agentSpendLimit ?? new BN(0),
+      0, // maxSessionsPerHour
+      null, // validUntil
     )
     .accounts({
       owner: owner.publicKey,
@@ -180,6 +182,9 @@ async function doAgentTransfer(
       protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
       tokenProgram: TOKEN_PROGRAM_ID,
       agentSpendOverlay: overlay,
+      agentSettings: null,
+      permitNonces: null,
+      spendRequest: null,
     } as any)
     .signers([agent])
     .rpc();
@@ -206,7 +211,15 @@ async function doComposedTx(
   });
 
   const validateIx = await program.methods
-    .validateAndAuthorize(usdcMint, amount, allowedProtocol, new BN(0))
+    .validateAndAuthorize(
+      usdcMint,
+      amount,
+      allowedProtocol,
+      new BN(0),
+      [], // legs
+      0, // sessionNonce
+      null, // expectedOutput
+    )
     .accounts({
       agent: agent.publicKey,
       vault,
@@ -222,6 +235,10 @@ async function doComposedTx(
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      agentSettings: null,
+      permitNonces: null,
+      pendingFulfillment: null,
+      outputTokenAccount: null,
     } as any)
     .instruction();
 
@@ -247,6 +264,8 @@ async function doComposedTx(
       outputStablecoinAccount: null,
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
+      outputTokenAccount: null,
+      pendingFulfillment: null,
     } as any)
     .instruction();
 
@@ -637,7 +656,7 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
       // Freeze
       await program.methods
         .freezeVault()
-        .accounts({ owner: owner.publicKey, vault: v.vault } as any)
+        .accounts({ authority: owner.publicKey, vault: v.vault } as any)
         .rpc();
 
       // Try transfer while frozen
@@ -837,7 +856,13 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
         .rpc();
 
       await program.methods
-        .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          destAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: destPdas.vaultPda,
@@ -911,6 +936,7 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           agentSpendOverlay: sourceV.overlay,
+          agentSettings: null,
         } as any)
         .signers([escrowAgent])
         .rpc();
@@ -988,6 +1014,8 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           multiAgent2.publicKey,
           FULL_CAPABILITY,
           new BN(75_000_000), // $75 per-agent
+          0, // maxSessionsPerHour
+          null, // validUntil
         )
         .accounts({
           owner: owner.publicKey,
@@ -1201,6 +1229,9 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           new BN(0), // amount=0 for non-spending
           allowedProtocol,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agentA.publicKey,
@@ -1217,6 +1248,10 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -1240,6 +1275,8 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -1297,6 +1334,9 @@ describe("🔥 SIGIL DEVNET STRESS TEST — Real Tokens, Real Limits", function
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           agentSpendOverlay: feeV.overlay,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agentA])
         .rpc();
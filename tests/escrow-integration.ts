@@ -153,6 +153,7 @@ describe("escrow-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        agentSettings: null,
       } as any)
       .signers([sourceAgent])
       .rpc();
@@ -302,7 +303,13 @@ describe("escrow-integration", () => {
 
     // Register source agent
     await program.methods
-      .registerAgent(sourceAgent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        sourceAgent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: sourceOwner.publicKey,
         vault: sourceVaultPda,
@@ -373,7 +380,13 @@ describe("escrow-integration", () => {
 
     // Register destination agent
     await destProgram.methods
-      .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        destAgent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: destOwnerKeypair.publicKey,
         vault: destVaultPda,
@@ -1059,6 +1072,7 @@ describe("escrow-integration", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .signers([sourceAgent])
         .rpc();
@@ -1126,6 +1140,7 @@ describe("escrow-integration", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .signers([sourceAgent])
         .rpc();
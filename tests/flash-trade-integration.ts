@@ -138,7 +138,15 @@ describe("flash-trade-integration", () => {
     const currentVersion = (polAcct as any).policyVersion ?? new BN(0);
 
     const validateIx = await program.methods
-      .validateAndAuthorize(tokenMint, amount, targetProtocol, currentVersion)
+      .validateAndAuthorize(
+        tokenMint,
+        amount,
+        targetProtocol,
+        currentVersion,
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accountsPartial({
         agent: agentKp.publicKey,
         vault,
@@ -154,6 +162,10 @@ describe("flash-trade-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
 
@@ -173,6 +185,8 @@ describe("flash-trade-integration", () => {
         outputStablecoinAccount: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
 
@@ -264,7 +278,13 @@ describe("flash-trade-integration", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -441,7 +461,13 @@ describe("flash-trade-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: frozenVault,
@@ -456,6 +482,7 @@ describe("flash-trade-integration", () => {
           owner: owner.publicKey,
           vault: frozenVault,
           agentSpendOverlay: frozenOverlay,
+          agentSettings: null,
         })
         .rpc();
     });
@@ -610,7 +637,13 @@ describe("flash-trade-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(capAgentKp.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          capAgentKp.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: capVault,
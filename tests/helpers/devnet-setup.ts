@@ -360,7 +360,13 @@ export async function createFullVault(
   // Register agent (multi-agent: agent pubkey + permissions bitmask + spending limit)
   if (!skipAgent) {
     await program.methods
-      .registerAgent(agent.publicKey, 2, new BN(0)) // FULL_CAPABILITY
+      .registerAgent(
+        agent.publicKey,
+        2,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      ) // FULL_CAPABILITY
       .accounts({
         owner: owner.publicKey,
         vault: pdas.vaultPda,
@@ -462,7 +468,15 @@ export async function buildAuthorizeIx(opts: AuthorizeOpts) {
     program.programId,
   );
   return program.methods
-    .validateAndAuthorize(mint, amount, protocol, policyVersion)
+    .validateAndAuthorize(
+      mint,
+      amount,
+      protocol,
+      policyVersion,
+      [], // legs
+      0, // sessionNonce
+      null, // expectedOutput
+    )
     .accounts({
       agent: agent.publicKey,
       vault: vaultPda,
@@ -478,6 +492,10 @@ export async function buildAuthorizeIx(opts: AuthorizeOpts) {
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      agentSettings: null,
+      permitNonces: null,
+      pendingFulfillment: null,
+      outputTokenAccount: null,
     } as any)
     .remainingAccounts(remainingAccounts)
     .instruction();
@@ -533,6 +551,8 @@ export async function buildFinalizeIx(opts: FinalizeOpts) {
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      outputTokenAccount: null,
+      pendingFulfillment: null,
     })
     .instruction();
 }
@@ -801,7 +801,13 @@ export async function setupVaultWithAgent(
 
   if (!skipAgent) {
     await program.methods
-      .registerAgent(agent.publicKey, agentCapability, agentSpendingLimit)
+      .registerAgent(
+        agent.publicKey,
+        agentCapability,
+        agentSpendingLimit,
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault: pdas.vaultPda,
@@ -182,7 +182,13 @@ describe("instruction-constraints", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -236,6 +242,8 @@ describe("instruction-constraints", () => {
         outputStablecoinAccount: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
   }
@@ -260,7 +268,15 @@ describe("instruction-constraints", () => {
       program.programId,
     );
     let builder = program.methods
-      .validateAndAuthorize(usdcMint, amount, targetProtocol, await pv())
+      .validateAndAuthorize(
+        usdcMint,
+        amount,
+        targetProtocol,
+        await pv(),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accounts({
         agent: agent.publicKey,
         vault: vaultPda,
@@ -276,6 +292,10 @@ describe("instruction-constraints", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       } as any);
     if (remainingAccounts) {
       builder = builder.remainingAccounts(remainingAccounts);
@@ -2095,7 +2115,13 @@ describe("instruction-constraints", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(cvAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          cvAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: cvVault,
@@ -2149,6 +2175,9 @@ describe("instruction-constraints", () => {
           amount,
           targetProtocol,
           await pv(cvPolicy),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: cvAgent.publicKey,
@@ -2165,6 +2194,10 @@ describe("instruction-constraints", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any);
       if (remainingAccounts)
         builder = builder.remainingAccounts(remainingAccounts);
@@ -2195,6 +2228,8 @@ describe("instruction-constraints", () => {
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
     }
@@ -3651,7 +3686,13 @@ describe("instruction-constraints", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(f1Agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          f1Agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: f1Vault,
@@ -3731,6 +3772,8 @@ describe("instruction-constraints", () => {
           outputStablecoinAccount: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
     }
@@ -3755,7 +3798,15 @@ describe("instruction-constraints", () => {
       const policyAccount = await program.account.policyConfig.fetch(f1Policy);
       const policyVer = (policyAccount as any).policyVersion ?? new BN(0);
       return program.methods
-        .validateAndAuthorize(usdcMint, amount, jupiterProgramId, policyVer)
+        .validateAndAuthorize(
+          usdcMint,
+          amount,
+          jupiterProgramId,
+          policyVer,
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accounts({
           agent: f1Agent.publicKey,
           vault: f1Vault,
@@ -3771,6 +3822,10 @@ describe("instruction-constraints", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .remainingAccounts(remainingAccounts)
         .instruction();
@@ -166,7 +166,15 @@ describe("jupiter-integration", () => {
 
     // 2. Validate and authorize
     const validateIx = await program.methods
-      .validateAndAuthorize(tokenMint, amount, targetProtocol, new BN(0))
+      .validateAndAuthorize(
+        tokenMint,
+        amount,
+        targetProtocol,
+        new BN(0),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accountsPartial({
         agent: agentKp.publicKey,
         vault,
@@ -182,6 +190,10 @@ describe("jupiter-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
 
@@ -204,6 +216,8 @@ describe("jupiter-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
 
@@ -295,7 +309,13 @@ describe("jupiter-integration", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -558,7 +578,13 @@ describe("jupiter-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: frozenVault,
@@ -573,6 +599,7 @@ describe("jupiter-integration", () => {
           owner: owner.publicKey,
           vault: frozenVault,
           agentSpendOverlay: frozenOverlay,
+          agentSettings: null,
         })
         .rpc();
 
@@ -684,7 +711,13 @@ describe("jupiter-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: rollingVault,
@@ -139,6 +139,9 @@ describe("jupiter-lend-integration", () => {
         amount,
         targetProtocol,
         new BN(0), // expectedPolicyVersion
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
       )
       .accountsPartial({
         agent: agentKp.publicKey,
@@ -155,6 +158,10 @@ describe("jupiter-lend-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
 
@@ -177,6 +184,8 @@ describe("jupiter-lend-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
 
@@ -264,7 +273,13 @@ describe("jupiter-lend-integration", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -487,7 +502,13 @@ describe("jupiter-lend-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: frozenVault,
@@ -506,6 +527,7 @@ describe("jupiter-lend-integration", () => {
           owner: owner.publicKey,
           vault: frozenVault,
           agentSpendOverlay: frozenOverlayRevoke,
+          agentSettings: null,
         })
         .rpc();
     });
@@ -604,7 +626,13 @@ describe("jupiter-lend-integration", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accountsPartial({
           owner: owner.publicKey,
           vault: rollingVault,
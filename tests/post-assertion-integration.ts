@@ -236,6 +236,9 @@ describe("post-assertion-integration", () => {
         amountUsd,
         jupiterProgramId,
         await currentPolicyVersion(),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
       )
       .accountsPartial({
         agent: agent.publicKey,
@@ -252,6 +255,10 @@ describe("post-assertion-integration", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
 
@@ -270,6 +277,8 @@ describe("post-assertion-integration", () => {
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         outputStablecoinAccount: null,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .remainingAccounts([
         { pubkey: postAssertionsPda, isSigner: false, isWritable: false },
@@ -395,7 +404,13 @@ describe("post-assertion-integration", () => {
 
     // Register agent with full capability.
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -586,6 +601,9 @@ describe("post-assertion-integration", () => {
           new BN(1_000_000),
           jupiterProgramId,
           await currentPolicyVersion(),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -602,6 +620,10 @@ describe("post-assertion-integration", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
       // Deliberately pass EMPTY remaining_accounts despite has_post_assertions=1.
@@ -620,6 +642,8 @@ describe("post-assertion-integration", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .remainingAccounts([])
         .instruction();
@@ -648,6 +672,9 @@ describe("post-assertion-integration", () => {
           new BN(1_000_000),
           jupiterProgramId,
           await currentPolicyVersion(),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -664,6 +691,10 @@ describe("post-assertion-integration", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
       // Pass the assertions PDA but OMIT the target account.
@@ -682,6 +713,8 @@ describe("post-assertion-integration", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .remainingAccounts([
           { pubkey: postAssertionsPda, isSigner: false, isWritable: false },
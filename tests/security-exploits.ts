@@ -180,7 +180,13 @@ describe("security-exploits", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -241,6 +247,9 @@ describe("security-exploits", () => {
         amount,
         jupiterProgramId,
         currentPolicyVersion,
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
       )
       .accounts({
         agent: agentKp.publicKey,
@@ -261,6 +270,10 @@ describe("security-exploits", () => {
           [Buffer.from("agent_spend"), vault.toBuffer(), Buffer.from([0])],
           program.programId,
         )[0],
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       } as any)
       .instruction();
 
@@ -283,6 +296,8 @@ describe("security-exploits", () => {
           [Buffer.from("agent_spend"), vault.toBuffer(), Buffer.from([0])],
           program.programId,
         )[0],
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       } as any)
       .instruction();
 
@@ -348,7 +363,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(sessAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          sessAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: sessVault,
@@ -396,6 +417,9 @@ describe("security-exploits", () => {
           new BN(10_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sessAgent.publicKey,
@@ -420,6 +444,10 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -446,6 +474,8 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -476,6 +506,8 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           } as any)
           .signers([sessAgent])
           .rpc();
@@ -505,6 +537,9 @@ describe("security-exploits", () => {
           new BN(5_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sessAgent.publicKey,
@@ -529,6 +564,10 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -555,6 +594,8 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -587,6 +628,9 @@ describe("security-exploits", () => {
           new BN(5_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sessAgent.publicKey,
@@ -611,6 +655,10 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -637,6 +685,8 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -710,7 +760,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(capAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          capAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: capVault,
@@ -785,7 +841,15 @@ describe("security-exploits", () => {
         program.programId,
       )[0];
       const validateIx = await program.methods
-        .validateAndAuthorize(usdcMint, new BN(1), jupiterProgramId, new BN(0))
+        .validateAndAuthorize(
+          usdcMint,
+          new BN(1),
+          jupiterProgramId,
+          new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accounts({
           agent: capAgent.publicKey,
           vault: capVault,
@@ -801,6 +865,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: overlayPda,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -818,6 +886,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: overlayPda,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       // TX succeeds because actual_spend=0 (no DeFi instruction to move tokens)
@@ -877,7 +947,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(txAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          txAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: txVault,
@@ -926,6 +1002,9 @@ describe("security-exploits", () => {
           new BN(41_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: txAgent.publicKey,
@@ -942,6 +1021,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: txOverlayPda,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -959,6 +1042,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: txOverlayPda,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       // Outcome-based model: TX succeeds because actual_spend=0 (no DeFi ix).
@@ -991,6 +1076,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: attacker.publicKey,
@@ -1022,6 +1125,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([attacker])
           .rpc();
@@ -1127,6 +1231,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: agent.publicKey,
@@ -1165,6 +1287,9 @@ describe("security-exploits", () => {
           new BN(5_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -1185,6 +1310,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), vaultPda.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -1207,6 +1336,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), vaultPda.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -1327,6 +1458,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -1416,7 +1565,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(stAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          stAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: stVault,
@@ -1457,6 +1612,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), stVault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -1484,6 +1640,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: stAgent.publicKey,
@@ -1500,6 +1659,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: stOverlayPda,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -1517,6 +1680,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: stOverlayPda,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -1564,6 +1729,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), stVault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -1678,6 +1844,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -1744,6 +1928,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -2105,7 +2307,13 @@ describe("security-exploits", () => {
         .rpc();
       try {
         await program.methods
-          .registerAgent(PublicKey.default, FULL_CAPABILITY, new BN(0)) // zero address
+          .registerAgent(
+            PublicKey.default,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          ) // zero address
           .accounts({
             owner: owner.publicKey,
             vault: rv,
@@ -2166,7 +2374,13 @@ describe("security-exploits", () => {
         .rpc();
       try {
         await program.methods
-          .registerAgent(owner.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            owner.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: rv,
@@ -2281,6 +2495,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           opts.destinationMode, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -2307,7 +2539,13 @@ describe("security-exploits", () => {
     }
 
     await program.methods
-      .registerAgent(agentKp.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agentKp.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault,
@@ -2379,7 +2617,15 @@ describe("security-exploits", () => {
       (policyAccount as any).policyVersion ?? new BN(0);
 
     const validateIx = await program.methods
-      .validateAndAuthorize(tokenMint, amount, protocol, currentPolicyVersion)
+      .validateAndAuthorize(
+        tokenMint,
+        amount,
+        protocol,
+        currentPolicyVersion,
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
+      )
       .accounts({
         agent: agentKp.publicKey,
         vault,
@@ -2399,6 +2645,10 @@ describe("security-exploits", () => {
           [Buffer.from("agent_spend"), vault.toBuffer(), Buffer.from([0])],
           program.programId,
         )[0],
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       } as any)
       .remainingAccounts(remainingAccounts)
       .instruction();
@@ -2422,6 +2672,8 @@ describe("security-exploits", () => {
           [Buffer.from("agent_spend"), vault.toBuffer(), Buffer.from([0])],
           program.programId,
         )[0],
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       } as any)
       .instruction();
 
@@ -2457,6 +2709,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v1.agentKp.publicKey,
@@ -2481,6 +2736,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([v1.agentKp])
           .rpc();
@@ -2512,6 +2771,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v1.agentKp.publicKey,
@@ -2536,6 +2798,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([v1.agentKp])
           .rpc();
@@ -2569,6 +2835,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v1.agentKp.publicKey,
@@ -2589,6 +2858,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v1.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -2612,6 +2885,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v1.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -2651,6 +2926,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v1.agentKp.publicKey,
@@ -2675,6 +2953,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([v1.agentKp])
           .rpc();
@@ -2710,6 +2992,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v1.agentKp.publicKey,
@@ -2734,6 +3019,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([v1.agentKp])
           .rpc();
@@ -2782,6 +3071,9 @@ describe("security-exploits", () => {
           new BN("18446744073709551615"),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -2798,6 +3090,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: maxOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -2815,6 +3111,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: maxOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -2892,7 +3190,15 @@ describe("security-exploits", () => {
         program.programId,
       )[0];
       const validateIx = await program.methods
-        .validateAndAuthorize(usdcMint, new BN(1), jupiterProgramId, new BN(0))
+        .validateAndAuthorize(
+          usdcMint,
+          new BN(1),
+          jupiterProgramId,
+          new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accounts({
           agent: aggV.agentKp.publicKey,
           vault: aggV.vault,
@@ -2908,6 +3214,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: aggOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -2925,6 +3235,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: aggOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       sendVersionedTx(svm, [validateIx, finalizeIx], aggV.agentKp);
@@ -2964,7 +3276,15 @@ describe("security-exploits", () => {
         program.programId,
       )[0];
       const validateIx = await program.methods
-        .validateAndAuthorize(usdcMint, new BN(2), jupiterProgramId, new BN(0))
+        .validateAndAuthorize(
+          usdcMint,
+          new BN(2),
+          jupiterProgramId,
+          new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accounts({
           agent: edgeV.agentKp.publicKey,
           vault: edgeV.vault,
@@ -2980,6 +3300,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: edgeOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -2997,6 +3321,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: edgeOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       // Succeeds: actual_spend=0, no cap consumed
@@ -3047,6 +3373,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([xferV.agentKp])
           .rpc();
@@ -3089,6 +3418,8 @@ describe("security-exploits", () => {
             Keypair.generate().publicKey,
             FULL_CAPABILITY,
             new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
           )
           .accounts({
             owner: owner.publicKey,
@@ -3116,6 +3447,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), dv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3152,6 +3484,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), fv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3188,6 +3521,7 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3210,6 +3544,7 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3232,6 +3567,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: fv2.agentKp.publicKey,
@@ -3248,6 +3586,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: fv2Overlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -3265,6 +3607,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: fv2Overlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -3293,6 +3637,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .rpc();
         await program.methods
@@ -3402,6 +3747,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3422,6 +3770,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3444,6 +3796,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3458,6 +3812,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3478,6 +3835,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3500,6 +3861,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3529,6 +3892,9 @@ describe("security-exploits", () => {
           new BN(5_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3549,6 +3915,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3571,6 +3941,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3604,6 +3976,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3624,6 +3999,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3646,6 +4025,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3696,6 +4077,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3716,6 +4100,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3738,6 +4126,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3750,6 +4140,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: sv.agentKp.publicKey,
@@ -3770,6 +4163,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -3792,6 +4189,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), sv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -3877,6 +4276,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3951,6 +4368,9 @@ describe("security-exploits", () => {
             new BN(50_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: rwv.agentKp.publicKey,
@@ -3974,6 +4394,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([rwv.agentKp])
           .rpc();
@@ -4016,6 +4440,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: denyV.agentKp.publicKey,
@@ -4032,6 +4459,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: denyOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -4049,6 +4480,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: denyOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -4112,6 +4545,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: agent.publicKey,
@@ -4181,6 +4632,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: rando.publicKey,
@@ -4205,6 +4659,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([rando])
           .rpc();
@@ -4234,6 +4692,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: fv.agentKp.publicKey,
@@ -4254,6 +4715,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), fv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -4280,6 +4745,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), fv.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -4412,6 +4879,9 @@ describe("security-exploits", () => {
             new BN(2),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: iv2.agentKp.publicKey,
@@ -4435,6 +4905,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([iv2.agentKp])
           .rpc();
@@ -4505,6 +4979,9 @@ describe("security-exploits", () => {
           new BN("18446744073709551615"),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: iv4.agentKp.publicKey,
@@ -4521,6 +4998,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: iv4Overlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -4538,6 +5019,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: iv4Overlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -4702,6 +5185,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: mvC.agentKp.publicKey, // agent from vault C
@@ -4726,6 +5212,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([mvC.agentKp])
           .rpc();
@@ -4791,6 +5281,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: mvG.agentKp.publicKey,
@@ -4815,6 +5308,10 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -4841,6 +5338,8 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -4919,6 +5418,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([trV.agentKp])
           .rpc();
@@ -4967,6 +5469,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([itV.agentKp])
           .rpc();
@@ -5018,6 +5523,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: vnaV.agentKp.publicKey,
@@ -5042,6 +5550,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([vnaV.agentKp])
           .rpc();
@@ -5089,6 +5601,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -5130,6 +5660,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: snaV.agentKp.publicKey,
@@ -5154,6 +5687,10 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -5180,6 +5717,8 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -5243,6 +5782,9 @@ describe("security-exploits", () => {
           new BN(2_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: ofV.agentKp.publicKey,
@@ -5259,6 +5801,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: ofOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -5276,6 +5822,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: ofOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       // Fee drain fix: actual_spend=0 but fees > 0, so fee-to-cap fallback
@@ -5373,6 +5921,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           blockedProtocol,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -5389,6 +5940,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: pmOverlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -5406,6 +5961,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: pmOverlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -5447,6 +6004,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -5486,6 +6061,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -5527,6 +6120,24 @@ describe("security-exploits", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -5558,7 +6169,13 @@ describe("security-exploits", () => {
       // passed the guard — the strict helper caught this.
       try {
         await program.methods
-          .registerAgent(v.agentKp.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            v.agentKp.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: v.vault,
@@ -5669,6 +6286,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -5710,6 +6345,9 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([v.agentKp])
         .rpc();
@@ -5753,6 +6391,24 @@ describe("security-exploits", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           1, // destinationMode = OpenWithCap (F-4)
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -5803,6 +6459,9 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([v.agentKp])
         .rpc();
@@ -5839,6 +6498,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -5864,6 +6524,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([frozenAgent])
           .rpc();
@@ -5914,6 +6577,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([v.agentKp])
           .rpc();
@@ -5984,7 +6650,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(nsAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          nsAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: nsVault,
@@ -6035,6 +6707,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6055,6 +6730,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6133,6 +6812,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6153,6 +6835,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6176,6 +6862,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6209,6 +6897,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v.agentKp.publicKey,
@@ -6233,6 +6924,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .instruction();
 
@@ -6258,6 +6953,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6293,6 +6990,9 @@ describe("security-exploits", () => {
             new BN(1_000_000),
             jupiterProgramId,
             new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: v.agentKp.publicKey,
@@ -6317,6 +7017,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .instruction();
 
@@ -6343,6 +7047,8 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           } as any)
           .instruction();
 
@@ -6411,6 +7117,9 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([v.agentKp])
           .rpc();
@@ -6552,6 +7261,9 @@ describe("security-exploits", () => {
           new BN(1_000_000_000), // 1 SOL token
           REAL_JUPITER,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6571,6 +7283,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6628,6 +7344,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6680,6 +7398,9 @@ describe("security-exploits", () => {
           new BN(10_000_000), // 10 USDC
           REAL_JUPITER,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6699,6 +7420,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6750,6 +7475,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6789,6 +7516,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6808,6 +7538,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6849,6 +7583,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6889,6 +7625,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -6908,6 +7647,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -6953,6 +7696,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -6993,6 +7738,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7012,6 +7760,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7052,6 +7804,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7087,6 +7841,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7106,6 +7863,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7146,6 +7907,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7185,6 +7948,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7204,6 +7970,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7249,6 +8019,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7288,6 +8060,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7307,6 +8082,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7347,6 +8126,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7390,6 +8171,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7409,6 +8193,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7453,6 +8241,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7506,6 +8296,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7525,6 +8318,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7559,6 +8356,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7596,6 +8395,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7615,6 +8417,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7651,6 +8457,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7688,6 +8496,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7707,6 +8518,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7747,6 +8562,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7786,6 +8603,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7805,6 +8625,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7845,6 +8669,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7883,6 +8709,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7902,6 +8731,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -7942,6 +8775,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -7980,6 +8815,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -7999,6 +8837,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const maliciousIx = new TransactionInstruction({
@@ -8028,6 +8870,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -8059,6 +8903,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8078,6 +8925,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const maliciousIx = new TransactionInstruction({
@@ -8106,6 +8957,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -8137,6 +8990,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8156,6 +9012,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const maliciousIx = new TransactionInstruction({
@@ -8185,6 +9045,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -8216,6 +9078,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8235,6 +9100,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const maliciousIx = new TransactionInstruction({
@@ -8264,6 +9133,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -8295,6 +9166,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8314,6 +9188,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const maliciousIx = new TransactionInstruction({
@@ -8343,6 +9221,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       try {
@@ -8387,6 +9267,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8406,6 +9289,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -8436,6 +9323,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -8529,6 +9418,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           REAL_JUPITER,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8548,6 +9440,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -8583,6 +9479,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -8624,6 +9522,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           REAL_JUPITER, // declares Jupiter
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8643,6 +9544,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -8673,6 +9578,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -8711,6 +9618,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           REAL_FLASH_TRADE, // declares Flash Trade
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -8730,6 +9640,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -8760,6 +9674,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -8801,6 +9717,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: rogue.publicKey,
@@ -8820,6 +9739,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([rogue])
         .instruction();
@@ -8842,6 +9765,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .signers([rogue])
         .instruction();
@@ -8869,6 +9794,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -8911,6 +9837,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: zeroAgent.publicKey,
@@ -8930,6 +9859,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([zeroAgent])
         .instruction();
@@ -8952,6 +9885,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .signers([zeroAgent])
         .instruction();
@@ -8982,6 +9917,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .rpc();
         expect.fail("Should have thrown");
@@ -9000,7 +9936,13 @@ describe("security-exploits", () => {
       try {
         // v already has agentKp registered — try to register same key again
         await program.methods
-          .registerAgent(v.agentKp.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            v.agentKp.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: v.vault,
@@ -9028,6 +9970,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -9071,6 +10014,9 @@ describe("security-exploits", () => {
           new BN(1_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: cpAgent.publicKey,
@@ -9090,6 +10036,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([cpAgent])
         .instruction();
@@ -9112,6 +10062,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .signers([cpAgent])
         .instruction();
@@ -9135,7 +10087,13 @@ describe("security-exploits", () => {
       const agentMax = Keypair.generate();
       const MAX_CAPABILITY = FULL_CAPABILITY; // 2
       await program.methods
-        .registerAgent(agentMax.publicKey, MAX_CAPABILITY, new BN(0))
+        .registerAgent(
+          agentMax.publicKey,
+          MAX_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: v.vault,
@@ -9155,7 +10113,13 @@ describe("security-exploits", () => {
       const BEYOND_CAPABILITY = 3;
       try {
         await program.methods
-          .registerAgent(agentBeyond.publicKey, BEYOND_CAPABILITY, new BN(0))
+          .registerAgent(
+            agentBeyond.publicKey,
+            BEYOND_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: v.vault,
@@ -9207,6 +10171,8 @@ describe("security-exploits", () => {
             agentWithLimit.publicKey,
             FULL_CAPABILITY,
             new BN(100_000_000),
+            0, // maxSessionsPerHour
+            null, // validUntil
           )
           .accounts({
             owner: owner.publicKey,
@@ -9224,7 +10190,13 @@ describe("security-exploits", () => {
       airdropSol(svm, agentNoLimit.publicKey, 5 * LAMPORTS_PER_SOL);
 
       await program.methods
-        .registerAgent(agentNoLimit.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agentNoLimit.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: v.vault,
@@ -9260,6 +10232,8 @@ describe("security-exploits", () => {
           limitAgent.publicKey,
           FULL_CAPABILITY,
           new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
         )
         .accounts({
           owner: owner.publicKey,
@@ -9294,6 +10268,9 @@ describe("security-exploits", () => {
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
             agentSpendOverlay: v.overlay,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([limitAgent])
           .rpc();
@@ -9317,6 +10294,9 @@ describe("security-exploits", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           agentSpendOverlay: v.overlay,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([limitAgent])
         .rpc();
@@ -9340,7 +10320,13 @@ describe("security-exploits", () => {
       airdropSol(svm, agentA.publicKey, 5 * LAMPORTS_PER_SOL);
 
       await program.methods
-        .registerAgent(agentA.publicKey, FULL_CAPABILITY, new BN(100_000_000))
+        .registerAgent(
+          agentA.publicKey,
+          FULL_CAPABILITY,
+          new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: v.vault,
@@ -9372,6 +10358,9 @@ describe("security-exploits", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           agentSpendOverlay: v.overlay,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agentA])
         .rpc();
@@ -9383,6 +10372,7 @@ describe("security-exploits", () => {
           owner: owner.publicKey,
           vault: v.vault,
           agentSpendOverlay: v.overlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -9391,7 +10381,13 @@ describe("security-exploits", () => {
       airdropSol(svm, agentB.publicKey, 5 * LAMPORTS_PER_SOL);
 
       await program.methods
-        .registerAgent(agentB.publicKey, FULL_CAPABILITY, new BN(100_000_000))
+        .registerAgent(
+          agentB.publicKey,
+          FULL_CAPABILITY,
+          new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: v.vault,
@@ -9425,6 +10421,9 @@ describe("security-exploits", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           agentSpendOverlay: v.overlay,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([agentB])
         .rpc();
@@ -9532,7 +10531,13 @@ describe("security-exploits", () => {
         } as any)
         .rpc();
       await destProgram.methods
-        .registerAgent(escrowDestAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          escrowDestAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: escrowDestOwnerKp.publicKey,
           vault: escrowDestVault,
@@ -9596,6 +10601,7 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -9648,6 +10654,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([noCreateAgent])
           .rpc();
@@ -9696,6 +10703,7 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .signers([escrowSourceAgent])
         .rpc();
@@ -9720,7 +10728,13 @@ describe("security-exploits", () => {
         program.programId,
       );
       await destProgram.methods
-        .registerAgent(noSettleAgent.publicKey, noSettlePerms, new BN(0))
+        .registerAgent(
+          noSettleAgent.publicKey,
+          noSettlePerms,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: escrowDestOwnerKp.publicKey,
           vault: escrowDestVault,
@@ -9791,6 +10805,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([escrowSourceAgent])
           .rpc();
@@ -9839,6 +10854,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([escrowSourceAgent])
           .rpc();
@@ -9886,6 +10902,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([escrowSourceAgent])
           .rpc();
@@ -9948,6 +10965,7 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
           } as any)
           .signers([escrowSourceAgent])
           .rpc();
@@ -9995,6 +11013,7 @@ describe("security-exploits", () => {
             ],
             program.programId,
           )[0],
+          agentSettings: null,
         } as any)
         .signers([escrowSourceAgent])
         .rpc();
@@ -10029,7 +11048,13 @@ describe("security-exploits", () => {
       airdropSol(svm, s3Agent.publicKey, 5 * LAMPORTS_PER_SOL);
 
       await program.methods
-        .registerAgent(s3Agent.publicKey, FULL_CAPABILITY, new BN(100_000_000))
+        .registerAgent(
+          s3Agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: s3Vault.vault,
@@ -10073,6 +11098,7 @@ describe("security-exploits", () => {
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           agentSpendOverlay: s3Vault.overlay,
+          agentSettings: null,
         } as any)
         .signers([s3Agent])
         .rpc();
@@ -10109,6 +11135,7 @@ describe("security-exploits", () => {
             systemProgram: SystemProgram.programId,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             agentSpendOverlay: s3Vault.overlay,
+            agentSettings: null,
           } as any)
           .signers([s3Agent])
           .rpc();
@@ -10243,7 +11270,15 @@ describe("security-exploits", () => {
         program.programId,
       )[0];
       const validateIx = await program.methods
-        .validateAndAuthorize(usdcMint, new BN(0), jupiterProgramId, new BN(0))
+        .validateAndAuthorize(
+          usdcMint,
+          new BN(0),
+          jupiterProgramId,
+          new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accounts({
           agent: v.agentKp.publicKey,
           vault: v.vault,
@@ -10259,6 +11294,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: overlayPda,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -10276,6 +11315,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: overlayPda,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
       // amount=0 spending should succeed (no cap/fee impact) or fail — document behavior
@@ -10314,6 +11355,24 @@ describe("security-exploits", () => {
           null, // has_protocol_caps
           null, // protocol_caps
           null, // destination_mode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -10377,6 +11436,8 @@ describe("security-exploits", () => {
           limitAgent.publicKey,
           FULL_CAPABILITY,
           new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
         )
         .accounts({
           owner: owner.publicKey,
@@ -10424,6 +11485,9 @@ describe("security-exploits", () => {
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
             agentSpendOverlay: v.overlay,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([limitAgent])
           .rpc();
@@ -10456,6 +11520,8 @@ describe("security-exploits", () => {
           limitAgent.publicKey,
           FULL_CAPABILITY,
           new BN(100_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
         )
         .accounts({
           owner: owner.publicKey,
@@ -10481,6 +11547,9 @@ describe("security-exploits", () => {
           new BN(101_000_000),
           jupiterProgramId,
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: limitAgent.publicKey,
@@ -10497,6 +11566,10 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: v.overlay,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
       const finalizeIx = await program.methods
@@ -10514,6 +11587,8 @@ describe("security-exploits", () => {
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
           agentSpendOverlay: v.overlay,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
@@ -10622,7 +11697,13 @@ describe("security-exploits", () => {
 
       // Register agent
       await program.methods
-        .registerAgent(auditAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          auditAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: auditVault,
@@ -10681,7 +11762,13 @@ describe("security-exploits", () => {
 
       // Register dest agent
       await program.methods
-        .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          destAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: destVault,
@@ -10730,6 +11817,7 @@ describe("security-exploits", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .signers([auditAgent])
         .rpc();
@@ -10987,7 +12075,13 @@ describe("security-exploits", () => {
 
       // Register agent with spending_limit=0 (no overlay slot needed)
       await program.methods
-        .registerAgent(olAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          olAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: olVault,
@@ -11011,6 +12105,12 @@ describe("security-exploits", () => {
           olAgent.publicKey,
           FULL_CAPABILITY,
           new BN(100_000_000), // 100 USDC
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
         )
         .accounts({
           owner: owner.publicKey,
@@ -11031,6 +12131,7 @@ describe("security-exploits", () => {
           policy: olPolicy,
           pendingAgentPerms: pendingAgentPermsPda,
           agentSpendOverlay: olOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -11057,6 +12158,12 @@ describe("security-exploits", () => {
           olAgent.publicKey,
           FULL_CAPABILITY,
           new BN(0),
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
         )
         .accounts({
           owner: owner.publicKey,
@@ -11077,6 +12184,7 @@ describe("security-exploits", () => {
           policy: olPolicy,
           pendingAgentPerms: pendingAgentPermsPda,
           agentSpendOverlay: olOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -11159,7 +12267,13 @@ describe("security-exploits", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(agentKp.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agentKp.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault,
@@ -11199,6 +12313,24 @@ describe("security-exploits", () => {
             null,
             null,
             null,
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -11254,6 +12386,24 @@ describe("security-exploits", () => {
             null,
             null,
             null,
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -11584,6 +12734,12 @@ describe("security-exploits", () => {
             f10Agent.publicKey,
             FULL_CAPABILITY,
             new BN(50_000_000),
+            0, // maxSessionsPerHour
+            null, // protocols
+            null, // maxTransactionSizeUsd
+            null, // sessionExpirySeconds
+            null, // validUntil
+            null, // maxConcurrentSessions
           )
           .accounts({
             owner: owner.publicKey,
@@ -11606,6 +12762,7 @@ describe("security-exploits", () => {
             policy,
             pendingAgentPerms: pendingAgent,
             agentSpendOverlay: overlay,
+            agentSettings: null,
           } as any)
           .rpc();
 
@@ -11637,6 +12794,12 @@ describe("security-exploits", () => {
             f10Agent.publicKey,
             FULL_CAPABILITY,
             new BN(75_000_000),
+            0, // maxSessionsPerHour
+            null, // protocols
+            null, // maxTransactionSizeUsd
+            null, // sessionExpirySeconds
+            null, // validUntil
+            null, // maxConcurrentSessions
           )
           .accounts({
             owner: owner.publicKey,
@@ -11660,6 +12823,7 @@ describe("security-exploits", () => {
               policy,
               pendingAgentPerms: pendingAgent,
               agentSpendOverlay: overlay,
+              agentSettings: null,
             } as any)
             .rpc();
           expect.fail("Should have thrown QueuedUpdateExpired");
@@ -11756,7 +12920,13 @@ describe("security-exploits", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(erAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          erAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: erVaultPda,
@@ -11765,7 +12935,13 @@ describe("security-exploits", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(erAgent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          erAgent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: erVaultPda,
@@ -11779,7 +12955,7 @@ describe("security-exploits", () => {
       await program.methods
         .pauseAgent(erAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -11814,7 +12990,7 @@ describe("security-exploits", () => {
       await program.methods
         .pauseAgent(erAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -11822,7 +12998,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -11855,7 +13031,7 @@ describe("security-exploits", () => {
       await program.methods
         .pauseAgent(erAgent2.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -11867,6 +13043,7 @@ describe("security-exploits", () => {
           owner: owner.publicKey,
           vault: erVaultPda,
           agentSpendOverlay: erOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -11880,7 +13057,13 @@ describe("security-exploits", () => {
 
       // Re-register for subsequent tests (starts unpaused)
       await program.methods
-        .registerAgent(erAgent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          erAgent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: erVaultPda,
@@ -11902,7 +13085,7 @@ describe("security-exploits", () => {
       await program.methods
         .pauseAgent(erAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -11918,7 +13101,17 @@ describe("security-exploits", () => {
         program.programId,
       );
       await program.methods
-        .queueAgentPermissionsUpdate(erAgent.publicKey, swapOnly, new BN(0))
+        .queueAgentPermissionsUpdate(
+          erAgent.publicKey,
+          swapOnly,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
+        )
         .accounts({
           owner: owner.publicKey,
           vault: erVaultPda,
@@ -11938,6 +13131,7 @@ describe("security-exploits", () => {
           policy: erPolicyPda,
           pendingAgentPerms: pendingAgentPermsPda,
           agentSpendOverlay: erOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -11963,6 +13157,12 @@ describe("security-exploits", () => {
           erAgent.publicKey,
           FULL_CAPABILITY,
           new BN(0),
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
         )
         .accounts({
           owner: owner.publicKey,
@@ -11983,6 +13183,7 @@ describe("security-exploits", () => {
           policy: erPolicyPda,
           pendingAgentPerms: pendingAgentPermsRestorePda,
           agentSpendOverlay: erOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -12000,7 +13201,7 @@ describe("security-exploits", () => {
       await program.methods
         .pauseAgent(erAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -12008,7 +13209,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: erVaultPda,
         } as any)
         .rpc();
@@ -12113,7 +13314,7 @@ describe("security-exploits", () => {
         await program.methods
           .freezeVault()
           .accounts({
-            owner: owner.publicKey,
+            authority: owner.publicKey,
             vault: closedVaultPda,
           } as any)
           .rpc();
@@ -12245,7 +13446,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12262,7 +13463,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12272,7 +13473,7 @@ describe("security-exploits", () => {
         await program.methods
           .freezeVault()
           .accounts({
-            owner: owner.publicKey,
+            authority: owner.publicKey,
             vault: v.vault,
             tokenProgram: TOKEN_PROGRAM_ID,
           } as any)
@@ -12291,7 +13492,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12342,7 +13543,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12394,7 +13595,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: vaultA.vault, // freezing A
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12451,7 +13652,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12499,7 +13700,7 @@ describe("security-exploits", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: v.vault,
           tokenProgram: TOKEN_PROGRAM_ID,
         } as any)
@@ -12636,7 +13837,15 @@ describe("security-exploits", () => {
 
         // amount = 0 → is_spending = false, takes the non-spending branch
         const validateIx = await program.methods
-          .validateAndAuthorize(usdcMint, new BN(0), REAL_JUPITER, new BN(0))
+          .validateAndAuthorize(
+            usdcMint,
+            new BN(0),
+            REAL_JUPITER,
+            new BN(0),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
+          )
           .accounts({
             agent: v.agentKp.publicKey,
             vault: v.vault,
@@ -12659,6 +13868,10 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .instruction();
 
@@ -12692,6 +13905,8 @@ describe("security-exploits", () => {
               ],
               program.programId,
             )[0],
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           } as any)
           .instruction();
 
@@ -12853,6 +14068,9 @@ describe("security-exploits", () => {
           amount,
           foreignProgramId, // declare it as target_protocol so policy.is_protocol_allowed passes
           new BN(0),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: v.agentKp.publicKey,
@@ -12872,6 +14090,10 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .instruction();
 
@@ -12901,6 +14123,8 @@ describe("security-exploits", () => {
             [Buffer.from("agent_spend"), v.vault.toBuffer(), Buffer.from([0])],
             program.programId,
           )[0],
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .instruction();
 
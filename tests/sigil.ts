@@ -383,7 +383,13 @@ describe("sigil", () => {
   describe("register_agent", () => {
     it("registers an agent pubkey", async () => {
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: vaultPda,
@@ -403,7 +409,13 @@ describe("sigil", () => {
       try {
         // Register the SAME agent pubkey that was already registered
         await program.methods
-          .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            agent.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: vaultPda,
@@ -468,7 +480,13 @@ describe("sigil", () => {
       // Try to register agent as non-owner
       try {
         await program.methods
-          .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            agent.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: unauthorizedUser.publicKey,
             vault: v,
@@ -511,6 +529,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -560,6 +596,24 @@ describe("sigil", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: unauthorizedUser.publicKey,
@@ -596,6 +650,24 @@ describe("sigil", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -671,7 +743,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: revokeVaultPda,
@@ -687,6 +765,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: revokeVaultPda,
           agentSpendOverlay: revokeOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -704,6 +783,7 @@ describe("sigil", () => {
             owner: owner.publicKey,
             vault: revokeVaultPda,
             agentSpendOverlay: revokeOverlay,
+            agentSettings: null,
           } as any)
           .rpc();
         expect.fail("Should have thrown");
@@ -720,6 +800,7 @@ describe("sigil", () => {
             owner: unauthorizedUser.publicKey,
             vault: revokeVaultPda,
             agentSpendOverlay: revokeOverlay,
+            agentSettings: null,
           } as any)
           .signers([unauthorizedUser])
           .rpc();
@@ -790,7 +871,13 @@ describe("sigil", () => {
 
       // Register agent then freeze by revoking
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: reactVaultPda,
@@ -804,6 +891,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: reactVaultPda,
           agentSpendOverlay: reactOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
     });
@@ -838,6 +926,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: reactVaultPda,
           agentSpendOverlay: reactOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -866,6 +955,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: reactVaultPda,
           agentSpendOverlay: reactOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -978,6 +1068,9 @@ describe("sigil", () => {
           amount,
           jupiterProgramId,
           await pv(), // expectedPolicyVersion
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -994,6 +1087,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1012,6 +1109,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1066,6 +1165,9 @@ describe("sigil", () => {
           amount,
           jupiterProgramId,
           await pv(), // restored pv() v2
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -1082,6 +1184,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1100,6 +1206,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1206,6 +1314,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1222,6 +1333,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -1241,6 +1356,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             fakeProtocol, // not in protocols
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1257,6 +1375,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -1276,6 +1398,9 @@ describe("sigil", () => {
             new BN(200_000_000), // would exceed max_transaction_size — but checked in finalize now
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1292,6 +1417,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -1315,6 +1444,9 @@ describe("sigil", () => {
             new BN(100_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1331,6 +1463,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -1364,6 +1500,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: fakeAgent.publicKey,
@@ -1380,6 +1519,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([fakeAgent])
           .rpc();
@@ -1443,6 +1586,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -1459,6 +1605,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -1766,6 +1916,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -1807,6 +1975,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -1854,6 +2040,24 @@ describe("sigil", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -1889,6 +2093,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -1918,7 +2140,13 @@ describe("sigil", () => {
         program.programId,
       );
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: feeVaultPda,
@@ -1964,6 +2192,9 @@ describe("sigil", () => {
           new BN(10_000_000),
           jupiterProgramId,
           await pv(feePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -1980,6 +2211,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1998,6 +2233,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2031,6 +2268,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -2088,6 +2343,9 @@ describe("sigil", () => {
           new BN(10_000_000),
           jupiterProgramId,
           await pv(feePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -2104,6 +2362,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2122,6 +2384,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2155,6 +2419,9 @@ describe("sigil", () => {
           new BN(10_000_000),
           jupiterProgramId,
           await pv(feePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -2171,6 +2438,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2189,6 +2460,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2329,7 +2602,13 @@ describe("sigil", () => {
 
       // Register agent
       await program.methods
-        .registerAgent(lifecycleAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          lifecycleAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: lifecycleVaultPda,
@@ -2365,6 +2644,9 @@ describe("sigil", () => {
           new BN(10_000_000),
           jupiterProgramId,
           await pv(lifecyclePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: lifecycleAgent.publicKey,
@@ -2381,6 +2663,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2399,6 +2685,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2421,6 +2709,9 @@ describe("sigil", () => {
           new BN(5_000_000),
           jupiterProgramId,
           await pv(lifecyclePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: lifecycleAgent.publicKey,
@@ -2437,6 +2728,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2455,6 +2750,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2475,6 +2772,9 @@ describe("sigil", () => {
             new BN(5_000_000),
             jupiterProgramId,
             await pv(lifecyclePolicyPda),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accountsPartial({
             agent: lifecycleAgent.publicKey,
@@ -2491,6 +2791,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           })
           .instruction();
 
@@ -2509,6 +2813,8 @@ describe("sigil", () => {
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
             outputStablecoinAccount: null,
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           })
           .instruction();
 
@@ -2573,7 +2879,13 @@ describe("sigil", () => {
         .rpc();
       try {
         await program.methods
-          .registerAgent(owner.publicKey, FULL_CAPABILITY, new BN(0)) // owner = agent → reject
+          .registerAgent(
+            owner.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          ) // owner = agent → reject
           .accounts({
             owner: owner.publicKey,
             vault: v,
@@ -2623,6 +2935,7 @@ describe("sigil", () => {
             owner: owner.publicKey,
             vault: rv,
             agentSpendOverlay: rvOverlay,
+            agentSettings: null,
           } as any)
           .rpc();
       } catch {
@@ -2652,6 +2965,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -2668,6 +2984,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -2736,7 +3056,13 @@ describe("sigil", () => {
 
       // Register agent then freeze by revoking
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: fv,
@@ -2750,6 +3076,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: fv,
           agentSpendOverlay: fvOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -2925,7 +3252,13 @@ describe("sigil", () => {
 
       // Register agent, then close
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: cv,
@@ -2952,6 +3285,9 @@ describe("sigil", () => {
             new BN(1_000_000),
             jupiterProgramId,
             await pv(),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accounts({
             agent: agent.publicKey,
@@ -2971,6 +3307,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           } as any)
           .signers([agent])
           .rpc();
@@ -3045,7 +3385,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(ringAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          ringAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: ringVaultPda,
@@ -3093,6 +3439,9 @@ describe("sigil", () => {
             new BN(1_000_000), // 1 USDC each
             jupiterProgramId,
             await pv(ringPolicyPda),
+            [], // legs
+            0, // sessionNonce
+            null, // expectedOutput
           )
           .accountsPartial({
             agent: ringAgent.publicKey,
@@ -3109,6 +3458,10 @@ describe("sigil", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            agentSettings: null,
+            permitNonces: null,
+            pendingFulfillment: null,
+            outputTokenAccount: null,
           })
           .instruction();
 
@@ -3127,6 +3480,8 @@ describe("sigil", () => {
             systemProgram: SystemProgram.programId,
             instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
             outputStablecoinAccount: null,
+            outputTokenAccount: null,
+            pendingFulfillment: null,
           })
           .instruction();
 
@@ -3205,7 +3560,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(feeEdgeAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          feeEdgeAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: feeEdgeVaultPda,
@@ -3255,6 +3616,9 @@ describe("sigil", () => {
           new BN(1), // 1 lamport
           jupiterProgramId,
           await pv(feeEdgePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: feeEdgeAgent.publicKey,
@@ -3271,6 +3635,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -3289,6 +3657,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -3320,6 +3690,9 @@ describe("sigil", () => {
           new BN(4_999),
           jupiterProgramId,
           await pv(feeEdgePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: feeEdgeAgent.publicKey,
@@ -3336,6 +3709,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -3354,6 +3731,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -3369,6 +3748,9 @@ describe("sigil", () => {
           new BN(5_000),
           jupiterProgramId,
           await pv(feeEdgePolicyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: feeEdgeAgent.publicKey,
@@ -3385,6 +3767,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -3403,6 +3789,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -3479,7 +3867,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(tlAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          tlAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: tlVaultPda,
@@ -3503,6 +3897,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3582,6 +3994,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3629,6 +4059,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3655,6 +4103,24 @@ describe("sigil", () => {
             null, // hasProtocolCaps
             null, // protocolCaps
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -3751,6 +4217,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3794,6 +4278,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3835,6 +4337,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -3871,6 +4391,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: tlVaultPda,
           agentSpendOverlay: tlOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3956,7 +4477,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          destAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: destVaultPda,
@@ -4016,6 +4543,9 @@ describe("sigil", () => {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([destAgent])
         .rpc();
@@ -4042,6 +4572,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: null,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([destAgent])
           .rpc();
@@ -4102,7 +4635,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          destAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: anyVault,
@@ -4151,6 +4690,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([destAgent])
           .rpc();
@@ -4195,6 +4737,24 @@ describe("sigil", () => {
           null, // hasProtocolCaps
           null, // protocolCaps
           1, // destinationMode = OpenWithCap
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -4242,6 +4802,9 @@ describe("sigil", () => {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([destAgent])
         .rpc();
@@ -4273,6 +4836,24 @@ describe("sigil", () => {
             null,
             null,
             2, // destinationMode out of range
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: owner.publicKey,
@@ -4367,6 +4948,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([destAgent])
           .rpc();
@@ -4388,6 +4972,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([destAgent])
           .rpc();
@@ -4414,6 +5001,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: null,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([destAgent])
           .rpc();
@@ -4480,7 +5070,13 @@ describe("sigil", () => {
         } as any)
         .rpc();
       await program.methods
-        .registerAgent(destAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          destAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: fv,
@@ -4539,6 +5135,9 @@ describe("sigil", () => {
           feeDestinationTokenAccount: feeDestUsdcAta,
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([destAgent])
         .rpc();
@@ -4641,7 +5240,13 @@ describe("sigil", () => {
     it("registers 2 agents with different capabilities", async () => {
       // Agent 1: viewer (capability = 1)
       await program.methods
-        .registerAgent(agent.publicKey, VIEWER_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          VIEWER_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: maVault,
@@ -4651,7 +5256,13 @@ describe("sigil", () => {
 
       // Agent 2: full capability (operator)
       await program.methods
-        .registerAgent(agent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: maVault,
@@ -4688,6 +5299,9 @@ describe("sigil", () => {
           new BN(0), // non-spending for Observer
           jupiterProgramId,
           await pv(maPolicy),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -4704,6 +5318,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([agent])
         .instruction();
@@ -4723,6 +5341,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .signers([agent])
         .instruction();
@@ -4748,6 +5368,9 @@ describe("sigil", () => {
           new BN(1_000_000),
           jupiterProgramId,
           await pv(maPolicy),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accounts({
           agent: agent.publicKey,
@@ -4764,6 +5387,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         } as any)
         .signers([agent])
         .instruction();
@@ -4783,6 +5410,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         } as any)
         .signers([agent])
         .instruction();
@@ -4802,6 +5431,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: maVault,
           agentSpendOverlay: maOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -4820,6 +5450,7 @@ describe("sigil", () => {
           owner: owner.publicKey,
           vault: maVault,
           agentSpendOverlay: maOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -4840,7 +5471,13 @@ describe("sigil", () => {
         const a = Keypair.generate();
         airdropSol(svm, a.publicKey, LAMPORTS_PER_SOL);
         await program.methods
-          .registerAgent(a.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            a.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: maVault,
@@ -4857,7 +5494,13 @@ describe("sigil", () => {
       const extra = Keypair.generate();
       try {
         await program.methods
-          .registerAgent(extra.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            extra.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: maVault,
@@ -4880,6 +5523,7 @@ describe("sigil", () => {
             owner: owner.publicKey,
             vault: maVault,
             agentSpendOverlay: maOverlay,
+            agentSettings: null,
           } as any)
           .rpc();
       }
@@ -4904,7 +5548,13 @@ describe("sigil", () => {
       const updAgent = Keypair.generate();
       airdropSol(svm, updAgent.publicKey, LAMPORTS_PER_SOL);
       await program.methods
-        .registerAgent(updAgent.publicKey, VIEWER_CAPABILITY, new BN(0))
+        .registerAgent(
+          updAgent.publicKey,
+          VIEWER_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: maVault,
@@ -4928,6 +5578,12 @@ describe("sigil", () => {
           updAgent.publicKey,
           FULL_CAPABILITY,
           new BN(0),
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
         )
         .accounts({
           owner: owner.publicKey,
@@ -4949,6 +5605,7 @@ describe("sigil", () => {
           policy: maPolicy,
           pendingAgentPerms: pendingAgentPermsPda,
           agentSpendOverlay: maOverlay,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -4964,7 +5621,13 @@ describe("sigil", () => {
       const badAgent = Keypair.generate();
       try {
         await program.methods
-          .registerAgent(badAgent.publicKey, BAD_CAPABILITY, new BN(0))
+          .registerAgent(
+            badAgent.publicKey,
+            BAD_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: owner.publicKey,
             vault: maVault,
@@ -5058,6 +5721,8 @@ describe("sigil", () => {
           epochAgent.publicKey,
           FULL_CAPABILITY,
           new BN(1_000_000_000),
+          0, // maxSessionsPerHour
+          null, // validUntil
         )
         .accounts({
           owner: owner.publicKey,
@@ -5114,6 +5779,9 @@ describe("sigil", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([epochAgent])
         .rpc();
@@ -5137,6 +5805,9 @@ describe("sigil", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([epochAgent])
         .rpc();
@@ -5162,6 +5833,9 @@ describe("sigil", () => {
             protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([epochAgent])
           .rpc();
@@ -5186,6 +5860,9 @@ describe("sigil", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([epochAgent])
         .rpc();
@@ -5215,6 +5892,9 @@ describe("sigil", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([epochAgent])
         .rpc();
@@ -5239,6 +5919,9 @@ describe("sigil", () => {
           protocolTreasuryTokenAccount: protocolTreasuryUsdcAta,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .signers([epochAgent])
         .rpc();
@@ -5356,7 +6039,13 @@ describe("sigil", () => {
 
       // Register agent
       await program.methods
-        .registerAgent(protoCapAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          protoCapAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: protoCapOwner.publicKey,
           vault: pcVault,
@@ -5379,7 +6068,15 @@ describe("sigil", () => {
       );
 
       const validateIx = await program.methods
-        .validateAndAuthorize(usdcMint, amount, protocol, await pv(pcPolicy))
+        .validateAndAuthorize(
+          usdcMint,
+          amount,
+          protocol,
+          await pv(pcPolicy),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
+        )
         .accountsPartial({
           agent: protoCapAgent.publicKey,
           vault: pcVault,
@@ -5395,6 +6092,10 @@ describe("sigil", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -5413,6 +6114,8 @@ describe("sigil", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -5452,6 +6155,24 @@ describe("sigil", () => {
           true, // hasProtocolCaps
           [new BN(0), new BN(200_000_000)], // protocolA: 0 (unlimited), protocolB: 200
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: protoCapOwner.publicKey,
@@ -5495,6 +6216,24 @@ describe("sigil", () => {
           true,
           [new BN(100_000_000), new BN(200_000_000)],
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: protoCapOwner.publicKey,
@@ -5550,6 +6289,24 @@ describe("sigil", () => {
           false, // hasProtocolCaps = false
           null,
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: protoCapOwner.publicKey,
@@ -5593,6 +6350,24 @@ describe("sigil", () => {
           true,
           [new BN(100_000_000), new BN(200_000_000)],
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: protoCapOwner.publicKey,
@@ -5640,6 +6415,24 @@ describe("sigil", () => {
             true,
             [new BN(100_000_000)], // only 1 cap but 2 protocols
             null, // destinationMode
+            null, // weeklySpendingCapUsd
+            null, // monthlySpendingCapUsd
+            null, // mintCaps
+            null, // destinationCaps
+            null, // tradingHours
+            null, // tradingHoursExemptNonSpending
+            null, // guardian
+            null, // recoveryAuthority
+            null, // recoveryDestination
+            null, // inactivityWindowSeconds
+            null, // beneficiary
+            null, // approvers
+            null, // approvalThreshold
+            null, // cpiCallers
+            null, // dailyFeeCapUsd
+            null, // maxAbandonedSessionsPerHour
+            null, // allowAsyncFulfillment
+            null, // maxProtocolSlippageBps
           )
           .accounts({
             owner: protoCapOwner.publicKey,
@@ -5778,7 +6571,13 @@ describe("sigil", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(freezeAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          freezeAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: freezeVaultPda,
@@ -5787,7 +6586,13 @@ describe("sigil", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(freezeAgent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          freezeAgent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: freezeVaultPda,
@@ -5800,7 +6605,7 @@ describe("sigil", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: freezeVaultPda,
         } as any)
         .rpc();
@@ -5825,7 +6630,7 @@ describe("sigil", () => {
         await program.methods
           .freezeVault()
           .accounts({
-            owner: owner.publicKey,
+            authority: owner.publicKey,
             vault: freezeVaultPda,
           } as any)
           .rpc();
@@ -5840,14 +6645,17 @@ describe("sigil", () => {
         await program.methods
           .freezeVault()
           .accounts({
-            owner: unauthorizedUser.publicKey,
+            authority: unauthorizedUser.publicKey,
             vault: freezeVaultPda,
           } as any)
           .signers([unauthorizedUser])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expectAnchorError(err, { name: "ConstraintSeeds", code: 2006 });
+        expectSigilError(err, {
+          name: "UnauthorizedOwnerOrGuardian",
+          code: 6104,
+        });
       }
     });
 
@@ -5905,7 +6713,7 @@ describe("sigil", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: freezeVaultPda,
         } as any)
         .rpc();
@@ -6000,7 +6808,13 @@ describe("sigil", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(pauseAgent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          pauseAgent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: pauseVaultPda,
@@ -6009,7 +6823,13 @@ describe("sigil", () => {
         .rpc();
 
       await program.methods
-        .registerAgent(pauseAgent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          pauseAgent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: owner.publicKey,
           vault: pauseVaultPda,
@@ -6022,7 +6842,7 @@ describe("sigil", () => {
       await program.methods
         .pauseAgent(pauseAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: pauseVaultPda,
         } as any)
         .rpc();
@@ -6039,7 +6859,7 @@ describe("sigil", () => {
         await program.methods
           .pauseAgent(pauseAgent.publicKey)
           .accounts({
-            owner: owner.publicKey,
+            authority: owner.publicKey,
             vault: pauseVaultPda,
           } as any)
           .rpc();
@@ -6055,7 +6875,7 @@ describe("sigil", () => {
         await program.methods
           .pauseAgent(fakeAgent.publicKey)
           .accounts({
-            owner: owner.publicKey,
+            authority: owner.publicKey,
             vault: pauseVaultPda,
           } as any)
           .rpc();
@@ -6070,14 +6890,17 @@ describe("sigil", () => {
         await program.methods
           .pauseAgent(pauseAgent2.publicKey)
           .accounts({
-            owner: unauthorizedUser.publicKey,
+            authority: unauthorizedUser.publicKey,
             vault: pauseVaultPda,
           } as any)
           .signers([unauthorizedUser])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expectAnchorError(err, { name: "ConstraintSeeds", code: 2006 });
+        expectSigilError(err, {
+          name: "UnauthorizedOwnerOrGuardian",
+          code: 6104,
+        });
       }
     });
 
@@ -6149,6 +6972,9 @@ describe("sigil", () => {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: null,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .signers([pauseAgent])
           .rpc();
@@ -6163,7 +6989,7 @@ describe("sigil", () => {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: pauseVaultPda,
         } as any)
         .rpc();
@@ -6172,7 +6998,7 @@ describe("sigil", () => {
       await program.methods
         .pauseAgent(pauseAgent2.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: pauseVaultPda,
         } as any)
         .rpc();
@@ -6233,7 +7059,7 @@ describe("sigil", () => {
       await program.methods
         .pauseAgent(pauseAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: pauseVaultPda,
         } as any)
         .rpc();
@@ -6267,7 +7093,7 @@ describe("sigil", () => {
       await program.methods
         .pauseAgent(pauseAgent.publicKey)
         .accounts({
-          owner: owner.publicKey,
+          authority: owner.publicKey,
           vault: pauseVaultPda,
         } as any)
         .rpc();
@@ -174,7 +174,13 @@ describe("surfpool-integration", function () {
     it("registers agent and deposits USDC", async () => {
       // Register agent
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: vaultPda,
@@ -228,6 +234,9 @@ describe("surfpool-integration", function () {
           new BN(50_000_000), // 50 USDC
           program.programId, // dummy protocol
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -244,6 +253,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -262,6 +275,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -344,7 +359,13 @@ describe("surfpool-integration", function () {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: vaultPda,
@@ -391,6 +412,9 @@ describe("surfpool-integration", function () {
           new BN(10_000_000), // 10 USDC
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -407,6 +431,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -425,6 +453,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -453,6 +483,9 @@ describe("surfpool-integration", function () {
           new BN(10_000_000),
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -469,6 +502,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -503,6 +540,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000), // 5 USDC
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -519,6 +559,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -537,6 +581,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -609,7 +655,13 @@ describe("surfpool-integration", function () {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: vaultPda,
@@ -656,6 +708,9 @@ describe("surfpool-integration", function () {
           new BN(25_000_000), // 25 USDC
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -672,6 +727,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -690,6 +749,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -721,6 +782,9 @@ describe("surfpool-integration", function () {
           new BN(25_000_000), // 25 USDC (valid amount)
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: rogueAgent.publicKey,
@@ -737,6 +801,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -755,6 +823,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -796,6 +866,9 @@ describe("surfpool-integration", function () {
           new BN(30_000_000), // 30 USDC
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -812,6 +885,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -830,6 +907,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -902,7 +981,13 @@ describe("surfpool-integration", function () {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: vaultPda,
@@ -972,6 +1057,9 @@ describe("surfpool-integration", function () {
           new BN(amount),
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -988,6 +1076,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1006,6 +1098,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1080,7 +1174,13 @@ describe("surfpool-integration", function () {
         .rpc();
 
       await program.methods
-        .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: vaultPda,
@@ -1127,6 +1227,9 @@ describe("surfpool-integration", function () {
           new BN(20_000_000),
           program.programId,
           await readPolicyVersion(program, policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -1143,6 +1246,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1161,6 +1268,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1500,6 +1609,24 @@ describe("surfpool-integration", function () {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: env.payer.publicKey,
@@ -1555,6 +1682,24 @@ describe("surfpool-integration", function () {
           null, // hasProtocolCaps
           null, // protocolCaps
           null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: env.payer.publicKey,
@@ -1702,6 +1847,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: transferSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -1760,7 +1908,13 @@ describe("surfpool-integration", function () {
       // Register a second agent for pause isolation tests
       agent2 = await createWallet(env.connection, "agent2", 10);
       await program.methods
-        .registerAgent(agent2.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          agent2.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: setup.vaultPda,
@@ -1773,7 +1927,7 @@ describe("surfpool-integration", function () {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: env.payer.publicKey,
+          authority: env.payer.publicKey,
           vault: setup.vaultPda,
         } as any)
         .rpc();
@@ -1794,6 +1948,9 @@ describe("surfpool-integration", function () {
           new BN(10_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -1810,6 +1967,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1828,6 +1989,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1864,6 +2027,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -1880,6 +2046,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -1898,6 +2068,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -1914,7 +2086,7 @@ describe("surfpool-integration", function () {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: env.payer.publicKey,
+          authority: env.payer.publicKey,
           vault: setup.vaultPda,
         } as any)
         .rpc();
@@ -1953,7 +2125,7 @@ describe("surfpool-integration", function () {
       await program.methods
         .pauseAgent(setup.agent.publicKey)
         .accounts({
-          owner: env.payer.publicKey,
+          authority: env.payer.publicKey,
           vault: setup.vaultPda,
         } as any)
         .rpc();
@@ -1977,6 +2149,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -1993,6 +2168,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2011,6 +2190,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2037,6 +2218,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent2.publicKey,
@@ -2053,6 +2237,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2071,6 +2259,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2110,6 +2300,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -2126,6 +2319,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2144,6 +2341,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2169,7 +2368,7 @@ describe("surfpool-integration", function () {
       await program.methods
         .freezeVault()
         .accounts({
-          owner: env.payer.publicKey,
+          authority: env.payer.publicKey,
           vault: setup.vaultPda,
         } as any)
         .rpc();
@@ -2189,6 +2388,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: setup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2253,6 +2455,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, swapSetup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: swapSetup.agent.publicKey,
@@ -2269,6 +2474,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: swapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2287,6 +2496,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: swapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2318,6 +2529,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           currentVersion,
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: noSwapSetup.agent.publicKey,
@@ -2334,6 +2548,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: noSwapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2352,6 +2570,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: noSwapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2380,6 +2600,12 @@ describe("surfpool-integration", function () {
           swapSetup.agent.publicKey,
           FULL_CAPABILITY,
           new BN(0),
+          0, // maxSessionsPerHour
+          null, // protocols
+          null, // maxTransactionSizeUsd
+          null, // sessionExpirySeconds
+          null, // validUntil
+          null, // maxConcurrentSessions
         )
         .accounts({
           owner: env.payer.publicKey,
@@ -2412,6 +2638,7 @@ describe("surfpool-integration", function () {
           policy: swapSetup.policyPda,
           pendingAgentPerms: pendingAgentPerms,
           agentSpendOverlay: swapSetup.overlayPda,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -2427,7 +2654,13 @@ describe("surfpool-integration", function () {
       // Register agent2 with transfer-only on the swap vault
       const agent2 = await createWallet(env.connection, "permAgent2", 10);
       await program.methods
-        .registerAgent(agent2.publicKey, OBSERVER_ONLY, new BN(0))
+        .registerAgent(
+          agent2.publicKey,
+          OBSERVER_ONLY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: swapSetup.vaultPda,
@@ -2451,6 +2684,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           currentVersion,
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent2.publicKey,
@@ -2467,6 +2703,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: swapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2485,6 +2725,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: swapSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2525,6 +2767,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: transferSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2554,6 +2799,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, zeroSetup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: zeroSetup.agent.publicKey,
@@ -2570,6 +2818,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: zeroSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -2588,6 +2840,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: zeroSetup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -2644,6 +2898,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: capSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2671,6 +2928,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: capSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2704,6 +2964,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: capSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2747,6 +3010,9 @@ describe("surfpool-integration", function () {
             feeDestinationTokenAccount: null,
             protocolTreasuryTokenAccount: seqSetup.protocolTreasuryAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            agentSettings: null,
+            permitNonces: null,
+            spendRequest: null,
           } as any)
           .instruction();
 
@@ -2768,6 +3034,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: seqSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2809,6 +3078,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: limitSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
       await sendVersionedTx(env.connection, [okIx], limitSetup.agent);
@@ -2828,6 +3100,9 @@ describe("surfpool-integration", function () {
           feeDestinationTokenAccount: null,
           protocolTreasuryTokenAccount: limitSetup.protocolTreasuryAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          agentSettings: null,
+          permitNonces: null,
+          spendRequest: null,
         } as any)
         .instruction();
 
@@ -2846,7 +3121,13 @@ describe("surfpool-integration", function () {
       for (let i = 2; i <= 10; i++) {
         const extra = await createWallet(env.connection, `maxAgent${i}`, 2);
         const regIx = await program.methods
-          .registerAgent(extra.publicKey, FULL_CAPABILITY, new BN(0))
+          .registerAgent(
+            extra.publicKey,
+            FULL_CAPABILITY,
+            new BN(0),
+            0, // maxSessionsPerHour
+            null, // validUntil
+          )
           .accounts({
             owner: env.payer.publicKey,
             vault: maxSetup.vaultPda,
@@ -2859,7 +3140,13 @@ describe("surfpool-integration", function () {
       // 11th agent should fail
       const eleventh = await createWallet(env.connection, "agent11", 2);
       const regIx = await program.methods
-        .registerAgent(eleventh.publicKey, FULL_CAPABILITY, new BN(0))
+        .registerAgent(
+          eleventh.publicKey,
+          FULL_CAPABILITY,
+          new BN(0),
+          0, // maxSessionsPerHour
+          null, // validUntil
+        )
         .accounts({
           owner: env.payer.publicKey,
           vault: maxSetup.vaultPda,
@@ -2972,6 +3259,7 @@ describe("surfpool-integration", function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .instruction();
 
@@ -3027,6 +3315,7 @@ describe("surfpool-integration", function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .instruction();
       await sendVersionedTx(env.connection, [createIx], srcSetup.agent);
@@ -3094,6 +3383,7 @@ describe("surfpool-integration", function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .instruction();
       await sendVersionedTx(env.connection, [createIx], srcSetup.agent);
@@ -3236,6 +3526,7 @@ describe("surfpool-integration", function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .instruction();
       await sendVersionedTx(env.connection, [createIx], srcSetup.agent);
@@ -3319,6 +3610,7 @@ describe("surfpool-integration", function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          agentSettings: null,
         } as any)
         .instruction();
 
@@ -3357,6 +3649,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -3373,6 +3668,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -3391,6 +3690,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -3423,6 +3724,9 @@ describe("surfpool-integration", function () {
           new BN(5_000_000),
           program.programId,
           await readPolicyVersion(program, setup.policyPda),
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: setup.agent.publicKey,
@@ -3439,6 +3743,10 @@ describe("surfpool-integration", function () {
           systemProgram: SystemProgram.programId,
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -3457,6 +3765,8 @@ describe("surfpool-integration", function () {
           agentSpendOverlay: setup.overlayPda,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -3485,6 +3795,7 @@ describe("surfpool-integration", function () {
           owner: env.payer.publicKey,
           vault: setup.vaultPda,
           agentSpendOverlay: setup.overlayPda,
+          agentSettings: null,
         } as any)
         .rpc();
 
@@ -3506,6 +3817,7 @@ describe("surfpool-integration", function () {
           owner: env.payer.publicKey,
           vault: closeSetup.vaultPda,
           agentSpendOverlay: closeSetup.overlayPda,
+          agentSettings: null,
         } as any)
         .rpc();
 
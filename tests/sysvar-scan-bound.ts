@@ -197,7 +197,13 @@ describe("sysvar-scan-bound (M11 / SIMD-0296 pad-attack guard)", () => {
 
     // Register agent
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accounts({
         owner: owner.publicKey,
         vault: vaultPda,
@@ -239,6 +245,9 @@ describe("sysvar-scan-bound (M11 / SIMD-0296 pad-attack guard)", () => {
         new BN(0), // amount=0 → non-spending → no DeFi ix required
         jupiterProgramId,
         await pv(),
+        [], // legs
+        0, // sessionNonce
+        null, // expectedOutput
       )
       .accountsPartial({
         agent: agent.publicKey,
@@ -255,6 +264,10 @@ describe("sysvar-scan-bound (M11 / SIMD-0296 pad-attack guard)", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        agentSettings: null,
+        permitNonces: null,
+        pendingFulfillment: null,
+        outputTokenAccount: null,
       })
       .instruction();
 
@@ -273,6 +286,8 @@ describe("sysvar-scan-bound (M11 / SIMD-0296 pad-attack guard)", () => {
         systemProgram: SystemProgram.programId,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         outputStablecoinAccount: null,
+        outputTokenAccount: null,
+        pendingFulfillment: null,
       })
       .instruction();
 
@@ -177,7 +177,13 @@ describe("TOCTOU Security Fix", () => {
       .rpc();
 
     await program.methods
-      .registerAgent(agent.publicKey, FULL_CAPABILITY, new BN(0))
+      .registerAgent(
+        agent.publicKey,
+        FULL_CAPABILITY,
+        new BN(0),
+        0, // maxSessionsPerHour
+        null, // validUntil
+      )
       .accountsPartial({
         owner: owner.publicKey,
         vault: pdas.vaultPda,
@@ -225,6 +231,25 @@ describe("TOCTOU Security Fix", () => {
         null, // sessionExpirySeconds
         null, // hasProtocolCaps
         null, // protocolCaps
+        null, // destinationMode
+        null, // weeklySpendingCapUsd
+        null, // monthlySpendingCapUsd
+        null, // mintCaps
+        null, // destinationCaps
+        null, // tradingHours
+        null, // tradingHoursExemptNonSpending
+        null, // guardian
+        null, // recoveryAuthority
+        null, // recoveryDestination
+        null, // inactivityWindowSeconds
+        null, // beneficiary
+        null, // approvers
+        null, // approvalThreshold
+        null, // cpiCallers
+        null, // dailyFeeCapUsd
+        null, // maxAbandonedSessionsPerHour
+        null, // allowAsyncFulfillment
+        null, // maxProtocolSlippageBps
       )
       .accounts({
         owner: owner.publicKey,
@@ -278,6 +303,9 @@ describe("TOCTOU Security Fix", () => {
           new BN(10_000_000),
           jupiterProgramId,
           new BN(0), // STALE: policy is now at version 1
+          [], // legs
+          0, // sessionNonce
+          null, // expectedOutput
         )
         .accountsPartial({
           agent: agent.publicKey,
@@ -294,6 +322,10 @@ describe("TOCTOU Security Fix", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          agentSettings: null,
+          permitNonces: null,
+          pendingFulfillment: null,
+          outputTokenAccount: null,
         })
         .instruction();
 
@@ -312,6 +344,8 @@ describe("TOCTOU Security Fix", () => {
           systemProgram: SystemProgram.programId,
           instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
           outputStablecoinAccount: null,
+          outputTokenAccount: null,
+          pendingFulfillment: null,
         })
         .instruction();
 
@@ -378,6 +412,25 @@ describe("TOCTOU Security Fix", () => {
           null, // sessionExpirySeconds
           null, // hasProtocolCaps
           null, // protocolCaps
+          null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,
@@ -412,6 +465,25 @@ describe("TOCTOU Security Fix", () => {
           null, // sessionExpirySeconds
           null, // hasProtocolCaps
           null, // protocolCaps
+          null, // destinationMode
+          null, // weeklySpendingCapUsd
+          null, // monthlySpendingCapUsd
+          null, // mintCaps
+          null, // destinationCaps
+          null, // tradingHours
+          null, // tradingHoursExemptNonSpending
+          null, // guardian
+          null, // recoveryAuthority
+          null, // recoveryDestination
+          null, // inactivityWindowSeconds
+          null, // beneficiary
+          null, // approvers
+          null, // approvalThreshold
+          null, // cpiCallers
+          null, // dailyFeeCapUsd
+          null, // maxAbandonedSessionsPerHour
+          null, // allowAsyncFulfillment
+          null, // maxProtocolSlippageBps
         )
         .accounts({
           owner: owner.publicKey,